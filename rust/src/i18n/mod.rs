@@ -0,0 +1,153 @@
+//! Internationalization
+//!
+//! UI strings live in simple `key = template` resource files under `assets/locales`,
+//! embedded at compile time. Templates use `{name}` placeholders filled in by the
+//! `t!` macro. Keys missing from the active locale fall back to English; keys
+//! missing from English render as the key itself and are logged in debug mode.
+//!
+//! Resource format: one `key = template` per line, `#` comments, `\n` for line breaks.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{Mutex, OnceLock};
+
+/// Look up a UI string, optionally filling `{name}` placeholders
+///
+/// `t!("services.found", count = 12)`
+macro_rules! t {
+    ($key:expr) => {
+        $crate::i18n::translate($key, &[])
+    };
+    ($key:expr, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::i18n::translate($key, &[$((stringify!($name), $value.to_string())),+])
+    };
+}
+
+/// Supported UI languages
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum Locale {
+    #[default]
+    #[serde(rename = "en-US")]
+    EnUs,
+    #[serde(rename = "de-DE")]
+    DeDe,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::EnUs, Locale::DeDe];
+
+    /// BCP 47 language tag
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::EnUs => "en-US",
+            Locale::DeDe => "de-DE",
+        }
+    }
+
+    /// Language name in its own language, for the language selector
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            Locale::EnUs => "English",
+            Locale::DeDe => "Deutsch",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Locale> {
+        Locale::ALL.into_iter().find(|l| l.code() == code)
+    }
+
+    fn source(&self) -> &'static str {
+        match self {
+            Locale::EnUs => include_str!("../../assets/locales/en-US.lang"),
+            Locale::DeDe => include_str!("../../assets/locales/de-DE.lang"),
+        }
+    }
+
+    fn messages(&self) -> &'static HashMap<&'static str, &'static str> {
+        static EN_US: OnceLock<HashMap<&'static str, &'static str>> = OnceLock::new();
+        static DE_DE: OnceLock<HashMap<&'static str, &'static str>> = OnceLock::new();
+
+        let cell = match self {
+            Locale::EnUs => &EN_US,
+            Locale::DeDe => &DE_DE,
+        };
+        cell.get_or_init(|| parse(self.source()))
+    }
+}
+
+static CURRENT: AtomicU8 = AtomicU8::new(0);
+
+/// Switch the language used by `t!`
+pub fn set_locale(locale: Locale) {
+    let index = Locale::ALL.iter().position(|l| *l == locale).unwrap_or(0);
+    CURRENT.store(index as u8, Ordering::Relaxed);
+}
+
+/// Language currently used by `t!`
+pub fn current_locale() -> Locale {
+    Locale::ALL
+        .get(CURRENT.load(Ordering::Relaxed) as usize)
+        .copied()
+        .unwrap_or_default()
+}
+
+/// Parse `key = template` lines; blank lines and `#` comments are skipped
+fn parse(source: &'static str) -> HashMap<&'static str, &'static str> {
+    source
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, template)| (key.trim(), template.trim()))
+        .collect()
+}
+
+/// Debug mode: debug builds, or `SANITY_I18N_DEBUG` set in release builds
+fn debug_mode() -> bool {
+    static DEBUG: OnceLock<bool> = OnceLock::new();
+    *DEBUG.get_or_init(|| cfg!(debug_assertions) || std::env::var_os("SANITY_I18N_DEBUG").is_some())
+}
+
+/// Log a lookup problem once per key so re-renders don't flood the log
+fn report(locale: Locale, key: &str, unknown: bool) {
+    static REPORTED: OnceLock<Mutex<HashSet<(Locale, String)>>> = OnceLock::new();
+    let reported = REPORTED.get_or_init(|| Mutex::new(HashSet::new()));
+    let Ok(mut reported) = reported.lock() else {
+        return;
+    };
+    if !reported.insert((locale, key.to_string())) {
+        return;
+    }
+
+    if unknown {
+        tracing::warn!("Unknown i18n key: {}", key);
+    } else {
+        tracing::debug!("Missing {} translation for {}, using English", locale.code(), key);
+    }
+}
+
+/// Resolve `key` in the current locale and fill in its placeholders
+pub fn translate(key: &str, args: &[(&str, String)]) -> String {
+    let locale = current_locale();
+
+    let template = match locale.messages().get(key) {
+        Some(template) => *template,
+        None => {
+            let fallback = Locale::EnUs.messages().get(key).copied();
+            if debug_mode() {
+                report(locale, key, fallback.is_none());
+            }
+            match fallback {
+                Some(template) => template,
+                None => return key.to_string(),
+            }
+        }
+    };
+
+    let mut text = template.replace("\\n", "\n");
+    for (name, value) in args {
+        text = text.replace(&format!("{{{}}}", name), value);
+    }
+    text
+}
@@ -6,6 +6,8 @@
 #![allow(non_snake_case)]
 #![windows_subsystem = "windows"]
 
+#[macro_use]
+mod i18n;
 mod state;
 mod system;
 mod ui;
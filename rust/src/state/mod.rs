@@ -3,6 +3,11 @@
 //! Data structures for storing scan results, network info, etc.
 
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+mod table;
+
+pub use table::{csv_field, TableColumn, TableController};
 
 /// Source of a port binding - where it was detected from
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
@@ -35,19 +40,124 @@ impl BindingSource {
     }
 }
 
-/// Represents a TCP port binding with process information
+/// Transport protocol of a socket
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Default)]
+pub enum Protocol {
+    #[default]
+    Tcp,
+    Udp,
+}
+
+impl Protocol {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Protocol::Tcp => "TCP",
+            Protocol::Udp => "UDP",
+        }
+    }
+}
+
+/// Which protocols a port scan covers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProtocolFilter {
+    #[default]
+    Tcp,
+    Udp,
+    Both,
+}
+
+impl ProtocolFilter {
+    pub const ALL: [ProtocolFilter; 3] = [ProtocolFilter::Tcp, ProtocolFilter::Udp, ProtocolFilter::Both];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ProtocolFilter::Tcp => "TCP",
+            ProtocolFilter::Udp => "UDP",
+            ProtocolFilter::Both => "Both",
+        }
+    }
+
+    /// Message key of the option text in the protocol selector
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            ProtocolFilter::Tcp => "ports.protocol.tcp",
+            ProtocolFilter::Udp => "ports.protocol.udp",
+            ProtocolFilter::Both => "ports.protocol.both",
+        }
+    }
+
+    pub fn includes(&self, protocol: Protocol) -> bool {
+        matches!(
+            (self, protocol),
+            (ProtocolFilter::Both, _) | (ProtocolFilter::Tcp, Protocol::Tcp) | (ProtocolFilter::Udp, Protocol::Udp)
+        )
+    }
+}
+
+/// Text format of the Ports page "Copy Report"
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReportFormat {
+    /// Tab-separated, pastes into spreadsheets
+    #[default]
+    Plain,
+    /// Table for GitHub issues and chat, with a scan context line
+    Markdown,
+    Csv,
+}
+
+impl ReportFormat {
+    pub const ALL: [ReportFormat; 3] = [ReportFormat::Plain, ReportFormat::Markdown, ReportFormat::Csv];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ReportFormat::Plain => "Plain",
+            ReportFormat::Markdown => "Markdown",
+            ReportFormat::Csv => "CSV",
+        }
+    }
+
+    /// Message key of the option text in the format selector
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            ReportFormat::Plain => "ports.report-format.plain",
+            ReportFormat::Markdown => "ports.report-format.markdown",
+            ReportFormat::Csv => "ports.report-format.csv",
+        }
+    }
+}
+
+/// Represents a TCP or UDP port binding with process information
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct PortBinding {
     /// Process ID (0 for Docker/WSL bindings without direct PID)
     pub pid: u32,
     /// Process name (or container name for Docker)
     pub process_name: String,
+    /// Full path of the process executable (None if unavailable or access denied)
+    #[serde(default)]
+    pub exe_path: Option<String>,
+    /// Process command line (None if unavailable or access denied)
+    #[serde(default)]
+    pub cmdline: Option<String>,
     /// Local IP address
     pub local_ip: String,
     /// Local port number
     pub local_port: u16,
-    /// Connection state (LISTEN, ESTABLISHED, etc.)
+    /// Transport protocol
+    #[serde(default)]
+    pub protocol: Protocol,
+    /// Remote IP address (None for listening and connectionless sockets)
+    #[serde(default)]
+    pub remote_ip: Option<String>,
+    /// Remote port number (None for listening and connectionless sockets)
+    #[serde(default)]
+    pub remote_port: Option<u16>,
+    /// Connection state (LISTEN, ESTABLISHED, etc.; "UDP" for connectionless sockets)
     pub state: String,
+    /// Whether this socket accepts connections on the port (LISTEN or UDP), as opposed to
+    /// a connection that merely uses the port locally
+    #[serde(default)]
+    pub is_listener: bool,
     /// Whether this is a loopback address (127.x.x.x or ::1)
     pub is_loopback: bool,
     /// Whether this binds to all interfaces (0.0.0.0 or ::)
@@ -62,9 +172,26 @@ pub struct PortBinding {
     /// Additional context (e.g., Docker container ID, WSL distro name)
     #[serde(default)]
     pub source_detail: String,
+    /// Windows services hosted by the process (svchost.exe and other service hosts)
+    #[serde(default)]
+    pub services: Vec<String>,
 }
 
 impl PortBinding {
+    /// Whether the local address is IPv6
+    pub fn is_ipv6(&self) -> bool {
+        self.local_ip.contains(':')
+    }
+
+    /// Whether the local address is link-local (fe80::/10 or 169.254.0.0/16)
+    pub fn is_link_local(&self) -> bool {
+        match self.local_ip.parse::<std::net::IpAddr>() {
+            Ok(std::net::IpAddr::V4(ip)) => ip.is_link_local(),
+            Ok(std::net::IpAddr::V6(ip)) => (ip.segments()[0] & 0xffc0) == 0xfe80,
+            Err(_) => false,
+        }
+    }
+
     /// Get a human-readable scope description
     pub fn scope_description(&self) -> &'static str {
         if self.is_loopback {
@@ -81,6 +208,14 @@ impl PortBinding {
         format!("{}:{}", self.local_ip, self.local_port)
     }
 
+    /// Format the remote address string (None for listening and connectionless sockets)
+    pub fn remote_address(&self) -> Option<String> {
+        match (&self.remote_ip, self.remote_port) {
+            (Some(ip), Some(port)) => Some(format!("{}:{}", ip, port)),
+            _ => None,
+        }
+    }
+
     /// Get process status description
     pub fn process_status(&self) -> &'static str {
         match self.source {
@@ -105,7 +240,7 @@ impl PortBinding {
 pub struct PortScanResult {
     /// All bindings found for the scanned port
     pub bindings: Vec<PortBinding>,
-    /// PIDs that have conflicts (both loopback and all-interfaces bindings)
+    /// PIDs that have conflicts (both loopback and all-interfaces TCP bindings)
     pub conflict_pids: Vec<u32>,
     /// PIDs that are orphaned (socket exists but process doesn't)
     pub orphan_pids: Vec<u32>,
@@ -113,8 +248,97 @@ pub struct PortScanResult {
     pub docker_bindings: Vec<DockerPortBinding>,
     /// WSL processes using this port
     pub wsl_bindings: Vec<WslPortBinding>,
-    /// True if socket probe detected the port is in use but no visible binding found
+    /// True if socket probe detected a port in use but no visible binding found
     pub shadow_detected: bool,
+    /// First port of the scanned range (equal to `end_port` for a single-port scan)
+    pub start_port: u16,
+    /// Last port of the scanned range
+    pub end_port: u16,
+    /// Number of Windows TCP sockets per state (LISTEN, ESTABLISHED, TIME_WAIT, ...),
+    /// in connection lifecycle order; states without sockets are left out
+    pub state_counts: Vec<(String, usize)>,
+    /// Why the socket table could not be read through the IP Helper API (None when it worked)
+    pub socket_error: Option<String>,
+    /// `socket_error` is set but the bindings were read from `netstat -ano` instead
+    pub from_netstat: bool,
+}
+
+impl PortScanResult {
+    /// The socket table could not be read at all, so an empty result does not mean "free"
+    pub fn scan_failed(&self) -> bool {
+        self.socket_error.is_some() && !self.from_netstat
+    }
+
+    /// True when more than one port was scanned
+    pub fn is_range(&self) -> bool {
+        self.end_port > self.start_port
+    }
+
+    /// Number of ports in the scanned range
+    pub fn port_count(&self) -> usize {
+        usize::from(self.end_port.saturating_sub(self.start_port)) + 1
+    }
+
+    /// Number of TCP sockets in `state` (e.g. "TIME_WAIT")
+    pub fn state_count(&self, state: &str) -> usize {
+        self.state_counts
+            .iter()
+            .find(|(s, _)| s == state)
+            .map_or(0, |(_, count)| *count)
+    }
+
+    /// Distinct ports with at least one binding, ascending
+    pub fn occupied_ports(&self) -> Vec<u16> {
+        let mut ports: Vec<u16> = self.bindings.iter().map(|b| b.local_port).collect();
+        ports.sort_unstable();
+        ports.dedup();
+        ports
+    }
+
+    /// Bindings collapsed into one group per process, see `group_bindings`
+    pub fn grouped(&self) -> Vec<(PortBinding, Vec<PortBinding>)> {
+        group_bindings(&self.bindings)
+    }
+}
+
+/// Collapse bindings into one group per PID, in order of first appearance
+///
+/// Only Windows sockets with a real PID are grouped; Docker, WSL, shadow, and PID 0 bindings
+/// stay on their own. The summary is the group's first binding with the states of all members
+/// merged and the address flags combined.
+fn group_bindings(bindings: &[PortBinding]) -> Vec<(PortBinding, Vec<PortBinding>)> {
+    let mut groups: Vec<(PortBinding, Vec<PortBinding>)> = Vec::new();
+    let mut group_of_pid: HashMap<u32, usize> = HashMap::new();
+
+    for binding in bindings {
+        let groupable = binding.source == BindingSource::Windows && binding.pid != 0;
+        match group_of_pid.get(&binding.pid).filter(|_| groupable) {
+            Some(&index) => groups[index].1.push(binding.clone()),
+            None => {
+                if groupable {
+                    group_of_pid.insert(binding.pid, groups.len());
+                }
+                groups.push((binding.clone(), vec![binding.clone()]));
+            }
+        }
+    }
+
+    for (summary, members) in groups.iter_mut().filter(|(_, members)| members.len() > 1) {
+        let mut states: Vec<&str> = Vec::new();
+        for member in members.iter() {
+            if !states.contains(&member.state.as_str()) {
+                states.push(&member.state);
+            }
+        }
+        summary.state = states.join(", ");
+        summary.remote_ip = None;
+        summary.remote_port = None;
+        summary.is_listener = members.iter().any(|b| b.is_listener);
+        summary.is_loopback = members.iter().all(|b| b.is_loopback);
+        summary.is_all_interfaces = members.iter().any(|b| b.is_all_interfaces);
+        summary.is_orphan = members.iter().any(|b| b.is_orphan);
+    }
+    groups
 }
 
 /// Docker container port binding
@@ -147,19 +371,45 @@ pub struct WslPortBinding {
     pub port: u16,
     /// Local address
     pub local_addr: String,
+    /// Transport protocol
+    pub protocol: Protocol,
 }
 
 /// Represents a network adapter with its addresses
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct NetworkAdapter {
-    /// Adapter name
+    /// Adapter name (e.g. "Ethernet", "Wi-Fi")
     pub name: String,
+    /// Adapter type from the ipconfig header (e.g. "Ethernet adapter")
+    pub kind: String,
+    /// Driver description (e.g. "Intel(R) Ethernet Connection I219-V")
+    pub description: String,
+    /// MAC address (None for tunnel adapters without one)
+    pub mac: Option<String>,
     /// IPv4 and IPv6 addresses
     pub addresses: Vec<String>,
-    /// Whether the adapter is up
+    pub gateways: Vec<String>,
+    pub dns_servers: Vec<String>,
+    /// Whether the adapter is up (ipconfig reports "Media disconnected" otherwise)
     pub is_up: bool,
 }
 
+/// Everything needed to run a command again from the output panel
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommandReplay {
+    /// Program to run (or the PowerShell script when `powershell` is set)
+    pub program: String,
+    /// Program arguments
+    pub args: Vec<String>,
+    /// Run `program` as a PowerShell command instead of an executable
+    pub powershell: bool,
+    /// Re-running needs Administrator
+    pub requires_admin: bool,
+    /// Danger level used to gate re-runs in safe mode; `Modifying` until the caller tags it,
+    /// so a command nobody classified is never treated as read-only
+    pub danger: DangerLevel,
+}
+
 /// Result of running a system command
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct CommandOutput {
@@ -173,9 +423,33 @@ pub struct CommandOutput {
     pub exit_code: i32,
     /// Duration in milliseconds
     pub duration_ms: u64,
+    /// How to re-run this command (None for streaming or stateful operations)
+    pub replay: Option<CommandReplay>,
 }
 
 impl CommandOutput {
+    /// Mark the command as needing Administrator to re-run
+    pub fn requires_admin(mut self) -> Self {
+        if let Some(replay) = self.replay.as_mut() {
+            replay.requires_admin = true;
+        }
+        self
+    }
+
+    /// Set the danger level that gates re-running this command
+    pub fn with_danger(mut self, danger: DangerLevel) -> Self {
+        if let Some(replay) = self.replay.as_mut() {
+            replay.danger = danger;
+        }
+        self
+    }
+
+    /// Drop the replay descriptor for operations that can't simply be run again
+    pub fn without_replay(mut self) -> Self {
+        self.replay = None;
+        self
+    }
+
     /// Check if the command succeeded
     pub fn succeeded(&self) -> bool {
         self.exit_code == 0
@@ -214,14 +488,36 @@ pub enum CurrentPage {
     Disk,
 }
 
+/// How much damage an action can do - every action button declares one
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DangerLevel {
+    /// Read-only diagnostics
+    #[default]
+    ReadOnly,
+    /// Changes system state but is easy to undo
+    Modifying,
+    /// Prunes, system-wide resets, deletions - disabled in safe mode
+    Destructive,
+}
+
+impl DangerLevel {
+    /// Check if this action is blocked while safe mode is on
+    pub fn blocked_in_safe_mode(&self) -> bool {
+        matches!(self, DangerLevel::Destructive)
+    }
+}
+
 /// Sort column options for ports table
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum PortsSortColumn {
     #[default]
     Source,
+    Protocol,
+    Port,
     Pid,
     Process,
     Address,
+    Remote,
     State,
     Scope,
 }
@@ -234,6 +530,8 @@ pub enum ServicesSortColumn {
     DisplayName,
     Status,
     StartType,
+    Account,
+    ProcessId,
 }
 
 /// Sort column options for processes table
@@ -244,10 +542,68 @@ pub enum ProcessesSortColumn {
     Pid,
     Name,
     Handles,
+    Threads,
     Cpu,
     Status,
 }
 
+/// Sort column options for the Network page routing table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RoutesSortColumn {
+    #[default]
+    Destination,
+    Netmask,
+    Gateway,
+    Interface,
+    Metric,
+}
+
+/// Sort column options for the Network page DNS cache table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DnsCacheSortColumn {
+    #[default]
+    Name,
+    Type,
+    Data,
+    Ttl,
+    Section,
+}
+
+/// Sort column options for the Docker container table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ContainersSortColumn {
+    #[default]
+    Name,
+    Image,
+    Status,
+    Health,
+    Ports,
+    Id,
+}
+
+/// Sort column options for the Docker stats table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ContainerStatsSortColumn {
+    #[default]
+    Name,
+    Cpu,
+    Memory,
+    NetIo,
+    BlockIo,
+    Pids,
+}
+
+/// Sort column options for the Docker images table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ImagesSortColumn {
+    #[default]
+    Repository,
+    Tag,
+    Id,
+    Size,
+    Created,
+}
+
 /// Sort column options for hosts table
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum HostsSortColumn {
@@ -266,11 +622,100 @@ pub enum DiskSortColumn {
     Files,
 }
 
+/// Scans kept in the Ports page history
+pub const PORT_SCAN_HISTORY_LEN: usize = 10;
+
+/// A past Ports page scan, kept for before/after comparison
+#[derive(Debug, Clone)]
+pub struct PortScanSnapshot {
+    pub taken_at: chrono::DateTime<chrono::Local>,
+    /// What was scanned ("3000", "3000-3100", "all listeners", ...)
+    pub target: String,
+    pub result: PortScanResult,
+}
+
+/// What the Ports page results were scanned from
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum PortsScanScope {
+    /// The port (or range) inputs
+    #[default]
+    Inputs,
+    /// Every listening TCP socket on the machine
+    AllListeners,
+    /// A saved port preset
+    Preset { name: String, ports: Vec<u16> },
+}
+
 /// Global application state that persists across tab switches
 #[derive(Debug, Clone, Default)]
 pub struct PortsPageState {
     pub port_input: u16,
+    /// Scan `port_input..=port_end_input` instead of a single port
+    pub range_mode: bool,
+    /// Last port of the range in range mode
+    pub port_end_input: u16,
+    /// Protocols included in the scan
+    pub protocol: ProtocolFilter,
+    /// What the current results were scanned from (repeated by rescans and watch mode)
+    pub scope: PortsScanScope,
+    /// Client-side filter over the results (port number or process name)
+    pub result_filter: String,
+    /// Hide connections and show only listening sockets
+    pub listeners_only: bool,
+    /// Address filters on the results table (display only - conflict and orphan
+    /// detection always use the full scan)
+    pub ipv4_only: bool,
+    pub ipv6_only: bool,
+    pub hide_loopback: bool,
+    pub hide_link_local: bool,
+    /// Format used by "Copy Report"
+    pub report_format: ReportFormat,
+    /// Collapse the results table into one row per process
+    pub group_by_process: bool,
+    /// Process groups whose bindings are shown (by PID)
+    pub expanded_groups: HashSet<u32>,
+    /// Scanned port with no bindings that lies in a Windows excluded port range
+    pub excluded_range: Option<(u16, std::ops::RangeInclusive<u16>)>,
+    /// Port being polled by "Notify when free"; clearing it cancels the wait
+    pub free_wait_port: Option<u16>,
+    /// Watch mode was started to wait out a TIME_WAIT pile-up; stops once none are left
+    pub watch_until_clear: bool,
+    /// Firewall rules covering `firewall_port` (None until checked)
+    pub firewall_rules: Option<Result<Vec<crate::system::ports::FirewallRule>, String>>,
+    pub firewall_port: u16,
+    pub checking_firewall: bool,
+    /// Name for a new inbound allow rule on `firewall_port`
+    pub allow_rule_name: String,
+    pub allow_rule_protocol: Protocol,
+    pub creating_allow_rule: bool,
+    /// Output of firewall commands run from the page
+    pub command_outputs: Vec<CommandOutput>,
+    /// A kill failed with access denied; offer restarting as administrator
+    pub kill_needs_admin: bool,
+    /// Process name entered for "Kill all by name"
+    pub kill_name_input: String,
+    /// Name awaiting confirmation before every matching process is killed
+    pub pending_kill_name: Option<String>,
+    /// Index into `AppSettings::port_presets` of the preset to scan/edit
+    pub selected_preset: usize,
+    /// Show the preset editor
+    pub editing_presets: bool,
+    /// Port typed into the preset editor's add field
+    pub new_preset_port: String,
+    /// Range searched by "Suggest Free Port"
+    pub suggest_start: u16,
+    pub suggest_end: u16,
+    pub suggesting: bool,
+    /// Re-scan automatically every `watch_interval_secs` while the Ports page is open
+    pub watching: bool,
+    pub watch_interval_secs: u64,
     pub scan_result: PortScanResult,
+    /// Recent scans, oldest first (at most `PORT_SCAN_HISTORY_LEN`)
+    pub history: Vec<PortScanSnapshot>,
+    /// History entry shown read-only instead of the live result
+    pub viewing_history: Option<usize>,
+    /// Highlight bindings that appeared/disappeared since the previous scan of the same target
+    pub show_diff: bool,
     pub status_message: String,
     pub status_type: String,
     pub is_scanning: bool,
@@ -284,9 +729,74 @@ impl PortsPageState {
     pub fn new() -> Self {
         Self {
             port_input: 3010,
+            port_end_input: 3100,
+            watch_interval_secs: 2,
+            listeners_only: true,
+            suggest_start: 3000,
+            suggest_end: 3100,
             ..Default::default()
         }
     }
+
+    /// Store a new scan result and add it to the history
+    ///
+    /// A rescan that found exactly the same bindings as the previous scan of the same target
+    /// (e.g. watch mode ticks) replaces the timestamp instead of adding another entry.
+    pub fn record_scan(&mut self, target: String, result: PortScanResult) {
+        let unchanged = self.history.last().is_some_and(|last| {
+            last.target == target && binding_keys(&last.result) == binding_keys(&result)
+        });
+        if unchanged {
+            if let Some(last) = self.history.last_mut() {
+                last.taken_at = chrono::Local::now();
+                last.result = result.clone();
+            }
+        } else {
+            self.history.push(PortScanSnapshot {
+                taken_at: chrono::Local::now(),
+                target,
+                result: result.clone(),
+            });
+            if self.history.len() > PORT_SCAN_HISTORY_LEN {
+                self.history.remove(0);
+            }
+        }
+        self.scan_result = result;
+        self.viewing_history = None;
+    }
+
+    /// The result on screen: a history entry while one is selected, else the live scan
+    pub fn displayed_result(&self) -> &PortScanResult {
+        self.viewing_history
+            .and_then(|index| self.history.get(index))
+            .map_or(&self.scan_result, |snapshot| &snapshot.result)
+    }
+
+    /// The scan before the displayed one with the same target, for the diff view
+    pub fn previous_result(&self) -> Option<&PortScanResult> {
+        let current = self.viewing_history.unwrap_or(self.history.len().checked_sub(1)?);
+        let target = &self.history.get(current)?.target;
+        self.history[..current]
+            .iter()
+            .rev()
+            .find(|snapshot| &snapshot.target == target)
+            .map(|snapshot| &snapshot.result)
+    }
+}
+
+/// Identity of a binding across scans (what "appeared"/"disappeared" compares)
+pub fn binding_key(binding: &PortBinding) -> (Protocol, String, u16, u32, String) {
+    (
+        binding.protocol,
+        binding.address(),
+        binding.local_port,
+        binding.pid,
+        binding.remote_address().unwrap_or_default(),
+    )
+}
+
+fn binding_keys(result: &PortScanResult) -> HashSet<(Protocol, String, u16, u32, String)> {
+    result.bindings.iter().map(binding_key).collect()
 }
 
 /// Network page state
@@ -297,6 +807,159 @@ pub struct NetworkPageState {
     pub status_type: String,
     pub is_running: bool,
     pub running_action: String,
+    /// URL health-check results, index-aligned with `AppSettings::url_checks`
+    pub url_results: Vec<crate::system::network::UrlCheckResult>,
+    /// True while a URL check pass is running
+    pub checking_urls: bool,
+    /// Re-check all URLs periodically while the Network page is open
+    pub url_auto_refresh: bool,
+    /// URL whose failure detail is expanded
+    pub expanded_url: Option<String>,
+    /// New URL check inputs
+    pub new_url_name: String,
+    pub new_url: String,
+    pub new_url_status: String,
+    /// True while a netsh packet capture is running
+    pub capture_running: bool,
+    /// Path of the current or last .etl capture file
+    pub capture_file: String,
+    /// When the running capture started (None if unknown)
+    pub capture_started: Option<std::time::SystemTime>,
+    /// Optional IPv4 address filter for the next capture
+    pub capture_ip_filter: String,
+    /// Network adapters from the last refresh
+    pub adapters: Vec<NetworkAdapter>,
+    /// True while the adapter list is being refreshed
+    pub loading_adapters: bool,
+    /// Why the last adapter refresh failed
+    pub adapters_error: Option<String>,
+    /// Live per-interface throughput and error counters
+    pub adapter_stats: Vec<crate::system::network::AdapterStats>,
+    /// Ping and traceroute target host or IP address
+    pub ping_target: String,
+    /// Echo requests per ping (ignored in continuous mode)
+    pub ping_count: u32,
+    /// Ping until stopped (`ping -t`)
+    pub ping_continuous: bool,
+    /// Set to stop the running ping
+    pub ping_cancel: crate::system::command::CancelFlag,
+    /// Maximum hops for traceroute
+    pub trace_max_hops: u32,
+    /// Set to stop the running traceroute
+    pub trace_cancel: crate::system::command::CancelFlag,
+    /// Proxy settings from the last refresh
+    pub proxy: Option<crate::system::network::ProxySettings>,
+    /// True while proxy settings are being read
+    pub loading_proxy: bool,
+    /// Why the last proxy refresh failed
+    pub proxy_error: Option<String>,
+    /// Port Test host input
+    pub port_test_host: String,
+    /// Port Test port input
+    pub port_test_port: String,
+    /// True while a port test is connecting
+    pub testing_port: bool,
+    /// Recent port tests, newest first
+    pub port_test_results: Vec<crate::system::network::RemotePortTest>,
+    /// IPv4 and IPv6 routes from the last refresh
+    pub routes: Vec<crate::system::network::RouteEntry>,
+    /// True while the routing table is being read
+    pub loading_routes: bool,
+    /// Why the last routing table refresh failed
+    pub routes_error: Option<String>,
+    /// Filter and sort state for the routing table
+    pub route_table: TableController<RoutesSortColumn>,
+    /// DNS client cache from the last load (None until loaded)
+    pub dns_cache: Option<Vec<crate::system::network::DnsCacheRecord>>,
+    /// True while the DNS cache is being read
+    pub loading_dns_cache: bool,
+    /// Why the last DNS cache load failed
+    pub dns_cache_error: Option<String>,
+    /// Filter and sort state for the DNS cache table
+    pub dns_cache_table: TableController<DnsCacheSortColumn>,
+    /// Connected networks and their categories
+    pub connection_profiles: Vec<crate::system::network::ConnectionProfile>,
+    /// True while connection profiles are being read
+    pub loading_profiles: bool,
+    /// Why the last connection profile refresh failed
+    pub profiles_error: Option<String>,
+    /// URL for the HTTP HEAD check
+    pub http_url: String,
+    /// Certificate from the last https HEAD check (None for http or before the first check)
+    pub tls_certificate: Option<Result<crate::system::network::TlsCertificate, String>>,
+    /// Latency monitor is pinging once a second
+    pub latency_monitoring: bool,
+    /// Host the latency monitor pings
+    pub latency_host: String,
+    /// Round-trip times, oldest first (None = lost), at most `LATENCY_SAMPLES`
+    pub latency_samples: Vec<Option<u32>>,
+    /// Set to stop the latency monitor
+    pub latency_cancel: crate::system::command::CancelFlag,
+    /// Name resolved by Diagnose DNS
+    pub dns_test_host: String,
+    /// Results of the last connectivity matrix run
+    pub connectivity: Option<crate::system::network::ConnectivityMatrix>,
+    /// Checklist from the last Diagnose DNS run
+    pub dns_diagnosis: Vec<crate::system::network::DiagnosticStep>,
+    /// Wake-on-LAN form inputs
+    pub wol_name: String,
+    pub wol_mac: String,
+    pub wol_broadcast: String,
+}
+
+/// Latency monitor samples kept (two minutes at one per second)
+pub const LATENCY_SAMPLES: usize = 120;
+
+/// Summary of the latency monitor samples
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LatencyStats {
+    pub min_ms: Option<u32>,
+    pub avg_ms: Option<f64>,
+    pub max_ms: Option<u32>,
+    pub loss_percent: f64,
+}
+
+impl NetworkPageState {
+    pub fn new() -> Self {
+        Self {
+            ping_target: "8.8.8.8".to_string(),
+            ping_count: 4,
+            trace_max_hops: 30,
+            http_url: "https://www.microsoft.com".to_string(),
+            dns_test_host: "www.microsoft.com".to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Add a latency sample, discarding the oldest beyond `LATENCY_SAMPLES`
+    pub fn push_latency_sample(&mut self, sample: Option<u32>) {
+        self.latency_samples.push(sample);
+        if self.latency_samples.len() > LATENCY_SAMPLES {
+            let excess = self.latency_samples.len() - LATENCY_SAMPLES;
+            self.latency_samples.drain(..excess);
+        }
+    }
+
+    /// Min/avg/max over replies and loss over all samples
+    pub fn latency_stats(&self) -> LatencyStats {
+        let replies: Vec<u32> = self.latency_samples.iter().flatten().copied().collect();
+        let total = self.latency_samples.len();
+        LatencyStats {
+            min_ms: replies.iter().min().copied(),
+            avg_ms: (!replies.is_empty()).then(|| replies.iter().map(|&ms| f64::from(ms)).sum::<f64>() / replies.len() as f64),
+            max_ms: replies.iter().max().copied(),
+            loss_percent: if total == 0 { 0.0 } else { (total - replies.len()) as f64 * 100.0 / total as f64 },
+        }
+    }
+}
+
+/// A container being watched for its exit (see `docker::watch_container_exit`)
+#[derive(Debug, Clone)]
+pub struct ExitWatch {
+    pub container: String,
+    pub since: chrono::DateTime<chrono::Local>,
+    /// Set to stop watching
+    pub cancel: crate::system::command::CancelFlag,
 }
 
 /// Docker page state
@@ -307,18 +970,122 @@ pub struct DockerPageState {
     pub status_type: String,
     pub is_running: bool,
     pub running_action: String,
-    /// Currently selected container for logs/actions
+    /// Container whose logs were fetched last
     pub selected_container: String,
     /// Image name input for pull
     pub image_input: String,
+    /// Set to stop the running pull
+    pub pull_cancel: crate::system::command::CancelFlag,
+    /// Registries with stored credentials (None until read)
+    pub registry_logins: Option<Vec<crate::system::docker::RegistryLogin>>,
+    /// Why the Docker CLI config could not be read
+    pub registry_logins_error: Option<String>,
+    /// Show the registry login form
+    pub login_open: bool,
+    /// Registry and username for the login form (the password is never kept in state)
+    pub login_registry: String,
+    pub login_username: String,
     /// Compose file path input
     pub compose_path: String,
-    /// Cached list of container names
-    pub container_names: Vec<String>,
+    /// Last validation of the compose file (cleared when the path changes)
+    pub compose_check: Option<crate::system::docker::ComposeCheck>,
+    /// Compose service for logs (all services when empty)
+    pub compose_service: String,
+    /// Engine checklist shown after `docker info` fails (None when the daemon answered)
+    pub engine_checks: Option<Vec<crate::system::docker::EngineCheck>>,
+    /// True while the engine checklist is being gathered
+    pub diagnosing_engine: bool,
+    /// Containers from the last `docker ps -a`
+    pub containers: Vec<crate::system::docker::ContainerInfo>,
+    /// True while the container list is being refreshed
+    pub loading_containers: bool,
+    /// Why the last container refresh failed
+    pub containers_error: Option<String>,
+    /// Filter and sort state for the container table
+    pub container_table: TableController<ContainersSortColumn>,
+    /// Parsed inspect output for the container whose Details were opened
+    pub container_details: Option<crate::system::docker::ContainerDetails>,
+    /// Show secret-looking env values in the details card
+    pub reveal_secrets: bool,
+    /// Env drift of the details container against its compose service (None until compared)
+    pub env_drift: Option<crate::system::docker::EnvDrift>,
+    /// Why the last env drift comparison failed
+    pub env_drift_error: Option<String>,
+    /// Healthcheck probes for the container whose Health log was opened
+    pub health_log: Option<(String, crate::system::docker::HealthInfo)>,
+    /// Resource usage from the last `docker stats` (None until loaded)
+    pub stats: Option<Vec<crate::system::docker::ContainerStats>>,
+    /// True while stats are being sampled
+    pub loading_stats: bool,
+    /// Why the last stats sample failed
+    pub stats_error: Option<String>,
+    /// Re-sample stats every few seconds while the page is open
+    pub stats_auto_refresh: bool,
+    /// Sort state for the stats table
+    pub stats_table: TableController<ContainerStatsSortColumn>,
+    /// Images from the last listing (None until loaded)
+    pub images: Option<Vec<crate::system::docker::ImageInfo>>,
+    /// True while images are being listed
+    pub loading_images: bool,
+    /// Why the last image listing failed
+    pub images_error: Option<String>,
+    /// List only dangling images
+    pub images_dangling_only: bool,
+    /// Filter and sort state for the image table
+    pub image_table: TableController<ImagesSortColumn>,
+    /// Volumes from the last listing (None until loaded)
+    pub volumes: Option<Vec<crate::system::docker::VolumeInfo>>,
+    /// True while volumes are being listed
+    pub loading_volumes: bool,
+    /// Why the last volume listing failed
+    pub volumes_error: Option<String>,
+    /// Published-port conflicts from the last check (None until checked)
+    pub port_conflicts: Option<Vec<crate::system::docker::PortConflict>>,
+    /// True while ports are being cross-checked
+    pub checking_port_conflicts: bool,
+    /// Why the last port conflict check failed
+    pub port_conflicts_error: Option<String>,
+    /// Docker networks from the last listing (None until loaded)
+    pub networks: Option<Vec<crate::system::docker::DockerNetwork>>,
+    /// True while networks are being listed
+    pub loading_networks: bool,
+    /// Why the last network listing failed
+    pub networks_error: Option<String>,
+    /// Container selected for `docker cp`
+    pub copy_container: String,
+    /// Path inside the container to copy from/to
+    pub copy_container_path: String,
+    /// Host file or folder on the other side of the copy
+    pub copy_host_path: String,
+    /// Copy host -> container instead of container -> host
+    pub copy_to_container: bool,
+    /// Docker Desktop's WSL data disk from the last check (None until checked)
+    pub vhdx_info: Option<crate::system::docker::VhdxInfo>,
+    /// True while the data disk is being located and measured
+    pub loading_vhdx: bool,
+    /// Why the last data disk check failed
+    pub vhdx_error: Option<String>,
+    /// Container picked for a new exit watch
+    pub exit_watch_container: String,
+    /// Active exit watches; they keep running when the page is left
+    pub exit_watches: Vec<ExitWatch>,
+    /// Files written by exit watches this session, as (container, file)
+    pub exit_captures: Vec<(String, std::path::PathBuf)>,
+    /// Build cache to keep when pruning ("2GB"; empty prunes all of it)
+    pub cache_keep_storage: String,
     /// Number of log lines to tail
     pub log_tail_lines: u32,
+    /// Container whose logs are being followed live
+    pub following_logs: Option<String>,
+    /// Stops the live log follow
+    pub log_follow_cancel: crate::system::command::CancelFlag,
+    /// Lines received while following, oldest first (capped at LOG_FOLLOW_MAX_LINES)
+    pub log_lines: std::collections::VecDeque<String>,
 }
 
+/// Most followed log lines kept in memory
+pub const LOG_FOLLOW_MAX_LINES: usize = 5000;
+
 impl DockerPageState {
     pub fn new() -> Self {
         Self {
@@ -326,6 +1093,28 @@ impl DockerPageState {
             ..Default::default()
         }
     }
+
+    /// Append followed log lines, dropping the oldest past the cap
+    pub fn push_log_lines(&mut self, lines: Vec<String>) {
+        self.log_lines.extend(lines);
+        let excess = self.log_lines.len().saturating_sub(LOG_FOLLOW_MAX_LINES);
+        self.log_lines.drain(..excess);
+    }
+}
+
+/// Entries kept in the Services page action history
+pub const SERVICE_HISTORY_LEN: usize = 300;
+
+/// A start/stop/restart or other change made from the Services page
+#[derive(Debug, Clone, Serialize)]
+pub struct ServiceHistoryEntry {
+    pub at: chrono::DateTime<chrono::Local>,
+    pub service: String,
+    /// "Start", "Stop", "Restart", "Start type: Manual", ...
+    pub action: String,
+    /// Where the service ended up, or what went wrong
+    pub result: String,
+    pub ok: bool,
 }
 
 /// Services page state
@@ -336,16 +1125,85 @@ pub struct ServicesPageState {
     pub status_type: String,
     pub is_running: bool,
     pub running_action: String,
-    /// Filter for service list
-    pub filter: String,
-    /// Selected service name
+    /// Selected service name (the row Details and start type changes apply to)
     pub selected_service: String,
+    /// Services ticked for Start/Stop/Restart, in the order they were ticked
+    pub checked_services: Vec<String>,
     /// Cached service list
     pub services: Vec<crate::system::services::ServiceInfo>,
-    /// Current sort column
-    pub sort_column: Option<ServicesSortColumn>,
-    /// Sort direction (true = ascending)
-    pub sort_ascending: bool,
+    /// The list came from sc.exe because PowerShell is blocked (no start types until a row is selected)
+    pub sc_fallback: bool,
+    /// Filter and sort state for the service table (the filter matches name and display name)
+    pub table: TableController<ServicesSortColumn>,
+    /// Scroll offset of the service table, restored when the page opens again
+    pub list_scroll_top: f64,
+    /// Status chip filter ("Running", "Stopped", ...); None shows every status
+    pub status_filter: Option<String>,
+    /// Only show services running as LocalSystem
+    pub local_system_only: bool,
+    /// Parsed details of the service whose Details were opened
+    pub details: Option<crate::system::services::ServiceDetails>,
+    /// Format-List text for the details card's raw view (loaded on first toggle)
+    pub details_raw: Option<String>,
+    /// Show the raw text instead of the parsed card
+    pub show_details_raw: bool,
+    /// Recovery settings (`sc qfailure`) of the service in the details card
+    pub failure_actions: Option<crate::system::services::FailureActions>,
+    pub failure_actions_error: Option<String>,
+    /// Services that were still pending when the last start/stop stopped waiting
+    pub stuck_services: Vec<crate::system::services::StatusWait>,
+    /// "Find service by port" input
+    pub port_lookup: String,
+    /// Listeners found by the last port lookup (None until one ran)
+    pub port_owners: Option<Vec<crate::system::services::PortOwner>>,
+    /// Starred service names (mirrors `AppSettings::favorite_services`)
+    pub favorites: Vec<String>,
+    /// Live status of the favorites, refreshed when the page opens
+    pub favorite_status: Vec<crate::system::services::ServiceInfo>,
+    pub loading_favorites: bool,
+    /// Re-list services in the background every `auto_refresh_secs`
+    pub auto_refresh: bool,
+    pub auto_refresh_secs: u64,
+    /// Services whose status changed in the last background refresh (highlighted rows)
+    pub changed_services: Vec<String>,
+    /// Result of the last "Automatic but not running" audit (None until one ran or after closing it)
+    pub auto_audit: Option<crate::system::services::AutoAudit>,
+    /// Actions taken on this page, oldest first (at most `SERVICE_HISTORY_LEN`)
+    pub history: Vec<ServiceHistoryEntry>,
+    /// History section is expanded
+    pub show_history: bool,
+    /// Advanced section (create/delete service) is expanded
+    pub show_advanced: bool,
+    /// Advanced section's "Create service" form
+    pub new_service_name: String,
+    pub new_service_display_name: String,
+    pub new_service_bin_path: String,
+    pub new_service_start_type: String,
+}
+
+impl ServicesPageState {
+    pub fn new(favorites: Vec<String>) -> Self {
+        Self {
+            favorites,
+            auto_refresh_secs: 10,
+            new_service_start_type: "Manual".to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Append to the action history, dropping the oldest entries past `SERVICE_HISTORY_LEN`
+    pub fn record_action(&mut self, service: &str, action: &str, result: String, ok: bool) {
+        self.history.push(ServiceHistoryEntry {
+            at: chrono::Local::now(),
+            service: service.to_string(),
+            action: action.to_string(),
+            result,
+            ok,
+        });
+        if self.history.len() > SERVICE_HISTORY_LEN {
+            self.history.remove(0);
+        }
+    }
 }
 
 /// Processes page state
@@ -359,14 +1217,16 @@ pub struct ProcessesPageState {
     pub processes: Vec<crate::system::processes::ProcessInfo>,
     /// System overview
     pub system_info: crate::system::processes::SystemOverview,
+    /// Top private-bytes consumers, loaded when commit pressure is critical
+    pub top_private: Vec<crate::system::processes::ProcessInfo>,
+    /// Fast Startup enabled (None if unknown)
+    pub fast_startup: Option<bool>,
     /// Sort mode: "memory" or "cpu"
     pub sort_by: String,
     /// Selected process PID
     pub selected_pid: Option<u32>,
-    /// Current sort column for table header clicks
-    pub sort_column: Option<ProcessesSortColumn>,
-    /// Sort direction (true = ascending)
-    pub sort_ascending: bool,
+    /// Filter and sort state for the process table (local to the fetched list)
+    pub table: TableController<ProcessesSortColumn>,
 }
 
 impl ProcessesPageState {
@@ -403,6 +1263,7 @@ pub struct HostsPageState {
 /// Disk page state
 #[derive(Debug, Clone, Default)]
 pub struct DiskPageState {
+    pub command_outputs: Vec<CommandOutput>,
     pub status_message: String,
     pub status_type: String,
     pub is_running: bool,
@@ -411,6 +1272,18 @@ pub struct DiskPageState {
     pub disks: Vec<crate::system::disk::DiskInfo>,
     /// Temp folder sizes
     pub temp_folders: Vec<crate::system::disk::FolderSize>,
+    /// Defender status (Err explains why it couldn't be read)
+    pub defender_status: Option<Result<crate::system::defender::DefenderStatus, String>>,
+    /// Defender exclusions (Err when not admin or Defender is off)
+    pub defender_exclusions: Option<Result<crate::system::defender::DefenderExclusions, String>>,
+    /// Folder chosen for a new exclusion, awaiting confirmation
+    pub pending_exclusion: Option<String>,
+    /// Integrity tool currently running (sfc or DISM)
+    pub integrity_tool: Option<crate::system::integrity::IntegrityTool>,
+    /// Last reported progress of the running integrity tool
+    pub integrity_progress: Option<f32>,
+    /// Set to cancel the running integrity tool
+    pub integrity_cancel: crate::system::command::CancelFlag,
     /// Current sort column
     pub sort_column: Option<DiskSortColumn>,
     /// Sort direction (true = ascending)
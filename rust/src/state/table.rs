@@ -0,0 +1,236 @@
+//! Shared table filtering, sorting, and export
+//!
+//! A page declares its columns once; the controller uses the same definitions to
+//! filter, sort, and export the rows the user can see.
+
+use std::cmp::Ordering;
+
+/// Column definition shared by headers, filtering, sorting, and export
+pub struct TableColumn<T, C> {
+    /// Column identifier used for sorting
+    pub id: C,
+    /// CSV/JSON field name (stays English so exports don't change with the UI language)
+    pub label: &'static str,
+    /// Message key of the header label
+    pub label_key: &'static str,
+    /// Cell text used for filtering and export
+    pub text: fn(&T) -> String,
+    /// Ascending comparison
+    pub compare: fn(&T, &T) -> Ordering,
+    /// Sort largest-first on the first click (sizes, counts, usage)
+    pub descending_first: bool,
+}
+
+/// Filter string and sort state for one table
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableController<C> {
+    /// Whitespace-separated terms; a row must match every term in some column
+    pub filter: String,
+    /// Current sort column (None keeps the fetched order)
+    pub sort_column: Option<C>,
+    /// Sort direction (true = ascending)
+    pub sort_ascending: bool,
+}
+
+impl<C> Default for TableController<C> {
+    fn default() -> Self {
+        Self {
+            filter: String::new(),
+            sort_column: None,
+            sort_ascending: true,
+        }
+    }
+}
+
+impl<C: Copy + PartialEq> TableController<C> {
+    /// Handle a header click: flip direction on the active column, otherwise switch columns
+    pub fn toggle_sort<T>(&mut self, column: C, columns: &[TableColumn<T, C>]) {
+        if self.sort_column == Some(column) {
+            self.sort_ascending = !self.sort_ascending;
+        } else {
+            self.sort_column = Some(column);
+            self.sort_ascending = !columns
+                .iter()
+                .find(|c| c.id == column)
+                .map(|c| c.descending_first)
+                .unwrap_or(false);
+        }
+    }
+
+    /// Check a row against every filter term (case-insensitive)
+    pub fn matches<T>(&self, row: &T, columns: &[TableColumn<T, C>]) -> bool {
+        let cells: Vec<String> = columns.iter().map(|c| (c.text)(row).to_lowercase()).collect();
+        self.filter
+            .split_whitespace()
+            .map(str::to_lowercase)
+            .all(|term| cells.iter().any(|cell| cell.contains(&term)))
+    }
+
+    /// Filtered and sorted rows (stable sort, so equal rows keep their fetched order)
+    pub fn visible_rows<T: Clone>(&self, rows: &[T], columns: &[TableColumn<T, C>]) -> Vec<T> {
+        let mut visible: Vec<T> = rows
+            .iter()
+            .filter(|row| self.matches(*row, columns))
+            .cloned()
+            .collect();
+
+        if let Some(column) = self.sort_column.and_then(|id| columns.iter().find(|c| c.id == id)) {
+            visible.sort_by(|a, b| {
+                let cmp = (column.compare)(a, b);
+                if self.sort_ascending { cmp } else { cmp.reverse() }
+            });
+        }
+        visible
+    }
+
+    /// Visible rows as CSV with a header row
+    pub fn export_csv<T: Clone>(&self, rows: &[T], columns: &[TableColumn<T, C>]) -> String {
+        let mut csv = columns.iter().map(|c| csv_field(c.label)).collect::<Vec<_>>().join(",");
+        csv.push_str("\r\n");
+        for row in self.visible_rows(rows, columns) {
+            let line = columns.iter().map(|c| csv_field(&(c.text)(&row))).collect::<Vec<_>>().join(",");
+            csv.push_str(&line);
+            csv.push_str("\r\n");
+        }
+        csv
+    }
+
+    /// Visible rows as a JSON array of objects keyed by column label
+    pub fn export_json<T: Clone>(&self, rows: &[T], columns: &[TableColumn<T, C>]) -> String {
+        let objects: Vec<serde_json::Value> = self
+            .visible_rows(rows, columns)
+            .iter()
+            .map(|row| {
+                let map = columns
+                    .iter()
+                    .map(|c| (c.label.to_string(), serde_json::Value::String((c.text)(row))))
+                    .collect::<serde_json::Map<_, _>>();
+                serde_json::Value::Object(map)
+            })
+            .collect();
+        serde_json::to_string_pretty(&objects).unwrap_or_default()
+    }
+}
+
+/// Quote a CSV field when it contains a delimiter, quote, or line break
+pub fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Col {
+        Name,
+        Size,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Row {
+        name: &'static str,
+        size: u32,
+    }
+
+    const COLUMNS: &[TableColumn<Row, Col>] = &[
+        TableColumn {
+            id: Col::Name,
+            label: "Name",
+            label_key: "test.col.name",
+            text: |r| r.name.to_string(),
+            compare: |a, b| a.name.cmp(b.name),
+            descending_first: false,
+        },
+        TableColumn {
+            id: Col::Size,
+            label: "Size",
+            label_key: "test.col.size",
+            text: |r| r.size.to_string(),
+            compare: |a, b| a.size.cmp(&b.size),
+            descending_first: true,
+        },
+    ];
+
+    fn rows() -> Vec<Row> {
+        vec![
+            Row { name: "alpha", size: 2 },
+            Row { name: "beta", size: 1 },
+            Row { name: "gamma", size: 2 },
+            Row { name: "delta", size: 1 },
+        ]
+    }
+
+    fn names(rows: &[Row]) -> Vec<&'static str> {
+        rows.iter().map(|r| r.name).collect()
+    }
+
+    #[test]
+    fn sort_keeps_fetched_order_for_equal_rows() {
+        let mut table = TableController::<Col>::default();
+        table.toggle_sort(Col::Size, COLUMNS);
+        assert!(!table.sort_ascending, "size sorts largest-first on the first click");
+        assert_eq!(names(&table.visible_rows(&rows(), COLUMNS)), ["alpha", "gamma", "beta", "delta"]);
+
+        table.toggle_sort(Col::Size, COLUMNS);
+        assert!(table.sort_ascending);
+        assert_eq!(names(&table.visible_rows(&rows(), COLUMNS)), ["beta", "delta", "alpha", "gamma"]);
+    }
+
+    #[test]
+    fn no_sort_column_keeps_fetched_order() {
+        let table = TableController::<Col>::default();
+        assert_eq!(table.visible_rows(&rows(), COLUMNS), rows());
+    }
+
+    #[test]
+    fn filter_terms_must_all_match() {
+        let mut table = TableController::<Col> {
+            filter: "A 2".to_string(),
+            ..Default::default()
+        };
+        // Each term can match a different column, case-insensitively
+        assert_eq!(names(&table.visible_rows(&rows(), COLUMNS)), ["alpha", "gamma"]);
+
+        table.filter = "alpha 1".to_string();
+        assert!(table.visible_rows(&rows(), COLUMNS).is_empty());
+
+        table.filter = "   ".to_string();
+        assert_eq!(table.visible_rows(&rows(), COLUMNS).len(), 4);
+    }
+
+    #[test]
+    fn filter_and_sort_compose() {
+        let mut table = TableController::<Col> {
+            filter: "ta".to_string(),
+            ..Default::default()
+        };
+        table.toggle_sort(Col::Name, COLUMNS);
+        assert_eq!(names(&table.visible_rows(&rows(), COLUMNS)), ["beta", "delta"]);
+        table.toggle_sort(Col::Name, COLUMNS);
+        assert_eq!(names(&table.visible_rows(&rows(), COLUMNS)), ["delta", "beta"]);
+    }
+
+    #[test]
+    fn csv_field_quotes_only_when_needed() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field(""), "");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("two\nlines"), "\"two\nlines\"");
+        assert_eq!(csv_field("cr\r"), "\"cr\r\"");
+    }
+
+    #[test]
+    fn export_csv_writes_header_and_visible_rows() {
+        let table = TableController::<Col> {
+            filter: "beta".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(table.export_csv(&rows(), COLUMNS), "Name,Size\r\nbeta,1\r\n");
+    }
+}
@@ -1,6 +1,6 @@
 //! Admin privilege detection
 //!
-//! Windows-specific check for elevated (admin) privileges.
+//! Windows-specific check for elevated (admin) privileges, and UAC relaunch.
 
 /// Check if the current process is running with elevated privileges
 #[cfg(target_os = "windows")]
@@ -80,3 +80,71 @@ pub fn is_elevated() -> bool {
 pub fn is_elevated() -> bool {
     false
 }
+
+/// Relaunch this executable elevated (UAC prompt) and exit the current process
+///
+/// Only returns when the relaunch failed, e.g. the user declined the UAC prompt.
+#[cfg(target_os = "windows")]
+pub fn restart_as_admin() -> Result<(), String> {
+    use std::os::windows::ffi::OsStrExt;
+    use std::ptr;
+
+    #[link(name = "shell32")]
+    extern "system" {
+        fn ShellExecuteW(
+            hwnd: *mut std::ffi::c_void,
+            lpOperation: *const u16,
+            lpFile: *const u16,
+            lpParameters: *const u16,
+            lpDirectory: *const u16,
+            nShowCmd: i32,
+        ) -> isize;
+    }
+
+    const SW_SHOWNORMAL: i32 = 1;
+    const SE_ERR_ACCESSDENIED: isize = 5;
+
+    fn wide(s: &std::ffi::OsStr) -> Vec<u16> {
+        s.encode_wide().chain(std::iter::once(0)).collect()
+    }
+
+    let exe = std::env::current_exe().map_err(|e| format!("Cannot locate executable: {}", e))?;
+    let args = std::env::args()
+        .skip(1)
+        .map(|arg| format!("\"{}\"", arg.replace('"', "\\\"")))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let dir = std::env::current_dir().unwrap_or_default();
+
+    let verb = wide("runas".as_ref());
+    let file = wide(exe.as_os_str());
+    let params = wide(args.as_ref());
+    let directory = wide(dir.as_os_str());
+
+    // ShellExecuteW returns a value greater than 32 on success
+    let result = unsafe {
+        ShellExecuteW(
+            ptr::null_mut(),
+            verb.as_ptr(),
+            file.as_ptr(),
+            params.as_ptr(),
+            directory.as_ptr(),
+            SW_SHOWNORMAL,
+        )
+    };
+
+    match result {
+        r if r > 32 => {
+            tracing::info!("Relaunched elevated, exiting");
+            std::process::exit(0);
+        }
+        SE_ERR_ACCESSDENIED => Err("The administrator prompt was declined".to_string()),
+        r => Err(format!("Failed to relaunch as administrator (error {})", r)),
+    }
+}
+
+/// Non-Windows fallback - elevation relaunch is not supported
+#[cfg(not(target_os = "windows"))]
+pub fn restart_as_admin() -> Result<(), String> {
+    Err("Restarting as administrator is only supported on Windows".to_string())
+}
@@ -2,11 +2,17 @@
 //!
 //! Provides helpers for running system commands without visible console windows.
 
-use crate::state::CommandOutput;
+use crate::state::{CommandOutput, CommandReplay, DangerLevel};
 use std::process::Stdio;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::time::Instant;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio::process::Command;
 
+/// Shared flag used to cancel a streaming command
+pub type CancelFlag = Arc<AtomicBool>;
+
 #[cfg(target_os = "windows")]
 use std::os::windows::process::CommandExt;
 
@@ -14,6 +20,10 @@ use std::os::windows::process::CommandExt;
 #[cfg(target_os = "windows")]
 pub const CREATE_NO_WINDOW: u32 = 0x08000000;
 
+/// Windows flag to give a console child its own window
+#[cfg(target_os = "windows")]
+pub const CREATE_NEW_CONSOLE: u32 = 0x00000010;
+
 /// Create an async Command configured to run without a visible window on Windows
 pub fn hidden_command(program: &str) -> Command {
     let mut cmd = Command::new(program);
@@ -34,10 +44,126 @@ pub fn hidden_command_sync(program: &str) -> std::process::Command {
     cmd
 }
 
+/// Create a sync Command that opens in its own console window (for interactive tools)
+pub fn console_command_sync(program: &str) -> std::process::Command {
+    let mut cmd = std::process::Command::new(program);
+
+    #[cfg(target_os = "windows")]
+    cmd.creation_flags(CREATE_NEW_CONSOLE);
+
+    cmd
+}
+
+/// Decode UTF-16LE output (what `wsl.exe` prints when piped)
+pub fn decode_utf16le(bytes: &[u8]) -> String {
+    String::from_utf16_lossy(
+        &bytes
+            .chunks_exact(2)
+            .map(|chunk| u16::from_le_bytes([chunk[0], chunk[1]]))
+            .collect::<Vec<u16>>(),
+    )
+}
+
+/// Open File Explorer with the given file selected
+pub fn reveal_in_explorer(path: &str) -> Result<(), String> {
+    std::process::Command::new("explorer")
+        .arg(format!("/select,{}", path))
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| format!("Failed to open Explorer: {}", e))
+}
+
+/// Show the Windows folder picker and return the chosen folder (None if cancelled)
+pub async fn pick_folder(description: &str) -> Option<String> {
+    let script = format!(
+        "Add-Type -AssemblyName System.Windows.Forms; \
+         $d = New-Object System.Windows.Forms.FolderBrowserDialog; \
+         $d.Description = '{}'; \
+         if ($d.ShowDialog() -eq 'OK') {{ $d.SelectedPath }}",
+        description.replace('\'', "''")
+    );
+    let output = run_powershell(&script).await;
+    let path = output.stdout.trim();
+    if output.succeeded() && !path.is_empty() {
+        Some(path.to_string())
+    } else {
+        None
+    }
+}
+
+/// Show the Windows save-file dialog and return the chosen path (None if cancelled)
+///
+/// `filter` uses the WinForms syntax, e.g. "Text files (*.txt)|*.txt".
+pub async fn pick_save_file(default_name: &str, filter: &str) -> Option<String> {
+    let script = format!(
+        "Add-Type -AssemblyName System.Windows.Forms; \
+         $d = New-Object System.Windows.Forms.SaveFileDialog; \
+         $d.FileName = '{}'; \
+         $d.Filter = '{}'; \
+         $d.InitialDirectory = [Environment]::GetFolderPath('Desktop'); \
+         if ($d.ShowDialog() -eq 'OK') {{ $d.FileName }}",
+        default_name.replace('\'', "''"),
+        filter.replace('\'', "''")
+    );
+    let output = run_powershell(&script).await;
+    let path = output.stdout.trim();
+    if output.succeeded() && !path.is_empty() {
+        Some(path.to_string())
+    } else {
+        None
+    }
+}
+
+/// Show the Windows open-file dialog and return the chosen file (None if cancelled)
+///
+/// `filter` uses the WinForms syntax, e.g. "Compose files (*.yml;*.yaml)|*.yml;*.yaml".
+pub async fn pick_open_file(title: &str, filter: &str) -> Option<String> {
+    let script = format!(
+        "Add-Type -AssemblyName System.Windows.Forms; \
+         $d = New-Object System.Windows.Forms.OpenFileDialog; \
+         $d.Title = '{}'; \
+         $d.Filter = '{}'; \
+         if ($d.ShowDialog() -eq 'OK') {{ $d.FileName }}",
+        title.replace('\'', "''"),
+        filter.replace('\'', "''")
+    );
+    let output = run_powershell(&script).await;
+    let path = output.stdout.trim();
+    if output.succeeded() && !path.is_empty() {
+        Some(path.to_string())
+    } else {
+        None
+    }
+}
+
+/// Command line as shown in the output panel, quoting arguments that contain spaces
+///
+/// Display only - arguments are passed to the process individually, so paths with
+/// spaces need no quoting when calling `run_command`.
+pub fn display_command(program: &str, args: &[&str]) -> String {
+    let mut line = program.to_string();
+    for arg in args {
+        line.push(' ');
+        if arg.is_empty() || arg.contains([' ', '\t']) {
+            line.push_str(&format!("\"{}\"", arg));
+        } else {
+            line.push_str(arg);
+        }
+    }
+    line
+}
+
 /// Run a command and capture output, without showing a console window
 pub async fn run_command(program: &str, args: &[&str]) -> CommandOutput {
-    let command_str = format!("{} {}", program, args.join(" "));
+    let command_str = display_command(program, args);
     let start = Instant::now();
+    let replay = Some(CommandReplay {
+        program: program.to_string(),
+        args: args.iter().map(|a| a.to_string()).collect(),
+        powershell: false,
+        requires_admin: false,
+        danger: DangerLevel::Modifying,
+    });
 
     let result = hidden_command(program)
         .args(args)
@@ -55,6 +181,7 @@ pub async fn run_command(program: &str, args: &[&str]) -> CommandOutput {
             stderr: String::from_utf8_lossy(&output.stderr).to_string(),
             exit_code: output.status.code().unwrap_or(-1),
             duration_ms,
+            replay,
         },
         Err(e) => CommandOutput {
             command: command_str,
@@ -62,13 +189,68 @@ pub async fn run_command(program: &str, args: &[&str]) -> CommandOutput {
             stderr: format!("Failed to execute: {}", e),
             exit_code: -1,
             duration_ms,
+            replay,
         },
     }
 }
 
+/// Run a command with `input` written to its stdin, e.g. a password for `--password-stdin`
+///
+/// The input never appears in the displayed command line, and the output is not
+/// replayable since the input isn't kept.
+pub async fn run_command_with_stdin(program: &str, args: &[&str], input: &str) -> CommandOutput {
+    let command_str = display_command(program, args);
+    let start = Instant::now();
+    let failed = |stderr: String| CommandOutput {
+        command: command_str.clone(),
+        stderr,
+        exit_code: -1,
+        duration_ms: start.elapsed().as_millis() as u64,
+        ..Default::default()
+    };
+
+    let mut child = match hidden_command(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(e) => return failed(format!("Failed to execute: {}", e)),
+    };
+
+    if let Some(mut stdin) = child.stdin.take() {
+        if let Err(e) = stdin.write_all(input.as_bytes()).await {
+            let _ = child.kill().await;
+            return failed(format!("Failed to write to stdin: {}", e));
+        }
+        // Dropping stdin closes it so the program sees end of input
+    }
+
+    match child.wait_with_output().await {
+        Ok(output) => CommandOutput {
+            command: command_str,
+            stdout: String::from_utf8_lossy(&output.stdout).to_string(),
+            stderr: String::from_utf8_lossy(&output.stderr).to_string(),
+            exit_code: output.status.code().unwrap_or(-1),
+            duration_ms: start.elapsed().as_millis() as u64,
+            replay: None,
+        },
+        Err(e) => failed(format!("Failed to execute: {}", e)),
+    }
+}
+
 /// Run a PowerShell command and capture output, without showing a console window
 pub async fn run_powershell(command: &str) -> CommandOutput {
     let start = Instant::now();
+    let replay = Some(CommandReplay {
+        program: command.to_string(),
+        args: Vec::new(),
+        powershell: true,
+        requires_admin: false,
+        danger: DangerLevel::Modifying,
+    });
 
     let result = hidden_command("powershell")
         .args(["-NoProfile", "-Command", command])
@@ -86,6 +268,7 @@ pub async fn run_powershell(command: &str) -> CommandOutput {
             stderr: String::from_utf8_lossy(&output.stderr).to_string(),
             exit_code: output.status.code().unwrap_or(-1),
             duration_ms,
+            replay,
         },
         Err(e) => CommandOutput {
             command: format!("powershell: {}", command),
@@ -93,7 +276,216 @@ pub async fn run_powershell(command: &str) -> CommandOutput {
             stderr: format!("Failed to execute: {}", e),
             exit_code: -1,
             duration_ms,
+            replay,
         },
     }
 }
 
+/// Run a command again from its replay descriptor
+pub async fn replay(replay: &CommandReplay) -> CommandOutput {
+    let output = if replay.powershell {
+        run_powershell(&replay.program).await
+    } else {
+        let args: Vec<&str> = replay.args.iter().map(String::as_str).collect();
+        run_command(&replay.program, &args).await
+    };
+
+    // Keep the original gating for the next re-run
+    CommandOutput {
+        replay: Some(replay.clone()),
+        ..output
+    }
+}
+
+/// Decode as much of `raw` as forms complete characters, leaving any partial character in place
+fn decode_available(raw: &mut Vec<u8>, utf16: bool) -> String {
+    if utf16 {
+        let usable = raw.len() - raw.len() % 2;
+        let units: Vec<u16> = raw[..usable]
+            .chunks_exact(2)
+            .map(|c| u16::from_le_bytes([c[0], c[1]]))
+            .filter(|u| *u != 0xFEFF)
+            .collect();
+        raw.drain(..usable);
+        String::from_utf16_lossy(&units)
+    } else {
+        let valid = match std::str::from_utf8(raw) {
+            Ok(s) => s.len(),
+            // Keep an incomplete trailing sequence for the next chunk
+            Err(e) if e.error_len().is_none() => e.valid_up_to(),
+            Err(_) => raw.len(),
+        };
+        let text = String::from_utf8_lossy(&raw[..valid]).to_string();
+        raw.drain(..valid);
+        text
+    }
+}
+
+/// Run a command, calling `on_line` for each stdout line as it arrives
+///
+/// Lines end at `\n` or `\r` so tools that redraw a progress line (sfc, DISM) report
+/// every update. UTF-16 output (sfc when redirected) is detected and decoded. Setting
+/// `cancel` kills the process.
+pub async fn run_command_streaming(
+    program: &str,
+    args: &[&str],
+    cancel: CancelFlag,
+    mut on_line: impl FnMut(&str),
+) -> CommandOutput {
+    let command_str = display_command(program, args);
+    let start = Instant::now();
+
+    let mut child = match hidden_command(program)
+        .args(args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(e) => {
+            return CommandOutput {
+                command: command_str,
+                stderr: format!("Failed to execute: {}", e),
+                exit_code: -1,
+                duration_ms: start.elapsed().as_millis() as u64,
+                ..Default::default()
+            };
+        }
+    };
+
+    let (Some(mut stdout), Some(mut stderr)) = (child.stdout.take(), child.stderr.take()) else {
+        return CommandOutput {
+            command: command_str,
+            stderr: "Failed to capture command output".to_string(),
+            exit_code: -1,
+            ..Default::default()
+        };
+    };
+    let stderr_task = tokio::spawn(async move {
+        let mut buf = Vec::new();
+        let _ = stderr.read_to_end(&mut buf).await;
+        buf
+    });
+
+    let mut raw = Vec::new();
+    let mut utf16: Option<bool> = None;
+    let mut pending = String::new();
+    let mut collected = String::new();
+    let mut cancelled = false;
+    let mut buf = [0u8; 4096];
+    // One interval for the whole run: a sleep rebuilt per pass never fires while output keeps coming
+    let mut cancel_check = tokio::time::interval(tokio::time::Duration::from_millis(200));
+
+    loop {
+        tokio::select! {
+            read = stdout.read(&mut buf) => {
+                let n = match read {
+                    Ok(0) | Err(_) => break,
+                    Ok(n) => n,
+                };
+                raw.extend_from_slice(&buf[..n]);
+
+                // ASCII text encoded as UTF-16LE has a zero in every second byte
+                let is_utf16 = *utf16.get_or_insert_with(|| {
+                    raw.len() >= 2 && (raw[1] == 0 || (raw[0] == 0xFF && raw[1] == 0xFE))
+                });
+                pending.push_str(&decode_available(&mut raw, is_utf16));
+
+                while let Some(pos) = pending.find(['\r', '\n']) {
+                    let line: String = pending.drain(..=pos).collect();
+                    let line = line.trim_end_matches(['\r', '\n']);
+                    if !line.trim().is_empty() {
+                        on_line(line);
+                        collected.push_str(line);
+                        collected.push('\n');
+                    }
+                }
+            }
+            _ = cancel_check.tick() => {
+                if cancel.load(Ordering::Relaxed) {
+                    let _ = child.kill().await;
+                    cancelled = true;
+                    break;
+                }
+            }
+        }
+    }
+
+    if !pending.trim().is_empty() {
+        on_line(pending.trim_end());
+        collected.push_str(pending.trim_end());
+        collected.push('\n');
+    }
+
+    let exit_code = if cancelled {
+        -1
+    } else {
+        match child.wait().await {
+            Ok(status) => status.code().unwrap_or(-1),
+            Err(_) => -1,
+        }
+    };
+    let stderr_bytes = stderr_task.await.unwrap_or_default();
+    let mut stderr_text = String::from_utf8_lossy(&stderr_bytes).to_string();
+    if cancelled {
+        stderr_text.push_str("Cancelled by user\n");
+    }
+
+    CommandOutput {
+        command: command_str,
+        stdout: collected,
+        stderr: stderr_text,
+        exit_code,
+        duration_ms: start.elapsed().as_millis() as u64,
+        replay: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MISSING_PROGRAM: &str = "sanity-suite-no-such-program";
+
+    #[tokio::test]
+    async fn untagged_replays_are_not_read_only() {
+        let output = run_command(MISSING_PROGRAM, &["--flag"]).await;
+        let replay = output.replay.expect("run_command attaches a replay");
+        assert_eq!(replay.danger, DangerLevel::Modifying);
+        assert_eq!(replay.args, vec!["--flag"]);
+        assert!(!replay.powershell && !replay.requires_admin);
+    }
+
+    #[tokio::test]
+    async fn tags_override_the_replay_default() {
+        let output = run_command(MISSING_PROGRAM, &[]).await.requires_admin().with_danger(DangerLevel::Destructive);
+        let replay = output.replay.as_ref().unwrap();
+        assert_eq!(replay.danger, DangerLevel::Destructive);
+        assert!(replay.requires_admin);
+        assert!(output.without_replay().replay.is_none());
+    }
+
+    /// A command that prints a line continuously until killed
+    #[cfg(target_os = "windows")]
+    const CHATTY: (&str, &[&str]) = ("cmd", &["/C", "for /L %i in (0,0,1) do @echo y"]);
+    #[cfg(not(target_os = "windows"))]
+    const CHATTY: (&str, &[&str]) = ("yes", &[]);
+
+    #[tokio::test]
+    async fn streaming_cancel_stops_a_process_that_never_pauses() {
+        let cancel = CancelFlag::default();
+        let flag = cancel.clone();
+        let mut lines = 0;
+        let run = run_command_streaming(CHATTY.0, CHATTY.1, cancel, |_| {
+            lines += 1;
+            flag.store(true, Ordering::Relaxed);
+        });
+        let output = tokio::time::timeout(std::time::Duration::from_secs(10), run)
+            .await
+            .expect("cancel is honoured while output keeps arriving");
+        assert_eq!(output.exit_code, -1);
+        assert!(output.stderr.contains("Cancelled by user"));
+        assert!(lines > 0);
+    }
+}
@@ -0,0 +1,174 @@
+//! Windows Defender utilities
+//!
+//! Read Defender status and exclusions, add/remove exclusions for dev folders.
+
+use serde::Deserialize;
+
+use crate::state::{CommandOutput, DangerLevel};
+use crate::system::command::run_powershell;
+
+/// Defender status from Get-MpComputerStatus
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DefenderStatus {
+    /// Antimalware service running
+    pub service_enabled: bool,
+    pub antivirus_enabled: bool,
+    pub real_time_protection: bool,
+    /// "Normal", "Passive Mode", "EDR Block Mode", ...
+    pub running_mode: String,
+}
+
+impl DefenderStatus {
+    /// Defender is installed but another antivirus is the active one
+    pub fn is_passive(&self) -> bool {
+        let mode = self.running_mode.to_lowercase();
+        mode.contains("passive") || mode.contains("edr")
+    }
+
+    /// Whether real-time scanning is actually slowing down file access
+    pub fn is_scanning(&self) -> bool {
+        self.service_enabled && self.antivirus_enabled && self.real_time_protection && !self.is_passive()
+    }
+}
+
+/// Type of Defender exclusion
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExclusionKind {
+    Path,
+    Process,
+}
+
+impl ExclusionKind {
+    /// Get-/Add-/Remove-MpPreference parameter name
+    fn parameter(&self) -> &'static str {
+        match self {
+            ExclusionKind::Path => "ExclusionPath",
+            ExclusionKind::Process => "ExclusionProcess",
+        }
+    }
+
+    /// Message key of the label in the exclusions table
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            ExclusionKind::Path => "disk.exclusion-kind.path",
+            ExclusionKind::Process => "disk.exclusion-kind.process",
+        }
+    }
+}
+
+/// Current Defender exclusions
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DefenderExclusions {
+    pub paths: Vec<String>,
+    pub processes: Vec<String>,
+}
+
+/// PowerShell serializes single-item arrays as a bare value
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OneOrMany {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl OneOrMany {
+    fn into_vec(self) -> Vec<String> {
+        match self {
+            OneOrMany::One(s) => vec![s],
+            OneOrMany::Many(v) => v,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawPreference {
+    exclusion_path: Option<OneOrMany>,
+    exclusion_process: Option<OneOrMany>,
+}
+
+#[derive(Deserialize)]
+struct RawStatus {
+    #[serde(rename = "AMServiceEnabled")]
+    am_service_enabled: Option<bool>,
+    #[serde(rename = "AntivirusEnabled")]
+    antivirus_enabled: Option<bool>,
+    #[serde(rename = "RealTimeProtectionEnabled")]
+    real_time_protection_enabled: Option<bool>,
+    #[serde(rename = "AMRunningMode")]
+    am_running_mode: Option<String>,
+}
+
+/// Explain a failed Defender cmdlet in user terms
+fn describe_failure(output: &CommandOutput) -> String {
+    let err = output.stderr.to_lowercase();
+    if err.contains("0x800106ba") || err.contains("not running") || err.contains("not recognized") {
+        "Windows Defender is not running - a third-party antivirus may have disabled it".to_string()
+    } else {
+        output
+            .stderr
+            .lines()
+            .map(str::trim)
+            .find(|l| !l.is_empty())
+            .unwrap_or("Defender query failed")
+            .to_string()
+    }
+}
+
+/// Get Defender status
+pub async fn get_status() -> (Result<DefenderStatus, String>, CommandOutput) {
+    let cmd = "Get-MpComputerStatus | Select-Object AMServiceEnabled, AntivirusEnabled, RealTimeProtectionEnabled, AMRunningMode | ConvertTo-Json";
+    let output = run_powershell(cmd).await;
+
+    if !output.succeeded() || output.stdout.trim().is_empty() {
+        return (Err(describe_failure(&output)), output);
+    }
+
+    let result = serde_json::from_str::<RawStatus>(output.stdout.trim())
+        .map(|raw| DefenderStatus {
+            service_enabled: raw.am_service_enabled.unwrap_or(false),
+            antivirus_enabled: raw.antivirus_enabled.unwrap_or(false),
+            real_time_protection: raw.real_time_protection_enabled.unwrap_or(false),
+            running_mode: raw.am_running_mode.unwrap_or_else(|| "Unknown".to_string()),
+        })
+        .map_err(|e| format!("Failed to parse Defender status: {}", e));
+
+    (result, output)
+}
+
+/// List exclusion paths and processes (reading them requires admin)
+pub async fn list_exclusions() -> (Result<DefenderExclusions, String>, CommandOutput) {
+    let cmd = "Get-MpPreference | Select-Object ExclusionPath, ExclusionProcess | ConvertTo-Json";
+    let output = run_powershell(cmd).await;
+
+    if !output.succeeded() || output.stdout.trim().is_empty() {
+        return (Err(describe_failure(&output)), output);
+    }
+
+    let raw = match serde_json::from_str::<RawPreference>(output.stdout.trim()) {
+        Ok(raw) => raw,
+        Err(e) => return (Err(format!("Failed to parse Defender preferences: {}", e)), output),
+    };
+
+    let paths = raw.exclusion_path.map(OneOrMany::into_vec).unwrap_or_default();
+    let processes = raw.exclusion_process.map(OneOrMany::into_vec).unwrap_or_default();
+
+    // Non-admin sessions get a placeholder entry instead of the real list
+    if paths.iter().chain(processes.iter()).any(|p| p.starts_with("N/A")) {
+        return (Err("Administrator required to read Defender exclusions".to_string()), output);
+    }
+
+    (Ok(DefenderExclusions { paths, processes }), output)
+}
+
+/// Add an exclusion (requires admin)
+pub async fn add_exclusion(kind: ExclusionKind, value: &str) -> CommandOutput {
+    let cmd = format!("Add-MpPreference -{} '{}'", kind.parameter(), value.replace('\'', "''"));
+    run_powershell(&cmd).await.requires_admin().with_danger(DangerLevel::Destructive)
+}
+
+/// Remove an exclusion (requires admin)
+pub async fn remove_exclusion(kind: ExclusionKind, value: &str) -> CommandOutput {
+    let cmd = format!("Remove-MpPreference -{} '{}'", kind.parameter(), value.replace('\'', "''"));
+    run_powershell(&cmd).await.requires_admin().with_danger(DangerLevel::Modifying)
+}
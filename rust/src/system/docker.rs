@@ -4,8 +4,17 @@
 
 #![allow(dead_code)]
 
-use crate::state::CommandOutput;
-use crate::system::command::run_command;
+use crate::state::{CommandOutput, DangerLevel, PortBinding, Protocol};
+use crate::system::command::{
+    console_command_sync, decode_utf16le, display_command, hidden_command, run_command, run_command_streaming,
+    run_command_with_stdin, run_powershell, CancelFlag,
+};
+use crate::system::services;
+use serde::Deserialize;
+use std::process::Stdio;
+use std::sync::atomic::Ordering;
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncBufReadExt, BufReader};
 
 
 /// Check if Docker is available
@@ -18,6 +27,282 @@ pub async fn docker_info() -> CommandOutput {
     run_command("docker", &["info"]).await
 }
 
+/// Outcome of one engine diagnosis step
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CheckState {
+    Ok,
+    /// Not necessarily broken (e.g. a manual-start service that is stopped)
+    Warning,
+    Failed,
+}
+
+/// One line of the engine checklist
+#[derive(Debug, Clone, PartialEq)]
+pub struct EngineCheck {
+    pub label: String,
+    pub state: CheckState,
+    pub detail: String,
+}
+
+impl EngineCheck {
+    fn new(label: String, state: CheckState, detail: impl Into<String>) -> Self {
+        Self { label, state, detail: detail.into() }
+    }
+}
+
+/// Windows service installed by Docker Desktop
+pub const DOCKER_SERVICE: &str = "com.docker.service";
+
+/// Named pipe the Docker engine listens on
+#[cfg(target_os = "windows")]
+const ENGINE_PIPE: &str = r"\\.\pipe\docker_engine";
+#[cfg(not(target_os = "windows"))]
+const ENGINE_PIPE: &str = "/var/run/docker.sock";
+
+/// Work out why `docker info` fails: CLI, engine pipe, service, Docker Desktop process, and WSL 2
+pub async fn diagnose_engine() -> (Vec<EngineCheck>, Vec<CommandOutput>) {
+    let mut checks = Vec::new();
+    let mut outputs = Vec::new();
+
+    checks.push(match which::which("docker") {
+        Ok(path) => EngineCheck::new(t!("docker.check.cli"), CheckState::Ok, path.display().to_string()),
+        Err(_) => EngineCheck::new(t!("docker.check.cli"), CheckState::Failed, t!("docker.check.cli-missing")),
+    });
+
+    checks.push(if std::path::Path::new(ENGINE_PIPE).exists() {
+        EngineCheck::new(t!("docker.check.pipe"), CheckState::Ok, ENGINE_PIPE)
+    } else {
+        EngineCheck::new(t!("docker.check.pipe"), CheckState::Failed, t!("docker.check.pipe-missing", pipe = ENGINE_PIPE))
+    });
+
+    let (service, output) = services::list_services_filtered(DOCKER_SERVICE).await;
+    outputs.push(output);
+    checks.push(match service.first() {
+        Some(s) if s.status == "Running" => EngineCheck::new(t!("docker.check.service"), CheckState::Ok, t!("docker.check.running")),
+        Some(s) if s.start_type == "Manual" => {
+            EngineCheck::new(t!("docker.check.service"), CheckState::Warning, t!("docker.check.service-manual", status = s.status))
+        }
+        Some(s) => EngineCheck::new(t!("docker.check.service"), CheckState::Failed, format!("{} ({})", s.status, s.start_type)),
+        None => EngineCheck::new(t!("docker.check.service"), CheckState::Warning, t!("docker.check.service-missing", service = DOCKER_SERVICE)),
+    });
+
+    checks.push(match docker_desktop_pid() {
+        Some(pid) => EngineCheck::new(t!("docker.check.desktop"), CheckState::Ok, t!("docker.check.desktop-running", pid = pid)),
+        None => EngineCheck::new(t!("docker.check.desktop"), CheckState::Failed, t!("docker.check.desktop-missing")),
+    });
+
+    let (wsl, output) = wsl_distros().await;
+    outputs.push(output);
+    checks.push(match wsl {
+        Err(e) => EngineCheck::new(t!("docker.check.wsl"), CheckState::Warning, e),
+        Ok(distros) => match distros.iter().find(|d| d.name == "docker-desktop") {
+            Some(d) if d.version != 2 => {
+                EngineCheck::new(t!("docker.check.wsl"), CheckState::Failed, t!("docker.check.wsl-version", version = d.version))
+            }
+            Some(d) if d.state == "Running" => EngineCheck::new(t!("docker.check.wsl"), CheckState::Ok, t!("docker.check.wsl-running")),
+            Some(d) => EngineCheck::new(t!("docker.check.wsl"), CheckState::Failed, t!("docker.check.wsl-state", state = d.state)),
+            None => EngineCheck::new(t!("docker.check.wsl"), CheckState::Warning, t!("docker.check.wsl-no-distro")),
+        },
+    });
+
+    (checks, outputs)
+}
+
+/// PID of the Docker Desktop UI process, if it is running
+fn docker_desktop_pid() -> Option<u32> {
+    let mut sys = sysinfo::System::new();
+    sys.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
+    sys.processes()
+        .values()
+        .find(|p| p.name().to_string_lossy().eq_ignore_ascii_case("Docker Desktop.exe"))
+        .map(|p| p.pid().as_u32())
+}
+
+/// A row of `wsl -l -v`
+#[derive(Debug, Clone, PartialEq)]
+pub struct WslDistro {
+    pub name: String,
+    pub state: String,
+    pub version: u8,
+}
+
+/// Run wsl.exe, which writes UTF-16LE instead of the console code page
+async fn run_wsl(args: &[&str]) -> CommandOutput {
+    let start = Instant::now();
+    let result = hidden_command("wsl").args(args).output().await;
+    let (stdout, stderr, exit_code) = match result {
+        Ok(o) => (decode_utf16le(&o.stdout), decode_utf16le(&o.stderr), o.status.code().unwrap_or(-1)),
+        Err(e) => (String::new(), format!("Failed to run wsl: {}", e), -1),
+    };
+    CommandOutput {
+        command: format!("wsl {}", args.join(" ")),
+        stdout,
+        stderr,
+        exit_code,
+        duration_ms: start.elapsed().as_millis() as u64,
+        replay: None,
+    }
+}
+
+/// List WSL distros with their state and WSL version
+pub async fn wsl_distros() -> (Result<Vec<WslDistro>, String>, CommandOutput) {
+    let output = run_wsl(&["-l", "-v"]).await;
+    let result = if output.succeeded() {
+        Ok(parse_wsl_list(&output.stdout))
+    } else {
+        let error = output.stderr.lines().chain(output.stdout.lines()).map(str::trim).find(|l| !l.is_empty());
+        Err(error.unwrap_or("wsl -l -v failed").to_string())
+    };
+    (result, output)
+}
+
+/// Parse `wsl -l -v` ("* Ubuntu  Running  2"; the header and default marker are skipped)
+pub fn parse_wsl_list(stdout: &str) -> Vec<WslDistro> {
+    stdout
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields: Vec<&str> =
+                line.trim_start_matches(|c: char| c == '*' || c.is_whitespace()).split_whitespace().collect();
+            let (version, rest) = fields.split_last()?;
+            let (state, name) = rest.split_last()?;
+            Some(WslDistro { name: name.join(" "), state: state.to_string(), version: version.parse().ok()? })
+        })
+        .collect()
+}
+
+/// Docker Desktop's WSL 2 data disk and how much of it Docker actually uses
+#[derive(Debug, Clone, PartialEq)]
+pub struct VhdxInfo {
+    pub path: std::path::PathBuf,
+    /// WSL distro backed by the disk, when `wsl -l` lists it
+    pub distro: Option<String>,
+    /// Size of the .vhdx file on the host disk
+    pub size_bytes: u64,
+    /// Images + containers + volumes + build cache per `docker system df` (None when the engine is down)
+    pub docker_used_bytes: Option<u64>,
+}
+
+impl VhdxInfo {
+    /// Roughly what a compaction gives back: file size minus what Docker reports using
+    pub fn reclaimable_bytes(&self) -> Option<u64> {
+        self.docker_used_bytes.map(|used| self.size_bytes.saturating_sub(used))
+    }
+}
+
+/// Data disk locations under %LOCALAPPDATA%, newest Docker Desktop layout first, with their distro
+const DOCKER_VHDX_PATHS: &[(&str, &str)] = &[
+    (r"Docker\wsl\disk\docker_data.vhdx", "docker-desktop"),
+    (r"Docker\wsl\data\ext4.vhdx", "docker-desktop-data"),
+];
+
+/// Locate Docker Desktop's data .vhdx and compare its size with `docker system df`
+pub async fn get_wsl_vhdx_info() -> (Result<VhdxInfo, String>, Vec<CommandOutput>) {
+    let Some(local) = dirs::data_local_dir() else {
+        return (Err("%LOCALAPPDATA% is not set".to_string()), Vec::new());
+    };
+    let Some((path, distro)) =
+        DOCKER_VHDX_PATHS.iter().map(|(rel, distro)| (local.join(rel), *distro)).find(|(path, _)| path.exists())
+    else {
+        return (Err(format!("No Docker data disk under {} (Hyper-V backend?)", local.join("Docker").display())), Vec::new());
+    };
+    let size_bytes = match std::fs::metadata(&path) {
+        Ok(m) => m.len(),
+        Err(e) => return (Err(format!("Cannot read {}: {}", path.display(), e)), Vec::new()),
+    };
+
+    let (distros, wsl) = wsl_distros().await;
+    let distro = distros.ok().and_then(|list| list.into_iter().find(|d| d.name == distro).map(|d| d.name));
+
+    let df = run_command("docker", &["system", "df", "--format", "{{json .}}"]).await;
+    let docker_used_bytes = df.succeeded().then(|| {
+        df.stdout
+            .lines()
+            .filter_map(|line| serde_json::from_str::<RawDiskUsage>(line.trim()).ok())
+            .filter_map(|raw| parse_size(&raw.size))
+            .sum()
+    });
+
+    (Ok(VhdxInfo { path, distro, size_bytes, docker_used_bytes }), vec![wsl, df])
+}
+
+/// Shut down WSL and compact a .vhdx with Optimize-VHD, falling back to diskpart without Hyper-V tools
+///
+/// Stops every WSL distro (and the Docker engine with them). Returns the file size before and after.
+pub async fn compact_vhdx(path: &std::path::Path) -> (Result<(u64, u64), String>, Vec<CommandOutput>) {
+    let size = |path: &std::path::Path| std::fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+    let before = size(path);
+
+    let shutdown = run_wsl(&["--shutdown"]).await.with_danger(DangerLevel::Destructive);
+    if !shutdown.succeeded() {
+        let error = shutdown.stderr.lines().chain(shutdown.stdout.lines()).map(str::trim).find(|l| !l.is_empty());
+        let error = error.unwrap_or("wsl --shutdown failed").to_string();
+        return (Err(error), vec![shutdown]);
+    }
+    let mut outputs = vec![shutdown];
+
+    let display = path.display().to_string();
+    let optimize = run_powershell(&format!("Optimize-VHD -Path '{}' -Mode Full", display.replace('\'', "''")))
+        .await
+        .requires_admin()
+        .with_danger(DangerLevel::Destructive);
+    let optimized = optimize.succeeded();
+    outputs.push(optimize);
+
+    if !optimized {
+        // Optimize-VHD ships with the Hyper-V module, which Home editions don't have
+        let script_path = std::env::temp_dir().join("sanity-suite-compact-vhdx.txt");
+        let script = format!(
+            "select vdisk file=\"{}\"\r\nattach vdisk readonly\r\ncompact vdisk\r\ndetach vdisk\r\n",
+            display
+        );
+        if let Err(e) = std::fs::write(&script_path, script) {
+            return (Err(format!("Cannot write the diskpart script: {}", e)), outputs);
+        }
+        let diskpart = run_command("diskpart", &["/s", &script_path.to_string_lossy()])
+            .await
+            .requires_admin()
+            .with_danger(DangerLevel::Destructive);
+        let _ = std::fs::remove_file(&script_path);
+        let compacted = diskpart.succeeded();
+        outputs.push(diskpart);
+        if !compacted {
+            return (Err("Optimize-VHD and diskpart both failed - see the output for details".to_string()), outputs);
+        }
+    }
+
+    (Ok((before, size(path))), outputs)
+}
+
+/// Docker Desktop.exe under Program Files, if installed there
+pub fn docker_desktop_exe() -> Option<std::path::PathBuf> {
+    let program_files = std::env::var_os("ProgramFiles").unwrap_or_else(|| r"C:\Program Files".into());
+    let exe = std::path::Path::new(&program_files).join(r"Docker\Docker\Docker Desktop.exe");
+    exe.exists().then_some(exe)
+}
+
+/// Launch Docker Desktop (which starts the engine)
+pub fn start_docker_desktop() -> Result<String, String> {
+    let exe = docker_desktop_exe().ok_or_else(|| t!("docker.desktop-not-found"))?;
+    std::process::Command::new(&exe)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(|_| t!("docker.desktop-started", path = exe.display()))
+        .map_err(|e| t!("docker.desktop-start-failed", error = e))
+}
+
+/// Restart the Docker Desktop service and any running dependents (requires admin)
+pub async fn restart_docker_service() -> services::RestartChain {
+    let (plan, _) = services::plan_restart(DOCKER_SERVICE).await;
+    let plan = plan.unwrap_or_else(|_| services::RestartPlan {
+        name: DOCKER_SERVICE.to_string(),
+        dependents: Vec::new(),
+    });
+    services::restart_service(&plan, Duration::from_secs(services::DEFAULT_STATUS_TIMEOUT_SECS)).await
+}
+
 /// List all containers (running and stopped)
 pub async fn docker_ps_all() -> CommandOutput {
     run_command("docker", &["ps", "-a", "--format", "table {{.ID}}\t{{.Image}}\t{{.Status}}\t{{.Names}}\t{{.Ports}}"]).await
@@ -33,29 +318,397 @@ pub async fn docker_logs(container: &str, tail_lines: u32) -> CommandOutput {
     run_command("docker", &["logs", "--tail", &tail_lines.to_string(), container]).await
 }
 
-/// Get container logs with follow (last N lines)
-pub async fn docker_logs_follow(container: &str, tail_lines: u32) -> CommandOutput {
+/// Get the last N log lines with timestamps (a snapshot - see `follow_logs` for live output)
+pub async fn docker_logs_timestamps(container: &str, tail_lines: u32) -> CommandOutput {
     run_command("docker", &["logs", "--tail", &tail_lines.to_string(), "--timestamps", container]).await
 }
 
+/// How often followed log lines are handed to the caller
+const LOG_FOLLOW_BATCH_MS: u64 = 200;
+
+/// Follow container logs (`docker logs -f`) until the container stops or `cancel` is set
+///
+/// The container's stdout and stderr arrive on docker's stdout and stderr, so both are
+/// read. Lines are delivered to `on_lines` in batches every 200 ms rather than one by one,
+/// so a chatty container doesn't re-render the page per line. The returned output has no
+/// stdout - the caller owns the (capped) line buffer. The process is killed if this future
+/// is dropped.
+pub async fn follow_logs(
+    container: &str,
+    tail_lines: u32,
+    cancel: CancelFlag,
+    mut on_lines: impl FnMut(Vec<String>),
+) -> CommandOutput {
+    let tail = tail_lines.to_string();
+    let args = ["logs", "-f", "--tail", &tail, container];
+    let command_str = display_command("docker", &args);
+    let start = Instant::now();
+
+    let mut child = match hidden_command("docker")
+        .args(args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(e) => {
+            return CommandOutput {
+                command: command_str,
+                stderr: format!("Failed to execute: {}", e),
+                exit_code: -1,
+                ..Default::default()
+            };
+        }
+    };
+    let (Some(stdout), Some(stderr)) = (child.stdout.take(), child.stderr.take()) else {
+        return CommandOutput {
+            command: command_str,
+            stderr: "Failed to capture command output".to_string(),
+            exit_code: -1,
+            ..Default::default()
+        };
+    };
+
+    let mut stdout = BufReader::new(stdout).lines();
+    let mut stderr = BufReader::new(stderr).lines();
+    let (mut stdout_open, mut stderr_open) = (true, true);
+    let mut batch = Vec::new();
+    let mut tick = tokio::time::interval(tokio::time::Duration::from_millis(LOG_FOLLOW_BATCH_MS));
+    let mut cancelled = false;
+
+    while stdout_open || stderr_open {
+        tokio::select! {
+            line = stdout.next_line(), if stdout_open => match line {
+                Ok(Some(line)) => batch.push(line),
+                _ => stdout_open = false,
+            },
+            line = stderr.next_line(), if stderr_open => match line {
+                Ok(Some(line)) => batch.push(line),
+                _ => stderr_open = false,
+            },
+            _ = tick.tick() => {
+                if cancel.load(Ordering::Relaxed) {
+                    let _ = child.kill().await;
+                    cancelled = true;
+                    break;
+                }
+                if !batch.is_empty() {
+                    on_lines(std::mem::take(&mut batch));
+                }
+            }
+        }
+    }
+    if !batch.is_empty() {
+        on_lines(batch);
+    }
+
+    let exit_code = if cancelled {
+        -1
+    } else {
+        match child.wait().await {
+            Ok(status) => status.code().unwrap_or(-1),
+            Err(_) => -1,
+        }
+    };
+    CommandOutput {
+        command: command_str,
+        stderr: if cancelled { "Stopped by user\n".to_string() } else { String::new() },
+        exit_code,
+        duration_ms: start.elapsed().as_millis() as u64,
+        ..Default::default()
+    }
+}
+
 /// Pull an image
 pub async fn docker_pull(image: &str) -> CommandOutput {
-    run_command("docker", &["pull", image]).await
+    run_command("docker", &["pull", image]).await.with_danger(DangerLevel::Modifying)
+}
+
+/// Pull an image, calling `on_line` for each progress line as it arrives; setting `cancel` kills the pull
+pub async fn docker_pull_streaming(image: &str, cancel: CancelFlag, on_line: impl FnMut(&str)) -> CommandOutput {
+    run_command_streaming("docker", &["pull", image], cancel, on_line).await.with_danger(DangerLevel::Modifying)
+}
+
+/// Layer states seen so far in `docker pull` output
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PullProgress {
+    /// (layer ID, finished) in first-seen order
+    layers: Vec<(String, bool)>,
+}
+
+impl PullProgress {
+    /// Track a `<layer id>: <status>` line; other lines are ignored
+    pub fn update(&mut self, line: &str) {
+        let Some((id, status)) = line.trim().split_once(": ") else {
+            return;
+        };
+        if id.len() != 12 || !id.chars().all(|c| c.is_ascii_hexdigit()) {
+            return;
+        }
+        let finished = matches!(status.trim(), "Pull complete" | "Already exists");
+        match self.layers.iter_mut().find(|(layer, _)| layer == id) {
+            Some(layer) => layer.1 |= finished,
+            None => self.layers.push((id.to_string(), finished)),
+        }
+    }
+
+    pub fn completed(&self) -> usize {
+        self.layers.iter().filter(|(_, finished)| *finished).count()
+    }
+
+    pub fn total(&self) -> usize {
+        self.layers.len()
+    }
+}
+
+/// A registry with stored credentials in the Docker CLI config
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegistryLogin {
+    /// Registry key as stored ("https://index.docker.io/v1/", "ghcr.io")
+    pub registry: String,
+    /// Where the credentials live: a credential helper ("desktop", "wincred") or "config.json"
+    pub store: String,
+}
+
+impl RegistryLogin {
+    /// Docker Hub's legacy URL key shown as "docker.io"
+    pub fn display_name(&self) -> &str {
+        if self.registry.contains("index.docker.io") {
+            "docker.io"
+        } else {
+            self.registry.trim_start_matches("https://").trim_end_matches('/')
+        }
+    }
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct RawDockerConfig {
+    #[serde(default)]
+    auths: std::collections::HashMap<String, serde_json::Value>,
+    #[serde(default)]
+    creds_store: Option<String>,
+    #[serde(default)]
+    cred_helpers: std::collections::HashMap<String, String>,
+}
+
+/// The Docker CLI config: %DOCKER_CONFIG%\config.json, or ~/.docker/config.json
+pub fn docker_config_path() -> Option<std::path::PathBuf> {
+    match std::env::var_os("DOCKER_CONFIG") {
+        Some(dir) => Some(std::path::PathBuf::from(dir).join("config.json")),
+        None => dirs::home_dir().map(|home| home.join(".docker").join("config.json")),
+    }
+}
+
+/// Registries with stored credentials, read from the Docker CLI config (the credentials themselves are never read)
+pub fn get_login_status() -> Result<Vec<RegistryLogin>, String> {
+    let path = docker_config_path().ok_or("Cannot locate the home directory")?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let text = std::fs::read_to_string(&path).map_err(|e| format!("Cannot read {}: {}", path.display(), e))?;
+    parse_login_status(&text).map_err(|e| format!("Unexpected {}: {}", path.display(), e))
+}
+
+/// Parse config.json into the registries it has credentials for
+pub fn parse_login_status(text: &str) -> Result<Vec<RegistryLogin>, String> {
+    let raw: RawDockerConfig = serde_json::from_str(text).map_err(|e| e.to_string())?;
+    let mut logins: Vec<RegistryLogin> = raw
+        .auths
+        .iter()
+        .map(|(registry, entry)| {
+            let store = match raw.cred_helpers.get(registry).or(raw.creds_store.as_ref()) {
+                Some(helper) => helper.clone(),
+                // An "auth" field means base64 user:password sits in the file itself
+                None if entry.get("auth").is_some() => "config.json".to_string(),
+                None => "unknown".to_string(),
+            };
+            RegistryLogin { registry: registry.clone(), store }
+        })
+        .collect();
+    for (registry, helper) in &raw.cred_helpers {
+        if !raw.auths.contains_key(registry) {
+            logins.push(RegistryLogin { registry: registry.clone(), store: helper.clone() });
+        }
+    }
+    logins.sort_by(|a, b| a.display_name().cmp(b.display_name()));
+    Ok(logins)
+}
+
+/// True when `docker pull` stderr says the registry refused us for lack of (valid) credentials
+pub fn is_pull_auth_error(stderr: &str) -> bool {
+    let lower = stderr.to_lowercase();
+    [
+        "unauthorized",
+        "authentication required",
+        "no basic auth credentials",
+        "pull access denied",
+        "requested access to the resource is denied",
+        "denied:",
+    ]
+    .iter()
+    .any(|marker| lower.contains(marker))
+}
+
+/// Registry an image reference pulls from ("ghcr.io/org/app:1" -> "ghcr.io", "nginx" -> "docker.io")
+pub fn registry_of(image: &str) -> String {
+    match image.split_once('/') {
+        Some((first, _)) if first.contains(['.', ':']) || first == "localhost" => first.to_string(),
+        _ => "docker.io".to_string(),
+    }
+}
+
+/// `docker login` with the password piped to `--password-stdin`, so it never appears in a command line
+pub async fn docker_login(registry: &str, username: &str, password: &str) -> CommandOutput {
+    run_command_with_stdin("docker", &["login", registry, "--username", username, "--password-stdin"], password)
+        .await
+        .with_danger(DangerLevel::Modifying)
 }
 
 /// Restart a container
 pub async fn docker_restart(container: &str) -> CommandOutput {
-    run_command("docker", &["restart", container]).await
+    run_command("docker", &["restart", container]).await.with_danger(DangerLevel::Modifying)
 }
 
 /// Stop a container
 pub async fn docker_stop(container: &str) -> CommandOutput {
-    run_command("docker", &["stop", container]).await
+    run_command("docker", &["stop", container]).await.with_danger(DangerLevel::Modifying)
 }
 
 /// Start a container
 pub async fn docker_start(container: &str) -> CommandOutput {
-    run_command("docker", &["start", container]).await
+    run_command("docker", &["start", container]).await.with_danger(DangerLevel::Modifying)
+}
+
+/// Remove a container; `force` kills it first if it is running
+pub async fn docker_rm(container: &str, force: bool) -> CommandOutput {
+    let args: &[&str] = if force { &["rm", "-f", container] } else { &["rm", container] };
+    run_command("docker", args).await.with_danger(DangerLevel::Destructive)
+}
+
+/// Shells tried inside a container, most capable first
+const CONTAINER_SHELLS: &[&str] = &["bash", "sh"];
+
+/// Pick the shell to exec into: bash, then sh, then cmd (Windows containers)
+async fn detect_container_shell(container: &str) -> &'static str {
+    for shell in CONTAINER_SHELLS {
+        if run_command("docker", &["exec", container, "which", shell]).await.succeeded() {
+            return shell;
+        }
+    }
+    "cmd"
+}
+
+/// Open an interactive shell in a container in a new terminal window
+///
+/// Uses Windows Terminal when installed, otherwise a plain console window.
+pub async fn open_shell(container: &str) -> Result<String, String> {
+    let state = run_command("docker", &["inspect", "--format", "{{.State.Running}}", container]).await;
+    if !state.succeeded() {
+        return Err(state
+            .stderr
+            .lines()
+            .find(|l| !l.trim().is_empty())
+            .unwrap_or("docker inspect failed")
+            .trim()
+            .to_string());
+    }
+    if state.stdout.trim() != "true" {
+        return Err(t!("docker.shell-container-stopped", container = container));
+    }
+
+    let shell = detect_container_shell(container).await;
+    let exec_args = ["exec", "-it", container, shell];
+
+    let spawned = if which::which("wt").is_ok() {
+        let mut args = vec!["new-tab", "--title", container, "docker"];
+        args.extend(exec_args);
+        console_command_sync("wt").args(&args).spawn()
+    } else {
+        console_command_sync("docker").args(exec_args).spawn()
+    };
+
+    spawned
+        .map(|_| t!("docker.shell-opened", shell = shell, container = container, command = display_command("docker", &exec_args)))
+        .map_err(|e| t!("docker.shell-failed", error = e))
+}
+
+/// `State.Health` of a container with a HEALTHCHECK
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct HealthInfo {
+    /// "starting", "healthy", or "unhealthy"
+    pub status: String,
+    /// Consecutive failed probes
+    #[serde(default)]
+    pub failing_streak: u32,
+    /// Most recent probes, oldest first (Docker keeps five)
+    #[serde(default)]
+    pub log: Vec<HealthProbe>,
+}
+
+/// One healthcheck run
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct HealthProbe {
+    /// RFC 3339 timestamps
+    pub start: String,
+    #[serde(default)]
+    pub end: String,
+    pub exit_code: i64,
+    #[serde(default)]
+    pub output: String,
+}
+
+/// Healthcheck state and recent probe results; Ok(None) when the container has no HEALTHCHECK
+pub async fn get_health(container: &str) -> (Result<Option<HealthInfo>, String>, CommandOutput) {
+    let output = run_command("docker", &["inspect", "--format", "{{json .State.Health}}", container]).await;
+    let result = if output.succeeded() {
+        parse_health(&output.stdout)
+    } else {
+        let error = output.stderr.lines().map(str::trim).find(|l| !l.is_empty());
+        Err(error.unwrap_or("docker inspect failed").to_string())
+    };
+    (result, output)
+}
+
+/// Parse the JSON printed for `{{json .State.Health}}` ("null" without a healthcheck)
+pub fn parse_health(stdout: &str) -> Result<Option<HealthInfo>, String> {
+    serde_json::from_str(stdout.trim()).map_err(|e| format!("Unexpected health output: {}", e))
+}
+
+/// Restart policies accepted by `docker update --restart`
+pub const RESTART_POLICIES: &[&str] = &["no", "on-failure", "always", "unless-stopped"];
+
+/// Change a container's restart policy (takes effect without recreating it)
+pub async fn update_restart_policy(container: &str, policy: &str) -> CommandOutput {
+    let restart = format!("--restart={}", policy);
+    run_command("docker", &["update", &restart, container]).await.with_danger(DangerLevel::Modifying)
+}
+
+/// Copy a file or folder out of a container (`docker cp <container>:<src> <dest>`)
+pub async fn copy_from(container: &str, src: &str, dest: &str) -> CommandOutput {
+    let from = format!("{}:{}", container, src);
+    run_command("docker", &["cp", &from, dest]).await.with_danger(DangerLevel::Modifying)
+}
+
+/// Copy a host file or folder into a container (`docker cp <src> <container>:<dest>`)
+pub async fn copy_to(container: &str, src: &str, dest: &str) -> CommandOutput {
+    let to = format!("{}:{}", container, dest);
+    run_command("docker", &["cp", src, &to]).await.with_danger(DangerLevel::Modifying)
+}
+
+/// Turn common `docker cp` failures into something readable
+pub fn explain_copy_error(stderr: &str, container: &str, container_path: &str) -> String {
+    let first = stderr.lines().map(str::trim).find(|l| !l.is_empty()).unwrap_or("docker cp failed");
+    if first.contains("No such container:path") || first.contains("Could not find the file") {
+        format!("{} does not exist in {}", container_path, container)
+    } else if first.contains("No such container") {
+        format!("No container named {}", container)
+    } else {
+        first.to_string()
+    }
 }
 
 /// Inspect a container (JSON output)
@@ -63,46 +716,549 @@ pub async fn docker_inspect(container: &str) -> CommandOutput {
     run_command("docker", &["inspect", container]).await
 }
 
+/// A published port: container port/protocol and the host address it is bound to
+#[derive(Debug, Clone, PartialEq)]
+pub struct PortMapping {
+    /// "80/tcp"
+    pub container_port: String,
+    /// None when the port is exposed but not published
+    pub host_ip: Option<String>,
+    pub host_port: Option<u16>,
+}
+
+/// A bind mount or volume attached to a container
+#[derive(Debug, Clone, PartialEq)]
+pub struct MountInfo {
+    /// "bind", "volume", "tmpfs", ...
+    pub kind: String,
+    /// Host path for binds, volume name for volumes
+    pub source: String,
+    pub destination: String,
+    pub read_only: bool,
+}
+
+/// The parts of `docker inspect` people actually look at
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ContainerDetails {
+    pub name: String,
+    pub image: String,
+    /// "running", "exited", ...
+    pub status: String,
+    /// "healthy" / "unhealthy" / "starting"; None without a HEALTHCHECK
+    pub health: Option<String>,
+    /// Exit code of the last run (0 while running)
+    pub exit_code: i64,
+    pub started_at: String,
+    pub finished_at: String,
+    /// "no", "always", "unless-stopped", or "on-failure:N"
+    pub restart_policy: String,
+    /// The container (or its image) defines a HEALTHCHECK
+    pub has_healthcheck: bool,
+    pub ports: Vec<PortMapping>,
+    pub mounts: Vec<MountInfo>,
+    /// Environment as (name, value), in the order Docker reports it
+    pub env: Vec<(String, String)>,
+    /// Compose service the container belongs to (`com.docker.compose.service` label)
+    pub compose_service: Option<String>,
+    /// Compose file the container was created from (first of `com.docker.compose.project.config_files`)
+    pub compose_file: Option<String>,
+}
+
+/// Env var names containing these are masked until revealed
+const SECRET_ENV_MARKERS: &[&str] = &["PASSWORD", "SECRET", "TOKEN", "KEY"];
+
+/// True when an env var's value should be hidden by default
+pub fn is_secret_env(name: &str) -> bool {
+    let upper = name.to_uppercase();
+    SECRET_ENV_MARKERS.iter().any(|m| upper.contains(m))
+}
+
+/// One element of `docker inspect <container>`
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawInspect {
+    name: String,
+    config: RawInspectConfig,
+    state: RawInspectState,
+    host_config: RawHostConfig,
+    network_settings: RawNetworkSettings,
+    #[serde(default)]
+    mounts: Option<Vec<RawMount>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawInspectConfig {
+    image: String,
+    #[serde(default)]
+    env: Option<Vec<String>>,
+    #[serde(default)]
+    healthcheck: Option<RawHealthcheck>,
+    #[serde(default)]
+    labels: Option<std::collections::HashMap<String, String>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawHealthcheck {
+    #[serde(default)]
+    test: Vec<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawInspectState {
+    status: String,
+    exit_code: i64,
+    #[serde(default)]
+    started_at: String,
+    #[serde(default)]
+    finished_at: String,
+    #[serde(default)]
+    health: Option<RawHealth>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawHealth {
+    status: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawHostConfig {
+    #[serde(default)]
+    restart_policy: Option<RawRestartPolicy>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawRestartPolicy {
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    maximum_retry_count: u32,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawNetworkSettings {
+    #[serde(default)]
+    ports: Option<std::collections::HashMap<String, Option<Vec<RawPortBinding>>>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawPortBinding {
+    #[serde(default)]
+    host_ip: String,
+    #[serde(default)]
+    host_port: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawMount {
+    #[serde(rename = "Type")]
+    kind: String,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    source: String,
+    destination: String,
+    #[serde(rename = "RW", default)]
+    rw: bool,
+}
+
+/// Inspect a container and pull out ports, mounts, env, restart policy, and health
+pub async fn inspect_container_parsed(container: &str) -> (Result<ContainerDetails, String>, CommandOutput) {
+    let output = docker_inspect(container).await;
+    let result = if output.succeeded() {
+        parse_container_inspect(&output.stdout)
+    } else {
+        let error = output.stderr.lines().map(str::trim).find(|l| !l.is_empty());
+        Err(error.unwrap_or("docker inspect failed").to_string())
+    };
+    (result, output)
+}
+
+/// Parse the JSON array printed by `docker inspect <container>`
+pub fn parse_container_inspect(stdout: &str) -> Result<ContainerDetails, String> {
+    let raw: Vec<RawInspect> =
+        serde_json::from_str(stdout.trim()).map_err(|e| format!("Unexpected docker inspect output: {}", e))?;
+    let mut raw = raw.into_iter().next().ok_or("docker inspect returned no containers")?;
+
+    let mut ports: Vec<PortMapping> = Vec::new();
+    for (container_port, bindings) in raw.network_settings.ports.unwrap_or_default() {
+        match bindings.filter(|b| !b.is_empty()) {
+            Some(bindings) => ports.extend(bindings.into_iter().map(|b| PortMapping {
+                container_port: container_port.clone(),
+                host_ip: Some(b.host_ip).filter(|ip| !ip.is_empty()),
+                host_port: b.host_port.parse().ok(),
+            })),
+            None => ports.push(PortMapping { container_port, host_ip: None, host_port: None }),
+        }
+    }
+    ports.sort_by(|a, b| {
+        let key = |p: &PortMapping| p.container_port.split('/').next().and_then(|n| n.parse::<u16>().ok());
+        key(a).cmp(&key(b)).then_with(|| a.container_port.cmp(&b.container_port)).then_with(|| a.host_ip.cmp(&b.host_ip))
+    });
+
+    let mut labels = raw.config.labels.take().unwrap_or_default();
+    let compose_file = labels
+        .remove("com.docker.compose.project.config_files")
+        .and_then(|files| files.split(',').next().map(str::trim).filter(|f| !f.is_empty()).map(String::from));
+
+    let restart_policy = match raw.host_config.restart_policy {
+        Some(p) if p.name == "on-failure" && p.maximum_retry_count > 0 => format!("on-failure:{}", p.maximum_retry_count),
+        Some(p) if !p.name.is_empty() => p.name,
+        _ => "no".to_string(),
+    };
+
+    Ok(ContainerDetails {
+        name: raw.name.trim_start_matches('/').to_string(),
+        image: raw.config.image,
+        status: raw.state.status,
+        health: raw.state.health.map(|h| h.status),
+        exit_code: raw.state.exit_code,
+        started_at: raw.state.started_at,
+        finished_at: raw.state.finished_at,
+        restart_policy,
+        // ["NONE"] disables a healthcheck inherited from the image
+        has_healthcheck: raw
+            .config
+            .healthcheck
+            .as_ref()
+            .is_some_and(|h| !h.test.is_empty() && h.test[0] != "NONE"),
+        ports,
+        mounts: raw
+            .mounts
+            .unwrap_or_default()
+            .into_iter()
+            .map(|m| MountInfo {
+                source: match (m.kind.as_str(), m.name) {
+                    ("volume", Some(name)) => name,
+                    _ => m.source,
+                },
+                kind: m.kind,
+                destination: m.destination,
+                read_only: !m.rw,
+            })
+            .collect(),
+        env: raw
+            .config
+            .env
+            .unwrap_or_default()
+            .into_iter()
+            .map(|e| match e.split_once('=') {
+                Some((name, value)) => (name.to_string(), value.to_string()),
+                None => (e, String::new()),
+            })
+            .collect(),
+        compose_service: labels.remove("com.docker.compose.service"),
+        compose_file,
+    })
+}
+
 /// List Docker images
 pub async fn docker_images() -> CommandOutput {
     run_command("docker", &["images", "--format", "table {{.Repository}}\t{{.Tag}}\t{{.Size}}\t{{.CreatedSince}}"]).await
 }
 
+/// An image from `docker images`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ImageInfo {
+    /// Short image ID
+    pub id: String,
+    /// "<none>" for dangling images
+    pub repository: String,
+    pub tag: String,
+    /// Size as docker prints it ("187MB")
+    pub size: String,
+    /// Size in bytes, for sorting and totals
+    pub size_bytes: u64,
+    /// "2 weeks ago"
+    pub created_since: String,
+    /// "2024-05-01 10:12:44 +0000 UTC" - sorts chronologically as text
+    pub created_at: String,
+}
+
+impl ImageInfo {
+    /// Untagged layers left behind by rebuilds; what `docker image prune` removes
+    pub fn is_dangling(&self) -> bool {
+        self.repository == "<none>" && self.tag == "<none>"
+    }
+
+    /// What to pass to `docker rmi`: repo:tag when tagged, the ID otherwise
+    pub fn reference(&self) -> String {
+        if self.repository == "<none>" || self.tag == "<none>" {
+            self.id.clone()
+        } else {
+            format!("{}:{}", self.repository, self.tag)
+        }
+    }
+}
+
+/// One line of `docker images --format "{{json .}}"`
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawImage {
+    #[serde(rename = "ID")]
+    id: String,
+    repository: String,
+    tag: String,
+    size: String,
+    #[serde(default)]
+    created_since: String,
+    #[serde(default)]
+    created_at: String,
+}
+
+/// List images, or only dangling ones (`-f dangling=true`)
+pub async fn list_images(dangling_only: bool) -> (Result<Vec<ImageInfo>, String>, CommandOutput) {
+    let mut args = vec!["images", "--format", "{{json .}}"];
+    if dangling_only {
+        args.extend(["-f", "dangling=true"]);
+    }
+    let output = run_command("docker", &args).await;
+    if !output.succeeded() {
+        let error = output.stderr.lines().map(str::trim).find(|l| !l.is_empty()).unwrap_or("docker images failed");
+        return (Err(error.to_string()), output);
+    }
+    (Ok(parse_image_lines(&output.stdout)), output)
+}
+
+/// Parse `docker images --format "{{json .}}"` output, one JSON object per line
+pub fn parse_image_lines(stdout: &str) -> Vec<ImageInfo> {
+    stdout
+        .lines()
+        .filter_map(|line| serde_json::from_str::<RawImage>(line.trim()).ok())
+        .map(|raw| ImageInfo {
+            size_bytes: parse_size(&raw.size).unwrap_or(0),
+            id: raw.id.trim_start_matches("sha256:").chars().take(12).collect(),
+            repository: raw.repository,
+            tag: raw.tag,
+            size: raw.size,
+            created_since: raw.created_since,
+            created_at: raw.created_at,
+        })
+        .collect()
+}
+
+/// Upper bound of what `docker image prune` frees (shared layers make the real number smaller)
+pub fn dangling_size_bytes(images: &[ImageInfo]) -> u64 {
+    images.iter().filter(|i| i.is_dangling()).map(|i| i.size_bytes).sum()
+}
+
+/// Remove an image; `force` also untags it from stopped containers that use it
+pub async fn remove_image(reference: &str, force: bool) -> CommandOutput {
+    let args: &[&str] = if force { &["rmi", "-f", reference] } else { &["rmi", reference] };
+    run_command("docker", args).await.with_danger(DangerLevel::Destructive)
+}
+
+/// True when `docker rmi` failed because a stopped container uses the image and `-f` would get past it
+///
+/// Images used by running containers fail with "(cannot be forced)" instead.
+pub fn image_remove_needs_force(stderr: &str) -> bool {
+    stderr.contains("conflict: unable to") && stderr.contains("(must force)")
+}
+
 /// Prune unused images
 pub async fn docker_image_prune() -> CommandOutput {
-    run_command("docker", &["image", "prune", "-f"]).await
+    run_command("docker", &["image", "prune", "-f"]).await.with_danger(DangerLevel::Destructive)
 }
 
-/// Prune entire system (containers, images, networks, cache)
-pub async fn docker_system_prune() -> CommandOutput {
-    run_command("docker", &["system", "prune", "-f"]).await
+/// Prune entire system (containers, images, networks, cache), plus unused volumes when `volumes` is set
+pub async fn docker_system_prune(volumes: bool) -> CommandOutput {
+    let args: &[&str] = if volumes { &["system", "prune", "-f", "--volumes"] } else { &["system", "prune", "-f"] };
+    run_command("docker", args).await.with_danger(DangerLevel::Destructive)
 }
 
-/// Docker Compose pull (in current directory or specified path)
-pub async fn docker_compose_pull(path: Option<&str>) -> CommandOutput {
+/// What `docker system prune` would remove right now
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PrunePreview {
+    /// Names of exited/created/dead containers
+    pub stopped_containers: Vec<String>,
+    pub dangling_images: usize,
+    /// Upper bound of the space dangling images free
+    pub dangling_image_bytes: u64,
+    /// Custom networks with no running container (after the stopped ones are removed)
+    pub unused_networks: Vec<String>,
+    /// Reclaimable build cache as `docker system df` prints it ("1.2GB")
+    pub build_cache: String,
+    /// Reclaimable volume space, only freed with `--volumes`
+    pub volumes_reclaimable: String,
+}
+
+/// One line of `docker system df --format "{{json .}}"`
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawDiskUsage {
+    #[serde(rename = "Type")]
+    kind: String,
+    #[serde(default)]
+    size: String,
+    reclaimable: String,
+}
+
+/// Reclaimable space per `docker system df` row ("Build Cache" -> "1.2GB"), without the percentage
+pub fn parse_df_reclaimable(stdout: &str) -> std::collections::HashMap<String, String> {
+    stdout
+        .lines()
+        .filter_map(|line| serde_json::from_str::<RawDiskUsage>(line.trim()).ok())
+        .map(|raw| {
+            let reclaimable = raw.reclaimable.split(" (").next().unwrap_or_default().trim().to_string();
+            (raw.kind, reclaimable)
+        })
+        .collect()
+}
+
+/// Build cache size and how much of it is reclaimable, as docker prints them ("21.4GB", "20.1GB")
+pub async fn build_cache_usage() -> (Result<(String, String), String>, CommandOutput) {
+    let output = run_command("docker", &["system", "df", "--format", "{{json .}}"]).await;
+    if !output.succeeded() {
+        let error = output.stderr.lines().map(str::trim).find(|l| !l.is_empty()).unwrap_or("docker system df failed");
+        return (Err(error.to_string()), output);
+    }
+    let usage = output
+        .stdout
+        .lines()
+        .filter_map(|line| serde_json::from_str::<RawDiskUsage>(line.trim()).ok())
+        .find(|raw| raw.kind == "Build Cache")
+        .map(|raw| (raw.size, raw.reclaimable.split(" (").next().unwrap_or_default().trim().to_string()))
+        .unwrap_or_else(|| ("0B".to_string(), "0B".to_string()));
+    (Ok(usage), output)
+}
+
+/// Remove build cache, keeping up to `keep_storage` ("2GB") of the most recently used entries
+pub async fn builder_prune(keep_storage: Option<String>) -> CommandOutput {
+    let mut args = vec!["builder", "prune", "-f"];
+    if let Some(keep) = keep_storage.as_deref() {
+        args.extend(["--keep-storage", keep]);
+    }
+    run_command("docker", &args).await.with_danger(DangerLevel::Destructive)
+}
+
+/// Dry run for `docker system prune`: stopped containers, dangling images, unused networks, build cache
+pub async fn prune_preview() -> (Result<PrunePreview, String>, Vec<CommandOutput>) {
+    let ((containers, ps), (images, images_output), (networks, network_outputs), df) = tokio::join!(
+        list_containers(),
+        list_images(true),
+        list_networks(),
+        run_command("docker", &["system", "df", "--format", "{{json .}}"]),
+    );
+    let mut outputs = vec![ps, images_output];
+    outputs.extend(network_outputs);
+    let df_ok = df.succeeded();
+    let df_error = df.stderr.lines().map(str::trim).find(|l| !l.is_empty()).unwrap_or("docker system df failed").to_string();
+    let reclaimable = parse_df_reclaimable(&df.stdout);
+    outputs.push(df);
+
+    let result = (|| {
+        let containers = containers?;
+        let images = images?;
+        let networks = networks?;
+        if !df_ok {
+            return Err(df_error);
+        }
+        let reclaimable_of = |kind: &str| reclaimable.get(kind).cloned().unwrap_or_else(|| "0B".to_string());
+        Ok(PrunePreview {
+            stopped_containers: containers
+                .iter()
+                .filter(|c| matches!(c.state.as_str(), "exited" | "created" | "dead"))
+                .map(|c| c.name().to_string())
+                .collect(),
+            dangling_images: images.len(),
+            dangling_image_bytes: dangling_size_bytes(&images),
+            unused_networks: networks.into_iter().filter(|n| n.is_prunable()).map(|n| n.name).collect(),
+            build_cache: reclaimable_of("Build Cache"),
+            volumes_reclaimable: reclaimable_of("Local Volumes"),
+        })
+    })();
+    (result, outputs)
+}
+
+/// Arguments for a `docker compose` subcommand, with `-f <path>` when a file is given
+///
+/// The path is a single argument, so spaces in it are passed through intact.
+pub fn compose_args<'a>(path: Option<&'a str>, subcommand: &[&'a str]) -> Vec<&'a str> {
+    let mut args = vec!["compose"];
     if let Some(p) = path {
-        run_command("docker", &["compose", "-f", p, "pull"]).await
-    } else {
-        run_command("docker", &["compose", "pull"]).await
+        args.extend(["-f", p]);
+    }
+    args.extend(subcommand);
+    args
+}
+
+/// Result of validating a compose file with `docker compose config`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ComposeCheck {
+    pub passed: bool,
+    /// Why the file is invalid (missing file, YAML error, bad interpolation...)
+    pub error: Option<String>,
+    /// Non-fatal problems, e.g. variables missing from .env that default to blank
+    pub warnings: Vec<String>,
+}
+
+/// Validate a compose file: `config --quiet` decides pass/fail, a full `config` run collects warnings
+pub async fn compose_config_check(path: Option<&str>) -> (ComposeCheck, Vec<CommandOutput>) {
+    let quiet = run_command("docker", &compose_args(path, &["config", "--quiet"])).await;
+    if !quiet.succeeded() {
+        let error: Vec<&str> = quiet.stderr.lines().map(str::trim).filter(|l| !l.is_empty()).collect();
+        let error = if error.is_empty() { "docker compose config failed".to_string() } else { error.join("\n") };
+        let check = ComposeCheck { passed: false, error: Some(error), warnings: compose_warnings(&quiet.stderr) };
+        return (check, vec![quiet]);
     }
+
+    let full = run_command("docker", &compose_args(path, &["config"])).await;
+    let check = ComposeCheck { passed: true, error: None, warnings: compose_warnings(&full.stderr) };
+    (check, vec![quiet, full])
+}
+
+/// Warning messages from compose's stderr (`WARN[0000] ...` or `level=warning msg="..."`)
+pub fn compose_warnings(stderr: &str) -> Vec<String> {
+    stderr
+        .lines()
+        .map(str::trim)
+        .filter_map(|line| {
+            if let Some(rest) = line.strip_prefix("WARN[") {
+                rest.split_once("] ").map(|(_, msg)| msg.trim().to_string())
+            } else if line.contains("level=warning") {
+                let msg = line.split_once("msg=").map(|(_, m)| m).unwrap_or(line);
+                Some(msg.trim().trim_matches('"').replace("\\\"", "\""))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Docker Compose pull (in current directory or specified path)
+pub async fn docker_compose_pull(path: Option<&str>) -> CommandOutput {
+    run_command("docker", &compose_args(path, &["pull"])).await.with_danger(DangerLevel::Modifying)
 }
 
 /// Docker Compose up -d (in current directory or specified path)
 pub async fn docker_compose_up(path: Option<&str>) -> CommandOutput {
-    if let Some(p) = path {
-        run_command("docker", &["compose", "-f", p, "up", "-d"]).await
-    } else {
-        run_command("docker", &["compose", "up", "-d"]).await
-    }
+    run_command("docker", &compose_args(path, &["up", "-d"])).await.with_danger(DangerLevel::Modifying)
 }
 
 /// Docker Compose down (in current directory or specified path)
 pub async fn docker_compose_down(path: Option<&str>) -> CommandOutput {
-    if let Some(p) = path {
-        run_command("docker", &["compose", "-f", p, "down"]).await
-    } else {
-        run_command("docker", &["compose", "down"]).await
-    }
+    run_command("docker", &compose_args(path, &["down"])).await.with_danger(DangerLevel::Modifying)
+}
+
+/// Docker Compose ps -a: the project's services and their state
+pub async fn docker_compose_ps(path: Option<&str>) -> CommandOutput {
+    run_command("docker", &compose_args(path, &["ps", "-a"])).await
+}
+
+/// Docker Compose logs for one service (or all when `service` is None), last N lines
+pub async fn docker_compose_logs(path: Option<&str>, service: Option<&str>, tail_lines: u32) -> CommandOutput {
+    let tail = tail_lines.to_string();
+    let mut subcommand = vec!["logs", "--no-color", "--timestamps", "--tail", &tail];
+    subcommand.extend(service);
+    run_command("docker", &compose_args(path, &subcommand)).await
 }
 
 /// Docker Compose pull and up (force refresh)
@@ -112,20 +1268,835 @@ pub async fn docker_compose_refresh(path: Option<&str>) -> Vec<CommandOutput> {
     vec![pull, up]
 }
 
-/// Get container names for dropdown
-pub async fn get_container_names() -> Vec<String> {
-    let output = run_command("docker", &["ps", "-a", "--format", "{{.Names}}"]).await;
-    if output.exit_code == 0 {
-        output.stdout
-            .lines()
-            .map(|s| s.trim().to_string())
+/// Docker Compose up -d --force-recreate for one service, so it picks up compose file changes
+pub async fn docker_compose_recreate(path: Option<&str>, service: &str) -> CommandOutput {
+    run_command("docker", &compose_args(path, &["up", "-d", "--force-recreate", service]))
+        .await
+        .with_danger(DangerLevel::Modifying)
+}
+
+/// How an env var differs between a running container and its compose service
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnvChange {
+    /// Declared in the compose file but missing from the container
+    Added,
+    /// Set in the container but no longer declared in the compose file
+    Removed,
+    /// Declared in both with different values
+    Changed,
+}
+
+impl EnvChange {
+    /// Message key of the badge label
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            EnvChange::Added => "docker.env-change.added",
+            EnvChange::Removed => "docker.env-change.removed",
+            EnvChange::Changed => "docker.env-change.changed",
+        }
+    }
+}
+
+/// One env var that differs; values of secret-looking names are already masked
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnvDiff {
+    pub name: String,
+    pub change: EnvChange,
+    /// Value in the running container (None for `Added`)
+    pub container_value: Option<String>,
+    /// Value the compose file declares (None for `Removed`)
+    pub compose_value: Option<String>,
+}
+
+/// Env drift between a container and the compose service it was created from
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnvDrift {
+    pub container: String,
+    pub service: String,
+    /// Compose file that was compared (None = compose's default lookup in the current directory)
+    pub compose_path: Option<String>,
+    pub diffs: Vec<EnvDiff>,
+}
+
+const MASKED_VALUE: &str = "••••••••";
+
+/// Compare a container's env with what `docker compose config` declares for its service
+///
+/// Uses `compose_path` when given, otherwise the compose file recorded in the container's labels.
+/// Variables baked into the image (PATH, etc.) are ignored unless compose overrides them.
+pub async fn env_drift(container: &str, compose_path: Option<&str>) -> (Result<EnvDrift, String>, Vec<CommandOutput>) {
+    let (details, inspect) = inspect_container_parsed(container).await;
+    let mut outputs = vec![inspect];
+    let details = match details {
+        Ok(d) => d,
+        Err(e) => return (Err(e), outputs),
+    };
+    let Some(service) = details.compose_service.clone() else {
+        return (Err(format!("{} was not created by docker compose", details.name)), outputs);
+    };
+    let compose_path = compose_path.map(String::from).or(details.compose_file.clone());
+
+    let config = run_command("docker", &compose_args(compose_path.as_deref(), &["config", "--format", "json"])).await;
+    let compose_env = if config.succeeded() {
+        parse_compose_service_env(&config.stdout, &service)
+    } else {
+        let error = config.stderr.lines().map(str::trim).find(|l| !l.is_empty());
+        Err(error.unwrap_or("docker compose config failed").to_string())
+    };
+    outputs.push(config);
+    let compose_env = match compose_env {
+        Ok(env) => env,
+        Err(e) => return (Err(e), outputs),
+    };
+
+    // A failed image lookup only means image defaults show up as removed
+    let image = run_command("docker", &["image", "inspect", "--format", "{{json .Config.Env}}", &details.image]).await;
+    let image_env: Vec<String> = serde_json::from_str::<Option<Vec<String>>>(image.stdout.trim())
+        .ok()
+        .flatten()
+        .unwrap_or_default();
+    outputs.push(image);
+
+    let drift = EnvDrift {
+        container: details.name.clone(),
+        service,
+        compose_path,
+        diffs: diff_env(&details.env, &compose_env, &image_env),
+    };
+    (Ok(drift), outputs)
+}
+
+/// The `environment` of one service in `docker compose config --format json` output
+///
+/// Variables declared without a value (passed through from the shell) come back as None.
+pub fn parse_compose_service_env(stdout: &str, service: &str) -> Result<Vec<(String, Option<String>)>, String> {
+    let config: serde_json::Value =
+        serde_json::from_str(stdout.trim()).map_err(|e| format!("Unexpected docker compose config output: {}", e))?;
+    let service_config = config
+        .get("services")
+        .and_then(|s| s.get(service))
+        .ok_or_else(|| format!("Service {} is not in the compose file", service))?;
+
+    let Some(environment) = service_config.get("environment").and_then(|e| e.as_object()) else {
+        return Ok(Vec::new());
+    };
+    Ok(environment
+        .iter()
+        .map(|(name, value)| {
+            let value = match value {
+                serde_json::Value::Null => None,
+                serde_json::Value::String(s) => Some(s.clone()),
+                other => Some(other.to_string()),
+            };
+            (name.clone(), value)
+        })
+        .collect())
+}
+
+/// Diff container env against compose env, skipping vars the container got from its image
+pub fn diff_env(container_env: &[(String, String)], compose_env: &[(String, Option<String>)], image_env: &[String]) -> Vec<EnvDiff> {
+    let mask = |name: &str, value: &str| if is_secret_env(name) { MASKED_VALUE.to_string() } else { value.to_string() };
+    let from_image = |name: &str, value: &str| {
+        image_env.iter().any(|e| e.split_once('=').map(|(n, v)| n == name && v == value).unwrap_or(e == name))
+    };
+
+    let mut diffs = Vec::new();
+    for (name, compose_value) in compose_env {
+        // A pass-through variable's value depends on the shell compose ran in
+        let Some(compose_value) = compose_value else { continue };
+        match container_env.iter().find(|(n, _)| n == name) {
+            None => diffs.push(EnvDiff {
+                name: name.clone(),
+                change: EnvChange::Added,
+                container_value: None,
+                compose_value: Some(mask(name, compose_value)),
+            }),
+            Some((_, value)) if value != compose_value => diffs.push(EnvDiff {
+                name: name.clone(),
+                change: EnvChange::Changed,
+                container_value: Some(mask(name, value)),
+                compose_value: Some(mask(name, compose_value)),
+            }),
+            Some(_) => {}
+        }
+    }
+    for (name, value) in container_env {
+        if compose_env.iter().any(|(n, _)| n == name) || from_image(name, value) {
+            continue;
+        }
+        diffs.push(EnvDiff {
+            name: name.clone(),
+            change: EnvChange::Removed,
+            container_value: Some(mask(name, value)),
+            compose_value: None,
+        });
+    }
+    diffs.sort_by(|a, b| a.name.cmp(&b.name));
+    diffs
+}
+
+/// A container from `docker ps -a`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ContainerInfo {
+    /// Short container ID
+    pub id: String,
+    pub image: String,
+    /// Container names (usually one)
+    pub names: Vec<String>,
+    /// Human-readable status ("Up 3 hours", "Exited (0) 2 days ago")
+    pub status: String,
+    /// Machine state ("running", "exited", "paused", "created", ...)
+    pub state: String,
+    /// Port mappings ("0.0.0.0:8080->80/tcp"), one per entry
+    pub ports: Vec<String>,
+}
+
+impl ContainerInfo {
+    /// Name used to address the container in docker commands
+    pub fn name(&self) -> &str {
+        self.names.first().map(String::as_str).unwrap_or(&self.id)
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.state == "running"
+    }
+
+    /// HEALTHCHECK state from the status text ("Up 5 minutes (unhealthy)"); None without a healthcheck
+    pub fn health(&self) -> Option<&'static str> {
+        if self.status.contains("(unhealthy)") {
+            Some("unhealthy")
+        } else if self.status.contains("(healthy)") {
+            Some("healthy")
+        } else if self.status.contains("(health: starting)") {
+            Some("starting")
+        } else {
+            None
+        }
+    }
+}
+
+/// One line of `docker ps --format "{{json .}}"`
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawContainer {
+    #[serde(rename = "ID")]
+    id: String,
+    image: String,
+    names: String,
+    status: String,
+    /// Missing before Docker 20.10
+    #[serde(default)]
+    state: String,
+    ports: String,
+}
+
+/// List all containers (running and stopped) with their state and port mappings
+pub async fn list_containers() -> (Result<Vec<ContainerInfo>, String>, CommandOutput) {
+    let output = run_command("docker", &["ps", "-a", "--format", "{{json .}}"]).await;
+    if !output.succeeded() {
+        let error = output.stderr.lines().map(str::trim).find(|l| !l.is_empty()).unwrap_or("docker ps failed");
+        return (Err(error.to_string()), output);
+    }
+    (Ok(parse_container_lines(&output.stdout)), output)
+}
+
+/// How often `watch_container_exit` polls `docker ps`
+pub const EXIT_WATCH_POLL: Duration = Duration::from_secs(15);
+
+/// Log lines saved when a watched container exits
+const EXIT_CAPTURE_TAIL: &str = "2000";
+
+/// Wait for a container to go from running to exited, then capture its logs and final state
+///
+/// Polls `docker ps` every `EXIT_WATCH_POLL`; a container that isn't running yet is
+/// watched once it starts. Writes a timestamped file under `on_exit_dir` and returns
+/// its path, or None when `cancel` is set first. Docker being briefly unreachable
+/// (engine restart) doesn't end the watch; the container being removed does.
+pub async fn watch_container_exit(
+    container: &str,
+    on_exit_dir: &std::path::Path,
+    cancel: CancelFlag,
+) -> Result<Option<std::path::PathBuf>, String> {
+    let mut was_running = false;
+    loop {
+        if cancel.load(Ordering::Relaxed) {
+            return Ok(None);
+        }
+        if let (Ok(containers), _) = list_containers().await {
+            let Some(info) = containers.iter().find(|c| c.name() == container) else {
+                return Err(format!("{} was removed before it could be captured", container));
+            };
+            match info.state.as_str() {
+                "running" | "restarting" | "paused" => was_running = true,
+                "exited" | "dead" if was_running => return capture_container_exit(container, on_exit_dir).await.map(Some),
+                _ => {}
+            }
+        }
+
+        let wait_until = Instant::now() + EXIT_WATCH_POLL;
+        while Instant::now() < wait_until {
+            if cancel.load(Ordering::Relaxed) {
+                return Ok(None);
+            }
+            tokio::time::sleep(Duration::from_millis(500)).await;
+        }
+    }
+}
+
+/// Write the last logs and `.State` of an exited container to `<dir>/<container>-<timestamp>.log`
+pub async fn capture_container_exit(container: &str, dir: &std::path::Path) -> Result<std::path::PathBuf, String> {
+    let captured_at = chrono::Local::now();
+    let state = run_command("docker", &["inspect", "--format", "{{json .State}}", container]).await;
+    let logs = run_command("docker", &["logs", "--tail", EXIT_CAPTURE_TAIL, "--timestamps", container]).await;
+
+    let state_json = match serde_json::from_str::<serde_json::Value>(state.stdout.trim()) {
+        Ok(value) => serde_json::to_string_pretty(&value).unwrap_or_else(|_| state.stdout.trim().to_string()),
+        Err(_) => format!("(docker inspect failed: {})", state.stderr.trim()),
+    };
+
+    // The container's stdout and stderr arrive on separate pipes; timestamps put them back in order
+    let mut lines: Vec<String> = logs.stdout.lines().map(String::from).collect();
+    lines.extend(logs.stderr.lines().map(|l| l.replacen(' ', " [stderr] ", 1)));
+    lines.sort_by(|a, b| a.split(' ').next().cmp(&b.split(' ').next()));
+
+    let mut content = format!(
+        "Container: {}\nCaptured: {}\n\n== docker inspect --format '{{{{json .State}}}}' ==\n{}\n\n== docker logs --tail {} --timestamps ({} lines) ==\n",
+        container,
+        captured_at.to_rfc3339(),
+        state_json,
+        EXIT_CAPTURE_TAIL,
+        lines.len()
+    );
+    for line in &lines {
+        content.push_str(line);
+        content.push('\n');
+    }
+
+    std::fs::create_dir_all(dir).map_err(|e| format!("Cannot create {}: {}", dir.display(), e))?;
+    let path = dir.join(format!("{}-{}.log", container, captured_at.format("%Y%m%d-%H%M%S")));
+    std::fs::write(&path, content).map_err(|e| format!("Cannot write {}: {}", path.display(), e))?;
+    Ok(path)
+}
+
+/// Parse `docker ps --format "{{json .}}"` output, one JSON object per line
+///
+/// Names and Ports are comma-separated lists in Docker's JSON; lines that don't parse
+/// (warnings printed to stdout) are skipped.
+pub fn parse_container_lines(stdout: &str) -> Vec<ContainerInfo> {
+    let split = |list: &str| -> Vec<String> {
+        list.split(',')
+            .map(str::trim)
             .filter(|s| !s.is_empty())
+            .map(str::to_string)
             .collect()
+    };
+
+    stdout
+        .lines()
+        .filter_map(|line| serde_json::from_str::<RawContainer>(line.trim()).ok())
+        .map(|raw| {
+            // Older daemons have no State field; derive it from the status text
+            let state = if raw.state.is_empty() {
+                if raw.status.starts_with("Up") { "running" } else { "exited" }.to_string()
+            } else {
+                raw.state.to_lowercase()
+            };
+            ContainerInfo {
+                id: raw.id,
+                image: raw.image,
+                names: split(&raw.names),
+                status: raw.status,
+                state,
+                ports: split(&raw.ports),
+            }
+        })
+        .collect()
+}
+
+/// Resource usage snapshot for one running container (`docker stats`)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ContainerStats {
+    pub id: String,
+    pub name: String,
+    pub cpu_percent: f64,
+    pub mem_percent: f64,
+    /// Memory in use, as docker prints it ("12.3MiB")
+    pub mem_usage: String,
+    /// Memory limit ("7.6GiB")
+    pub mem_limit: String,
+    /// Memory in use in bytes, for sorting
+    pub mem_bytes: u64,
+    /// Network received / sent ("1.2kB / 0B")
+    pub net_io: String,
+    /// Block device read / written
+    pub block_io: String,
+    pub pids: u32,
+}
+
+/// One line of `docker stats --format "{{json .}}"`
+#[derive(Deserialize)]
+struct RawStats {
+    #[serde(rename = "ID")]
+    id: String,
+    #[serde(rename = "Name")]
+    name: String,
+    #[serde(rename = "CPUPerc")]
+    cpu_perc: String,
+    #[serde(rename = "MemPerc")]
+    mem_perc: String,
+    #[serde(rename = "MemUsage")]
+    mem_usage: String,
+    #[serde(rename = "NetIO")]
+    net_io: String,
+    #[serde(rename = "BlockIO")]
+    block_io: String,
+    #[serde(rename = "PIDs", default)]
+    pids: String,
+}
+
+/// One-shot resource usage of all running containers
+pub async fn container_stats() -> (Result<Vec<ContainerStats>, String>, CommandOutput) {
+    let output = run_command("docker", &["stats", "--no-stream", "--format", "{{json .}}"]).await;
+    if !output.succeeded() {
+        let error = output.stderr.lines().map(str::trim).find(|l| !l.is_empty()).unwrap_or("docker stats failed");
+        return (Err(error.to_string()), output);
+    }
+    (Ok(parse_stats_lines(&output.stdout)), output)
+}
+
+/// Parse `docker stats --format "{{json .}}"` output, one JSON object per line
+pub fn parse_stats_lines(stdout: &str) -> Vec<ContainerStats> {
+    let percent = |value: &str| value.trim().trim_end_matches('%').parse::<f64>().unwrap_or(0.0);
+
+    stdout
+        .lines()
+        .filter_map(|line| serde_json::from_str::<RawStats>(line.trim()).ok())
+        .map(|raw| {
+            let (usage, limit) = raw.mem_usage.split_once('/').unwrap_or((&raw.mem_usage, ""));
+            ContainerStats {
+                id: raw.id,
+                name: raw.name,
+                cpu_percent: percent(&raw.cpu_perc),
+                mem_percent: percent(&raw.mem_perc),
+                mem_usage: usage.trim().to_string(),
+                mem_limit: limit.trim().to_string(),
+                mem_bytes: parse_size(usage).unwrap_or(0),
+                net_io: raw.net_io,
+                block_io: raw.block_io,
+                pids: raw.pids.trim().parse().unwrap_or(0),
+            }
+        })
+        .collect()
+}
+
+/// Parse a docker size ("12.3MiB", "1.2kB", "0B") into bytes
+///
+/// Docker mixes binary (KiB, MiB) and decimal (kB, MB) units depending on the column.
+pub fn parse_size(size: &str) -> Option<u64> {
+    let size = size.trim();
+    let split = size.find(|c: char| c.is_ascii_alphabetic())?;
+    let (number, unit) = size.split_at(split);
+    let number: f64 = number.trim().parse().ok()?;
+    let multiplier: f64 = match unit {
+        "B" => 1.0,
+        "kB" | "KB" => 1e3,
+        "MB" => 1e6,
+        "GB" => 1e9,
+        "TB" => 1e12,
+        "KiB" => 1024.0,
+        "MiB" => 1024.0 * 1024.0,
+        "GiB" => 1024.0 * 1024.0 * 1024.0,
+        "TiB" => 1024.0 * 1024.0 * 1024.0 * 1024.0,
+        _ => return None,
+    };
+    Some((number * multiplier) as u64)
+}
+
+/// Format bytes the way docker does (decimal units: "187MB", "1.2GB")
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "kB", "MB", "GB", "TB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1000.0 && unit < UNITS.len() - 1 {
+        value /= 1000.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{}{}", bytes, UNITS[0])
+    } else {
+        format!("{:.1}{}", value, UNITS[unit])
+    }
+}
+
+/// Total bytes of an "in / out" I/O pair ("1.2kB / 648B"), for sorting
+pub fn io_total_bytes(io: &str) -> u64 {
+    io.split('/').filter_map(parse_size).sum()
+}
+
+/// A named or anonymous volume from `docker volume ls`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VolumeInfo {
+    pub name: String,
+    pub driver: String,
+    /// Size as docker prints it; None when `docker system df -v` didn't report it
+    pub size: Option<String>,
+    /// Size in bytes, for sorting
+    pub size_bytes: u64,
+    /// Number of containers referencing the volume
+    pub links: u32,
+}
+
+/// One line of `docker volume ls --format "{{json .}}"`
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawVolume {
+    name: String,
+    driver: String,
+}
+
+/// List volumes with their size and link count, largest first
+///
+/// Sizes come from `docker system df -v`, which can take a few seconds on large volumes.
+pub async fn list_volumes() -> (Result<Vec<VolumeInfo>, String>, Vec<CommandOutput>) {
+    let ls = run_command("docker", &["volume", "ls", "--format", "{{json .}}"]).await;
+    if !ls.succeeded() {
+        let error = ls.stderr.lines().map(str::trim).find(|l| !l.is_empty()).unwrap_or("docker volume ls failed");
+        return (Err(error.to_string()), vec![ls]);
+    }
+    let df = run_command("docker", &["system", "df", "-v"]).await;
+    let usage = parse_df_volumes(&df.stdout);
+
+    let mut volumes: Vec<VolumeInfo> = ls
+        .stdout
+        .lines()
+        .filter_map(|line| serde_json::from_str::<RawVolume>(line.trim()).ok())
+        .map(|raw| {
+            let (links, size) = usage.get(&raw.name).cloned().unzip();
+            VolumeInfo {
+                size_bytes: size.as_deref().and_then(parse_size).unwrap_or(0),
+                size,
+                links: links.unwrap_or(0),
+                name: raw.name,
+                driver: raw.driver,
+            }
+        })
+        .collect();
+    volumes.sort_by_key(|v| std::cmp::Reverse(v.size_bytes));
+    (Ok(volumes), vec![ls, df])
+}
+
+/// Link count and size per volume from the "Local Volumes space usage" table of `docker system df -v`
+pub fn parse_df_volumes(stdout: &str) -> std::collections::HashMap<String, (u32, String)> {
+    stdout
+        .lines()
+        .skip_while(|line| !line.trim_start().starts_with("VOLUME NAME"))
+        .skip(1)
+        .take_while(|line| !line.trim().is_empty())
+        .filter_map(|line| {
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            match tokens.as_slice() {
+                [name, links, .., size] => Some((name.to_string(), (links.parse().unwrap_or(0), size.to_string()))),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Show a volume's driver, mountpoint, labels, and options (JSON)
+pub async fn inspect_volume(name: &str) -> CommandOutput {
+    run_command("docker", &["volume", "inspect", name]).await
+}
+
+/// Names of containers (running or stopped) that mount a volume
+pub async fn volume_users(name: &str) -> Vec<String> {
+    let filter = format!("volume={}", name);
+    let output = run_command("docker", &["ps", "-a", "--filter", &filter, "--format", "{{.Names}}"]).await;
+    output
+        .stdout
+        .lines()
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Remove one volume (fails if a container still uses it)
+pub async fn remove_volume(name: &str) -> CommandOutput {
+    run_command("docker", &["volume", "rm", name]).await.with_danger(DangerLevel::Destructive)
+}
+
+/// Remove volumes not used by any container
+///
+/// Docker 23+ only prunes anonymous volumes here; named volumes must be removed individually.
+pub async fn volume_prune() -> CommandOutput {
+    run_command("docker", &["volume", "prune", "-f"]).await.with_danger(DangerLevel::Destructive)
+}
+
+/// A Docker network with its subnets and attached containers
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DockerNetwork {
+    pub id: String,
+    pub name: String,
+    pub driver: String,
+    /// IPAM subnets ("172.18.0.0/16"); empty for host/none
+    pub subnets: Vec<String>,
+    /// Names of running containers attached to the network
+    pub containers: Vec<String>,
+}
+
+impl DockerNetwork {
+    /// The default bridge, host, and none networks can't be removed
+    pub fn is_builtin(&self) -> bool {
+        matches!(self.name.as_str(), "bridge" | "host" | "none" | "nat")
+    }
+
+    /// No running container is attached, so `docker network prune` may remove it
+    pub fn is_prunable(&self) -> bool {
+        !self.is_builtin() && self.containers.is_empty()
+    }
+}
+
+/// One element of `docker network inspect`
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawNetwork {
+    id: String,
+    name: String,
+    driver: String,
+    #[serde(rename = "IPAM", default)]
+    ipam: Option<RawIpam>,
+    #[serde(default)]
+    containers: Option<std::collections::HashMap<String, RawEndpoint>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawIpam {
+    #[serde(default)]
+    config: Option<Vec<RawIpamConfig>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawIpamConfig {
+    #[serde(default)]
+    subnet: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawEndpoint {
+    name: String,
+}
+
+/// List networks with driver, subnets, and attached containers
+pub async fn list_networks() -> (Result<Vec<DockerNetwork>, String>, Vec<CommandOutput>) {
+    let ls = run_command("docker", &["network", "ls", "-q"]).await;
+    if !ls.succeeded() {
+        let error = ls.stderr.lines().map(str::trim).find(|l| !l.is_empty()).unwrap_or("docker network ls failed");
+        return (Err(error.to_string()), vec![ls]);
+    }
+    let ids: Vec<&str> = ls.stdout.lines().map(str::trim).filter(|l| !l.is_empty()).collect();
+    if ids.is_empty() {
+        return (Ok(Vec::new()), vec![ls]);
+    }
+
+    let mut args = vec!["network", "inspect"];
+    args.extend(&ids);
+    let inspect = run_command("docker", &args).await;
+    let result = if inspect.succeeded() {
+        parse_network_inspect(&inspect.stdout)
     } else {
-        Vec::new()
+        let error = inspect.stderr.lines().map(str::trim).find(|l| !l.is_empty());
+        Err(error.unwrap_or("docker network inspect failed").to_string())
+    };
+    (result, vec![ls, inspect])
+}
+
+/// Parse the JSON array printed by `docker network inspect`
+pub fn parse_network_inspect(stdout: &str) -> Result<Vec<DockerNetwork>, String> {
+    let raw: Vec<RawNetwork> =
+        serde_json::from_str(stdout.trim()).map_err(|e| format!("Unexpected docker network inspect output: {}", e))?;
+    let mut networks: Vec<DockerNetwork> = raw
+        .into_iter()
+        .map(|n| {
+            let mut containers: Vec<String> =
+                n.containers.unwrap_or_default().into_values().map(|e| e.name).collect();
+            containers.sort();
+            DockerNetwork {
+                id: n.id.chars().take(12).collect(),
+                name: n.name,
+                driver: n.driver,
+                subnets: n
+                    .ipam
+                    .and_then(|i| i.config)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|c| c.subnet)
+                    .filter(|s| !s.is_empty())
+                    .collect(),
+                containers,
+            }
+        })
+        .collect();
+    networks.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(networks)
+}
+
+/// Remove networks not used by any container
+pub async fn network_prune() -> CommandOutput {
+    run_command("docker", &["network", "prune", "-f"]).await.with_danger(DangerLevel::Destructive)
+}
+
+/// Host TCP ports a container publishes, from `HostConfig.PortBindings`
+///
+/// Unlike `docker ps`, this is known while the container is stopped.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ContainerPorts {
+    pub name: String,
+    pub running: bool,
+    pub host_ports: Vec<u16>,
+}
+
+/// A published port that can't be (or isn't) bound the way Docker expects
+#[derive(Debug, Clone, PartialEq)]
+pub enum PortConflict {
+    /// A non-Docker process listens on a port a stopped container publishes
+    HostProcess { container: String, port: u16, process_name: String, pid: u32 },
+    /// A running container already publishes the port a stopped container wants
+    OtherContainer { container: String, port: u16, holder: String },
+    /// Docker holds a port no running container publishes (left over from a removed container)
+    OrphanedDockerPort { port: u16, process_name: String, pid: u32 },
+}
+
+impl PortConflict {
+    pub fn port(&self) -> u16 {
+        match self {
+            Self::HostProcess { port, .. } | Self::OtherContainer { port, .. } | Self::OrphanedDockerPort { port, .. } => *port,
+        }
     }
 }
 
+/// Processes that listen on published ports on Docker's behalf
+pub fn is_docker_process(process_name: &str) -> bool {
+    let name = process_name.to_lowercase();
+    name.contains("docker") || name.contains("vpnkit") || name.contains("containerd")
+}
+
+/// Cross-reference containers' published ports against host TCP listeners
+///
+/// `listeners` may contain any bindings; only TCP listeners are considered.
+pub fn find_port_conflicts(containers: &[ContainerPorts], listeners: &[PortBinding]) -> Vec<PortConflict> {
+    let listeners: Vec<&PortBinding> =
+        listeners.iter().filter(|b| b.is_listener && b.protocol == Protocol::Tcp).collect();
+    let published_by_running = |port: u16| {
+        containers.iter().find(|c| c.running && c.host_ports.contains(&port)).map(|c| c.name.clone())
+    };
+
+    let mut conflicts = Vec::new();
+    for container in containers.iter().filter(|c| !c.running) {
+        for &port in &container.host_ports {
+            let on_port: Vec<&&PortBinding> = listeners.iter().filter(|b| b.local_port == port).collect();
+            if let Some(holder) = published_by_running(port) {
+                conflicts.push(PortConflict::OtherContainer { container: container.name.clone(), port, holder });
+            } else if let Some(host) = on_port.iter().find(|b| !is_docker_process(&b.process_name)) {
+                conflicts.push(PortConflict::HostProcess {
+                    container: container.name.clone(),
+                    port,
+                    process_name: host.process_name.clone(),
+                    pid: host.pid,
+                });
+            }
+        }
+    }
+
+    let mut orphaned: Vec<u16> = Vec::new();
+    for binding in listeners.iter().filter(|b| is_docker_process(&b.process_name)) {
+        if orphaned.contains(&binding.local_port) || published_by_running(binding.local_port).is_some() {
+            continue;
+        }
+        orphaned.push(binding.local_port);
+        conflicts.push(PortConflict::OrphanedDockerPort {
+            port: binding.local_port,
+            process_name: binding.process_name.clone(),
+            pid: binding.pid,
+        });
+    }
+
+    conflicts.sort_by_key(|c| c.port());
+    conflicts
+}
+
+/// Published ports of every container, running or not
+pub async fn list_container_ports() -> (Result<Vec<ContainerPorts>, String>, Vec<CommandOutput>) {
+    let ps = run_command("docker", &["ps", "-aq"]).await;
+    if !ps.succeeded() {
+        let error = ps.stderr.lines().map(str::trim).find(|l| !l.is_empty()).unwrap_or("docker ps failed");
+        return (Err(error.to_string()), vec![ps]);
+    }
+    let ids: Vec<&str> = ps.stdout.lines().map(str::trim).filter(|l| !l.is_empty()).collect();
+    if ids.is_empty() {
+        return (Ok(Vec::new()), vec![ps]);
+    }
+
+    let mut args = vec!["inspect", "--format", "{{.Name}}|{{.State.Running}}|{{json .HostConfig.PortBindings}}"];
+    args.extend(&ids);
+    let inspect = run_command("docker", &args).await;
+    let result = if inspect.succeeded() {
+        Ok(parse_container_ports(&inspect.stdout))
+    } else {
+        let error = inspect.stderr.lines().map(str::trim).find(|l| !l.is_empty());
+        Err(error.unwrap_or("docker inspect failed").to_string())
+    };
+    (result, vec![ps, inspect])
+}
+
+/// Parse `name|running|{port bindings json}` lines; UDP and unassigned host ports are skipped
+pub fn parse_container_ports(stdout: &str) -> Vec<ContainerPorts> {
+    stdout
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.trim().splitn(3, '|');
+            let name = parts.next()?.trim_start_matches('/').to_string();
+            let running = parts.next()? == "true";
+            let bindings: Option<std::collections::HashMap<String, Option<Vec<RawPortBinding>>>> =
+                serde_json::from_str(parts.next()?).ok()?;
+            let mut host_ports: Vec<u16> = bindings
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|(container_port, _)| !container_port.ends_with("/udp"))
+                .flat_map(|(_, b)| b.unwrap_or_default())
+                .filter_map(|b| b.host_port.parse().ok())
+                .collect();
+            host_ports.sort_unstable();
+            host_ports.dedup();
+            Some(ContainerPorts { name, running, host_ports })
+        })
+        .collect()
+}
+
+/// Check every container's published ports against the host's TCP listeners
+pub async fn port_conflict_scan() -> (Result<Vec<PortConflict>, String>, Vec<CommandOutput>) {
+    let (containers, outputs) = list_container_ports().await;
+    let containers = match containers {
+        Ok(c) => c,
+        Err(e) => return (Err(e), outputs),
+    };
+    let listeners = crate::system::ports::list_all_listeners().await;
+    (Ok(find_port_conflicts(&containers, &listeners.bindings)), outputs)
+}
+
+/// Get container names for dropdown
+pub async fn get_container_names() -> Vec<String> {
+    list_containers()
+        .await
+        .0
+        .unwrap_or_default()
+        .iter()
+        .map(|c| c.name().to_string())
+        .collect()
+}
+
 /// Get Docker disk usage
 pub async fn docker_disk_usage() -> CommandOutput {
     run_command("docker", &["system", "df"]).await
@@ -143,3 +2114,295 @@ pub async fn check_docker_socket() -> CommandOutput {
     // On Unix, check the socket file
     run_command("ls", &["-la", "/var/run/docker.sock"]).await
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compose_args_keeps_a_spaced_path_as_one_argument() {
+        assert_eq!(
+            compose_args(Some(r"C:\My Projects\docker-compose.yml"), &["up", "-d"]),
+            vec!["compose", "-f", r"C:\My Projects\docker-compose.yml", "up", "-d"]
+        );
+        assert_eq!(compose_args(None, &["ps"]), vec!["compose", "ps"]);
+    }
+
+    /// `docker ps -a --format "{{json .}}"` with a warning line, a linked container, and a pre-20.10 row
+    const DOCKER_PS: &str = r#"WARNING: Error loading config file: .docker/config.json: permission denied
+{"Command":"\"docker-entrypoint.s…\"","CreatedAt":"2026-10-14 09:12:03 +0200 CEST","ID":"3f4e5d6c7b8a","Image":"postgres:16","Labels":"com.docker.compose.project=shop","LocalVolumes":"1","Mounts":"shop_pgdata","Names":"shop-db-1,shop-api-1/db","Networks":"shop_default","Ports":"0.0.0.0:5432->5432/tcp, [::]:5432->5432/tcp","RunningFor":"2 days ago","Size":"63B (virtual 432MB)","State":"running","Status":"Up 2 days (healthy)"}
+{"Command":"\"/docker-entrypoint.…\"","CreatedAt":"2026-10-14 09:12:04 +0200 CEST","ID":"9a8b7c6d5e4f","Image":"nginx:alpine","Labels":"","LocalVolumes":"0","Mounts":"","Names":"shop-web-1","Networks":"shop_default","Ports":"0.0.0.0:8080->80/tcp, 0.0.0.0:8443->443/tcp, 9000/tcp","RunningFor":"2 days ago","Size":"2B (virtual 52MB)","State":"Running","Status":"Up 2 days (unhealthy)"}
+{"ID":"1b2c3d4e5f60","Image":"redis:7","Names":"cache","Ports":"","Status":"Exited (0) 3 hours ago"}
+"#;
+
+    #[test]
+    fn container_lines_split_names_and_port_lists() {
+        let containers = parse_container_lines(DOCKER_PS);
+        assert_eq!(containers.len(), 3);
+
+        let db = &containers[0];
+        assert_eq!(db.names, vec!["shop-db-1", "shop-api-1/db"]);
+        assert_eq!(db.name(), "shop-db-1");
+        assert_eq!(db.ports, vec!["0.0.0.0:5432->5432/tcp", "[::]:5432->5432/tcp"]);
+        assert_eq!(db.health(), Some("healthy"));
+
+        let web = &containers[1];
+        assert_eq!(web.ports, vec!["0.0.0.0:8080->80/tcp", "0.0.0.0:8443->443/tcp", "9000/tcp"]);
+        assert!(web.is_running());
+        assert_eq!(web.health(), Some("unhealthy"));
+    }
+
+    #[test]
+    fn container_lines_derive_state_for_old_daemons() {
+        let cache = &parse_container_lines(DOCKER_PS)[2];
+        assert_eq!(cache.state, "exited");
+        assert!(cache.ports.is_empty());
+        assert_eq!(cache.health(), None);
+        assert!(parse_container_lines("").is_empty());
+    }
+
+    /// `docker inspect shop-api-1`, trimmed to the fields that matter plus a few it ignores
+    const DOCKER_INSPECT: &str = r#"[
+    {
+        "Id": "7c1d2e3f4a5b6c7d8e9f0a1b2c3d4e5f6a7b8c9d0e1f2a3b4c5d6e7f8a9b0c1d",
+        "Created": "2026-10-14T07:12:05.123456789Z",
+        "Path": "node",
+        "Args": ["server.js"],
+        "State": {
+            "Status": "running",
+            "Running": true,
+            "Paused": false,
+            "Restarting": false,
+            "OOMKilled": false,
+            "Dead": false,
+            "Pid": 4242,
+            "ExitCode": 0,
+            "Error": "",
+            "StartedAt": "2026-10-14T07:12:06.5Z",
+            "FinishedAt": "0001-01-01T00:00:00Z",
+            "Health": {"Status": "healthy", "FailingStreak": 0, "Log": []}
+        },
+        "Name": "/shop-api-1",
+        "RestartCount": 0,
+        "HostConfig": {
+            "NetworkMode": "shop_default",
+            "RestartPolicy": {"Name": "on-failure", "MaximumRetryCount": 3}
+        },
+        "Mounts": [
+            {"Type": "bind", "Source": "C:\\Users\\dev\\shop\\api", "Destination": "/app", "Mode": "", "RW": true, "Propagation": "rprivate"},
+            {"Type": "volume", "Name": "shop_uploads", "Source": "/var/lib/docker/volumes/shop_uploads/_data", "Destination": "/data", "Driver": "local", "Mode": "z", "RW": false, "Propagation": ""}
+        ],
+        "Config": {
+            "Hostname": "7c1d2e3f4a5b",
+            "Image": "shop/api:latest",
+            "Env": ["NODE_ENV=production", "DATABASE_URL=postgres://db:5432/shop", "EMPTY"],
+            "Healthcheck": {"Test": ["CMD-SHELL", "curl -f http://localhost:3000/health"], "Interval": 30000000000},
+            "Labels": {
+                "com.docker.compose.project": "shop",
+                "com.docker.compose.project.config_files": "C:\\Users\\dev\\shop\\docker-compose.yml,C:\\Users\\dev\\shop\\docker-compose.override.yml",
+                "com.docker.compose.service": "api"
+            }
+        },
+        "NetworkSettings": {
+            "Ports": {
+                "9229/tcp": null,
+                "3000/tcp": [{"HostIp": "0.0.0.0", "HostPort": "3000"}, {"HostIp": "::", "HostPort": "3000"}],
+                "443/tcp": []
+            }
+        }
+    }
+]"#;
+
+    #[test]
+    fn inspect_payload_maps_to_details() {
+        let details = parse_container_inspect(DOCKER_INSPECT).unwrap();
+        assert_eq!(details.name, "shop-api-1");
+        assert_eq!(details.image, "shop/api:latest");
+        assert_eq!(details.status, "running");
+        assert_eq!(details.health.as_deref(), Some("healthy"));
+        assert_eq!(details.exit_code, 0);
+        assert_eq!(details.started_at, "2026-10-14T07:12:06.5Z");
+        assert_eq!(details.restart_policy, "on-failure:3");
+        assert!(details.has_healthcheck);
+        assert_eq!(details.compose_service.as_deref(), Some("api"));
+        assert_eq!(details.compose_file.as_deref(), Some(r"C:\Users\dev\shop\docker-compose.yml"));
+        assert_eq!(
+            details.env,
+            vec![
+                ("NODE_ENV".to_string(), "production".to_string()),
+                ("DATABASE_URL".to_string(), "postgres://db:5432/shop".to_string()),
+                ("EMPTY".to_string(), String::new()),
+            ]
+        );
+    }
+
+    #[test]
+    fn inspect_ports_are_sorted_and_unpublished_ports_kept() {
+        let details = parse_container_inspect(DOCKER_INSPECT).unwrap();
+        let mapping = |port: &str, ip: Option<&str>, host: Option<u16>| PortMapping {
+            container_port: port.to_string(),
+            host_ip: ip.map(String::from),
+            host_port: host,
+        };
+        assert_eq!(
+            details.ports,
+            vec![
+                mapping("443/tcp", None, None),
+                mapping("3000/tcp", Some("0.0.0.0"), Some(3000)),
+                mapping("3000/tcp", Some("::"), Some(3000)),
+                mapping("9229/tcp", None, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn inspect_mounts_use_volume_names_and_read_only_flag() {
+        let details = parse_container_inspect(DOCKER_INSPECT).unwrap();
+        assert_eq!(
+            details.mounts,
+            vec![
+                MountInfo {
+                    kind: "bind".to_string(),
+                    source: r"C:\Users\dev\shop\api".to_string(),
+                    destination: "/app".to_string(),
+                    read_only: false,
+                },
+                MountInfo {
+                    kind: "volume".to_string(),
+                    source: "shop_uploads".to_string(),
+                    destination: "/data".to_string(),
+                    read_only: true,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn inspect_without_healthcheck_or_policy_uses_defaults() {
+        let json = r#"[{"Name":"/scratch","Config":{"Image":"alpine","Healthcheck":{"Test":["NONE"]}},
+            "State":{"Status":"exited","ExitCode":137},"HostConfig":{},"NetworkSettings":{"Ports":{}}}]"#;
+        let details = parse_container_inspect(json).unwrap();
+        assert_eq!(details.health, None);
+        assert!(!details.has_healthcheck);
+        assert_eq!(details.restart_policy, "no");
+        assert_eq!(details.exit_code, 137);
+        assert!(details.ports.is_empty() && details.mounts.is_empty() && details.env.is_empty());
+        assert!(parse_container_inspect("[]").is_err());
+        assert!(parse_container_inspect("Error: No such object: scratch").is_err());
+    }
+
+    fn container(name: &str, running: bool, host_ports: &[u16]) -> ContainerPorts {
+        ContainerPorts { name: name.to_string(), running, host_ports: host_ports.to_vec() }
+    }
+
+    fn socket(port: u16, process_name: &str, pid: u32, protocol: Protocol, is_listener: bool) -> PortBinding {
+        PortBinding {
+            pid,
+            process_name: process_name.to_string(),
+            exe_path: None,
+            cmdline: None,
+            local_ip: "0.0.0.0".to_string(),
+            local_port: port,
+            protocol,
+            remote_ip: None,
+            remote_port: None,
+            state: if is_listener { "LISTEN" } else { "ESTABLISHED" }.to_string(),
+            is_listener,
+            is_loopback: false,
+            is_all_interfaces: true,
+            is_orphan: false,
+            is_system: false,
+            source: Default::default(),
+            source_detail: String::new(),
+            services: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn port_conflicts_name_the_process_or_container_in_the_way() {
+        let containers = [
+            container("web", true, &[8080]),
+            container("db", false, &[5432]),
+            container("web-old", false, &[8080]),
+            container("cache", false, &[6379]),
+        ];
+        let listeners = [
+            socket(8080, "com.docker.backend.exe", 9100, Protocol::Tcp, true),
+            socket(5432, "postgres.exe", 3120, Protocol::Tcp, true),
+            socket(6379, "redis-server.exe", 4410, Protocol::Tcp, false),
+            socket(6379, "redis-server.exe", 4410, Protocol::Udp, true),
+        ];
+        assert_eq!(
+            find_port_conflicts(&containers, &listeners),
+            vec![
+                PortConflict::HostProcess {
+                    container: "db".to_string(),
+                    port: 5432,
+                    process_name: "postgres.exe".to_string(),
+                    pid: 3120,
+                },
+                PortConflict::OtherContainer { container: "web-old".to_string(), port: 8080, holder: "web".to_string() },
+            ]
+        );
+    }
+
+    #[test]
+    fn docker_listeners_without_a_running_container_are_orphaned_once() {
+        let containers = [container("api", true, &[3000]), container("worker", false, &[9000])];
+        let listeners = [
+            socket(3000, "com.docker.backend.exe", 9100, Protocol::Tcp, true),
+            socket(9000, "com.docker.backend.exe", 9100, Protocol::Tcp, true),
+            socket(9000, "wslrelay.exe", 0, Protocol::Tcp, false),
+            socket(4000, "vpnkit.exe", 9200, Protocol::Tcp, true),
+            socket(4000, "vpnkit.exe", 9200, Protocol::Tcp, true),
+        ];
+        assert_eq!(
+            find_port_conflicts(&containers, &listeners),
+            vec![
+                PortConflict::OrphanedDockerPort { port: 4000, process_name: "vpnkit.exe".to_string(), pid: 9200 },
+                PortConflict::OrphanedDockerPort {
+                    port: 9000,
+                    process_name: "com.docker.backend.exe".to_string(),
+                    pid: 9100,
+                },
+            ]
+        );
+        assert!(find_port_conflicts(&[], &[]).is_empty());
+    }
+
+    #[test]
+    fn health_parses_healthy_state() {
+        let json = r#"{"Status":"healthy","FailingStreak":0,"Log":[{"Start":"2026-10-16T08:00:00.1Z","End":"2026-10-16T08:00:00.3Z","ExitCode":0,"Output":"ok\n"}]}"#;
+        let health = parse_health(json).unwrap().unwrap();
+        assert_eq!(health.status, "healthy");
+        assert_eq!(health.failing_streak, 0);
+        assert_eq!(
+            health.log,
+            vec![HealthProbe {
+                start: "2026-10-16T08:00:00.1Z".to_string(),
+                end: "2026-10-16T08:00:00.3Z".to_string(),
+                exit_code: 0,
+                output: "ok\n".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn health_parses_unhealthy_streak() {
+        let json = r#"{"Status":"unhealthy","FailingStreak":4,"Log":[
+            {"Start":"2026-10-16T08:00:00Z","End":"2026-10-16T08:00:30Z","ExitCode":-1,"Output":"Health check exceeded timeout (30s)"},
+            {"Start":"2026-10-16T08:01:00Z","End":"2026-10-16T08:01:01Z","ExitCode":1,"Output":"curl: (7) Failed to connect to localhost port 3000"}
+        ]}"#;
+        let health = parse_health(json).unwrap().unwrap();
+        assert_eq!(health.status, "unhealthy");
+        assert_eq!(health.failing_streak, 4);
+        assert_eq!(health.log.iter().map(|p| p.exit_code).collect::<Vec<_>>(), vec![-1, 1]);
+    }
+
+    #[test]
+    fn health_is_none_without_a_healthcheck() {
+        assert_eq!(parse_health("null\n"), Ok(None));
+        assert_eq!(parse_health(r#"{"Status":"starting"}"#).unwrap().unwrap().log, Vec::new());
+        assert!(parse_health("template parsing error").is_err());
+    }
+}
@@ -0,0 +1,167 @@
+//! System health checks
+//!
+//! Detects conditions that explain "nothing works" reports, like a pending reboot
+//! or Fast Startup making "Shut down" skip the real reboot.
+
+use crate::state::{CommandOutput, DangerLevel};
+use crate::system::command::run_command;
+use crate::system::registry::{self, Hive};
+
+/// A registry indicator that Windows is waiting on a reboot
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RebootIndicator {
+    /// Component Based Servicing (feature/servicing stack installs)
+    ComponentServicing,
+    /// Windows Update finished installing and needs a restart
+    WindowsUpdate,
+    /// Files queued for replacement on next boot
+    PendingFileRename,
+    /// Computer name changed but not yet active
+    ComputerRename,
+}
+
+impl RebootIndicator {
+    /// Message key of the short label for banners and reports
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            RebootIndicator::ComponentServicing => "reboot.component-servicing",
+            RebootIndicator::WindowsUpdate => "reboot.windows-update",
+            RebootIndicator::PendingFileRename => "reboot.pending-file-rename",
+            RebootIndicator::ComputerRename => "reboot.computer-rename",
+        }
+    }
+}
+
+const CBS_REBOOT_PENDING: &str =
+    r"SOFTWARE\Microsoft\Windows\CurrentVersion\Component Based Servicing\RebootPending";
+const WU_REBOOT_REQUIRED: &str =
+    r"SOFTWARE\Microsoft\Windows\CurrentVersion\WindowsUpdate\Auto Update\RebootRequired";
+const SESSION_MANAGER: &str = r"SYSTEM\CurrentControlSet\Control\Session Manager";
+const POWER_KEY: &str = r"SYSTEM\CurrentControlSet\Control\Session Manager\Power";
+const ACTIVE_COMPUTER_NAME: &str = r"SYSTEM\CurrentControlSet\Control\ComputerName\ActiveComputerName";
+const PENDING_COMPUTER_NAME: &str = r"SYSTEM\CurrentControlSet\Control\ComputerName\ComputerName";
+
+/// Raw registry values behind the pending-reboot indicators
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RebootSignals {
+    /// The CBS RebootPending key exists
+    pub component_servicing: bool,
+    /// The Windows Update RebootRequired key exists
+    pub windows_update: bool,
+    /// Non-empty PendingFileRenameOperations entries (none when the value is missing)
+    pub file_renames: Vec<String>,
+    /// Computer name in effect now
+    pub active_computer_name: Option<String>,
+    /// Computer name that takes effect after the reboot
+    pub pending_computer_name: Option<String>,
+}
+
+impl RebootSignals {
+    /// Read the indicator values from the registry
+    pub fn read() -> Self {
+        RebootSignals {
+            component_servicing: registry::key_exists(Hive::LocalMachine, CBS_REBOOT_PENDING),
+            windows_update: registry::key_exists(Hive::LocalMachine, WU_REBOOT_REQUIRED),
+            file_renames: registry::read_multi_string(Hive::LocalMachine, SESSION_MANAGER, "PendingFileRenameOperations")
+                .unwrap_or_default(),
+            active_computer_name: registry::read_string(Hive::LocalMachine, ACTIVE_COMPUTER_NAME, "ComputerName"),
+            pending_computer_name: registry::read_string(Hive::LocalMachine, PENDING_COMPUTER_NAME, "ComputerName"),
+        }
+    }
+
+    /// Indicators that are set, in banner order
+    pub fn indicators(&self) -> Vec<RebootIndicator> {
+        let mut indicators = Vec::new();
+
+        if self.component_servicing {
+            indicators.push(RebootIndicator::ComponentServicing);
+        }
+
+        if self.windows_update {
+            indicators.push(RebootIndicator::WindowsUpdate);
+        }
+
+        // The value often exists but empty - only count it when something is queued
+        if !self.file_renames.is_empty() {
+            indicators.push(RebootIndicator::PendingFileRename);
+        }
+
+        if let (Some(active), Some(pending)) = (&self.active_computer_name, &self.pending_computer_name) {
+            if !active.eq_ignore_ascii_case(pending) {
+                indicators.push(RebootIndicator::ComputerRename);
+            }
+        }
+
+        indicators
+    }
+}
+
+/// Check the standard pending-reboot indicators and return the ones that are set
+pub fn pending_reboot() -> Vec<RebootIndicator> {
+    RebootSignals::read().indicators()
+}
+
+/// Check whether Fast Startup (hybrid shutdown) is enabled - None if the value is missing
+///
+/// With Fast Startup on, "Shut down" hibernates the kernel, so uptime keeps counting.
+pub fn fast_startup_enabled() -> Option<bool> {
+    registry::read_dword(Hive::LocalMachine, POWER_KEY, "HiberbootEnabled").map(|v| v != 0)
+}
+
+/// Turn Fast Startup on or off (requires admin)
+pub async fn set_fast_startup(enabled: bool) -> CommandOutput {
+    let key = format!(r"HKLM\{}", POWER_KEY);
+    let value = if enabled { "1" } else { "0" };
+    run_command(
+        "reg",
+        &["add", &key, "/v", "HiberbootEnabled", "/t", "REG_DWORD", "/d", value, "/f"],
+    )
+    .await
+    .requires_admin()
+    .with_danger(DangerLevel::Modifying)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(active: &str, pending: &str) -> RebootSignals {
+        RebootSignals {
+            active_computer_name: Some(active.to_string()),
+            pending_computer_name: Some(pending.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn clean_system_has_no_indicators() {
+        assert!(RebootSignals::default().indicators().is_empty());
+        assert!(names("DESKTOP-1", "DESKTOP-1").indicators().is_empty());
+    }
+
+    #[test]
+    fn every_signal_maps_to_its_indicator_in_order() {
+        let signals = RebootSignals {
+            component_servicing: true,
+            windows_update: true,
+            file_renames: vec![r"\??\C:\Windows\Temp\old.dll".to_string()],
+            ..names("DESKTOP-1", "BUILD-BOX")
+        };
+        assert_eq!(
+            signals.indicators(),
+            vec![
+                RebootIndicator::ComponentServicing,
+                RebootIndicator::WindowsUpdate,
+                RebootIndicator::PendingFileRename,
+                RebootIndicator::ComputerRename,
+            ]
+        );
+    }
+
+    #[test]
+    fn computer_name_compare_ignores_case_and_missing_values() {
+        assert!(names("desktop-1", "DESKTOP-1").indicators().is_empty());
+        let missing = RebootSignals { active_computer_name: Some("A".to_string()), ..Default::default() };
+        assert!(missing.indicators().is_empty());
+    }
+}
@@ -0,0 +1,230 @@
+//! System file integrity checks
+//!
+//! `sfc /scannow` and `DISM /RestoreHealth` with progress and verdict parsing.
+
+use crate::state::CommandOutput;
+use crate::system::command::{run_command_streaming, CancelFlag};
+
+/// Which integrity tool to run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntegrityTool {
+    Sfc,
+    Dism,
+}
+
+impl IntegrityTool {
+    pub fn label(&self) -> &'static str {
+        match self {
+            IntegrityTool::Sfc => "SFC Scan",
+            IntegrityTool::Dism => "DISM RestoreHealth",
+        }
+    }
+
+    fn program(&self) -> &'static str {
+        match self {
+            IntegrityTool::Sfc => "sfc",
+            IntegrityTool::Dism => "DISM",
+        }
+    }
+
+    fn args(&self) -> &'static [&'static str] {
+        match self {
+            IntegrityTool::Sfc => &["/scannow"],
+            IntegrityTool::Dism => &["/Online", "/Cleanup-Image", "/RestoreHealth"],
+        }
+    }
+}
+
+/// Final result of an integrity run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntegrityVerdict {
+    /// No corruption found
+    Clean,
+    /// Corruption found and repaired
+    Repaired,
+    /// Corruption found but some of it could not be repaired
+    Unrepaired,
+    /// The tool failed or was cancelled before reaching a verdict
+    Failed,
+}
+
+impl IntegrityVerdict {
+    /// Status bar type for this verdict
+    pub fn status_type(&self) -> &'static str {
+        match self {
+            IntegrityVerdict::Clean => "success",
+            IntegrityVerdict::Repaired => "warning",
+            IntegrityVerdict::Unrepaired | IntegrityVerdict::Failed => "error",
+        }
+    }
+
+    pub fn summary(&self, tool: IntegrityTool) -> String {
+        match self {
+            IntegrityVerdict::Clean => t!("integrity.clean", tool = tool.label()),
+            IntegrityVerdict::Repaired => t!("integrity.repaired", tool = tool.label()),
+            IntegrityVerdict::Unrepaired => match tool {
+                IntegrityTool::Sfc => t!("integrity.sfc-unrepaired", tool = tool.label()),
+                IntegrityTool::Dism => t!("integrity.dism-unrepaired", tool = tool.label()),
+            },
+            IntegrityVerdict::Failed => t!("integrity.failed", tool = tool.label()),
+        }
+    }
+}
+
+/// Extract the percentage from a progress line ("Verification 42% complete.", "Überprüfung 42 % abgeschlossen.",
+/// "[=== 18.0% ===]")
+pub fn parse_progress(line: &str) -> Option<f32> {
+    let end = line.rfind('%')?;
+    let digits = line[..end].trim_end();
+    let start = digits.trim_end_matches(|c: char| c.is_ascii_digit() || c == '.' || c == ',').len();
+    digits[start..].replace(',', ".").parse::<f32>().ok().map(|p| p.clamp(0.0, 100.0))
+}
+
+/// sfc exit code when no integrity violations were found
+const SFC_EXIT_CLEAN: i32 = 0;
+/// sfc exit code when corrupt files were found and repaired
+const SFC_EXIT_REPAIRED: i32 = 1;
+/// sfc exit code when some corrupt files could not be repaired
+const SFC_EXIT_UNREPAIRED: i32 = 2;
+
+/// DISM exit codes (HRESULTs) for "no repair source": CBS_E_SOURCE_MISSING and the
+/// Windows Update download failures
+const DISM_SOURCE_MISSING: &[u32] = &[0x800F_081F, 0x800F_0906, 0x800F_0907, 0x800F_0950];
+
+/// English and German DISM messages for corruption it could not repair
+const DISM_UNREPAIRED_TEXT: &[&str] =
+    &["source files could not be found", "could not be repaired", "quelldateien wurden nicht gefunden"];
+
+/// Map the tool output to a verdict
+///
+/// The exit code decides where it is unambiguous, since the text is localized; the English
+/// and German messages are only consulted to tell "clean" from "repaired" after a DISM
+/// success and for exit codes the tools don't document.
+pub fn parse_verdict(tool: IntegrityTool, output: &CommandOutput) -> IntegrityVerdict {
+    let text = output.stdout.to_lowercase();
+    let has = |markers: &[&str]| markers.iter().any(|m| text.contains(m));
+    match tool {
+        IntegrityTool::Sfc => match output.exit_code {
+            SFC_EXIT_CLEAN => IntegrityVerdict::Clean,
+            SFC_EXIT_REPAIRED => IntegrityVerdict::Repaired,
+            SFC_EXIT_UNREPAIRED => IntegrityVerdict::Unrepaired,
+            _ if has(&["unable to fix", "konnte aber einige nicht reparieren"]) => IntegrityVerdict::Unrepaired,
+            _ if has(&["successfully repaired", "erfolgreich repariert"]) => IntegrityVerdict::Repaired,
+            _ if has(&["did not find any integrity violations", "keine integritätsverletzungen gefunden"]) => {
+                IntegrityVerdict::Clean
+            }
+            _ => IntegrityVerdict::Failed,
+        },
+        IntegrityTool::Dism => match output.exit_code {
+            0 if has(&["corruption was repaired", "beschädigung wurde repariert"]) => IntegrityVerdict::Repaired,
+            0 => IntegrityVerdict::Clean,
+            code if DISM_SOURCE_MISSING.contains(&(code as u32)) => IntegrityVerdict::Unrepaired,
+            _ if has(DISM_UNREPAIRED_TEXT) => IntegrityVerdict::Unrepaired,
+            _ => IntegrityVerdict::Failed,
+        },
+    }
+}
+
+/// Run an integrity tool (requires admin), reporting each output line as it arrives
+pub async fn run(tool: IntegrityTool, cancel: CancelFlag, on_line: impl FnMut(&str)) -> (IntegrityVerdict, CommandOutput) {
+    let output = run_command_streaming(tool.program(), tool.args(), cancel, on_line).await;
+    let verdict = parse_verdict(tool, &output);
+    (verdict, output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `sfc /scannow` on a healthy English system (progress lines are redrawn with `\r`)
+    const SFC_CLEAN: &str = "
+Beginning system scan.  This process will take some time.
+
+Beginning verification phase of system scan.
+Verification 100% complete.
+
+Windows Resource Protection did not find any integrity violations.
+";
+
+    /// `sfc /scannow` on a German system that repaired files
+    const SFC_REPAIRED_DE: &str = "
+Systemüberprüfung wird gestartet. Dieser Vorgang nimmt einige Zeit in Anspruch.
+
+Die Überprüfungsphase der Systemüberprüfung wird gestartet.
+Überprüfung 100 % abgeschlossen.
+
+Der Windows-Ressourcenschutz hat beschädigte Dateien gefunden und erfolgreich repariert.
+Ausführliche Informationen sind in der Datei \"CBS.log\" unter \"windir\\Logs\\CBS\\CBS.log\" enthalten.
+";
+
+    /// `DISM /Online /Cleanup-Image /RestoreHealth` without a reachable repair source
+    const DISM_SOURCE_MISSING_OUTPUT: &str = "
+Deployment Image Servicing and Management tool
+Version: 10.0.22621.2792
+
+Image Version: 10.0.22631.4317
+
+[===========================84.9%=================         ]
+Error: 0x800f081f
+
+The source files could not be found.
+Use the \"Source\" option to specify the location of the files that are required to restore the feature.
+";
+
+    /// German DISM run that found and repaired component store corruption
+    const DISM_REPAIRED_DE: &str = "
+Tool zur Imageverwaltung für die Bereitstellung
+Version: 10.0.19041.3636
+
+Abbildversion: 10.0.19045.4291
+
+[==========================100.0%==========================] Die Komponentenspeicherbeschädigung wurde repariert.
+Der Vorgang wurde erfolgreich beendet.
+";
+
+    fn output(stdout: &str, exit_code: i32) -> CommandOutput {
+        CommandOutput { stdout: stdout.to_string(), exit_code, ..Default::default() }
+    }
+
+    #[test]
+    fn progress_reads_sfc_and_dism_lines() {
+        assert_eq!(parse_progress("Verification 42% complete."), Some(42.0));
+        assert_eq!(parse_progress("Überprüfung 42 % abgeschlossen."), Some(42.0));
+        assert_eq!(parse_progress("[===========================84.9%=================         ]"), Some(84.9));
+        assert_eq!(parse_progress("[==== 18,5% ====]"), Some(18.5));
+        assert_eq!(parse_progress("Beginning system scan."), None);
+        assert_eq!(parse_progress("100%% done"), None);
+    }
+
+    #[test]
+    fn sfc_verdict_follows_exit_code_first() {
+        assert_eq!(parse_verdict(IntegrityTool::Sfc, &output(SFC_CLEAN, 0)), IntegrityVerdict::Clean);
+        assert_eq!(parse_verdict(IntegrityTool::Sfc, &output(SFC_REPAIRED_DE, 1)), IntegrityVerdict::Repaired);
+        assert_eq!(parse_verdict(IntegrityTool::Sfc, &output("", 2)), IntegrityVerdict::Unrepaired);
+    }
+
+    #[test]
+    fn sfc_verdict_falls_back_to_english_or_german_text() {
+        assert_eq!(parse_verdict(IntegrityTool::Sfc, &output(SFC_CLEAN, 7)), IntegrityVerdict::Clean);
+        assert_eq!(parse_verdict(IntegrityTool::Sfc, &output(SFC_REPAIRED_DE, 7)), IntegrityVerdict::Repaired);
+        // Cancelled runs stop before any verdict line
+        let cancelled = output("Verification 12% complete.", -1);
+        assert_eq!(parse_verdict(IntegrityTool::Sfc, &cancelled), IntegrityVerdict::Failed);
+    }
+
+    #[test]
+    fn dism_verdict_uses_exit_code_and_repair_message() {
+        assert_eq!(
+            parse_verdict(IntegrityTool::Dism, &output("The operation completed successfully.", 0)),
+            IntegrityVerdict::Clean
+        );
+        assert_eq!(parse_verdict(IntegrityTool::Dism, &output(DISM_REPAIRED_DE, 0)), IntegrityVerdict::Repaired);
+        assert_eq!(
+            parse_verdict(IntegrityTool::Dism, &output(DISM_SOURCE_MISSING_OUTPUT, 0x800F_081Fu32 as i32)),
+            IntegrityVerdict::Unrepaired
+        );
+        assert_eq!(parse_verdict(IntegrityTool::Dism, &output("", 0x800F_0906u32 as i32)), IntegrityVerdict::Unrepaired);
+        let elevation = output("Error: 740\n\nElevated permissions are required.", 740);
+        assert_eq!(parse_verdict(IntegrityTool::Dism, &elevation), IntegrityVerdict::Failed);
+    }
+}
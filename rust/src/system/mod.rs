@@ -1,14 +1,21 @@
 //! System utilities module
 //!
-//! Low-level system interaction for ports, network, docker, services, processes, hosts, disk, and admin checks.
+//! Low-level system interaction for ports, network, docker, services, processes, hosts, disk, Defender, admin checks, health checks, system file integrity, registry reads, restore points, desktop notifications, and persisted settings.
 
 pub mod admin;
 pub mod command;
+pub mod defender;
 pub mod disk;
 pub mod docker;
+pub mod health;
 pub mod hosts;
+pub mod integrity;
 pub mod network;
+pub mod notify;
 pub mod ports;
 pub mod processes;
+pub mod registry;
+pub mod restore;
 pub mod services;
+pub mod settings;
 
@@ -2,48 +2,917 @@
 //!
 //! Network diagnostics and quick-fix commands.
 
-use crate::state::{CommandOutput, NetworkAdapter};
-use crate::system::command::run_command;
+use crate::state::{CommandOutput, DangerLevel, NetworkAdapter};
+use crate::system::command::{run_command, run_command_streaming, run_powershell, CancelFlag};
+use crate::system::hosts;
+use crate::system::ports::ConnectOutcome;
+use crate::system::registry::{self, Hive};
+use crate::system::services;
+use serde::{Deserialize, Serialize};
+use std::sync::{Mutex, OnceLock};
+use std::time::Instant;
 use sysinfo::Networks;
 
 
 /// Flush DNS cache
 pub async fn flush_dns() -> CommandOutput {
-    run_command("ipconfig", &["/flushdns"]).await
+    run_command("ipconfig", &["/flushdns"]).await.with_danger(DangerLevel::Modifying)
 }
 
 /// Release and renew IP address
 pub async fn renew_ip() -> Vec<CommandOutput> {
-    let release = run_command("ipconfig", &["/release"]).await;
-    let renew = run_command("ipconfig", &["/renew"]).await;
+    let release = run_command("ipconfig", &["/release"]).await.with_danger(DangerLevel::Modifying);
+    let renew = run_command("ipconfig", &["/renew"]).await.with_danger(DangerLevel::Modifying);
     vec![release, renew]
 }
 
 /// Reset Winsock (requires admin)
 pub async fn winsock_reset() -> CommandOutput {
-    run_command("netsh", &["winsock", "reset"]).await
+    run_command("netsh", &["winsock", "reset"])
+        .await
+        .requires_admin()
+        .with_danger(DangerLevel::Destructive)
+}
+
+/// Reset the TCP/IP stack to defaults (requires admin, takes effect after reboot)
+///
+/// Runs `netsh int ip reset`, then `netsh int ipv6 reset` when `include_ipv6` is set.
+pub async fn ip_stack_reset(include_ipv6: bool) -> Vec<CommandOutput> {
+    let mut outputs = vec![run_command("netsh", &["int", "ip", "reset"])
+        .await
+        .requires_admin()
+        .with_danger(DangerLevel::Destructive)];
+    if include_ipv6 {
+        outputs.push(
+            run_command("netsh", &["int", "ipv6", "reset"])
+                .await
+                .requires_admin()
+                .with_danger(DangerLevel::Destructive),
+        );
+    }
+    outputs
+}
+
+/// Pause between disabling and re-enabling an adapter so the driver fully resets
+const ADAPTER_RESTART_DELAY: std::time::Duration = std::time::Duration::from_secs(3);
+
+/// Disable and re-enable a network adapter (requires admin)
+///
+/// The connection drops for several seconds - including any remote session over this adapter.
+pub async fn restart_adapter(name: &str) -> Vec<CommandOutput> {
+    let name = name.replace('\'', "''");
+    let disable = run_powershell(&format!("Disable-NetAdapter -Name '{}' -Confirm:$false", name))
+        .await
+        .requires_admin()
+        .with_danger(DangerLevel::Destructive);
+    if !disable.succeeded() {
+        return vec![disable];
+    }
+    tokio::time::sleep(ADAPTER_RESTART_DELAY).await;
+    let enable = run_powershell(&format!("Enable-NetAdapter -Name '{}' -Confirm:$false", name))
+        .await
+        .requires_admin()
+        .with_danger(DangerLevel::Modifying);
+    vec![disable, enable]
+}
+
+/// Running inside a Remote Desktop session
+pub fn is_remote_session() -> bool {
+    std::env::var("SESSIONNAME").is_ok_and(|s| s.to_uppercase().starts_with("RDP-"))
 }
 
 /// Restart HTTP.sys service (requires admin)
 /// This clears orphaned HTTP sockets that may be holding ports
 pub async fn restart_http_service() -> Vec<CommandOutput> {
-    let stop = run_command("net", &["stop", "http", "/y"]).await;
+    let stop = run_command("net", &["stop", "http", "/y"])
+        .await
+        .requires_admin()
+        .with_danger(DangerLevel::Destructive);
     // Small delay to ensure service fully stops
     tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
-    let start = run_command("net", &["start", "http"]).await;
+    let start = run_command("net", &["start", "http"])
+        .await
+        .requires_admin()
+        .with_danger(DangerLevel::Modifying);
     vec![stop, start]
 }
 
-/// Ping a host
-pub async fn ping_host(host: &str) -> CommandOutput {
-    run_command("ping", &["-n", "4", host]).await
+/// One IPv4 or IPv6 routing table entry
+#[derive(Debug, Clone, PartialEq)]
+pub struct RouteEntry {
+    /// Destination network address
+    pub destination: String,
+    /// Prefix length of the destination (0 = default route)
+    pub prefix_len: u8,
+    /// Next hop; "0.0.0.0" / "::" mean on-link
+    pub gateway: String,
+    /// Interface alias (e.g. "Ethernet", "Wi-Fi")
+    pub interface: String,
+    pub metric: u32,
+    pub ipv6: bool,
+}
+
+impl RouteEntry {
+    /// Route for all destinations not matched by a more specific route
+    pub fn is_default(&self) -> bool {
+        self.prefix_len == 0
+    }
+
+    /// Destination is reached directly rather than via a router
+    pub fn is_on_link(&self) -> bool {
+        matches!(self.gateway.as_str(), "0.0.0.0" | "::" | "")
+    }
+
+    /// Netmask in route-print notation (dotted for IPv4, /len for IPv6)
+    pub fn netmask(&self) -> String {
+        if self.ipv6 {
+            format!("/{}", self.prefix_len)
+        } else {
+            let bits = u32::MAX.checked_shl(32u32.saturating_sub(u32::from(self.prefix_len))).unwrap_or(0);
+            std::net::Ipv4Addr::from(bits).to_string()
+        }
+    }
+}
+
+/// Directly connected IPv4 subnets as (CIDR, interface), from on-link routes
+///
+/// Host (/32) and broadcast routes, loopback, and multicast are skipped.
+pub fn local_subnets(routes: &[RouteEntry]) -> Vec<(String, String)> {
+    routes
+        .iter()
+        .filter(|r| !r.ipv6 && r.is_on_link() && (8..=30).contains(&r.prefix_len) && !r.destination.starts_with("127."))
+        .map(|r| (format!("{}/{}", r.destination, r.prefix_len), r.interface.clone()))
+        .collect()
+}
+
+/// Parse an IPv4 CIDR ("172.18.0.0/16") into (address, prefix length)
+pub fn parse_ipv4_cidr(cidr: &str) -> Option<(u32, u8)> {
+    let (address, prefix) = cidr.trim().split_once('/')?;
+    let address: std::net::Ipv4Addr = address.parse().ok()?;
+    let prefix: u8 = prefix.parse().ok().filter(|p| *p <= 32)?;
+    Some((u32::from(address), prefix))
+}
+
+/// Whether two IPv4 CIDR ranges share any address (false if either doesn't parse)
+pub fn ipv4_subnets_overlap(a: &str, b: &str) -> bool {
+    let (Some((a, a_len)), Some((b, b_len))) = (parse_ipv4_cidr(a), parse_ipv4_cidr(b)) else {
+        return false;
+    };
+    let shorter = a_len.min(b_len);
+    let mask = u32::MAX.checked_shl(32 - u32::from(shorter)).unwrap_or(0);
+    a & mask == b & mask
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawRoute {
+    destination_prefix: String,
+    next_hop: Option<String>,
+    interface_alias: Option<String>,
+    route_metric: Option<u32>,
+}
+
+/// ConvertTo-Json writes a bare object when there is a single route
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OneOrMany {
+    Many(Vec<RawRoute>),
+    One(RawRoute),
+}
+
+/// Read the IPv4 and IPv6 routing tables
+///
+/// Uses `Get-NetRoute` rather than `route print` so the result doesn't depend on the display language.
+pub async fn get_route_table() -> (Result<Vec<RouteEntry>, String>, CommandOutput) {
+    let cmd = "ConvertTo-Json -Compress -InputObject @(Get-NetRoute | Select-Object DestinationPrefix, NextHop, InterfaceAlias, RouteMetric)";
+    let output = run_powershell(cmd).await;
+    if !output.succeeded() {
+        let error = output.stderr.lines().map(str::trim).find(|l| !l.is_empty()).unwrap_or("Get-NetRoute failed");
+        return (Err(error.to_string()), output);
+    }
+
+    (parse_route_table(output.stdout.trim()), output)
+}
+
+/// Parse the `Get-NetRoute` JSON, skipping entries without a usable prefix
+fn parse_route_table(json: &str) -> Result<Vec<RouteEntry>, String> {
+    let raw = match serde_json::from_str::<OneOrMany>(json) {
+        Ok(OneOrMany::Many(routes)) => routes,
+        Ok(OneOrMany::One(route)) => vec![route],
+        Err(e) => return Err(format!("Failed to parse route table: {}", e)),
+    };
+    Ok(raw.into_iter().filter_map(parse_route).collect())
+}
+
+/// Split `DestinationPrefix` ("10.0.0.0/8") into address and prefix length
+fn parse_route(raw: RawRoute) -> Option<RouteEntry> {
+    let (destination, prefix_len) = raw.destination_prefix.split_once('/')?;
+    Some(RouteEntry {
+        destination: destination.to_string(),
+        prefix_len: prefix_len.parse().ok()?,
+        gateway: raw.next_hop.unwrap_or_default(),
+        interface: raw.interface_alias.unwrap_or_default(),
+        metric: raw.route_metric.unwrap_or(0),
+        ipv6: destination.contains(':'),
+    })
+}
+
+/// Gateway of the preferred default route (IPv4 first, then lowest metric)
+pub fn default_gateway(routes: &[RouteEntry]) -> Option<&str> {
+    routes
+        .iter()
+        .filter(|r| r.is_default() && !r.is_on_link())
+        .min_by_key(|r| (r.ipv6, r.metric))
+        .map(|r| r.gateway.as_str())
+}
+
+/// One record from the DNS client cache
+#[derive(Debug, Clone, PartialEq)]
+pub struct DnsCacheRecord {
+    /// Name that was looked up
+    pub entry: String,
+    /// Owner name of the record (differs from `entry` for CNAME chains)
+    pub name: String,
+    /// Record type ("A", "AAAA", "CNAME", ...)
+    pub record_type: String,
+    pub data: String,
+    /// Seconds until the record expires
+    pub ttl: u32,
+    /// "Answer", "Authority", or "Additional"
+    pub section: String,
+    /// An enabled hosts file entry maps this name to this address
+    pub from_hosts: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawDnsRecord {
+    entry: Option<String>,
+    name: Option<String>,
+    #[serde(rename = "Type")]
+    record_type: Option<serde_json::Value>,
+    data: Option<String>,
+    time_to_live: Option<u32>,
+    section: Option<serde_json::Value>,
+}
+
+/// Read the DNS client cache and flag records pinned by the hosts file
+pub async fn get_dns_cache() -> (Result<Vec<DnsCacheRecord>, String>, CommandOutput) {
+    let cmd = "ConvertTo-Json -Compress -InputObject @(Get-DnsClientCache | Select-Object Entry, Name, Type, Data, TimeToLive, Section)";
+    let output = run_powershell(cmd).await;
+    if !output.succeeded() {
+        let error = output.stderr.lines().map(str::trim).find(|l| !l.is_empty()).unwrap_or("Get-DnsClientCache failed");
+        return (Err(error.to_string()), output);
+    }
+
+    // A missing or unreadable hosts file just means nothing is flagged
+    let pinned: Vec<(String, String)> = hosts::read_hosts()
+        .map(|(entries, _)| entries)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|e| e.enabled)
+        .map(|e| (e.hostname.to_lowercase(), e.ip))
+        .collect();
+
+    let result = serde_json::from_str::<Vec<RawDnsRecord>>(output.stdout.trim())
+        .map(|raw| {
+            raw.into_iter()
+                .map(|r| {
+                    let name = r.name.unwrap_or_default();
+                    let data = r.data.unwrap_or_default();
+                    let lower = name.to_lowercase();
+                    let from_hosts = pinned.iter().any(|(host, ip)| *host == lower && *ip == data);
+                    DnsCacheRecord {
+                        entry: r.entry.unwrap_or_else(|| name.clone()),
+                        name,
+                        record_type: dns_record_type(r.record_type.as_ref()),
+                        data,
+                        ttl: r.time_to_live.unwrap_or(0),
+                        section: dns_section(r.section.as_ref()),
+                        from_hosts,
+                    }
+                })
+                .collect()
+        })
+        .map_err(|e| format!("Failed to parse DNS cache: {}", e));
+    (result, output)
+}
+
+/// Record type name; Windows PowerShell serializes the enum as its number
+fn dns_record_type(value: Option<&serde_json::Value>) -> String {
+    match value {
+        Some(serde_json::Value::Number(n)) => match n.as_u64() {
+            Some(1) => "A".to_string(),
+            Some(2) => "NS".to_string(),
+            Some(5) => "CNAME".to_string(),
+            Some(6) => "SOA".to_string(),
+            Some(12) => "PTR".to_string(),
+            Some(15) => "MX".to_string(),
+            Some(16) => "TXT".to_string(),
+            Some(28) => "AAAA".to_string(),
+            Some(33) => "SRV".to_string(),
+            _ => n.to_string(),
+        },
+        Some(serde_json::Value::String(s)) => s.clone(),
+        _ => String::new(),
+    }
+}
+
+/// Response section name; serialized as a number like the record type
+fn dns_section(value: Option<&serde_json::Value>) -> String {
+    match value {
+        Some(serde_json::Value::Number(n)) => match n.as_u64() {
+            Some(1) => "Answer".to_string(),
+            Some(2) => "Authority".to_string(),
+            Some(3) => "Additional".to_string(),
+            _ => n.to_string(),
+        },
+        Some(serde_json::Value::String(s)) => s.clone(),
+        _ => String::new(),
+    }
 }
 
-/// HTTP HEAD request
-pub async fn http_head(url: &str) -> CommandOutput {
+/// Windows network location category, which selects the firewall profile
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetworkCategory {
+    Public,
+    Private,
+    DomainAuthenticated,
+}
+
+impl NetworkCategory {
+    /// Message key of the category name shown in badges and dialogs
+    pub fn label_key(self) -> &'static str {
+        match self {
+            NetworkCategory::Public => "network.category.public",
+            NetworkCategory::Private => "network.category.private",
+            NetworkCategory::DomainAuthenticated => "network.category.domain",
+        }
+    }
+
+    /// Value for `Set-NetConnectionProfile -NetworkCategory`
+    fn parameter(self) -> &'static str {
+        match self {
+            NetworkCategory::Public => "Public",
+            NetworkCategory::Private => "Private",
+            NetworkCategory::DomainAuthenticated => "DomainAuthenticated",
+        }
+    }
+
+    /// Windows PowerShell serializes the enum as its number, PowerShell 7 as its name
+    fn from_json(value: &serde_json::Value) -> Option<Self> {
+        match value {
+            serde_json::Value::Number(n) => match n.as_u64()? {
+                0 => Some(NetworkCategory::Public),
+                1 => Some(NetworkCategory::Private),
+                2 => Some(NetworkCategory::DomainAuthenticated),
+                _ => None,
+            },
+            serde_json::Value::String(s) => match s.as_str() {
+                "Public" => Some(NetworkCategory::Public),
+                "Private" => Some(NetworkCategory::Private),
+                "DomainAuthenticated" => Some(NetworkCategory::DomainAuthenticated),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// A connected network and the category Windows assigned to it
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConnectionProfile {
+    /// Network name (Wi-Fi SSID, "Network 2", domain name...)
+    pub name: String,
+    /// Adapter the network is connected through
+    pub interface_alias: String,
+    pub category: NetworkCategory,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawConnectionProfile {
+    name: Option<String>,
+    interface_alias: Option<String>,
+    network_category: Option<serde_json::Value>,
+}
+
+/// List connected networks with their Public/Private/Domain category
+pub async fn get_connection_profiles() -> (Result<Vec<ConnectionProfile>, String>, CommandOutput) {
+    let cmd = "ConvertTo-Json -Compress -InputObject @(Get-NetConnectionProfile | Select-Object Name, InterfaceAlias, NetworkCategory)";
+    let output = run_powershell(cmd).await;
+    if !output.succeeded() {
+        let error = output.stderr.lines().map(str::trim).find(|l| !l.is_empty()).unwrap_or("Get-NetConnectionProfile failed");
+        return (Err(error.to_string()), output);
+    }
+
+    let result = serde_json::from_str::<Vec<RawConnectionProfile>>(output.stdout.trim())
+        .map(|raw| {
+            raw.into_iter()
+                .filter_map(|p| {
+                    Some(ConnectionProfile {
+                        name: p.name.unwrap_or_default(),
+                        interface_alias: p.interface_alias?,
+                        category: NetworkCategory::from_json(p.network_category.as_ref()?)?,
+                    })
+                })
+                .collect()
+        })
+        .map_err(|e| format!("Failed to parse connection profiles: {}", e));
+    (result, output)
+}
+
+/// Switch the network on an adapter to Public or Private (requires admin)
+///
+/// Domain networks are detected by Windows and can't be set this way.
+pub async fn set_connection_profile(interface_alias: &str, category: NetworkCategory) -> CommandOutput {
+    let cmd = format!(
+        "Set-NetConnectionProfile -InterfaceAlias '{}' -NetworkCategory {}",
+        interface_alias.replace('\'', "''"),
+        category.parameter()
+    );
+    run_powershell(&cmd).await.requires_admin().with_danger(DangerLevel::Modifying)
+}
+
+/// Public resolver compared against the configured DNS servers
+const REFERENCE_DNS_SERVER: &str = "1.1.1.1";
+
+/// One step of a guided diagnostic, rendered as a ✓/✗ checklist item
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiagnosticStep {
+    pub label: String,
+    pub passed: bool,
+    /// What was found, or what to do about it
+    pub detail: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawResolve {
+    ms: u64,
+    addresses: Vec<String>,
+    error: Option<String>,
+}
+
+/// Resolve `host` over DNS only (no hosts file, no cache-only answers), timed inside PowerShell
+///
+/// `server` None uses the adapters' configured DNS servers.
+async fn timed_resolve(host: &str, server: Option<&str>) -> (Result<(Vec<String>, u64), String>, CommandOutput) {
+    let server = server.map(|s| format!(" -Server {}", s)).unwrap_or_default();
+    let cmd = format!(
+        "$sw = [Diagnostics.Stopwatch]::StartNew(); $e = $null; \
+         try {{ $r = Resolve-DnsName -Name '{}' -DnsOnly -QuickTimeout{} -ErrorAction Stop }} catch {{ $e = $_.Exception.Message }}; $sw.Stop(); \
+         [pscustomobject]@{{ Ms = $sw.ElapsedMilliseconds; Addresses = @($r | Where-Object {{ $_.IPAddress }} | ForEach-Object {{ $_.IPAddress }}); Error = $e }} | ConvertTo-Json -Compress",
+        host.replace('\'', "''"),
+        server
+    );
+    let output = run_powershell(&cmd).await;
+    let result = serde_json::from_str::<RawResolve>(output.stdout.trim())
+        .map_err(|_| {
+            let error = output.stderr.lines().map(str::trim).find(|l| !l.is_empty());
+            error.unwrap_or("Resolve-DnsName failed").to_string()
+        })
+        .and_then(|raw| match raw.error {
+            Some(e) => Err(e),
+            None if raw.addresses.is_empty() => Err("No addresses returned".to_string()),
+            None => Ok((raw.addresses, raw.ms)),
+        });
+    (result, output)
+}
+
+/// Step-by-step DNS check: DNS Client service, configured resolver, public resolver, hosts file
+pub async fn diagnose_dns(test_host: &str) -> (Vec<DiagnosticStep>, Vec<CommandOutput>) {
+    let mut steps = Vec::new();
+    let mut outputs = Vec::new();
+
+    let (found, output) = services::list_services_filtered("dnscache").await;
+    outputs.push(output);
+    let status = found.first().map(|s| s.status.clone());
+    steps.push(DiagnosticStep {
+        label: t!("network.dns-step.service"),
+        passed: status.as_deref() == Some("Running"),
+        detail: match status {
+            Some(status) if status == "Running" => t!("network.dns-step.service-running"),
+            Some(status) => t!("network.dns-step.service-stopped", status = status),
+            None => t!("network.dns-step.service-missing"),
+        },
+    });
+
+    let (system, output) = timed_resolve(test_host, None).await;
+    outputs.push(output);
+    let (reference, output) = timed_resolve(test_host, Some(REFERENCE_DNS_SERVER)).await;
+    outputs.push(output);
+
+    steps.push(DiagnosticStep {
+        label: t!("network.dns-step.configured", host = test_host),
+        passed: system.is_ok(),
+        detail: match (&system, &reference) {
+            (Ok((addresses, ms)), _) => t!("network.dns-step.resolved", addresses = addresses.join(", "), ms = ms),
+            (Err(e), Ok(_)) => t!("network.dns-step.configured-failed", error = e, server = REFERENCE_DNS_SERVER),
+            (Err(e), Err(_)) => e.clone(),
+        },
+    });
+    steps.push(DiagnosticStep {
+        label: t!("network.dns-step.reference", host = test_host, server = REFERENCE_DNS_SERVER),
+        passed: reference.is_ok(),
+        detail: match (&reference, &system) {
+            (Ok((addresses, ms)), _) => t!("network.dns-step.resolved", addresses = addresses.join(", "), ms = ms),
+            (Err(e), Ok(_)) => t!("network.dns-step.reference-blocked", error = e),
+            (Err(e), Err(_)) => t!("network.dns-step.both-failed", error = e),
+        },
+    });
+
+    let overrides: Vec<String> = hosts::read_hosts()
+        .map(|(entries, _)| entries)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|e| e.enabled && e.hostname.eq_ignore_ascii_case(test_host))
+        .map(|e| e.ip)
+        .collect();
+    steps.push(DiagnosticStep {
+        label: t!("network.dns-step.hosts", host = test_host),
+        passed: overrides.is_empty(),
+        detail: if overrides.is_empty() {
+            t!("network.dns-step.hosts-clear")
+        } else {
+            t!("network.dns-step.hosts-pinned", host = test_host, addresses = overrides.join(", "))
+        },
+    });
+
+    (steps, outputs)
+}
+
+/// Name resolved by the connectivity matrix DNS probe
+const CONNECTIVITY_DNS_HOST: &str = "www.microsoft.com";
+
+/// Fetched directly (proxy bypassed) by the connectivity matrix HTTPS probe
+const CONNECTIVITY_HTTPS_URL: &str = "https://www.microsoft.com/";
+
+/// Windows' own connectivity check (NCSI); fetched through the user proxy
+const CONNECTIVITY_HTTP_URL: &str = "http://www.msftconnecttest.com/connecttest.txt";
+
+/// Body NCSI expects - anything else means a captive portal or an intercepting proxy
+const CONNECTIVITY_HTTP_BODY: &str = "Microsoft Connect Test";
+
+/// Per-probe timeout for the connectivity matrix
+const CONNECTIVITY_TIMEOUT_SECS: u64 = 5;
+
+/// Outcome of one connectivity matrix probe
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProbeResult {
+    pub passed: bool,
+    pub latency_ms: Option<u64>,
+    /// What was reached, or why it failed
+    pub detail: String,
+}
+
+impl ProbeResult {
+    fn failed(detail: impl Into<String>) -> Self {
+        Self { passed: false, latency_ms: None, detail: detail.into() }
+    }
+}
+
+/// Four probes that tell "is it DNS, the proxy, or the pipe?" apart
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConnectivityMatrix {
+    /// Ping the default gateway
+    pub gateway: ProbeResult,
+    /// Resolve a well-known name with the configured DNS servers
+    pub dns: ProbeResult,
+    /// HTTPS request with the proxy bypassed
+    pub https: ProbeResult,
+    /// HTTP request through the per-user proxy (direct when none is set)
+    pub proxy: ProbeResult,
+}
+
+/// Run the four connectivity probes concurrently
+pub async fn connectivity_matrix() -> ConnectivityMatrix {
+    let (gateway, dns, https, proxy) = tokio::join!(probe_gateway(), probe_dns(), probe_https_direct(), probe_http_via_proxy());
+    ConnectivityMatrix { gateway, dns, https, proxy }
+}
+
+async fn probe_gateway() -> ProbeResult {
+    let (routes, _) = get_route_table().await;
+    let routes = match routes {
+        Ok(routes) => routes,
+        Err(e) => return ProbeResult::failed(e),
+    };
+    let Some(gateway) = default_gateway(&routes) else {
+        return ProbeResult::failed(t!("network.probe.no-gateway"));
+    };
+    let start = std::time::Instant::now();
+    match ping_once(gateway, (CONNECTIVITY_TIMEOUT_SECS * 1000) as u32).await {
+        Some(ms) => ProbeResult {
+            passed: true,
+            latency_ms: Some(ms as u64),
+            detail: t!("network.probe.gateway-replied", gateway = gateway),
+        },
+        None => ProbeResult {
+            passed: false,
+            latency_ms: Some(start.elapsed().as_millis() as u64),
+            detail: t!("network.probe.gateway-silent", gateway = gateway),
+        },
+    }
+}
+
+async fn probe_dns() -> ProbeResult {
+    match timed_resolve(CONNECTIVITY_DNS_HOST, None).await.0 {
+        Ok((addresses, ms)) => ProbeResult {
+            passed: true,
+            latency_ms: Some(ms),
+            detail: format!("{} → {}", CONNECTIVITY_DNS_HOST, addresses.first().map(String::as_str).unwrap_or_default()),
+        },
+        Err(e) => ProbeResult::failed(format!("{}: {}", CONNECTIVITY_DNS_HOST, e)),
+    }
+}
+
+async fn probe_https_direct() -> ProbeResult {
+    let client = match reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(CONNECTIVITY_TIMEOUT_SECS))
+        .no_proxy()
+        .build()
+    {
+        Ok(c) => c,
+        Err(e) => return ProbeResult::failed(t!("network.probe.client-failed", error = e)),
+    };
+    let start = std::time::Instant::now();
+    match client.head(CONNECTIVITY_HTTPS_URL).send().await {
+        Ok(resp) => ProbeResult {
+            passed: true,
+            latency_ms: Some(start.elapsed().as_millis() as u64),
+            detail: format!("{} → HTTP {}", CONNECTIVITY_HTTPS_URL, resp.status().as_u16()),
+        },
+        Err(e) => ProbeResult::failed(error_detail(&e)),
+    }
+}
+
+/// Proxy URL for plain HTTP from a ProxyServer value ("host:port" or "http=host:port;https=...")
+fn http_proxy_url(server: &str) -> String {
+    let server = if server.contains('=') {
+        server
+            .split(';')
+            .find_map(|part| part.trim().strip_prefix("http="))
+            .unwrap_or(server)
+    } else {
+        server
+    };
+    if server.contains("://") {
+        server.to_string()
+    } else {
+        format!("http://{}", server)
+    }
+}
+
+async fn probe_http_via_proxy() -> ProbeResult {
+    let settings = get_proxy_settings().await.ok();
+    let server = settings
+        .as_ref()
+        .filter(|p| p.user_proxy_enabled)
+        .and_then(|p| p.user_proxy_server.clone());
+
+    let builder = reqwest::Client::builder().timeout(std::time::Duration::from_secs(CONNECTIVITY_TIMEOUT_SECS));
+    let (builder, route) = match &server {
+        Some(server) => match reqwest::Proxy::http(http_proxy_url(server)) {
+            Ok(proxy) => (builder.proxy(proxy), t!("network.probe.via-proxy", server = server)),
+            Err(e) => return ProbeResult::failed(t!("network.probe.proxy-unusable", server = server, error = e)),
+        },
+        None if settings.as_ref().is_some_and(|p| p.auto_config_url.is_some()) => {
+            (builder.no_proxy(), t!("network.probe.direct-pac"))
+        }
+        None => (builder.no_proxy(), t!("network.probe.direct")),
+    };
+    let client = match builder.build() {
+        Ok(c) => c,
+        Err(e) => return ProbeResult::failed(t!("network.probe.client-failed", error = e)),
+    };
+
+    let start = std::time::Instant::now();
+    let response = match client.get(CONNECTIVITY_HTTP_URL).send().await {
+        Ok(resp) => resp,
+        Err(e) => return ProbeResult::failed(format!("{} - {}", route, error_detail(&e))),
+    };
+    let status = response.status().as_u16();
+    let body = response.text().await.unwrap_or_default();
+    let latency_ms = Some(start.elapsed().as_millis() as u64);
+    if body.trim() == CONNECTIVITY_HTTP_BODY {
+        ProbeResult { passed: true, latency_ms, detail: format!("HTTP {} {}", status, route) }
+    } else {
+        ProbeResult {
+            passed: false,
+            latency_ms,
+            detail: t!("network.probe.captive", status = status, route = route),
+        }
+    }
+}
+
+/// Longest host name accepted by `validate_host` (DNS name limit)
+const MAX_HOST_LEN: usize = 253;
+
+/// Check a user-entered host name or IP address before passing it to a command
+///
+/// Only letters, digits, and `.-_:` (hostnames, IPv4, IPv6) are allowed,
+/// so nothing can be read as a shell metacharacter or a command-line switch.
+pub fn validate_host(host: &str) -> Result<(), String> {
+    if host.is_empty() {
+        return Err(t!("network.host-empty"));
+    }
+    if host.len() > MAX_HOST_LEN {
+        return Err(t!("network.host-too-long", max = MAX_HOST_LEN));
+    }
+    if host.starts_with('-') {
+        return Err(t!("network.host-dash"));
+    }
+    if let Some(c) = host.chars().find(|c| !(c.is_ascii_alphanumeric() || ".-_:".contains(*c))) {
+        return Err(t!("network.host-invalid-char", char = c));
+    }
+    Ok(())
+}
+
+/// Ping a host, streaming each reply line to `on_line`
+///
+/// Sends `count` echo requests, or pings until `cancel` is set when `continuous` (`ping -t`).
+pub async fn ping_host(
+    host: &str,
+    count: u32,
+    continuous: bool,
+    cancel: CancelFlag,
+    on_line: impl FnMut(&str),
+) -> CommandOutput {
+    let count = count.to_string();
+    let args = if continuous { vec!["-t", host] } else { vec!["-n", &count, host] };
+    run_command_streaming("ping", &args, cancel, on_line).await
+}
+
+/// Send a single echo request and return the round-trip time in ms (None = lost)
+pub async fn ping_once(host: &str, timeout_ms: u32) -> Option<u32> {
+    let timeout = timeout_ms.to_string();
+    let output = run_command("ping", &["-n", "1", "-w", &timeout, host]).await;
+    parse_ping_time(&output.stdout)
+}
+
+/// Round-trip time from a ping reply line ("time=12ms", "Zeit<1ms")
+///
+/// Only lines with a TTL count as replies; "Destination host unreachable" has none.
+pub fn parse_ping_time(output: &str) -> Option<u32> {
+    let line = output.lines().find(|l| l.contains("TTL="))?;
+    let ms = line.find("ms")?;
+    let start = line[..ms].rfind(['=', '<'])? + 1;
+    line[start..ms].trim().parse().ok()
+}
+
+/// Trace the route to a host, streaming each hop line to `on_line`
+///
+/// Runs `tracert -d` (no reverse DNS, which is most of tracert's runtime) until `cancel` is set.
+pub async fn trace_route(
+    host: &str,
+    max_hops: u32,
+    cancel: CancelFlag,
+    on_line: impl FnMut(&str),
+) -> CommandOutput {
+    let max_hops = max_hops.to_string();
+    run_command_streaming("tracert", &["-d", "-h", &max_hops, host], cancel, on_line).await
+}
+
+/// How many probes of a tracert hop line went unanswered
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HopTimeouts {
+    None,
+    Some,
+    All,
+}
+
+/// Classify a tracert hop line (`  3    12 ms     *       14 ms  10.0.0.1`) by its `*` probes
+///
+/// Returns None for header, footer, and blank lines.
+pub fn parse_trace_hop(line: &str) -> Option<HopTimeouts> {
+    let mut tokens = line.split_whitespace().peekable();
+    tokens.next()?.parse::<u32>().ok()?;
+
+    let mut probes = 0;
+    let mut timeouts = 0;
+    while let Some(token) = tokens.peek() {
+        if *token == "*" {
+            timeouts += 1;
+        } else if token.trim_start_matches('<').parse::<u32>().is_ok() {
+            tokens.next();
+            if tokens.peek() != Some(&"ms") {
+                return None;
+            }
+        } else {
+            break;
+        }
+        probes += 1;
+        tokens.next();
+    }
+
+    match (probes, timeouts) {
+        (0, _) => None,
+        (_, 0) => Some(HopTimeouts::None),
+        (p, t) if t == p => Some(HopTimeouts::All),
+        _ => Some(HopTimeouts::Some),
+    }
+}
+
+/// Result of `test_remote_port`
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemotePortTest {
+    pub host: String,
+    pub port: u16,
+    /// Address the host resolved to and was connected to
+    pub resolved: Option<std::net::IpAddr>,
+    pub outcome: ConnectOutcome,
+    /// Time spent connecting (excludes name resolution)
+    pub elapsed_ms: u128,
+    pub tested_at: chrono::DateTime<chrono::Local>,
+}
+
+/// Check whether a remote TCP port accepts connections (like `Test-NetConnection -Port`)
+///
+/// Resolves `host` and connects to the first address; `timeout_ms` applies to resolution and to the connect.
+pub async fn test_remote_port(host: &str, port: u16, timeout_ms: u64) -> RemotePortTest {
+    let timeout = std::time::Duration::from_millis(timeout_ms);
+    let mut test = RemotePortTest {
+        host: host.to_string(),
+        port,
+        resolved: None,
+        outcome: ConnectOutcome::Failed(String::new()),
+        elapsed_ms: 0,
+        tested_at: chrono::Local::now(),
+    };
+
+    let addr = match tokio::time::timeout(timeout, tokio::net::lookup_host((host, port))).await {
+        Ok(Ok(mut addrs)) => addrs.next(),
+        Ok(Err(e)) => {
+            test.outcome = ConnectOutcome::Failed(format!("Could not resolve {}: {}", host, e));
+            return test;
+        }
+        Err(_) => {
+            test.outcome = ConnectOutcome::Failed(format!("Resolving {} timed out", host));
+            return test;
+        }
+    };
+    let Some(addr) = addr else {
+        test.outcome = ConnectOutcome::Failed(format!("{} has no addresses", host));
+        return test;
+    };
+    test.resolved = Some(addr.ip());
+
+    let start = std::time::Instant::now();
+    test.outcome = match tokio::time::timeout(timeout, tokio::net::TcpStream::connect(addr)).await {
+        Ok(Ok(_stream)) => ConnectOutcome::Connected,
+        Ok(Err(e)) if e.kind() == std::io::ErrorKind::ConnectionRefused => ConnectOutcome::Refused,
+        Ok(Err(e)) if e.kind() == std::io::ErrorKind::TimedOut => ConnectOutcome::TimedOut,
+        Ok(Err(e)) => ConnectOutcome::Failed(e.to_string()),
+        Err(_) => ConnectOutcome::TimedOut,
+    };
+    test.elapsed_ms = start.elapsed().as_millis();
+    test
+}
+
+/// Server certificate presented on an https URL
+#[derive(Debug, Clone, PartialEq)]
+pub struct TlsCertificate {
+    pub subject: String,
+    pub issuer: String,
+    pub expires: chrono::DateTime<chrono::Utc>,
+    /// Validation problems reported by Windows (name mismatch, untrusted chain); None if valid
+    pub errors: Option<String>,
+}
+
+impl TlsCertificate {
+    /// Whole days until expiry (negative once expired)
+    pub fn days_left(&self) -> i64 {
+        (self.expires - chrono::Utc::now()).num_days()
+    }
+}
+
+/// Headers of an HTTP HEAD request, plus the certificate for https URLs
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpHeadReport {
+    pub outputs: Vec<CommandOutput>,
+    /// The HEAD request itself succeeded
+    pub succeeded: bool,
+    /// None for http URLs
+    pub certificate: Option<Result<TlsCertificate, String>>,
+}
+
+/// HTTP HEAD request against a user-supplied URL (curl, PowerShell fallback)
+///
+/// For https URLs the certificate is read from curl's verbose output, or probed with
+/// .NET's SslStream when curl uses Schannel (which doesn't print certificate details).
+pub async fn http_head(url: &str) -> Result<HttpHeadReport, String> {
+    validate_endpoint_url(url)?;
+    let https = url.starts_with("https://");
+
     // Try curl first (modern Windows has it)
-    if which::which("curl").is_ok() {
-        run_command("curl", &["-I", "--connect-timeout", "10", url]).await
+    let curl = which::which("curl").is_ok();
+    let output = if curl {
+        let mut args = vec!["-I", "--connect-timeout", "10"];
+        if https {
+            args.push("-v");
+        }
+        args.push(url);
+        run_command("curl", &args).await
     } else {
         // Fallback to PowerShell
         let script = format!(
@@ -51,32 +920,1001 @@ pub async fn http_head(url: &str) -> CommandOutput {
             url
         );
         run_command("powershell", &["-NoProfile", "-Command", &script]).await
+    };
+    let succeeded = output.succeeded();
+
+    let mut report = HttpHeadReport {
+        certificate: None,
+        succeeded,
+        outputs: Vec::new(),
+    };
+    if https {
+        let from_curl = if curl { parse_curl_certificate(&output.stderr) } else { None };
+        report.certificate = Some(match from_curl {
+            Some(cert) => Ok(cert),
+            None => {
+                let (cert, probe) = probe_tls_certificate(url).await;
+                report.outputs.push(probe);
+                cert
+            }
+        });
     }
+    report.outputs.insert(0, output);
+    Ok(report)
 }
 
-/// Get network adapters with their addresses
-/// Reserved for future network adapter display feature
-#[allow(dead_code)]
-pub fn get_network_adapters() -> Vec<NetworkAdapter> {
-    let networks = Networks::new_with_refreshed_list();
-    
-    networks
-        .iter()
-        .map(|(name, _data)| {
-            // sysinfo doesn't give us IP addresses directly in newer versions,
-            // so we'll use a simpler approach for now
-            NetworkAdapter {
-                name: name.to_string(),
-                addresses: vec![], // We'll populate this via ipconfig parsing if needed
-                is_up: true, // Assume up if listed
+/// Pull subject, issuer, and expiry from `curl -v` output (OpenSSL builds only)
+fn parse_curl_certificate(verbose: &str) -> Option<TlsCertificate> {
+    let mut subject = None;
+    let mut issuer = None;
+    let mut expires = None;
+
+    for line in verbose.lines() {
+        let Some((key, value)) = line.trim_start_matches(['*', ' ']).split_once(": ") else {
+            continue;
+        };
+        match key {
+            "subject" => subject = Some(value.trim().to_string()),
+            "issuer" => issuer = Some(value.trim().to_string()),
+            "expire date" => {
+                // "Jan  2 12:00:00 2027 GMT" - collapse the padding before parsing
+                let value = value.split_whitespace().collect::<Vec<_>>().join(" ");
+                expires = chrono::NaiveDateTime::parse_from_str(&value, "%b %d %H:%M:%S %Y GMT")
+                    .ok()
+                    .map(|d| d.and_utc());
             }
+            _ => {}
+        }
+    }
+
+    Some(TlsCertificate {
+        subject: subject?,
+        issuer: issuer?,
+        expires: expires?,
+        errors: None,
+    })
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawCertificate {
+    subject: String,
+    issuer: String,
+    not_after: String,
+    errors: String,
+}
+
+/// Read the server certificate with .NET SslStream, accepting it so invalid certificates can be inspected
+async fn probe_tls_certificate(url: &str) -> (Result<TlsCertificate, String>, CommandOutput) {
+    let Some((host, port)) = reqwest::Url::parse(url)
+        .ok()
+        .and_then(|u| Some((u.host_str()?.trim_matches(['[', ']']).to_string(), u.port_or_known_default()?)))
+    else {
+        return (Err(format!("'{}' has no host", url)), CommandOutput::default());
+    };
+    let host = host.replace('\'', "''");
+    let script = format!(
+        "$r = @{{ e = 'None' }}; $t = New-Object Net.Sockets.TcpClient; \
+         if (-not $t.ConnectAsync('{host}', {port}).Wait(10000)) {{ throw 'Connection to {host}:{port} timed out' }}; \
+         $s = New-Object Net.Security.SslStream($t.GetStream(), $false, {{ param($a, $c, $ch, $err) $r.e = [string]$err; $true }}); \
+         $s.AuthenticateAsClient('{host}'); \
+         $c = New-Object Security.Cryptography.X509Certificates.X509Certificate2($s.RemoteCertificate); $t.Close(); \
+         [pscustomobject]@{{ Subject = $c.Subject; Issuer = $c.Issuer; NotAfter = $c.NotAfter.ToUniversalTime().ToString('o'); Errors = $r.e }} | ConvertTo-Json -Compress",
+        host = host,
+        port = port
+    );
+    let output = run_powershell(&script).await;
+    if !output.succeeded() {
+        let error = output.stderr.lines().map(str::trim).find(|l| !l.is_empty()).unwrap_or("TLS handshake failed");
+        return (Err(error.to_string()), output);
+    }
+
+    let result = serde_json::from_str::<RawCertificate>(output.stdout.trim())
+        .map_err(|e| format!("Failed to parse certificate: {}", e))
+        .and_then(|raw| {
+            let expires = chrono::DateTime::parse_from_rfc3339(&raw.not_after)
+                .map_err(|e| format!("Invalid certificate expiry '{}': {}", raw.not_after, e))?
+                .with_timezone(&chrono::Utc);
+            Ok(TlsCertificate {
+                subject: raw.subject,
+                issuer: raw.issuer,
+                expires,
+                errors: (raw.errors != "None").then_some(raw.errors),
+            })
+        });
+    (result, output)
+}
+
+/// UDP port for Wake-on-LAN magic packets (discard)
+const WOL_PORT: u16 = 9;
+
+/// A saved Wake-on-LAN target
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct WolTarget {
+    /// Display name (e.g. "Lab box 3")
+    pub name: String,
+    /// MAC address as entered
+    pub mac: String,
+    /// Broadcast address; the limited broadcast 255.255.255.255 when unset
+    #[serde(default)]
+    pub broadcast: Option<String>,
+}
+
+/// Parse a MAC address written with colons, dashes, dots, or no separators
+pub fn parse_mac(mac: &str) -> Result<[u8; 6], String> {
+    let hex: String = mac.chars().filter(|c| !matches!(c, ':' | '-' | '.' | ' ')).collect();
+    if hex.len() != 12 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(format!("'{}' is not a MAC address (expected 12 hex digits, e.g. 00:11:22:33:44:55)", mac));
+    }
+    let mut bytes = [0u8; 6];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).map_err(|e| e.to_string())?;
+    }
+    Ok(bytes)
+}
+
+/// Build a magic packet: 6 bytes of 0xFF followed by the MAC repeated 16 times
+pub fn magic_packet(mac: [u8; 6]) -> [u8; 102] {
+    let mut packet = [0xFFu8; 102];
+    for chunk in packet[6..].chunks_exact_mut(6) {
+        chunk.copy_from_slice(&mac);
+    }
+    packet
+}
+
+/// Send a Wake-on-LAN magic packet to `mac` via UDP broadcast
+pub fn send_wol(mac: &str, broadcast_addr: Option<&str>) -> Result<(), String> {
+    let mac = parse_mac(mac)?;
+    let broadcast: std::net::Ipv4Addr = match broadcast_addr.map(str::trim).filter(|b| !b.is_empty()) {
+        Some(addr) => addr.parse().map_err(|_| format!("'{}' is not an IPv4 broadcast address", addr))?,
+        None => std::net::Ipv4Addr::BROADCAST,
+    };
+
+    let socket = std::net::UdpSocket::bind(("0.0.0.0", 0)).map_err(|e| format!("Failed to open UDP socket: {}", e))?;
+    socket.set_broadcast(true).map_err(|e| format!("Failed to enable broadcast: {}", e))?;
+    socket
+        .send_to(&magic_packet(mac), (broadcast, WOL_PORT))
+        .map_err(|e| format!("Failed to send magic packet to {}: {}", broadcast, e))?;
+    Ok(())
+}
+
+/// Default service for the public IP lookup (returns the address as plain text)
+pub const DEFAULT_PUBLIC_IP_ENDPOINT: &str = "https://api.ipify.org";
+
+/// Public address as seen by an external service
+#[derive(Debug, Clone, PartialEq)]
+pub struct PublicIp {
+    pub ip: std::net::IpAddr,
+    /// City/region/country when the endpoint returns JSON (e.g. https://ipinfo.io/json)
+    pub location: Option<String>,
+    /// Provider or AS name when the endpoint returns JSON
+    pub org: Option<String>,
+}
+
+/// Check a user-configured endpoint URL before building a curl/PowerShell command from it
+pub fn validate_endpoint_url(url: &str) -> Result<(), String> {
+    if !url.starts_with("http://") && !url.starts_with("https://") {
+        return Err("Endpoint must start with http:// or https://".to_string());
+    }
+    if let Some(c) = url.chars().find(|c| c.is_whitespace() || "'\"`$;|&<>".contains(*c)) {
+        return Err(format!("Endpoint contains an invalid character: '{}'", c));
+    }
+    Ok(())
+}
+
+/// Look up the public IP address by fetching `endpoint` (curl, PowerShell fallback)
+///
+/// The endpoint may return a bare address or JSON with an `ip` field plus optional location.
+pub async fn get_public_ip(endpoint: &str) -> (Result<PublicIp, String>, CommandOutput) {
+    if let Err(e) = validate_endpoint_url(endpoint) {
+        return (Err(e), CommandOutput::default());
+    }
+
+    let curl = which::which("curl").is_ok();
+    let output = if curl {
+        run_command("curl", &["-sS", "--fail", "--max-time", "10", endpoint]).await
+    } else {
+        let script = format!(
+            "(Invoke-WebRequest -Uri '{}' -UseBasicParsing -TimeoutSec 10).Content",
+            endpoint
+        );
+        run_command("powershell", &["-NoProfile", "-Command", &script]).await
+    };
+
+    if !output.succeeded() {
+        // curl: 6 = can't resolve host, 7 = can't connect, 28 = timed out
+        let stderr = output.stderr.to_lowercase();
+        let offline = if curl {
+            matches!(output.exit_code, 6 | 7 | 28)
+        } else {
+            ["could not be resolved", "unable to connect", "timed out", "no such host"]
+                .iter()
+                .any(|s| stderr.contains(s))
+        };
+        let error = if offline {
+            format!("No internet connectivity - could not reach {}", endpoint)
+        } else {
+            let detail = output.stderr.lines().find(|l| !l.trim().is_empty()).unwrap_or("").trim();
+            format!("Public IP lookup failed: {}", detail)
+        };
+        return (Err(error), output);
+    }
+
+    let result = parse_public_ip(output.stdout.trim())
+        .ok_or_else(|| format!("{} did not return an IP address", endpoint));
+    (result, output)
+}
+
+/// Parse a bare IP address or an ipinfo/ipapi-style JSON object
+fn parse_public_ip(body: &str) -> Option<PublicIp> {
+    if let Ok(ip) = body.parse() {
+        return Some(PublicIp { ip, location: None, org: None });
+    }
+
+    let json: serde_json::Value = serde_json::from_str(body).ok()?;
+    let field = |names: &[&str]| {
+        names
+            .iter()
+            .find_map(|n| json.get(*n).and_then(|v| v.as_str()))
+            .filter(|v| !v.is_empty())
+            .map(str::to_string)
+    };
+    let ip = field(&["ip", "query"])?.parse().ok()?;
+    let location: Vec<String> = [
+        field(&["city"]),
+        field(&["region", "regionName"]),
+        field(&["country_name", "country"]),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    Some(PublicIp {
+        ip,
+        location: (!location.is_empty()).then(|| location.join(", ")),
+        org: field(&["org", "isp"]),
+    })
+}
+
+/// Per-user (WinINet) proxy settings key under HKCU
+const INTERNET_SETTINGS_KEY: &str = r"Software\Microsoft\Windows\CurrentVersion\Internet Settings";
+
+/// System-wide WinHTTP proxy and per-user proxy configuration
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProxySettings {
+    /// WinHTTP proxy server (None = direct access); used by services and many CLI tools
+    pub winhttp_proxy: Option<String>,
+    /// WinHTTP bypass list
+    pub winhttp_bypass: Option<String>,
+    /// Per-user proxy switch (ProxyEnable); used by browsers and most desktop apps
+    pub user_proxy_enabled: bool,
+    /// Per-user proxy server (ProxyServer), kept even while disabled
+    pub user_proxy_server: Option<String>,
+    /// Proxy auto-config script (AutoConfigURL)
+    pub auto_config_url: Option<String>,
+}
+
+/// Read the WinHTTP proxy (`netsh winhttp show proxy`) and the per-user Internet Settings
+pub async fn get_proxy_settings() -> Result<ProxySettings, String> {
+    let output = run_command("netsh", &["winhttp", "show", "proxy"]).await;
+    if !output.succeeded() {
+        return Err(format!("netsh winhttp show proxy failed: {}", output.stderr.trim()));
+    }
+    let (winhttp_proxy, winhttp_bypass) = parse_winhttp_proxy(&output.stdout);
+
+    let non_empty = |v: Option<String>| v.filter(|v| !v.trim().is_empty());
+    Ok(ProxySettings {
+        winhttp_proxy,
+        winhttp_bypass,
+        user_proxy_enabled: registry::read_dword(Hive::CurrentUser, INTERNET_SETTINGS_KEY, "ProxyEnable")
+            .is_some_and(|v| v != 0),
+        user_proxy_server: non_empty(registry::read_string(Hive::CurrentUser, INTERNET_SETTINGS_KEY, "ProxyServer")),
+        auto_config_url: non_empty(registry::read_string(Hive::CurrentUser, INTERNET_SETTINGS_KEY, "AutoConfigURL")),
+    })
+}
+
+/// Extract proxy server and bypass list from `netsh winhttp show proxy` (English or German)
+///
+/// Direct access prints no `key : value` lines, so both come back None.
+fn parse_winhttp_proxy(output: &str) -> (Option<String>, Option<String>) {
+    let mut proxy = None;
+    let mut bypass = None;
+
+    for line in output.lines() {
+        // Split at the first colon - the value itself may be host:port
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let key = key.trim().to_lowercase();
+        let value = value.trim();
+        if value.is_empty() {
+            continue;
+        }
+        if key.contains("bypass") || key.contains("umgehung") {
+            bypass = Some(value.to_string());
+        } else if key.contains("proxy") {
+            proxy = Some(value.to_string());
+        }
+    }
+
+    (proxy, bypass)
+}
+
+/// Reset the WinHTTP proxy to direct access (requires admin)
+pub async fn reset_winhttp_proxy() -> CommandOutput {
+    run_command("netsh", &["winhttp", "reset", "proxy"])
+        .await
+        .requires_admin()
+        .with_danger(DangerLevel::Modifying)
+}
+
+/// Turn off the per-user proxy (ProxyEnable = 0), keeping the server and PAC URL
+///
+/// Running apps may keep the old setting until restarted.
+pub async fn disable_user_proxy() -> CommandOutput {
+    let key = format!(r"HKCU\{}", INTERNET_SETTINGS_KEY);
+    run_command(
+        "reg",
+        &["add", &key, "/v", "ProxyEnable", "/t", "REG_DWORD", "/d", "0", "/f"],
+    )
+    .await
+    .with_danger(DangerLevel::Modifying)
+}
+
+/// Maximum capture file size in MB before netsh wraps/stops
+pub const CAPTURE_MAX_SIZE_MB: u32 = 256;
+
+/// State of a `netsh trace` session
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CaptureStatus {
+    /// True if a trace session is running (possibly started by a previous app session)
+    pub running: bool,
+    /// Path of the .etl file being written
+    pub trace_file: Option<String>,
+}
+
+/// Directory where captures are written
+pub fn capture_dir() -> std::path::PathBuf {
+    std::env::temp_dir().join("SanitySuite")
+}
+
+/// Query `netsh trace show status` for a running capture
+pub async fn capture_status() -> (CaptureStatus, CommandOutput) {
+    let output = run_command("netsh", &["trace", "show", "status"]).await;
+    let mut status = CaptureStatus::default();
+
+    for line in output.stdout.lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        match key.trim() {
+            "Status" => status.running = value.eq_ignore_ascii_case("Running"),
+            "Trace File" if !value.is_empty() => status.trace_file = Some(value.to_string()),
+            _ => {}
+        }
+    }
+
+    (status, output)
+}
+
+/// Start a packet capture (requires admin), optionally filtered to one IPv4 address
+///
+/// netsh capture filters have no port filter - filter by port when analysing the trace.
+pub async fn start_capture(ipv4_filter: Option<std::net::Ipv4Addr>) -> (String, CommandOutput) {
+    let dir = capture_dir();
+    let _ = std::fs::create_dir_all(&dir);
+    let file = dir
+        .join(format!("capture-{}.etl", chrono::Local::now().format("%Y%m%d-%H%M%S")))
+        .to_string_lossy()
+        .to_string();
+
+    let tracefile = format!("tracefile={}", file);
+    let maxsize = format!("maxsize={}", CAPTURE_MAX_SIZE_MB);
+    let mut args = vec!["trace", "start", "capture=yes", tracefile.as_str(), maxsize.as_str(), "persistent=no"];
+
+    let address = ipv4_filter.map(|ip| format!("IPv4.Address={}", ip));
+    if let Some(address) = &address {
+        args.push(address.as_str());
+    }
+
+    // Each capture needs a fresh file name, so it can't be replayed as-is
+    let output = run_command("netsh", &args).await.without_replay();
+    (file, output)
+}
+
+/// Stop the running packet capture (requires admin) - netsh finalizes the .etl file here
+pub async fn stop_capture() -> CommandOutput {
+    run_command("netsh", &["trace", "stop"]).await.without_replay()
+}
+
+/// One command in the network snapshot bundle
+enum SnapshotCommand {
+    Program(&'static str, &'static [&'static str]),
+    PowerShell(&'static str),
+}
+
+/// Everything support needs to see when "the network is weird", in capture order
+const SNAPSHOT_COMMANDS: &[SnapshotCommand] = &[
+    SnapshotCommand::Program("ipconfig", &["/all"]),
+    SnapshotCommand::Program("route", &["print"]),
+    SnapshotCommand::Program("arp", &["-a"]),
+    SnapshotCommand::Program("netstat", &["-ano"]),
+    SnapshotCommand::PowerShell("Get-DnsClientServerAddress | Format-Table -AutoSize | Out-String -Width 200"),
+    SnapshotCommand::Program("netsh", &["winhttp", "show", "proxy"]),
+    SnapshotCommand::Program("netsh", &["wlan", "show", "interfaces"]),
+];
+
+/// Number of commands `capture_snapshot` runs
+pub const SNAPSHOT_STEPS: usize = SNAPSHOT_COMMANDS.len();
+
+/// Run the read-only network snapshot commands one after another
+///
+/// `on_step` is called with the number of completed commands after each one finishes, so
+/// callers can show progress. Failed commands (e.g. `netsh wlan` without a wireless adapter)
+/// are kept - their error text is part of the picture.
+pub async fn capture_snapshot(mut on_step: impl FnMut(usize, &CommandOutput)) -> Vec<CommandOutput> {
+    let mut outputs = Vec::with_capacity(SNAPSHOT_STEPS);
+    for command in SNAPSHOT_COMMANDS {
+        let output = match command {
+            SnapshotCommand::Program(program, args) => run_command(program, args).await,
+            SnapshotCommand::PowerShell(script) => run_powershell(script).await,
+        };
+        outputs.push(output);
+        on_step(outputs.len(), &outputs[outputs.len() - 1]);
+    }
+    outputs
+}
+
+/// Render snapshot outputs as one plain-text report
+pub fn format_snapshot(outputs: &[CommandOutput]) -> String {
+    let mut report = format!(
+        "Sanity Suite network snapshot\nComputer: {}\nCaptured: {}\n",
+        std::env::var("COMPUTERNAME").unwrap_or_default(),
+        chrono::Local::now().format("%Y-%m-%d %H:%M:%S %z"),
+    );
+    for output in outputs {
+        report.push_str(&format!(
+            "\n==== {} (exit code {}, {} ms) ====\n",
+            output.command, output.exit_code, output.duration_ms
+        ));
+        report.push_str(output.stdout.trim_end());
+        report.push('\n');
+        if !output.stderr.trim().is_empty() {
+            report.push_str("---- stderr ----\n");
+            report.push_str(output.stderr.trim_end());
+            report.push('\n');
+        }
+    }
+    report
+}
+
+/// Default file name for a snapshot taken now
+pub fn snapshot_file_name() -> String {
+    format!("network-snapshot-{}.txt", chrono::Local::now().format("%Y%m%d-%H%M%S"))
+}
+
+/// Per-request timeout for URL health checks
+pub const URL_CHECK_TIMEOUT_SECS: u64 = 5;
+
+/// A user-defined URL to health-check
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct UrlCheck {
+    /// Display name (e.g. "API")
+    pub name: String,
+    /// Full URL including scheme
+    pub url: String,
+    /// Expected status code; any non-error status (< 400) counts as up when unset
+    #[serde(default)]
+    pub expected_status: Option<u16>,
+}
+
+impl UrlCheck {
+    /// Host and port of the URL (default port for the scheme when omitted)
+    pub fn host_port(&self) -> Option<(String, u16)> {
+        let url = reqwest::Url::parse(&self.url).ok()?;
+        Some((url.host_str()?.to_string(), url.port_or_known_default()?))
+    }
+}
+
+/// Why a URL check ended the way it did
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UrlOutcome {
+    /// Responded with the expected status
+    #[default]
+    Up,
+    /// Responded, but with an unexpected status
+    BadStatus,
+    /// No response within the timeout
+    Timeout,
+    /// Nothing listening on the port
+    ConnectionRefused,
+    /// TLS handshake or certificate failure
+    Tls,
+    /// Invalid URL, DNS failure, or anything else
+    Error,
+}
+
+impl UrlOutcome {
+    /// Message key of the short badge label
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            UrlOutcome::Up => "network.url-outcome.up",
+            UrlOutcome::BadStatus => "network.url-outcome.bad-status",
+            UrlOutcome::Timeout => "network.url-outcome.timeout",
+            UrlOutcome::ConnectionRefused => "network.url-outcome.refused",
+            UrlOutcome::Tls => "network.url-outcome.tls",
+            UrlOutcome::Error => "network.url-outcome.down",
+        }
+    }
+
+    pub fn is_up(&self) -> bool {
+        matches!(self, UrlOutcome::Up)
+    }
+}
+
+/// Result of checking one URL
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UrlCheckResult {
+    pub url: String,
+    pub outcome: UrlOutcome,
+    /// HTTP status code, if a response came back
+    pub status: Option<u16>,
+    /// Time until the response headers arrived (or the failure)
+    pub latency_ms: u64,
+    /// Error detail for failed checks
+    pub error: String,
+}
+
+/// Classify a reqwest error by walking its source chain
+fn classify_request_error(err: &reqwest::Error) -> UrlOutcome {
+    if err.is_timeout() {
+        return UrlOutcome::Timeout;
+    }
+
+    let mut source: Option<&(dyn std::error::Error + 'static)> = std::error::Error::source(err);
+    while let Some(e) = source {
+        if let Some(io) = e.downcast_ref::<std::io::Error>() {
+            match io.kind() {
+                std::io::ErrorKind::ConnectionRefused => return UrlOutcome::ConnectionRefused,
+                std::io::ErrorKind::TimedOut => return UrlOutcome::Timeout,
+                _ => {}
+            }
+        }
+        let text = e.to_string().to_lowercase();
+        if text.contains("certificate") || text.contains("tls") || text.contains("handshake") {
+            return UrlOutcome::Tls;
+        }
+        source = e.source();
+    }
+
+    UrlOutcome::Error
+}
+
+/// Full error text including the source chain
+fn error_detail(err: &reqwest::Error) -> String {
+    let mut detail = err.to_string();
+    let mut source = std::error::Error::source(err);
+    while let Some(e) = source {
+        detail.push_str(&format!(": {}", e));
+        source = e.source();
+    }
+    detail
+}
+
+/// Check a single URL with an existing client
+async fn check_url(client: &reqwest::Client, check: &UrlCheck) -> UrlCheckResult {
+    let start = std::time::Instant::now();
+    let response = client.get(&check.url).send().await;
+    let latency_ms = start.elapsed().as_millis() as u64;
+
+    match response {
+        Ok(resp) => {
+            let code = resp.status().as_u16();
+            let up = match check.expected_status {
+                Some(expected) => code == expected,
+                None => code < 400,
+            };
+            UrlCheckResult {
+                url: check.url.clone(),
+                outcome: if up { UrlOutcome::Up } else { UrlOutcome::BadStatus },
+                status: Some(code),
+                latency_ms,
+                error: if up {
+                    String::new()
+                } else {
+                    match check.expected_status {
+                        Some(expected) => format!("Expected status {}, got {}", expected, code),
+                        None => format!("Server returned {}", resp.status()),
+                    }
+                },
+            }
+        }
+        Err(e) => UrlCheckResult {
+            url: check.url.clone(),
+            outcome: classify_request_error(&e),
+            status: None,
+            latency_ms,
+            error: error_detail(&e),
+        },
+    }
+}
+
+/// Check all URLs concurrently; results are returned in the same order as `checks`
+pub async fn check_urls(checks: &[UrlCheck]) -> Vec<UrlCheckResult> {
+    let client = match reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(URL_CHECK_TIMEOUT_SECS))
+        .build()
+    {
+        Ok(c) => c,
+        Err(e) => {
+            return checks
+                .iter()
+                .map(|c| UrlCheckResult {
+                    url: c.url.clone(),
+                    outcome: UrlOutcome::Error,
+                    error: format!("Failed to create HTTP client: {}", e),
+                    ..Default::default()
+                })
+                .collect();
+        }
+    };
+
+    let mut tasks = tokio::task::JoinSet::new();
+    for (index, check) in checks.iter().cloned().enumerate() {
+        let client = client.clone();
+        tasks.spawn(async move { (index, check_url(&client, &check).await) });
+    }
+
+    let mut results: Vec<Option<UrlCheckResult>> = vec![None; checks.len()];
+    while let Some(joined) = tasks.join_next().await {
+        if let Ok((index, result)) = joined {
+            results[index] = Some(result);
+        }
+    }
+
+    results
+        .into_iter()
+        .zip(checks)
+        .map(|(result, check)| {
+            result.unwrap_or_else(|| UrlCheckResult {
+                url: check.url.clone(),
+                outcome: UrlOutcome::Error,
+                error: "Check task panicked".to_string(),
+                ..Default::default()
+            })
         })
         .collect()
 }
 
-/// Get detailed network adapter info using ipconfig
-/// Reserved for future network adapter display feature
-#[allow(dead_code)]
-pub async fn get_adapter_details() -> CommandOutput {
-    run_command("ipconfig", &["/all"]).await
+/// Live traffic and error counters for one interface
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AdapterStats {
+    /// Interface name (matches the ipconfig adapter name)
+    pub name: String,
+    /// Bytes received per second since the previous sample
+    pub rx_per_sec: f64,
+    /// Bytes transmitted per second since the previous sample
+    pub tx_per_sec: f64,
+    pub total_received: u64,
+    pub total_transmitted: u64,
+    pub packets_received: u64,
+    pub packets_transmitted: u64,
+    /// Receive errors since boot/driver load
+    pub rx_errors: u64,
+    /// Transmit errors since boot/driver load
+    pub tx_errors: u64,
+}
+
+impl AdapterStats {
+    /// Error counters point at bad cables, duplex mismatches, or driver trouble
+    pub fn has_errors(&self) -> bool {
+        self.rx_errors > 0 || self.tx_errors > 0
+    }
+}
+
+/// Sample per-interface counters; rates are deltas since the previous call
+///
+/// The sysinfo handle is kept between calls so the deltas span exactly one refresh
+/// interval. The first call reports zero rates.
+pub fn get_adapter_stats() -> Vec<AdapterStats> {
+    static SAMPLER: OnceLock<Mutex<(Networks, Instant)>> = OnceLock::new();
+    let sampler = SAMPLER.get_or_init(|| Mutex::new((Networks::new_with_refreshed_list(), Instant::now())));
+    let mut guard = sampler.lock().unwrap_or_else(|e| e.into_inner());
+    let (networks, last) = &mut *guard;
+
+    networks.refresh();
+    let elapsed = last.elapsed().as_secs_f64();
+    *last = Instant::now();
+    let per_sec = |bytes: u64| if elapsed > 0.0 { bytes as f64 / elapsed } else { 0.0 };
+
+    let mut stats: Vec<AdapterStats> = networks
+        .iter()
+        .map(|(name, data)| AdapterStats {
+            name: name.clone(),
+            rx_per_sec: per_sec(data.received()),
+            tx_per_sec: per_sec(data.transmitted()),
+            total_received: data.total_received(),
+            total_transmitted: data.total_transmitted(),
+            packets_received: data.total_packets_received(),
+            packets_transmitted: data.total_packets_transmitted(),
+            rx_errors: data.total_errors_on_received(),
+            tx_errors: data.total_errors_on_transmitted(),
+        })
+        .collect();
+    stats.sort_by(|a, b| a.name.cmp(&b.name));
+    stats
+}
+
+/// Get network adapters with their addresses, gateways, DNS servers, and link state
+pub async fn get_network_adapters() -> Result<Vec<NetworkAdapter>, String> {
+    let output = run_command("ipconfig", &["/all"]).await;
+    if !output.succeeded() {
+        return Err(format!("ipconfig failed (exit code {}): {}", output.exit_code, output.stderr.trim()));
+    }
+    Ok(parse_ipconfig_all(&output.stdout))
+}
+
+/// Which list a continuation line of `ipconfig /all` belongs to
+#[derive(Clone, Copy, PartialEq)]
+enum IpconfigField {
+    Address,
+    Gateway,
+    DnsServer,
+    Other,
+}
+
+/// Parse `ipconfig /all` output into adapters
+///
+/// Adapter blocks start with an unindented header ("Ethernet adapter Ethernet:"); their
+/// fields are "Label . . . : value" lines, and multi-valued fields (addresses, gateways,
+/// DNS servers) continue on indented lines without a label. English and German labels
+/// are recognized.
+pub fn parse_ipconfig_all(output: &str) -> Vec<NetworkAdapter> {
+    let mut adapters: Vec<NetworkAdapter> = Vec::new();
+    let mut field = IpconfigField::Other;
+
+    for line in output.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        // Adapter header; the "Windows IP Configuration" header has no trailing colon
+        if !line.starts_with(char::is_whitespace) {
+            field = IpconfigField::Other;
+            if let Some(header) = line.trim_end().strip_suffix(':') {
+                let (kind, name) = match header.find(" adapter ").or_else(|| header.find("-Adapter ")) {
+                    Some(i) => (header[..i + 8].trim(), header[i + 9..].trim()),
+                    None => ("", header.trim()),
+                };
+                adapters.push(NetworkAdapter {
+                    name: name.to_string(),
+                    kind: kind.to_string(),
+                    is_up: true,
+                    ..Default::default()
+                });
+            }
+            continue;
+        }
+
+        let Some(adapter) = adapters.last_mut() else {
+            continue;
+        };
+
+        let (label, value) = match line.split_once(" :") {
+            Some((label, value)) => (Some(label.trim().trim_end_matches(['.', ' ']).to_lowercase()), value.trim()),
+            None => (None, line.trim()),
+        };
+
+        if let Some(label) = label {
+            field = if label.contains("ipv4") || label.contains("ipv6") {
+                IpconfigField::Address
+            } else if label.contains("gateway") {
+                IpconfigField::Gateway
+            } else if label.contains("dns") && label.contains("server") {
+                IpconfigField::DnsServer
+            } else {
+                IpconfigField::Other
+            };
+
+            if label.starts_with("media state") || label.starts_with("medienstatus") {
+                adapter.is_up = false;
+            } else if label.starts_with("description") || label.starts_with("beschreibung") {
+                adapter.description = value.to_string();
+            } else if label.starts_with("physical address") || label.starts_with("physische adresse") {
+                adapter.mac = (!value.is_empty()).then(|| value.to_string());
+            }
+        }
+
+        // Addresses carry a "(Preferred)"/"(Deprecated)" suffix; link-local ones a "%zone" index
+        let value = value.split('(').next().unwrap_or_default().trim();
+        let Ok(ip) = value.split('%').next().unwrap_or_default().parse::<std::net::IpAddr>() else {
+            continue;
+        };
+        let target = match field {
+            IpconfigField::Address => &mut adapter.addresses,
+            IpconfigField::Gateway => &mut adapter.gateways,
+            IpconfigField::DnsServer => &mut adapter.dns_servers,
+            IpconfigField::Other => continue,
+        };
+        target.push(ip.to_string());
+    }
+
+    adapters
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `ipconfig /all` with a connected dual-stack adapter, a disconnected one, and a tunnel
+    const IPCONFIG_ALL: &str = r"
+Windows IP Configuration
+
+   Host Name . . . . . . . . . . . . : DEVBOX
+   Primary Dns Suffix  . . . . . . . :
+   Node Type . . . . . . . . . . . . : Hybrid
+   IP Routing Enabled. . . . . . . . : No
+
+Ethernet adapter Ethernet:
+
+   Connection-specific DNS Suffix  . : lan
+   Description . . . . . . . . . . . : Intel(R) Ethernet Connection (7) I219-V
+   Physical Address. . . . . . . . . : 3C-7C-3F-1A-2B-3C
+   DHCP Enabled. . . . . . . . . . . : Yes
+   IPv6 Address. . . . . . . . . . . : 2001:db8::1c(Preferred)
+   Temporary IPv6 Address. . . . . . : 2001:db8::5d2e(Deprecated)
+   Link-local IPv6 Address . . . . . : fe80::1c2d:3e4f:5a6b:7c8d%12(Preferred)
+   IPv4 Address. . . . . . . . . . . : 192.168.1.50(Preferred)
+   Subnet Mask . . . . . . . . . . . : 255.255.255.0
+   IPv4 Address. . . . . . . . . . . : 192.168.1.51(Preferred)
+   Subnet Mask . . . . . . . . . . . : 255.255.255.0
+   Lease Obtained. . . . . . . . . . : Friday, October 16, 2026 8:02:11 AM
+   Default Gateway . . . . . . . . . : fe80::1%12
+                                       192.168.1.1
+   DHCP Server . . . . . . . . . . . : 192.168.1.1
+   DHCPv6 IAID . . . . . . . . . . . : 104627263
+   DNS Servers . . . . . . . . . . . : 192.168.1.1
+                                       1.1.1.1
+   NetBIOS over Tcpip. . . . . . . . : Enabled
+
+Wireless LAN adapter Wi-Fi:
+
+   Media State . . . . . . . . . . . : Media disconnected
+   Connection-specific DNS Suffix  . :
+   Description . . . . . . . . . . . : Intel(R) Wi-Fi 6 AX201 160MHz
+   Physical Address. . . . . . . . . : 70-9C-D1-4E-5F-60
+   DHCP Enabled. . . . . . . . . . . : Yes
+
+Tunnel adapter Teredo Tunneling Pseudo-Interface:
+
+   Description . . . . . . . . . . . : Teredo Tunneling Pseudo-Interface
+   Physical Address. . . . . . . . . :
+";
+
+    #[test]
+    fn ipconfig_collects_multiple_addresses_and_continuation_lines() {
+        let adapters = parse_ipconfig_all(IPCONFIG_ALL);
+        assert_eq!(adapters.len(), 3);
+
+        let ethernet = &adapters[0];
+        assert_eq!(ethernet.name, "Ethernet");
+        assert_eq!(ethernet.kind, "Ethernet adapter");
+        assert_eq!(ethernet.description, "Intel(R) Ethernet Connection (7) I219-V");
+        assert_eq!(ethernet.mac.as_deref(), Some("3C-7C-3F-1A-2B-3C"));
+        assert!(ethernet.is_up);
+        assert_eq!(
+            ethernet.addresses,
+            vec!["2001:db8::1c", "2001:db8::5d2e", "fe80::1c2d:3e4f:5a6b:7c8d", "192.168.1.50", "192.168.1.51"]
+        );
+        assert_eq!(ethernet.gateways, vec!["fe80::1", "192.168.1.1"]);
+        assert_eq!(ethernet.dns_servers, vec!["192.168.1.1", "1.1.1.1"]);
+    }
+
+    #[test]
+    fn ipconfig_marks_disconnected_adapters_down() {
+        let adapters = parse_ipconfig_all(IPCONFIG_ALL);
+
+        let wifi = &adapters[1];
+        assert_eq!((wifi.name.as_str(), wifi.kind.as_str()), ("Wi-Fi", "Wireless LAN adapter"));
+        assert!(!wifi.is_up);
+        assert!(wifi.addresses.is_empty() && wifi.gateways.is_empty() && wifi.dns_servers.is_empty());
+
+        let teredo = &adapters[2];
+        assert_eq!(teredo.name, "Teredo Tunneling Pseudo-Interface");
+        assert_eq!(teredo.mac, None);
+    }
+
+    #[test]
+    fn ipconfig_reads_german_labels() {
+        let output = "Ethernet-Adapter Ethernet 2:\n\n   \
+            Medienstatus. . . . . . . . . . . : Medium getrennt\n   \
+            Beschreibung. . . . . . . . . . . : Realtek USB GbE\n   \
+            Physische Adresse . . . . . . . . : 00-E0-4C-68-01-02\n";
+        let adapters = parse_ipconfig_all(output);
+        assert_eq!(adapters.len(), 1);
+        assert_eq!((adapters[0].kind.as_str(), adapters[0].name.as_str()), ("Ethernet-Adapter", "Ethernet 2"));
+        assert_eq!(adapters[0].description, "Realtek USB GbE");
+        assert_eq!(adapters[0].mac.as_deref(), Some("00-E0-4C-68-01-02"));
+        assert!(!adapters[0].is_up);
+    }
+
+    /// `Get-NetRoute | Select-Object ... | ConvertTo-Json` rows, trimmed to a typical laptop
+    const NET_ROUTES: &str = r#"[
+        {"DestinationPrefix":"0.0.0.0/0","NextHop":"192.168.1.1","InterfaceAlias":"Wi-Fi","RouteMetric":0},
+        {"DestinationPrefix":"0.0.0.0/0","NextHop":"10.8.0.1","InterfaceAlias":"VPN","RouteMetric":5},
+        {"DestinationPrefix":"192.168.1.0/24","NextHop":"0.0.0.0","InterfaceAlias":"Wi-Fi","RouteMetric":256},
+        {"DestinationPrefix":"127.0.0.0/8","NextHop":"0.0.0.0","InterfaceAlias":"Loopback Pseudo-Interface 1","RouteMetric":256},
+        {"DestinationPrefix":"::/0","NextHop":"fe80::1","InterfaceAlias":"Wi-Fi","RouteMetric":0},
+        {"DestinationPrefix":"fe80::/64","NextHop":"::","InterfaceAlias":"Wi-Fi","RouteMetric":256},
+        {"DestinationPrefix":"garbage","NextHop":null,"InterfaceAlias":null,"RouteMetric":null}
+    ]"#;
+
+    #[test]
+    fn route_table_parses_ipv4_and_ipv6_entries() {
+        let routes = parse_route_table(NET_ROUTES).unwrap();
+        assert_eq!(routes.len(), 6);
+        assert_eq!(
+            routes[2],
+            RouteEntry {
+                destination: "192.168.1.0".to_string(),
+                prefix_len: 24,
+                gateway: "0.0.0.0".to_string(),
+                interface: "Wi-Fi".to_string(),
+                metric: 256,
+                ipv6: false,
+            }
+        );
+        assert!(routes[2].is_on_link() && !routes[2].is_default());
+        assert_eq!(routes[2].netmask(), "255.255.255.0");
+        assert!(routes[5].ipv6 && routes[5].is_on_link());
+        assert_eq!(routes[5].netmask(), "/64");
+        assert_eq!(local_subnets(&routes), vec![("192.168.1.0/24".to_string(), "Wi-Fi".to_string())]);
+    }
+
+    #[test]
+    fn default_route_prefers_ipv4_with_lowest_metric() {
+        let routes = parse_route_table(NET_ROUTES).unwrap();
+        assert!(routes[0].is_default() && routes[4].is_default());
+        assert_eq!(default_gateway(&routes), Some("192.168.1.1"));
+        assert_eq!(default_gateway(&routes[4..]), Some("fe80::1"));
+        assert_eq!(default_gateway(&routes[2..4]), None);
+    }
+
+    #[test]
+    fn route_table_accepts_a_single_object() {
+        let json = r#"{"DestinationPrefix":"0.0.0.0/0","NextHop":"192.168.1.1","InterfaceAlias":"Ethernet","RouteMetric":25}"#;
+        let routes = parse_route_table(json).unwrap();
+        assert_eq!(routes.len(), 1);
+        assert!(routes[0].is_default());
+        assert_eq!(routes[0].metric, 25);
+        assert!(parse_route_table("not json").is_err());
+    }
+
+    const MAC: [u8; 6] = [0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e];
+
+    #[test]
+    fn mac_accepts_common_notations() {
+        assert_eq!(parse_mac("00:1A:2B:3C:4D:5E"), Ok(MAC));
+        assert_eq!(parse_mac("00-1a-2b-3c-4d-5e"), Ok(MAC));
+        assert_eq!(parse_mac("001A2B3C4D5E"), Ok(MAC));
+        assert_eq!(parse_mac("001a.2b3c.4d5e"), Ok(MAC));
+    }
+
+    #[test]
+    fn mac_rejects_wrong_length_and_non_hex() {
+        assert!(parse_mac("").is_err());
+        assert!(parse_mac("00:1A:2B:3C:4D").is_err());
+        assert!(parse_mac("00:1A:2B:3C:4D:5E:6F").is_err());
+        assert!(parse_mac("00:1A:2B:3C:4D:5G").is_err());
+        assert!(parse_mac("+0:1A:2B:3C:4D:5E").is_err());
+    }
+
+    #[test]
+    fn magic_packet_is_sync_stream_then_sixteen_macs() {
+        let packet = magic_packet(MAC);
+        assert_eq!(packet[..6], [0xFF; 6]);
+        assert_eq!(packet[6..].len(), 16 * 6);
+        assert!(packet[6..].chunks_exact(6).all(|chunk| chunk == MAC));
+    }
 }
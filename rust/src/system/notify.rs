@@ -0,0 +1,38 @@
+//! Desktop notifications
+//!
+//! Windows toast notifications through the WinRT API, driven from PowerShell so no extra
+//! crate or app registration is needed.
+
+use crate::system::command::run_powershell;
+
+/// AppUserModelID toasts are shown under (Windows PowerShell is registered on every machine)
+const TOAST_APP_ID: &str = r"{1AC14E77-02E7-4E5D-B744-2EB1AE5198B7}\WindowsPowerShell\v1.0\powershell.exe";
+
+/// Show a two-line toast notification
+pub async fn show_toast(title: &str, message: &str) -> Result<(), String> {
+    let script = format!(
+        "[Windows.UI.Notifications.ToastNotificationManager, Windows.UI.Notifications, ContentType = WindowsRuntime] | Out-Null; \
+        $template = [Windows.UI.Notifications.ToastNotificationManager]::GetTemplateContent([Windows.UI.Notifications.ToastTemplateType]::ToastText02); \
+        $text = $template.GetElementsByTagName('text'); \
+        $text.Item(0).AppendChild($template.CreateTextNode('{}')) | Out-Null; \
+        $text.Item(1).AppendChild($template.CreateTextNode('{}')) | Out-Null; \
+        $toast = [Windows.UI.Notifications.ToastNotification]::new($template); \
+        [Windows.UI.Notifications.ToastNotificationManager]::CreateToastNotifier('{}').Show($toast)",
+        title.replace('\'', "''"),
+        message.replace('\'', "''"),
+        TOAST_APP_ID,
+    );
+
+    let output = run_powershell(&script).await;
+    if output.succeeded() {
+        Ok(())
+    } else {
+        Err(output
+            .stderr
+            .lines()
+            .map(str::trim)
+            .find(|l| !l.is_empty())
+            .unwrap_or("Failed to show notification")
+            .to_string())
+    }
+}
@@ -1,15 +1,21 @@
 //! Port scanning utilities
 //!
-//! Enumerate TCP socket bindings and process information.
+//! Enumerate TCP and UDP socket bindings and process information.
 //! Supports multi-layer detection: Windows TCP stack, Docker, and WSL.
 
-use crate::state::{BindingSource, DockerPortBinding, PortBinding, PortScanResult, WslPortBinding};
-use crate::system::command::hidden_command;
-use netstat2::{get_sockets_info, AddressFamilyFlags, ProtocolFlags, ProtocolSocketInfo};
-use std::collections::HashSet;
-use std::net::{TcpListener, SocketAddr};
+use crate::state::{
+    BindingSource, CommandOutput, DockerPortBinding, PortBinding, PortScanResult, Protocol, ProtocolFilter,
+    WslPortBinding,
+};
+use crate::system::command::{decode_utf16le, hidden_command, hidden_command_sync, run_command, run_powershell};
+use netstat2::{get_sockets_info, AddressFamilyFlags, ProtocolFlags, ProtocolSocketInfo, TcpState};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::ops::RangeInclusive;
+use std::net::{IpAddr, SocketAddr, TcpListener, UdpSocket};
 use std::process::Stdio;
-use sysinfo::System;
+use std::time::{Duration, Instant};
+use sysinfo::{ProcessRefreshKind, System, UpdateKind};
 
 /// Test if a port is actually in use at the kernel level via socket probe
 /// Returns true if the port is in use (bind fails), false if free
@@ -41,6 +47,20 @@ pub fn probe_port_in_use(port: u16) -> bool {
     false
 }
 
+/// UDP counterpart of `probe_port_in_use`
+pub fn probe_udp_port_in_use(port: u16) -> bool {
+    let addrs = [
+        SocketAddr::from(([0, 0, 0, 0], port)),
+        SocketAddr::from(([0, 0, 0, 0, 0, 0, 0, 0], port)),
+    ];
+
+    addrs.iter().any(|addr| match UdpSocket::bind(addr) {
+        Ok(_socket) => false,
+        // 10048 = WSAEADDRINUSE
+        Err(e) => e.raw_os_error() == Some(10048),
+    })
+}
+
 /// Check if Docker Desktop is running
 pub async fn is_docker_running() -> bool {
     match hidden_command("docker")
@@ -55,8 +75,11 @@ pub async fn is_docker_running() -> bool {
     }
 }
 
-/// Get Docker container port mappings for a specific port
-pub async fn get_docker_port_bindings(port: u16) -> Vec<DockerPortBinding> {
+/// Largest number of ports a single range scan covers
+pub const MAX_RANGE_PORTS: u32 = 2048;
+
+/// Get Docker container port mappings for the ports in a range
+pub async fn get_docker_port_bindings(ports: &RangeInclusive<u16>) -> Vec<DockerPortBinding> {
     let output = match hidden_command("docker")
         .args(["ps", "--format", "{{.ID}}|{{.Names}}|{{.Image}}|{{.Ports}}"])
         .stdout(Stdio::piped())
@@ -84,7 +107,7 @@ pub async fn get_docker_port_bindings(port: u16) -> Vec<DockerPortBinding> {
         
         // Parse port mappings like "0.0.0.0:8080->80/tcp, :::8080->80/tcp"
         for port_mapping in ports_str.split(", ") {
-            if let Some(parsed) = parse_docker_port_mapping(port_mapping, port) {
+            if let Some(parsed) = parse_docker_port_mapping(port_mapping, ports) {
                 bindings.push(DockerPortBinding {
                     container_id: container_id.clone(),
                     container_name: container_name.clone(),
@@ -101,8 +124,8 @@ pub async fn get_docker_port_bindings(port: u16) -> Vec<DockerPortBinding> {
 }
 
 /// Parse a Docker port mapping string like "0.0.0.0:8080->80/tcp"
-/// Returns (host_port, container_port, protocol) if it matches the target port
-fn parse_docker_port_mapping(mapping: &str, target_port: u16) -> Option<(u16, u16, String)> {
+/// Returns (host_port, container_port, protocol) if the host port is in the target range
+fn parse_docker_port_mapping(mapping: &str, target_ports: &RangeInclusive<u16>) -> Option<(u16, u16, String)> {
     // Format: "0.0.0.0:8080->80/tcp" or ":::8080->80/tcp"
     let arrow_pos = mapping.find("->")?;
     let host_part = &mapping[..arrow_pos];
@@ -111,7 +134,7 @@ fn parse_docker_port_mapping(mapping: &str, target_port: u16) -> Option<(u16, u1
     // Extract host port (after the last colon)
     let host_port: u16 = host_part.rsplit(':').next()?.parse().ok()?;
     
-    if host_port != target_port {
+    if !target_ports.contains(&host_port) {
         return None;
     }
     
@@ -141,12 +164,7 @@ pub async fn get_running_wsl_distros() -> Vec<String> {
     };
     
     // WSL output is UTF-16LE on Windows
-    let stdout = String::from_utf16_lossy(
-        &output.stdout
-            .chunks_exact(2)
-            .map(|chunk| u16::from_le_bytes([chunk[0], chunk[1]]))
-            .collect::<Vec<u16>>()
-    );
+    let stdout = decode_utf16le(&output.stdout);
     
     stdout
         .lines()
@@ -155,30 +173,36 @@ pub async fn get_running_wsl_distros() -> Vec<String> {
         .collect()
 }
 
-/// Get WSL port bindings for a specific port across all running distros
-pub async fn get_wsl_port_bindings(port: u16) -> Vec<WslPortBinding> {
+/// Get WSL port bindings for the ports in a range across all running distros
+pub async fn get_wsl_port_bindings(ports: &RangeInclusive<u16>, filter: ProtocolFilter) -> Vec<WslPortBinding> {
     let distros = get_running_wsl_distros().await;
     let mut bindings = Vec::new();
     
     for distro in distros {
-        // Run ss -tlnp inside WSL to get listening ports
-        // Format: State  Recv-Q Send-Q  Local Address:Port   Peer Address:Port  Process
-        let output = match hidden_command("wsl")
-            .args(["-d", &distro, "--", "ss", "-tlnp"])
-            .stdout(Stdio::piped())
-            .stderr(Stdio::null())
-            .output()
-            .await
-        {
-            Ok(o) if o.status.success() => o,
-            _ => continue,
-        };
-        
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        
-        for line in stdout.lines().skip(1) { // Skip header
-            if let Some(binding) = parse_ss_line(line, port, &distro) {
-                bindings.push(binding);
+        for (protocol, ss_flags) in [(Protocol::Tcp, "-tlnp"), (Protocol::Udp, "-ulnp")] {
+            if !filter.includes(protocol) {
+                continue;
+            }
+
+            // Run ss inside WSL to get listening (TCP) or bound (UDP) ports
+            // Format: State  Recv-Q Send-Q  Local Address:Port   Peer Address:Port  Process
+            let output = match hidden_command("wsl")
+                .args(["-d", &distro, "--", "ss", ss_flags])
+                .stdout(Stdio::piped())
+                .stderr(Stdio::null())
+                .output()
+                .await
+            {
+                Ok(o) if o.status.success() => o,
+                _ => continue,
+            };
+
+            let stdout = String::from_utf8_lossy(&output.stdout);
+
+            for line in stdout.lines().skip(1) { // Skip header
+                if let Some(binding) = parse_ss_line(line, ports, &distro, protocol) {
+                    bindings.push(binding);
+                }
             }
         }
     }
@@ -186,8 +210,13 @@ pub async fn get_wsl_port_bindings(port: u16) -> Vec<WslPortBinding> {
     bindings
 }
 
-/// Parse a line from `ss -tlnp` output
-fn parse_ss_line(line: &str, target_port: u16, distro: &str) -> Option<WslPortBinding> {
+/// Parse a line from `ss -tlnp` / `ss -ulnp` output
+fn parse_ss_line(
+    line: &str,
+    target_ports: &RangeInclusive<u16>,
+    distro: &str,
+    protocol: Protocol,
+) -> Option<WslPortBinding> {
     // Example: LISTEN  0  4096  0.0.0.0:8080  0.0.0.0:*  users:(("node",pid=1234,fd=21))
     let parts: Vec<&str> = line.split_whitespace().collect();
     if parts.len() < 5 {
@@ -201,7 +230,7 @@ fn parse_ss_line(line: &str, target_port: u16, distro: &str) -> Option<WslPortBi
     let port_str = local_addr.rsplit(':').next()?;
     let port: u16 = port_str.parse().ok()?;
     
-    if port != target_port {
+    if !target_ports.contains(&port) {
         return None;
     }
     
@@ -218,6 +247,7 @@ fn parse_ss_line(line: &str, target_port: u16, distro: &str) -> Option<WslPortBi
         pid,
         port,
         local_addr: local_addr.to_string(),
+        protocol,
     })
 }
 
@@ -248,45 +278,56 @@ fn parse_ss_process_info(info: &str) -> (String, u32) {
     ("unknown".to_string(), 0)
 }
 
-/// Enhanced port scan that checks Windows TCP stack, Docker, and WSL
-pub async fn list_bindings_enhanced(port: u16) -> PortScanResult {
+/// Enhanced port scan over `start..=end` that checks Windows TCP/UDP stack, Docker, and WSL
+///
+/// Pass the same port twice for a single-port scan. Each source is queried once for the whole range.
+pub async fn list_bindings_enhanced_range(start: u16, end: u16, filter: ProtocolFilter) -> PortScanResult {
     // Start with traditional Windows scan
-    let mut result = list_bindings(port);
-    
-    // Socket probe to detect shadow bindings
-    let port_in_use = probe_port_in_use(port);
-    let has_visible_bindings = !result.bindings.is_empty();
+    let mut result = list_bindings_range(start, end, filter).await;
+    let ports = result.start_port..=result.end_port;
     
     // Check Docker (silently fails if not running)
     let docker_bindings = if is_docker_running().await {
-        get_docker_port_bindings(port).await
+        get_docker_port_bindings(&ports).await
     } else {
         Vec::new()
     };
+    let docker_bindings: Vec<DockerPortBinding> = docker_bindings
+        .into_iter()
+        .filter(|db| filter.includes(docker_protocol(&db.protocol)))
+        .collect();
     
     // Add Docker bindings to main bindings list
     for db in &docker_bindings {
+        let protocol = docker_protocol(&db.protocol);
         result.bindings.push(PortBinding {
             pid: 0,
             process_name: format!("{} ({})", db.container_name, db.image),
+            exe_path: None,
+            cmdline: None,
             local_ip: "0.0.0.0".to_string(),
             local_port: db.host_port,
-            state: "LISTEN".to_string(),
+            protocol,
+            remote_ip: None,
+            remote_port: None,
+            state: listen_state(protocol),
+            is_listener: true,
             is_loopback: false,
             is_all_interfaces: true,
             is_orphan: false,
             is_system: false,
             source: BindingSource::Docker,
             source_detail: db.container_id.clone(),
+            services: Vec::new(),
         });
     }
     
     // Check WSL distros
-    let wsl_bindings = get_wsl_port_bindings(port).await;
+    let wsl_bindings = get_wsl_port_bindings(&ports, filter).await;
     
     // Add WSL bindings to main bindings list (but avoid duplicates with Docker)
     // Docker containers often show up in both - dedupe by checking if Docker already has it
-    let docker_has_port = !docker_bindings.is_empty();
+    let docker_ports: HashSet<u16> = docker_bindings.iter().map(|db| db.host_port).collect();
     
     for wb in &wsl_bindings {
         // Skip if this looks like a Docker process (common patterns)
@@ -294,22 +335,29 @@ pub async fn list_bindings_enhanced(port: u16) -> PortScanResult {
             || wb.process_name.contains("containerd")
             || wb.process_name.contains("com.docker");
         
-        if docker_has_port && is_docker_process {
+        if docker_ports.contains(&wb.port) && is_docker_process {
             continue;
         }
         
         result.bindings.push(PortBinding {
             pid: wb.pid,
             process_name: format!("{} [WSL:{}]", wb.process_name, wb.distro),
+            exe_path: None,
+            cmdline: None,
             local_ip: wb.local_addr.rsplit(':').nth(1).unwrap_or("0.0.0.0").to_string(),
             local_port: wb.port,
-            state: "LISTEN".to_string(),
+            protocol: wb.protocol,
+            remote_ip: None,
+            remote_port: None,
+            state: listen_state(wb.protocol),
+            is_listener: true,
             is_loopback: wb.local_addr.starts_with("127.") || wb.local_addr.starts_with("[::1]"),
             is_all_interfaces: wb.local_addr.starts_with("0.0.0.0") || wb.local_addr.starts_with("[::]") || wb.local_addr.starts_with("*"),
             is_orphan: false,
             is_system: false,
             source: BindingSource::Wsl,
             source_detail: wb.distro.clone(),
+            services: Vec::new(),
         });
     }
     
@@ -317,115 +365,468 @@ pub async fn list_bindings_enhanced(port: u16) -> PortScanResult {
     result.docker_bindings = docker_bindings;
     result.wsl_bindings = wsl_bindings;
     
-    // Detect shadow binding: port is in use but no visible source
-    result.shadow_detected = port_in_use && !has_visible_bindings && result.docker_bindings.is_empty() && result.wsl_bindings.is_empty();
-    
-    // If we detected a shadow binding, add a placeholder entry
-    if result.shadow_detected {
+    // Detect shadow bindings: socket probe says the port is in use but no source reported it
+    let visible_ports: HashSet<u16> = result.bindings.iter().map(|b| b.local_port).collect();
+    for port in ports {
+        if visible_ports.contains(&port) {
+            continue;
+        }
+        let tcp_in_use = filter.includes(Protocol::Tcp) && probe_port_in_use(port);
+        let udp_in_use = filter.includes(Protocol::Udp) && probe_udp_port_in_use(port);
+        if !(tcp_in_use || udp_in_use) {
+            continue;
+        }
+
+        // Add a placeholder entry for the shadow binding
+        result.shadow_detected = true;
         result.bindings.push(PortBinding {
             pid: 0,
             process_name: "<shadow binding>".to_string(),
+            exe_path: None,
+            cmdline: None,
             local_ip: "?".to_string(),
             local_port: port,
+            protocol: if tcp_in_use { Protocol::Tcp } else { Protocol::Udp },
+            remote_ip: None,
+            remote_port: None,
             state: "UNKNOWN".to_string(),
+            is_listener: true,
             is_loopback: false,
             is_all_interfaces: true,
             is_orphan: false,
             is_system: false,
             source: BindingSource::UnknownShadow,
             source_detail: "Port in use but source not detected".to_string(),
+            services: Vec::new(),
         });
     }
-    
+
+    attach_service_names(&mut result).await;
     result
 }
 
-/// List all TCP bindings for a specific port (Windows TCP stack only)
-pub fn list_bindings(port: u16) -> PortScanResult {
-    let mut bindings = Vec::new();
-    let mut sys = System::new();
-    sys.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
+/// Map a Docker port-mapping protocol ("tcp", "udp", "sctp") onto the protocols we scan
+fn docker_protocol(protocol: &str) -> Protocol {
+    if protocol.eq_ignore_ascii_case("udp") {
+        Protocol::Udp
+    } else {
+        Protocol::Tcp
+    }
+}
+
+/// State column text for a listening socket: UDP sockets have no connection state
+fn listen_state(protocol: Protocol) -> String {
+    match protocol {
+        Protocol::Tcp => "LISTEN".to_string(),
+        Protocol::Udp => "UDP".to_string(),
+    }
+}
+
+/// TCP states in connection lifecycle order, with the labels shown in the UI
+const TCP_STATES: &[(TcpState, &str)] = &[
+    (TcpState::Listen, "LISTEN"),
+    (TcpState::SynSent, "SYN_SENT"),
+    (TcpState::SynReceived, "SYN_RECEIVED"),
+    (TcpState::Established, "ESTABLISHED"),
+    (TcpState::FinWait1, "FIN_WAIT_1"),
+    (TcpState::FinWait2, "FIN_WAIT_2"),
+    (TcpState::CloseWait, "CLOSE_WAIT"),
+    (TcpState::Closing, "CLOSING"),
+    (TcpState::LastAck, "LAST_ACK"),
+    (TcpState::TimeWait, "TIME_WAIT"),
+    (TcpState::Closed, "CLOSED"),
+    (TcpState::DeleteTcb, "DELETE_TCB"),
+];
+
+/// TIME_WAIT sockets on the scanned ports above which the "port looks busy" warning shows
+pub const TIME_WAIT_WARN_THRESHOLD: usize = 100;
+
+/// Display label for a TCP state (netstat spelling)
+fn tcp_state_label(state: &TcpState) -> &'static str {
+    TCP_STATES
+        .iter()
+        .find(|(s, _)| s == state)
+        .map_or("UNKNOWN", |(_, label)| label)
+}
+
+/// List all TCP and/or UDP bindings whose local port is in `start..=end` (Windows stack only)
+///
+/// The bounds may be given in either order; the range is capped at `MAX_RANGE_PORTS`.
+/// The socket table is read once regardless of the range size.
+pub async fn list_bindings_range(start: u16, end: u16, filter: ProtocolFilter) -> PortScanResult {
+    let (start_port, end_port) = clamp_range(start, end);
+    let mut result = collect_bindings(filter, |s| (start_port..=end_port).contains(&s.local_port)).await;
+    result.start_port = start_port;
+    result.end_port = end_port;
+    result
+}
+
+/// A named set of ports scanned together (persisted in settings)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct PortPreset {
+    pub name: String,
+    /// Ports in ascending order
+    pub ports: Vec<u16>,
+}
+
+/// List all bindings on any of `ports` (Windows stack only), ordered (grouped) by port
+///
+/// The socket table is read once for the whole set.
+pub async fn list_bindings_for_ports(ports: &[u16], filter: ProtocolFilter) -> PortScanResult {
+    let mut result = collect_bindings(filter, |s| ports.contains(&s.local_port)).await;
+    result.bindings.sort_by_key(|b| b.local_port);
+    result.start_port = ports.iter().copied().min().unwrap_or(0);
+    result.end_port = ports.iter().copied().max().unwrap_or(0);
+    attach_service_names(&mut result).await;
+    result
+}
+
+/// Every TCP socket in LISTEN state on the machine, ordered (grouped) by port
+pub async fn list_all_listeners() -> PortScanResult {
+    let mut result = collect_bindings(ProtocolFilter::Tcp, |s| s.protocol == Protocol::Tcp && s.is_listener).await;
+    result.bindings.sort_by(|a, b| {
+        a.local_port
+            .cmp(&b.local_port)
+            .then_with(|| a.local_ip.cmp(&b.local_ip))
+            .then_with(|| a.pid.cmp(&b.pid))
+    });
+    result.start_port = 1;
+    result.end_port = u16::MAX;
+    attach_service_names(&mut result).await;
+    result
+}
+
+/// Processes that host Windows services, where the process name alone says nothing
+const SERVICE_HOSTS: &[&str] = &["svchost.exe", "services.exe", "lsass.exe", "spoolsv.exe", "wininit.exe"];
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawService {
+    process_id: u32,
+    display_name: String,
+}
+
+/// Display names of the services running in each of `pids`
+///
+/// One Win32_Service query covers all PIDs; PIDs without services are left out.
+pub async fn get_services_by_pid(pids: &[u32]) -> HashMap<u32, Vec<String>> {
+    let mut services: HashMap<u32, Vec<String>> = HashMap::new();
+    if pids.is_empty() {
+        return services;
+    }
+
+    let filter = pids
+        .iter()
+        .map(|pid| format!("ProcessId={}", pid))
+        .collect::<Vec<_>>()
+        .join(" OR ");
+    let cmd = format!(
+        "ConvertTo-Json -Compress -InputObject @(Get-CimInstance Win32_Service -Filter '{}' | Select-Object ProcessId, DisplayName)",
+        filter
+    );
+    let output = run_powershell(&cmd).await;
+    if !output.succeeded() {
+        tracing::warn!("Service lookup failed: {}", output.stderr.trim());
+        return services;
+    }
+
+    match serde_json::from_str::<Vec<RawService>>(output.stdout.trim()) {
+        Ok(raw) => {
+            for service in raw {
+                services.entry(service.process_id).or_default().push(service.display_name);
+            }
+            for names in services.values_mut() {
+                names.sort();
+            }
+        }
+        Err(e) => tracing::warn!("Failed to parse service list: {}", e),
+    }
+    services
+}
+
+/// Fill in `PortBinding::services` for bindings owned by a service host (one lookup per scan)
+async fn attach_service_names(result: &mut PortScanResult) {
+    let mut pids: Vec<u32> = result
+        .bindings
+        .iter()
+        .filter(|b| b.source == BindingSource::Windows && !b.is_system)
+        .filter(|b| SERVICE_HOSTS.contains(&b.process_name.to_lowercase().as_str()))
+        .map(|b| b.pid)
+        .collect();
+    pids.sort_unstable();
+    pids.dedup();
+    if pids.is_empty() {
+        return;
+    }
 
-    // Get all TCP sockets
+    let services = get_services_by_pid(&pids).await;
+    for binding in result.bindings.iter_mut() {
+        if let Some(names) = services.get(&binding.pid) {
+            binding.services = names.clone();
+        }
+    }
+}
+
+/// One row of the socket table, read through the IP Helper API or parsed from `netstat -ano`
+struct SocketEntry {
+    protocol: Protocol,
+    local_addr: IpAddr,
+    local_port: u16,
+    /// Remote endpoint of a connected TCP socket
+    remote: Option<(String, u16)>,
+    state: String,
+    is_listener: bool,
+    pid: u32,
+}
+
+/// Read the socket table for the selected protocols through the IP Helper API
+fn read_socket_table(filter: ProtocolFilter) -> Result<Vec<SocketEntry>, String> {
     let af_flags = AddressFamilyFlags::IPV4 | AddressFamilyFlags::IPV6;
-    let proto_flags = ProtocolFlags::TCP;
+    let proto_flags = match filter {
+        ProtocolFilter::Tcp => ProtocolFlags::TCP,
+        ProtocolFilter::Udp => ProtocolFlags::UDP,
+        ProtocolFilter::Both => ProtocolFlags::TCP | ProtocolFlags::UDP,
+    };
+    let sockets = get_sockets_info(af_flags, proto_flags).map_err(|e| e.to_string())?;
+
+    Ok(sockets
+        .into_iter()
+        .map(|socket| {
+            let pid = socket.associated_pids.first().copied().unwrap_or(0);
+            match socket.protocol_socket_info {
+                ProtocolSocketInfo::Tcp(tcp_info) => {
+                    let is_listener = tcp_info.state == TcpState::Listen;
+                    SocketEntry {
+                        protocol: Protocol::Tcp,
+                        local_addr: tcp_info.local_addr,
+                        local_port: tcp_info.local_port,
+                        // A listener's remote side is the unspecified 0.0.0.0:0
+                        remote: (!is_listener).then(|| (tcp_info.remote_addr.to_string(), tcp_info.remote_port)),
+                        state: tcp_state_label(&tcp_info.state).to_string(),
+                        is_listener,
+                        pid,
+                    }
+                }
+                ProtocolSocketInfo::Udp(udp_info) => SocketEntry {
+                    protocol: Protocol::Udp,
+                    local_addr: udp_info.local_addr,
+                    local_port: udp_info.local_port,
+                    remote: None,
+                    state: "UDP".to_string(),
+                    is_listener: true,
+                    pid,
+                },
+            }
+        })
+        .collect())
+}
+
+/// Fallback socket table for when the API enumeration fails: parse `netstat -ano`
+async fn read_socket_table_netstat(filter: ProtocolFilter) -> Result<Vec<SocketEntry>, String> {
+    let output = run_command("netstat", &["-ano"]).await;
+    if !output.succeeded() {
+        return Err(output
+            .stderr
+            .lines()
+            .map(str::trim)
+            .find(|l| !l.is_empty())
+            .unwrap_or("netstat failed")
+            .to_string());
+    }
 
-    let sockets = match get_sockets_info(af_flags, proto_flags) {
-        Ok(s) => s,
+    Ok(output
+        .stdout
+        .lines()
+        .filter_map(parse_netstat_line)
+        .filter(|entry| filter.includes(entry.protocol))
+        .collect())
+}
+
+/// Parse one `netstat -ano` row, e.g. "TCP 0.0.0.0:135 0.0.0.0:0 LISTENING 1104" or "UDP [::]:500 *:* 4480"
+///
+/// Header and blank lines yield None.
+fn parse_netstat_line(line: &str) -> Option<SocketEntry> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    match fields[..] {
+        ["TCP", local, remote, state, pid] => {
+            let (local_addr, local_port) = parse_netstat_endpoint(local)?;
+            let (remote_addr, remote_port) = parse_netstat_endpoint(remote)?;
+            // State names are localized, so listeners are recognized by their unspecified remote port
+            let is_listener = remote_port == 0;
+            Some(SocketEntry {
+                protocol: Protocol::Tcp,
+                local_addr,
+                local_port,
+                remote: (!is_listener).then(|| (remote_addr.to_string(), remote_port)),
+                state: if is_listener { "LISTEN".to_string() } else { state.to_string() },
+                is_listener,
+                pid: pid.parse().ok()?,
+            })
+        }
+        ["UDP", local, _, pid] => {
+            let (local_addr, local_port) = parse_netstat_endpoint(local)?;
+            Some(SocketEntry {
+                protocol: Protocol::Udp,
+                local_addr,
+                local_port,
+                remote: None,
+                state: "UDP".to_string(),
+                is_listener: true,
+                pid: pid.parse().ok()?,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Parse a netstat endpoint ("0.0.0.0:135", "[::]:135", "[fe80::1%12]:49664")
+fn parse_netstat_endpoint(endpoint: &str) -> Option<(IpAddr, u16)> {
+    let (addr, port) = endpoint.rsplit_once(':')?;
+    let addr = addr.trim_start_matches('[').trim_end_matches(']');
+    // Drop the IPv6 zone index
+    let addr = addr.split('%').next()?;
+    Some((addr.parse().ok()?, port.parse().ok()?))
+}
+
+/// Read the socket table once and build bindings for the sockets `keep` accepts
+///
+/// When the API enumeration fails the table is read from `netstat -ano` instead; the API
+/// error is kept in `socket_error` either way so the UI never shows an empty result as "free".
+async fn collect_bindings(filter: ProtocolFilter, keep: impl Fn(&SocketEntry) -> bool) -> PortScanResult {
+    let mut bindings = Vec::new();
+
+    let (sockets, socket_error, from_netstat) = match read_socket_table(filter) {
+        Ok(sockets) => (sockets, None, false),
         Err(e) => {
             tracing::error!("Failed to get socket info: {}", e);
-            return PortScanResult::default();
+            match read_socket_table_netstat(filter).await {
+                Ok(sockets) => (sockets, Some(e), true),
+                Err(netstat_error) => {
+                    tracing::error!("netstat fallback failed: {}", netstat_error);
+                    return PortScanResult {
+                        socket_error: Some(e),
+                        ..Default::default()
+                    };
+                }
+            }
         }
     };
 
+    // Keep the wanted sockets, then load process details (incl. command lines) only for their owners
+    let sockets: Vec<_> = sockets.into_iter().filter(|s| keep(s)).collect();
+    let pids: Vec<sysinfo::Pid> = sockets.iter().map(|s| sysinfo::Pid::from_u32(s.pid)).collect();
+
+    let mut sys = System::new();
+    sys.refresh_processes_specifics(
+        sysinfo::ProcessesToUpdate::Some(&pids),
+        true,
+        ProcessRefreshKind::new()
+            .with_exe(UpdateKind::OnlyIfNotSet)
+            .with_cmd(UpdateKind::OnlyIfNotSet),
+    );
+
     for socket in sockets {
-        if let ProtocolSocketInfo::Tcp(tcp_info) = socket.protocol_socket_info {
-            // Filter by port
-            if tcp_info.local_port != port {
-                continue;
-            }
+        let SocketEntry { protocol, local_addr, local_port, remote, state, is_listener, pid } = socket;
+        let (remote_ip, remote_port) = remote.unzip();
 
-            let local_ip = tcp_info.local_addr.to_string();
-            let pid = socket.associated_pids.first().copied().unwrap_or(0);
+        let local_ip = local_addr.to_string();
 
-            // Check if this is a system/kernel socket (PID 0 or 4)
-            let is_system = pid == 0 || pid == 4;
-
-            // Try to get process info and detect orphans
-            let (process_name, is_orphan) = if is_system {
-                // System process - use special names
-                let name = if pid == 0 {
-                    "[System Idle]".to_string()
-                } else {
-                    "[System]".to_string()
-                };
-                (name, false)
-            } else if pid > 0 {
-                match sys.process(sysinfo::Pid::from_u32(pid)) {
-                    Some(p) => (p.name().to_string_lossy().to_string(), false),
-                    None => {
-                        // Process doesn't exist - this is an orphaned socket!
-                        ("<orphaned>".to_string(), true)
-                    }
-                }
+        // Check if this is a system/kernel socket (PID 0 or 4)
+        let is_system = pid == 0 || pid == 4;
+
+        // Try to get process info and detect orphans
+        let (process_name, is_orphan) = if is_system {
+            // System process - use special names
+            let name = if pid == 0 {
+                "[System Idle]".to_string()
             } else {
-                ("<unknown>".to_string(), false)
+                "[System]".to_string()
             };
+            (name, false)
+        } else if pid > 0 {
+            match sys.process(sysinfo::Pid::from_u32(pid)) {
+                Some(p) => (p.name().to_string_lossy().to_string(), false),
+                None => {
+                    // Process doesn't exist - this is an orphaned socket!
+                    // (Only tracked for TCP; the force-close tooling is TCP/http.sys specific)
+                    ("<orphaned>".to_string(), protocol == Protocol::Tcp)
+                }
+            }
+        } else {
+            ("<unknown>".to_string(), false)
+        };
 
-            // Determine address type
-            let is_loopback = local_ip.starts_with("127.") || local_ip == "::1";
-            let is_all_interfaces = local_ip == "0.0.0.0" || local_ip == "::";
-
-            bindings.push(PortBinding {
-                pid,
-                process_name,
-                local_ip,
-                local_port: tcp_info.local_port,
-                state: format!("{:?}", tcp_info.state),
-                is_loopback,
-                is_all_interfaces,
-                is_orphan,
-                is_system,
-                source: BindingSource::Windows,
-                source_detail: String::new(),
-            });
-        }
+        // Executable path and command line; both stay None when access is denied
+        // (e.g. a standard user inspecting an elevated process)
+        let (exe_path, cmdline) = match sys.process(sysinfo::Pid::from_u32(pid)) {
+            Some(p) if !is_system => {
+                let exe_path = p.exe().map(|path| path.display().to_string());
+                let cmdline = p
+                    .cmd()
+                    .iter()
+                    .map(|arg| arg.to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                (exe_path, (!cmdline.is_empty()).then_some(cmdline))
+            }
+            _ => (None, None),
+        };
+
+        // Determine address type
+        let is_loopback = local_ip.starts_with("127.") || local_ip == "::1";
+        let is_all_interfaces = local_ip == "0.0.0.0" || local_ip == "::";
+
+        bindings.push(PortBinding {
+            pid,
+            process_name,
+            exe_path,
+            cmdline,
+            local_ip,
+            local_port,
+            protocol,
+            remote_ip,
+            remote_port,
+            state,
+            is_listener,
+            is_loopback,
+            is_all_interfaces,
+            is_orphan,
+            is_system,
+            source: BindingSource::Windows,
+            source_detail: String::new(),
+            services: Vec::new(),
+        });
     }
 
-    // Detect conflicts: PIDs that have both loopback and all-interfaces bindings
-    let loopback_pids: HashSet<u32> = bindings
+    // Detect conflicts: PIDs that have both loopback and all-interfaces TCP bindings on the same port
+    let loopback: HashSet<(u32, u16)> = bindings
         .iter()
-        .filter(|b| b.is_loopback)
-        .map(|b| b.pid)
+        .filter(|b| b.protocol == Protocol::Tcp && b.is_loopback)
+        .map(|b| (b.pid, b.local_port))
         .collect();
-    let all_interface_pids: HashSet<u32> = bindings
+    let all_interfaces: HashSet<(u32, u16)> = bindings
         .iter()
-        .filter(|b| b.is_all_interfaces)
-        .map(|b| b.pid)
+        .filter(|b| b.protocol == Protocol::Tcp && b.is_all_interfaces)
+        .map(|b| (b.pid, b.local_port))
         .collect();
-    let conflict_pids: Vec<u32> = loopback_pids
-        .intersection(&all_interface_pids)
-        .copied()
+    let conflict_pids: Vec<u32> = loopback
+        .intersection(&all_interfaces)
+        .map(|(pid, _)| *pid)
+        .collect::<HashSet<u32>>()
+        .into_iter()
+        .collect();
+
+    // Count TCP sockets per state (a TIME_WAIT pile-up makes a port look busy)
+    let state_counts: Vec<(String, usize)> = TCP_STATES
+        .iter()
+        .map(|(_, label)| {
+            let count = bindings
+                .iter()
+                .filter(|b| b.protocol == Protocol::Tcp && b.state == *label)
+                .count();
+            (label.to_string(), count)
+        })
+        .filter(|(_, count)| *count > 0)
         .collect();
 
     // Collect orphaned PIDs
@@ -442,34 +843,475 @@ pub fn list_bindings(port: u16) -> PortScanResult {
         docker_bindings: Vec::new(),
         wsl_bindings: Vec::new(),
         shadow_detected: false,
+        start_port: 0,
+        end_port: 0,
+        state_counts,
+        socket_error,
+        from_netstat,
+    }
+}
+
+/// Order the bounds and cap the span at `MAX_RANGE_PORTS`
+pub fn clamp_range(start: u16, end: u16) -> (u16, u16) {
+    let (start, end) = (start.min(end), start.max(end));
+    let max_end = start.saturating_add((MAX_RANGE_PORTS - 1) as u16);
+    (start, end.min(max_end))
+}
+
+/// Why killing a process failed
+#[derive(Debug, Clone, PartialEq)]
+pub enum KillError {
+    /// System/Idle process (PID 0 or 4)
+    Protected(u32),
+    NotFound(u32),
+    /// The process is still running and we are not elevated - most likely it runs
+    /// elevated or as another user; restarting as administrator should help
+    AccessDenied(u32),
+    Failed(String),
+}
+
+impl std::fmt::Display for KillError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KillError::Protected(pid) => write!(f, "Cannot kill system process {}", pid),
+            KillError::NotFound(pid) => write!(f, "Process {} not found", pid),
+            KillError::AccessDenied(pid) => write!(f, "Access denied killing process {} (not running as administrator)", pid),
+            KillError::Failed(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+/// A process that survived a kill attempt: access denied when we aren't elevated
+fn kill_denied_or_failed(pid: u32, message: String) -> KillError {
+    if crate::system::admin::is_elevated() {
+        KillError::Failed(message)
+    } else {
+        KillError::AccessDenied(pid)
     }
 }
 
 /// Kill a process by PID
-pub fn kill_process(pid: u32) -> Result<(), String> {
-    if pid == 0 {
-        return Err("Cannot kill PID 0".to_string());
+pub fn kill_process(pid: u32) -> Result<(), KillError> {
+    if pid == 0 || pid == 4 {
+        return Err(KillError::Protected(pid));
     }
 
     let mut sys = System::new();
     sys.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
 
-    if let Some(process) = sys.process(sysinfo::Pid::from_u32(pid)) {
+    let target = sysinfo::Pid::from_u32(pid);
+    let Some(process) = sys.process(target) else {
+        return Err(KillError::NotFound(pid));
+    };
+    if process.kill() {
+        tracing::info!("Killed process {}", pid);
+        return Ok(());
+    }
+
+    // Kill failed - it may have exited in the meantime
+    sys.refresh_processes(sysinfo::ProcessesToUpdate::Some(&[target]), true);
+    if sys.process(target).is_none() {
+        return Ok(());
+    }
+    Err(kill_denied_or_failed(pid, format!("Failed to kill process {}", pid)))
+}
+
+/// Kill every process whose name matches `name` case-insensitively (".exe" optional)
+///
+/// Returns the PIDs terminated. Never touches the System/Idle processes (PID 0 and 4) or
+/// this app itself, so a respawning dev server can be stopped without chasing its PID.
+pub fn kill_processes_by_name(name: &str) -> Result<Vec<u32>, String> {
+    let wanted = name.trim().to_lowercase();
+    let wanted = wanted.strip_suffix(".exe").unwrap_or(&wanted).to_string();
+    if wanted.is_empty() {
+        return Err("Enter a process name".to_string());
+    }
+    if matches!(wanted.as_str(), "system" | "[system]" | "system idle process" | "[system idle]") {
+        return Err(format!("Refusing to kill '{}'", name.trim()));
+    }
+
+    let mut sys = System::new();
+    sys.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
+
+    let own_pid = std::process::id();
+    let matching: Vec<&sysinfo::Process> = sys
+        .processes()
+        .values()
+        .filter(|p| {
+            let pid = p.pid().as_u32();
+            let process_name = p.name().to_string_lossy().to_lowercase();
+            pid != 0 && pid != 4 && pid != own_pid
+                && process_name.strip_suffix(".exe").unwrap_or(&process_name) == wanted
+        })
+        .collect();
+
+    if matching.is_empty() {
+        return Err(format!("No running process named '{}'", name.trim()));
+    }
+
+    let mut killed = Vec::new();
+    for process in matching {
+        let pid = process.pid().as_u32();
         if process.kill() {
-            tracing::info!("Killed process {}", pid);
-            Ok(())
+            tracing::info!("Killed process {} ({})", pid, name.trim());
+            killed.push(pid);
         } else {
-            Err(format!("Failed to kill process {}", pid))
+            tracing::warn!("Failed to kill process {} ({})", pid, name.trim());
         }
+    }
+
+    if killed.is_empty() {
+        Err(format!("Failed to kill any '{}' process (access denied?)", name.trim()))
     } else {
-        Err(format!("Process {} not found", pid))
+        killed.sort_unstable();
+        Ok(killed)
     }
 }
 
-/// Suggest a free port in the given range (uses socket probe for accuracy)
-pub fn suggest_free_port(start: u16, end: u16) -> Option<u16> {
-    // Use socket probe for accurate detection (catches Docker/WSL too)
-    (start..=end).find(|&port| !probe_port_in_use(port))
+/// One row of a process snapshot, as far as tree walking is concerned
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ProcessEntry {
+    pid: u32,
+    parent: Option<u32>,
+    /// Seconds since the epoch
+    start_time: u64,
+}
+
+/// Process snapshot and kill primitives behind `kill_process_tree`
+trait ProcessTable {
+    /// Every process at the time of the snapshot
+    fn entries(&self) -> Vec<ProcessEntry>;
+    /// Terminate a process from the snapshot; false when it refused or is already gone
+    fn kill(&mut self, pid: u32) -> bool;
+    /// Re-check whether a process is still running
+    fn is_running(&mut self, pid: u32) -> bool;
+    /// Last-resort forced kill of a process and its children
+    fn force_kill(&mut self, pid: u32) -> bool;
+}
+
+/// `ProcessTable` over a live sysinfo snapshot, with taskkill as the forced kill
+struct SystemProcessTable(System);
+
+impl SystemProcessTable {
+    fn snapshot() -> Self {
+        let mut sys = System::new();
+        sys.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
+        SystemProcessTable(sys)
+    }
+}
+
+impl ProcessTable for SystemProcessTable {
+    fn entries(&self) -> Vec<ProcessEntry> {
+        self.0
+            .processes()
+            .iter()
+            .map(|(pid, p)| ProcessEntry {
+                pid: pid.as_u32(),
+                parent: p.parent().map(|parent| parent.as_u32()),
+                start_time: p.start_time(),
+            })
+            .collect()
+    }
+
+    fn kill(&mut self, pid: u32) -> bool {
+        self.0.process(sysinfo::Pid::from_u32(pid)).is_some_and(|p| p.kill())
+    }
+
+    fn is_running(&mut self, pid: u32) -> bool {
+        let pid = sysinfo::Pid::from_u32(pid);
+        self.0.refresh_processes(sysinfo::ProcessesToUpdate::Some(&[pid]), true);
+        self.0.process(pid).is_some()
+    }
+
+    fn force_kill(&mut self, pid: u32) -> bool {
+        hidden_command_sync("taskkill")
+            .args(["/T", "/F", "/PID", &pid.to_string()])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .is_ok_and(|s| s.success())
+    }
+}
+
+/// Breadth-first walk of the parent -> child relation starting at `root`
+///
+/// A child must have started no earlier than its parent: Windows keeps the parent PID of
+/// orphans, so a reused PID would otherwise adopt unrelated processes (and can form loops).
+fn process_tree(entries: &[ProcessEntry], root: u32) -> Vec<u32> {
+    let mut tree = vec![root];
+    let mut next = 0;
+    while next < tree.len() {
+        let parent = tree[next];
+        let parent_start = entries.iter().find(|e| e.pid == parent).map_or(0, |e| e.start_time);
+        let children: Vec<u32> = entries
+            .iter()
+            .filter(|e| e.parent == Some(parent) && e.start_time >= parent_start && !tree.contains(&e.pid))
+            .map(|e| e.pid)
+            .collect();
+        tree.extend(children);
+        next += 1;
+    }
+    tree
+}
+
+/// Kill a process and all of its descendants, children first
+///
+/// Returns how many processes were terminated. Descendants that exit on their own while the
+/// tree is being walked count as done rather than as failures. Anything sysinfo can't kill is
+/// retried with `taskkill /T /F`.
+pub fn kill_process_tree(pid: u32) -> Result<usize, KillError> {
+    kill_tree_in(&mut SystemProcessTable::snapshot(), pid)
+}
+
+fn kill_tree_in(table: &mut impl ProcessTable, pid: u32) -> Result<usize, KillError> {
+    if pid == 0 || pid == 4 {
+        return Err(KillError::Protected(pid));
+    }
+
+    let entries = table.entries();
+    if !entries.iter().any(|e| e.pid == pid) {
+        return Err(KillError::NotFound(pid));
+    }
+
+    // Reverse BFS order kills every descendant before its ancestors
+    let mut killed = 0;
+    let mut failed = Vec::new();
+    for target in process_tree(&entries, pid).into_iter().rev() {
+        if table.kill(target) {
+            killed += 1;
+        } else if table.is_running(target) {
+            // Kill failed and it did not simply exit in the meantime
+            failed.push(target);
+        }
+    }
+
+    // Fall back to the forced kill for whatever is still running
+    let mut still_running: Vec<u32> = Vec::new();
+    for target in failed {
+        if table.force_kill(target) {
+            killed += 1;
+        } else {
+            still_running.push(target);
+        }
+    }
+
+    match still_running.first() {
+        None => {
+            tracing::info!("Killed process tree of {} ({} processes)", pid, killed);
+            Ok(killed)
+        }
+        Some(&first) => Err(kill_denied_or_failed(
+            first,
+            format!(
+                "Killed {} process(es), but could not kill PID(s) {}",
+                killed,
+                still_running.iter().map(u32::to_string).collect::<Vec<_>>().join(", ")
+            ),
+        )),
+    }
+}
+
+/// Common dev/infra ports and what usually listens on them, sorted by port
+/// (binary-searched by `well_known_service`; keep it sorted when adding entries)
+const WELL_KNOWN_PORTS: &[(u16, &str)] = &[
+    (20, "FTP data"),
+    (21, "FTP"),
+    (22, "SSH"),
+    (23, "Telnet"),
+    (25, "SMTP"),
+    (53, "DNS"),
+    (67, "DHCP server"),
+    (68, "DHCP client"),
+    (69, "TFTP"),
+    (80, "HTTP"),
+    (88, "Kerberos"),
+    (110, "POP3"),
+    (123, "NTP"),
+    (135, "RPC endpoint mapper"),
+    (137, "NetBIOS name"),
+    (138, "NetBIOS datagram"),
+    (139, "NetBIOS session"),
+    (143, "IMAP"),
+    (161, "SNMP"),
+    (162, "SNMP trap"),
+    (389, "LDAP"),
+    (443, "HTTPS"),
+    (445, "SMB"),
+    (465, "SMTPS"),
+    (500, "IKE (IPsec)"),
+    (514, "Syslog"),
+    (587, "SMTP submission"),
+    (631, "IPP (printing)"),
+    (636, "LDAPS"),
+    (853, "DNS over TLS"),
+    (873, "rsync"),
+    (993, "IMAPS"),
+    (995, "POP3S"),
+    (1080, "SOCKS proxy"),
+    (1194, "OpenVPN"),
+    (1433, "SQL Server"),
+    (1434, "SQL Server Browser"),
+    (1521, "Oracle DB"),
+    (1701, "L2TP"),
+    (1723, "PPTP"),
+    (1883, "MQTT"),
+    (1900, "SSDP/UPnP"),
+    (2049, "NFS"),
+    (2375, "Docker API"),
+    (2376, "Docker API (TLS)"),
+    (2377, "Docker Swarm"),
+    (2379, "etcd client"),
+    (2380, "etcd peer"),
+    (3000, "Node/React dev server"),
+    (3001, "Node dev server"),
+    (3128, "Squid proxy"),
+    (3268, "LDAP Global Catalog"),
+    (3306, "MySQL"),
+    (3389, "Remote Desktop"),
+    (3478, "STUN/TURN"),
+    (3500, "Dapr sidecar"),
+    (3690, "Subversion"),
+    (4000, "Dev server"),
+    (4200, "Angular dev server"),
+    (4222, "NATS"),
+    (4317, "OTLP gRPC"),
+    (4318, "OTLP HTTP"),
+    (4369, "Erlang EPMD"),
+    (4443, "HTTPS alt"),
+    (4500, "IPsec NAT-T"),
+    (5000, "Flask/ASP.NET dev server"),
+    (5001, "ASP.NET dev server (HTTPS)"),
+    (5037, "Android ADB"),
+    (5173, "Vite dev server"),
+    (5222, "XMPP"),
+    (5353, "mDNS"),
+    (5355, "LLMNR"),
+    (5357, "WSDAPI"),
+    (5432, "PostgreSQL"),
+    (5500, "Live Server"),
+    (5601, "Kibana"),
+    (5672, "RabbitMQ (AMQP)"),
+    (5900, "VNC"),
+    (5984, "CouchDB"),
+    (5985, "WinRM (HTTP)"),
+    (5986, "WinRM (HTTPS)"),
+    (6006, "Storybook"),
+    (6379, "Redis"),
+    (6443, "Kubernetes API"),
+    (6660, "IRC"),
+    (6667, "IRC"),
+    (7000, "Cassandra"),
+    (7001, "WebLogic"),
+    (7687, "Neo4j Bolt"),
+    (8000, "HTTP alt / Django dev server"),
+    (8008, "HTTP alt"),
+    (8080, "HTTP alt / proxy"),
+    (8081, "HTTP alt"),
+    (8086, "InfluxDB"),
+    (8088, "HTTP alt"),
+    (8200, "Vault"),
+    (8443, "HTTPS alt"),
+    (8500, "Consul"),
+    (8761, "Eureka"),
+    (8787, "RStudio"),
+    (8888, "Jupyter"),
+    (9000, "PHP-FPM / SonarQube"),
+    (9042, "Cassandra CQL"),
+    (9090, "Prometheus"),
+    (9092, "Kafka"),
+    (9093, "Alertmanager"),
+    (9100, "Node exporter / printing"),
+    (9200, "Elasticsearch"),
+    (9229, "Node.js inspector"),
+    (9300, "Elasticsearch transport"),
+    (9418, "Git"),
+    (9999, "Dev server"),
+    (10000, "Webmin"),
+    (11211, "Memcached"),
+    (15672, "RabbitMQ management"),
+    (19000, "Expo"),
+    (24678, "Vite HMR"),
+    (27017, "MongoDB"),
+    (27018, "MongoDB shard"),
+    (28017, "MongoDB web"),
+    (50051, "gRPC"),
+];
+
+/// What a port is usually used for, from an offline table plus the IANA range heuristics
+pub fn well_known_service(port: u16) -> Option<&'static str> {
+    match WELL_KNOWN_PORTS.binary_search_by_key(&port, |&(p, _)| p) {
+        Ok(index) => Some(WELL_KNOWN_PORTS[index].1),
+        Err(_) if port < 1024 => Some("IANA system port"),
+        Err(_) if port >= 49152 => Some("Dynamic/ephemeral"),
+        Err(_) => None,
+    }
+}
+
+/// TCP port ranges reserved by Windows (Hyper-V, WSL, Docker) that nothing may bind
+///
+/// Parses `netsh int ipv4 show excludedportrange protocol=tcp`; rows look like
+/// `     50000       50059     *` (the `*` marks administered exclusions).
+pub async fn get_excluded_port_ranges() -> Vec<RangeInclusive<u16>> {
+    let output = run_command("netsh", &["int", "ipv4", "show", "excludedportrange", "protocol=tcp"]).await;
+    if !output.succeeded() {
+        tracing::warn!("Failed to list excluded port ranges: {}", output.stderr.trim());
+        return Vec::new();
+    }
+    parse_excluded_port_ranges(&output.stdout)
+}
+
+/// Parse the start/end rows of `netsh int ipv4 show excludedportrange` output
+///
+/// Header, separator, and footnote lines don't start with two port numbers and are skipped.
+fn parse_excluded_port_ranges(output: &str) -> Vec<RangeInclusive<u16>> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            let start = parts.next()?.parse::<u16>().ok()?;
+            let end = parts.next()?.parse::<u16>().ok()?;
+            Some(start..=end)
+        })
+        .collect()
+}
+
+/// The excluded port range (see `get_excluded_port_ranges`) that contains `port`, if any
+pub async fn excluded_range_for_port(port: u16) -> Option<RangeInclusive<u16>> {
+    get_excluded_port_ranges().await.into_iter().find(|range| range.contains(&port))
+}
+
+/// Commands for getting `port` out of an excluded range, for pasting into an elevated prompt
+///
+/// Dynamic reservations are made by the WinNAT driver (Hyper-V, WSL, Docker). Restarting it
+/// releases them, and an administered exclusion of the single port keeps it from being
+/// reserved again while still letting applications bind it.
+pub fn excluded_range_guidance(port: u16, range: &RangeInclusive<u16>) -> String {
+    format!(
+        "REM Port {port} is inside the excluded port range {start}-{end}\r\n\
+         REM Run from an elevated command prompt:\r\n\
+         net stop winnat\r\n\
+         netsh int ipv4 add excludedportrange protocol=tcp startport={port} numberofports=1\r\n\
+         net start winnat\r\n\
+         REM Check the result with: netsh int ipv4 show excludedportrange protocol=tcp\r\n",
+        port = port,
+        start = range.start(),
+        end = range.end(),
+    )
+}
+
+/// Suggest a free port in the given range
+///
+/// Skips Windows excluded port ranges, then returns the first port that a real
+/// loopback bind accepts and that nothing holds on the wildcard addresses either.
+pub async fn suggest_free_port(start: u16, end: u16) -> Option<u16> {
+    let excluded = get_excluded_port_ranges().await;
+    let (start, end) = (start.min(end), start.max(end));
+
+    (start..=end)
+        .filter(|port| !excluded.iter().any(|range| range.contains(port)))
+        .find(|&port| {
+            TcpListener::bind(SocketAddr::from(([127, 0, 0, 1], port))).is_ok() && !probe_port_in_use(port)
+        })
 }
 
 /// Attempt to force close an orphaned socket
@@ -557,3 +1399,341 @@ pub async fn force_close_socket(binding: &PortBinding) -> Result<String, String>
         binding.pid
     ))
 }
+
+/// Outcome of a TCP connect test
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConnectOutcome {
+    Connected,
+    /// Nothing accepted the connection (RST)
+    Refused,
+    /// No answer within the timeout - a hung accept loop or a filtering firewall
+    TimedOut,
+    Failed(String),
+}
+
+/// Result of `test_connect`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConnectTest {
+    /// Address actually connected to (wildcard binds are tested over loopback)
+    pub target: String,
+    pub outcome: ConnectOutcome,
+    pub elapsed_ms: u128,
+}
+
+/// Address to connect to for a binding's local IP; wildcard binds are reached over loopback
+fn connect_target_ip(ip: &str) -> &str {
+    match ip {
+        "0.0.0.0" => "127.0.0.1",
+        "::" => "::1",
+        other => other,
+    }
+}
+
+/// Open (and immediately drop) a TCP connection to `ip:port` to check the listener accepts it
+pub async fn test_connect(ip: &str, port: u16, timeout: Duration) -> ConnectTest {
+    let start = Instant::now();
+    let target_ip = connect_target_ip(ip);
+    let target = match target_ip.parse::<IpAddr>() {
+        Ok(addr) => SocketAddr::new(addr, port),
+        Err(_) => {
+            return ConnectTest {
+                target: format!("{}:{}", target_ip, port),
+                outcome: ConnectOutcome::Failed(format!("'{}' is not an IP address", target_ip)),
+                elapsed_ms: 0,
+            }
+        }
+    };
+
+    let outcome = match tokio::time::timeout(timeout, tokio::net::TcpStream::connect(target)).await {
+        Ok(Ok(_stream)) => ConnectOutcome::Connected,
+        Ok(Err(e)) if e.kind() == std::io::ErrorKind::ConnectionRefused => ConnectOutcome::Refused,
+        Ok(Err(e)) if e.kind() == std::io::ErrorKind::TimedOut => ConnectOutcome::TimedOut,
+        Ok(Err(e)) => ConnectOutcome::Failed(e.to_string()),
+        Err(_) => ConnectOutcome::TimedOut,
+    };
+
+    ConnectTest {
+        target: target.to_string(),
+        outcome,
+        elapsed_ms: start.elapsed().as_millis(),
+    }
+}
+
+/// A Windows Firewall rule whose local port filter covers a scanned port
+#[derive(Debug, Clone, PartialEq)]
+pub struct FirewallRule {
+    /// Display name of the rule
+    pub name: String,
+    /// "Inbound" or "Outbound"
+    pub direction: String,
+    /// "Allow" or "Block"
+    pub action: String,
+    pub enabled: bool,
+    /// "TCP", "UDP", or "Any"
+    pub protocol: String,
+    /// Local port filter as configured (e.g. "80, 8000-8100")
+    pub local_ports: String,
+}
+
+impl FirewallRule {
+    /// Enabled inbound Block rule - the port is bound but unreachable from outside
+    pub fn blocks_inbound(&self) -> bool {
+        self.enabled
+            && self.action.eq_ignore_ascii_case("block")
+            && self.direction.eq_ignore_ascii_case("inbound")
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawFirewallRule {
+    name: String,
+    direction: String,
+    action: String,
+    enabled: String,
+    protocol: String,
+    local_port: Vec<String>,
+}
+
+/// Whether a firewall LocalPort entry ("8080", "8000-8100") includes `port`
+fn firewall_port_matches(spec: &str, port: u16) -> bool {
+    match spec.split_once('-') {
+        Some((start, end)) => match (start.trim().parse::<u16>(), end.trim().parse::<u16>()) {
+            (Ok(start), Ok(end)) => (start..=end).contains(&port),
+            _ => false,
+        },
+        None => spec.trim().parse::<u16>() == Ok(port),
+    }
+}
+
+/// List firewall rules with a local port filter covering `port`
+///
+/// Rules with LocalPort "Any" (most program-based rules) are not included.
+pub async fn get_firewall_rules_for_port(port: u16) -> Result<Vec<FirewallRule>, String> {
+    // Port filters are joined to their rules by InstanceID instead of querying the filter
+    // per rule, which takes tens of seconds on machines with many rules
+    let cmd = "$rules = @{}; Get-NetFirewallRule | ForEach-Object { $rules[$_.Name] = $_ }; \
+        $found = Get-NetFirewallPortFilter | Where-Object { $_.LocalPort -ne 'Any' } | ForEach-Object { \
+            $r = $rules[$_.InstanceID]; \
+            if ($r) { [pscustomobject]@{ Name = $r.DisplayName; Direction = \"$($r.Direction)\"; Action = \"$($r.Action)\"; \
+                Enabled = \"$($r.Enabled)\"; Protocol = \"$($_.Protocol)\"; LocalPort = @($_.LocalPort) } } }; \
+        ConvertTo-Json -InputObject @($found) -Compress";
+    let output = run_powershell(cmd).await;
+
+    if !output.succeeded() {
+        return Err(output
+            .stderr
+            .lines()
+            .map(str::trim)
+            .find(|l| !l.is_empty())
+            .unwrap_or("Firewall query failed")
+            .to_string());
+    }
+
+    let raw: Vec<RawFirewallRule> = serde_json::from_str(output.stdout.trim())
+        .map_err(|e| format!("Failed to parse firewall rules: {}", e))?;
+
+    let mut rules: Vec<FirewallRule> = raw
+        .into_iter()
+        .filter(|r| r.local_port.iter().any(|spec| firewall_port_matches(spec, port)))
+        .map(|r| FirewallRule {
+            name: r.name,
+            direction: r.direction,
+            action: r.action,
+            enabled: r.enabled.eq_ignore_ascii_case("true"),
+            protocol: r.protocol,
+            local_ports: r.local_port.join(", "),
+        })
+        .collect();
+
+    // Blocking rules first, then by name
+    rules.sort_by(|a, b| b.blocks_inbound().cmp(&a.blocks_inbound()).then_with(|| a.name.cmp(&b.name)));
+    Ok(rules)
+}
+
+/// Longest rule name accepted for rules created from the Ports page
+const MAX_FIREWALL_RULE_NAME_LEN: usize = 255;
+
+/// Check a firewall rule name before it is passed to netsh
+///
+/// Quotes and control characters would break the `name=` argument, and "all" is reserved
+/// (`netsh ... delete rule name=all` matches every rule).
+pub fn validate_firewall_rule_name(name: &str) -> Result<(), String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Rule name cannot be empty".to_string());
+    }
+    if name.chars().count() > MAX_FIREWALL_RULE_NAME_LEN {
+        return Err(format!("Rule name is longer than {} characters", MAX_FIREWALL_RULE_NAME_LEN));
+    }
+    if name.chars().any(|c| c == '"' || c.is_control()) {
+        return Err("Rule name cannot contain quotes or control characters".to_string());
+    }
+    if name.eq_ignore_ascii_case("all") {
+        return Err("\"all\" is reserved by netsh".to_string());
+    }
+    Ok(())
+}
+
+/// Add an inbound allow rule for `port` (requires admin)
+pub async fn create_inbound_allow_rule(port: u16, protocol: Protocol, name: &str) -> Result<CommandOutput, String> {
+    validate_firewall_rule_name(name)?;
+    let name = format!("name={}", name.trim());
+    let protocol = format!("protocol={}", protocol.label());
+    let local_port = format!("localport={}", port);
+    // Running it again would add a duplicate rule, so there is no re-run
+    Ok(run_command(
+        "netsh",
+        &["advfirewall", "firewall", "add", "rule", &name, "dir=in", "action=allow", &protocol, &local_port],
+    )
+    .await
+    .without_replay())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// In-memory process table; `gone` processes vanish before they can be killed and
+    /// `stubborn` ones refuse the normal kill
+    #[derive(Default)]
+    struct FakeTable {
+        entries: Vec<ProcessEntry>,
+        gone: Vec<u32>,
+        stubborn: Vec<u32>,
+        force_kill_works: bool,
+        killed: Vec<u32>,
+        force_killed: Vec<u32>,
+    }
+
+    /// (pid, parent, start time) rows
+    fn entries(rows: &[(u32, Option<u32>, u64)]) -> Vec<ProcessEntry> {
+        rows.iter().map(|&(pid, parent, start_time)| ProcessEntry { pid, parent, start_time }).collect()
+    }
+
+    impl FakeTable {
+        fn with(rows: &[(u32, Option<u32>, u64)]) -> Self {
+            FakeTable {
+                entries: entries(rows),
+                force_kill_works: true,
+                ..Default::default()
+            }
+        }
+    }
+
+    impl ProcessTable for FakeTable {
+        fn entries(&self) -> Vec<ProcessEntry> {
+            self.entries.clone()
+        }
+
+        fn kill(&mut self, pid: u32) -> bool {
+            if self.gone.contains(&pid) || self.stubborn.contains(&pid) {
+                return false;
+            }
+            self.killed.push(pid);
+            true
+        }
+
+        fn is_running(&mut self, pid: u32) -> bool {
+            !self.gone.contains(&pid)
+        }
+
+        fn force_kill(&mut self, pid: u32) -> bool {
+            if self.force_kill_works {
+                self.force_killed.push(pid);
+            }
+            self.force_kill_works
+        }
+    }
+
+    #[test]
+    fn kills_descendants_before_ancestors() {
+        let mut table =
+            FakeTable::with(&[(100, Some(1), 10), (200, Some(100), 11), (300, Some(200), 12), (400, Some(1), 13)]);
+        assert_eq!(kill_tree_in(&mut table, 100), Ok(3));
+        assert_eq!(table.killed, vec![300, 200, 100]);
+    }
+
+    #[test]
+    fn child_that_exits_during_the_walk_is_not_a_failure() {
+        let mut table = FakeTable::with(&[(100, Some(1), 10), (200, Some(100), 11), (300, Some(100), 12)]);
+        table.gone = vec![200];
+        assert_eq!(kill_tree_in(&mut table, 100), Ok(2));
+        assert_eq!(table.killed, vec![300, 100]);
+        assert!(table.force_killed.is_empty());
+    }
+
+    #[test]
+    fn reused_pid_older_than_parent_is_not_a_child() {
+        // 200 started before 100 existed, so its parent was an earlier process that held PID 100
+        let rows = entries(&[(100, Some(1), 50), (200, Some(100), 20), (300, Some(100), 50)]);
+        assert_eq!(process_tree(&rows, 100), vec![100, 300]);
+    }
+
+    #[test]
+    fn parent_pid_loops_terminate() {
+        let rows = entries(&[(100, Some(200), 10), (200, Some(100), 10)]);
+        assert_eq!(process_tree(&rows, 100), vec![100, 200]);
+    }
+
+    #[test]
+    fn stubborn_processes_fall_back_to_force_kill() {
+        let mut table = FakeTable::with(&[(100, Some(1), 10), (200, Some(100), 11)]);
+        table.stubborn = vec![200];
+        assert_eq!(kill_tree_in(&mut table, 100), Ok(2));
+        assert_eq!(table.force_killed, vec![200]);
+
+        let mut table = FakeTable::with(&[(100, Some(1), 10), (200, Some(100), 11)]);
+        table.stubborn = vec![200];
+        table.force_kill_works = false;
+        assert!(matches!(
+            kill_tree_in(&mut table, 100),
+            Err(KillError::AccessDenied(200)) | Err(KillError::Failed(_))
+        ));
+    }
+
+    #[test]
+    fn protected_and_missing_roots_are_rejected() {
+        let mut table = FakeTable::with(&[(100, Some(1), 10)]);
+        assert_eq!(kill_tree_in(&mut table, 4), Err(KillError::Protected(4)));
+        assert_eq!(kill_tree_in(&mut table, 999), Err(KillError::NotFound(999)));
+        assert!(table.killed.is_empty());
+    }
+
+    /// `netsh int ipv4 show excludedportrange protocol=tcp` on a machine with Hyper-V
+    const NETSH_EXCLUDED: &str = r"
+Protocol tcp Port Exclusion Ranges
+
+Start Port    End Port
+----------    --------
+      5357        5357
+     50000       50059     *
+     54236       54335
+
+* - Administered port exclusions.
+
+";
+
+    #[test]
+    fn excluded_ranges_skip_header_marker_and_footnote() {
+        assert_eq!(parse_excluded_port_ranges(NETSH_EXCLUDED), vec![5357..=5357, 50000..=50059, 54236..=54335]);
+        assert_eq!(parse_excluded_port_ranges(&NETSH_EXCLUDED.replace('\n', "\r\n")).len(), 3);
+        assert!(parse_excluded_port_ranges("").is_empty());
+    }
+
+    #[test]
+    fn well_known_ports_table_is_sorted_for_binary_search() {
+        assert!(WELL_KNOWN_PORTS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn well_known_service_uses_table_then_iana_ranges() {
+        assert_eq!(well_known_service(443), Some("HTTPS"));
+        assert_eq!(well_known_service(3389), Some("Remote Desktop"));
+        assert_eq!(well_known_service(5432), Some("PostgreSQL"));
+        assert_eq!(well_known_service(50051), Some("gRPC"));
+        assert_eq!(well_known_service(999), Some("IANA system port"));
+        assert_eq!(well_known_service(49152), Some("Dynamic/ephemeral"));
+        assert_eq!(well_known_service(12345), None);
+    }
+}
@@ -1,9 +1,10 @@
 //! Process inspection utilities
 //!
-//! List processes by handle count, memory, CPU usage.
+//! List processes by handle count, memory, CPU usage; restart Explorer.
+//! Handle and thread counts are read natively (not available from sysinfo).
 
-use crate::system::command::hidden_command_sync;
 use sysinfo::{ProcessesToUpdate, System};
+use std::collections::HashMap;
 use std::process::Stdio;
 
 /// Process info for display
@@ -14,9 +15,28 @@ pub struct ProcessInfo {
     pub memory_mb: f64,
     pub cpu_percent: f32,
     pub handle_count: u32,
+    pub thread_count: u32,
     pub status: String,
 }
 
+/// Look up a single process by PID
+pub fn get_process(pid: u32) -> Option<ProcessInfo> {
+    let mut sys = System::new();
+    let pid = sysinfo::Pid::from_u32(pid);
+    sys.refresh_processes(ProcessesToUpdate::Some(&[pid]), true);
+    let mut info = sys.process(pid).map(|proc| ProcessInfo {
+        pid: pid.as_u32(),
+        name: proc.name().to_string_lossy().to_string(),
+        memory_mb: proc.memory() as f64 / 1024.0 / 1024.0,
+        cpu_percent: proc.cpu_usage(),
+        handle_count: 0,
+        thread_count: 0,
+        status: format!("{:?}", proc.status()),
+    })?;
+    fill_counts(std::slice::from_mut(&mut info));
+    Some(info)
+}
+
 /// Get top processes by memory usage
 pub fn get_top_by_memory(limit: usize) -> Vec<ProcessInfo> {
     let mut sys = System::new_all();
@@ -30,13 +50,15 @@ pub fn get_top_by_memory(limit: usize) -> Vec<ProcessInfo> {
             name: proc.name().to_string_lossy().to_string(),
             memory_mb: proc.memory() as f64 / 1024.0 / 1024.0,
             cpu_percent: proc.cpu_usage(),
-            handle_count: 0, // Not available from sysinfo
+            handle_count: 0,
+            thread_count: 0,
             status: format!("{:?}", proc.status()),
         })
         .collect();
 
     processes.sort_by(|a, b| b.memory_mb.partial_cmp(&a.memory_mb).unwrap_or(std::cmp::Ordering::Equal));
     processes.truncate(limit);
+    fill_counts(&mut processes);
     processes
 }
 
@@ -57,59 +79,131 @@ pub fn get_top_by_cpu(limit: usize) -> Vec<ProcessInfo> {
             memory_mb: proc.memory() as f64 / 1024.0 / 1024.0,
             cpu_percent: proc.cpu_usage(),
             handle_count: 0,
+            thread_count: 0,
             status: format!("{:?}", proc.status()),
         })
         .collect();
 
     processes.sort_by(|a, b| b.cpu_percent.partial_cmp(&a.cpu_percent).unwrap_or(std::cmp::Ordering::Equal));
     processes.truncate(limit);
+    fill_counts(&mut processes);
     processes
 }
 
-/// Get top processes by handle count (Windows only - uses PowerShell)
+/// Get top processes by handle count
 /// This is useful for detecting handle leaks
 pub fn get_top_by_handles(limit: usize) -> Vec<ProcessInfo> {
-    // Use PowerShell to get handle counts - this is Windows-specific
-    let ps_script = r#"
-Get-Process | Sort-Object HandleCount -Descending | Select-Object -First 50 | 
-ForEach-Object { 
-    "$($_.Id),$($_.ProcessName),$($_.HandleCount),$([math]::Round($_.WorkingSet64/1MB,1))" 
-}
-"#;
-
-    let output = hidden_command_sync("powershell")
-        .args(["-NoProfile", "-Command", ps_script])
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .output();
-
-    match output {
-        Ok(out) => {
-            let stdout = String::from_utf8_lossy(&out.stdout);
-            let mut processes: Vec<ProcessInfo> = stdout
-                .lines()
-                .filter_map(|line| {
-                    let parts: Vec<&str> = line.split(',').collect();
-                    if parts.len() >= 4 {
-                        Some(ProcessInfo {
-                            pid: parts[0].parse().unwrap_or(0),
-                            name: parts[1].to_string(),
-                            handle_count: parts[2].parse().unwrap_or(0),
-                            memory_mb: parts[3].parse().unwrap_or(0.0),
-                            cpu_percent: 0.0, // Not available from this query
-                            status: "Running".to_string(),
-                        })
-                    } else {
-                        None
-                    }
-                })
-                .collect();
-
-            processes.truncate(limit);
-            processes
+    let mut sys = System::new();
+    sys.refresh_processes(ProcessesToUpdate::All, true);
+
+    let mut processes: Vec<ProcessInfo> = sys
+        .processes()
+        .iter()
+        .map(|(pid, proc)| ProcessInfo {
+            pid: pid.as_u32(),
+            name: proc.name().to_string_lossy().to_string(),
+            memory_mb: proc.memory() as f64 / 1024.0 / 1024.0,
+            cpu_percent: 0.0, // Needs a second refresh; not worth the delay here
+            handle_count: 0,
+            thread_count: 0,
+            status: format!("{:?}", proc.status()),
+        })
+        .collect();
+
+    // Every process needs its count before sorting, not just the ones we keep
+    fill_counts(&mut processes);
+    processes.sort_by_key(|p| std::cmp::Reverse(p.handle_count));
+    processes.truncate(limit);
+    processes
+}
+
+/// Fill in handle and thread counts; processes that can't be opened keep 0
+fn fill_counts(processes: &mut [ProcessInfo]) {
+    let threads = query_thread_counts();
+    for proc in processes.iter_mut() {
+        proc.handle_count = query_handle_count(proc.pid).unwrap_or(0);
+        proc.thread_count = threads.get(&proc.pid).copied().unwrap_or(0);
+    }
+}
+
+/// Get the number of open handles for a PID
+#[cfg(target_os = "windows")]
+fn query_handle_count(pid: u32) -> Option<u32> {
+    use windows_sys::Win32::Foundation::CloseHandle;
+    use windows_sys::Win32::System::Threading::{GetProcessHandleCount, OpenProcess, PROCESS_QUERY_LIMITED_INFORMATION};
+
+    unsafe {
+        let handle = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, 0, pid);
+        if handle.is_null() {
+            return None;
+        }
+
+        let mut count = 0u32;
+        let ok = GetProcessHandleCount(handle, &mut count);
+        CloseHandle(handle);
+
+        if ok == 0 {
+            None
+        } else {
+            Some(count)
+        }
+    }
+}
+
+/// Non-Windows fallback - handle counts are not available
+#[cfg(not(target_os = "windows"))]
+fn query_handle_count(_pid: u32) -> Option<u32> {
+    None
+}
+
+/// Get the thread count of every process in one system snapshot, keyed by PID
+#[cfg(target_os = "windows")]
+fn query_thread_counts() -> HashMap<u32, u32> {
+    use windows_sys::Wdk::System::SystemInformation::{NtQuerySystemInformation, SystemProcessInformation};
+    use windows_sys::Win32::Foundation::STATUS_INFO_LENGTH_MISMATCH;
+    use windows_sys::Win32::System::WindowsProgramming::SYSTEM_PROCESS_INFORMATION;
+
+    let mut counts = HashMap::new();
+    // u64 elements keep the buffer aligned for the structs inside it
+    let mut buffer: Vec<u64> = vec![0; 64 * 1024];
+    let mut status = STATUS_INFO_LENGTH_MISMATCH;
+    // Processes can start between the size query and the read, so retry a few times with headroom
+    for _ in 0..4 {
+        let mut needed = 0u32;
+        status = unsafe {
+            NtQuerySystemInformation(
+                SystemProcessInformation,
+                buffer.as_mut_ptr().cast(),
+                (buffer.len() * 8) as u32,
+                &mut needed,
+            )
+        };
+        if status != STATUS_INFO_LENGTH_MISMATCH {
+            break;
+        }
+        buffer = vec![0; (needed as usize + 64 * 1024) / 8 + 1];
+    }
+    if status < 0 {
+        return counts;
+    }
+
+    let base = buffer.as_ptr() as *const u8;
+    let mut offset = 0usize;
+    loop {
+        let entry = unsafe { &*(base.add(offset) as *const SYSTEM_PROCESS_INFORMATION) };
+        counts.insert(entry.UniqueProcessId as usize as u32, entry.NumberOfThreads);
+        if entry.NextEntryOffset == 0 {
+            break;
         }
-        Err(_) => Vec::new(),
+        offset += entry.NextEntryOffset as usize;
     }
+    counts
+}
+
+/// Non-Windows fallback - thread counts are not available
+#[cfg(not(target_os = "windows"))]
+fn query_thread_counts() -> HashMap<u32, u32> {
+    HashMap::new()
 }
 
 /// Kill a process by PID (requires elevated privileges for some processes)
@@ -128,15 +222,307 @@ pub fn kill_process(pid: u32) -> Result<(), String> {
     }
 }
 
-/// Get system overview (total memory, CPU count, etc.)
+/// PIDs of explorer.exe processes owned by the current user
+fn current_user_explorer_pids() -> Vec<u32> {
+    let sys = System::new_all();
+    let own_user = sys
+        .process(sysinfo::get_current_pid().unwrap_or(sysinfo::Pid::from_u32(0)))
+        .and_then(|p| p.user_id().cloned());
+
+    sys.processes()
+        .iter()
+        .filter(|(_, proc)| proc.name().to_string_lossy().eq_ignore_ascii_case("explorer.exe"))
+        .filter(|(_, proc)| match (&own_user, proc.user_id()) {
+            (Some(own), Some(user)) => own == user,
+            // If ownership can't be determined, fall back to all explorer instances
+            _ => true,
+        })
+        .map(|(pid, _)| pid.as_u32())
+        .collect()
+}
+
+/// Poll for an explorer.exe PID that wasn't in `old_pids`
+async fn wait_for_new_explorer(old_pids: &[u32], timeout_ms: u64) -> Option<u32> {
+    let mut waited = 0;
+    while waited < timeout_ms {
+        tokio::time::sleep(tokio::time::Duration::from_millis(250)).await;
+        waited += 250;
+
+        if let Some(pid) = current_user_explorer_pids().into_iter().find(|p| !old_pids.contains(p)) {
+            return Some(pid);
+        }
+    }
+    None
+}
+
+/// Restart Windows Explorer (taskbar, tray, desktop) for the current user
+///
+/// Open File Explorer windows are closed. Winlogon usually relaunches the shell
+/// by itself after a kill, so we only start explorer.exe if it doesn't come back.
+pub async fn restart_explorer() -> Result<String, String> {
+    let old_pids = current_user_explorer_pids();
+
+    let sys = System::new_all();
+    for pid in &old_pids {
+        if let Some(process) = sys.process(sysinfo::Pid::from_u32(*pid)) {
+            if !process.kill() {
+                return Err(t!("processes.explorer-kill-failed", pid = pid));
+            }
+        }
+    }
+
+    // Give the shell a moment to auto-restart before launching our own
+    if let Some(pid) = wait_for_new_explorer(&old_pids, 2000).await {
+        return Ok(t!("processes.explorer-came-back", closed = old_pids.len(), pid = pid));
+    }
+
+    std::process::Command::new("explorer.exe")
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| t!("processes.explorer-relaunch-failed", error = e))?;
+
+    match wait_for_new_explorer(&old_pids, 5000).await {
+        Some(pid) => Ok(t!("processes.explorer-relaunched", closed = old_pids.len(), pid = pid)),
+        None => Err(t!("processes.explorer-gone")),
+    }
+}
+
+/// Uptime beyond this many days gets the warning style
+pub const LONG_UPTIME_DAYS: u64 = 14;
+
+/// Commit usage at or above this percentage is shown as a warning
+pub const COMMIT_WARNING_PERCENT: f64 = 85.0;
+
+/// Commit usage at or above this percentage is shown as danger with a banner
+pub const COMMIT_DANGER_PERCENT: f64 = 95.0;
+
+/// Memory pressure level derived from commit usage
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MemoryPressure {
+    #[default]
+    Normal,
+    Warning,
+    Danger,
+}
+
+impl MemoryPressure {
+    /// Classify a commit usage percentage against the warning/danger thresholds
+    pub fn from_percent(percent: f64) -> Self {
+        if percent >= COMMIT_DANGER_PERCENT {
+            MemoryPressure::Danger
+        } else if percent >= COMMIT_WARNING_PERCENT {
+            MemoryPressure::Warning
+        } else {
+            MemoryPressure::Normal
+        }
+    }
+
+    /// Stat card class for this pressure level
+    pub fn card_class(&self) -> &'static str {
+        match self {
+            MemoryPressure::Normal => "stat-card",
+            MemoryPressure::Warning => "stat-card stat-card-warning",
+            MemoryPressure::Danger => "stat-card stat-card-danger",
+        }
+    }
+}
+
+/// Commit charge breakdown in GB
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CommitBreakdown {
+    /// Committed memory currently charged
+    pub used_gb: f64,
+    /// Commit limit (physical RAM + pagefiles)
+    pub limit_gb: f64,
+    /// Portion of the limit backed by physical RAM
+    pub physical_gb: f64,
+    /// Portion of the limit backed by pagefiles
+    pub pagefile_gb: f64,
+}
+
+impl CommitBreakdown {
+    /// Compute the breakdown from raw byte counters
+    pub fn from_bytes(total_phys: u64, commit_limit: u64, commit_available: u64) -> Self {
+        const GB: f64 = 1024.0 * 1024.0 * 1024.0;
+        let used = commit_limit.saturating_sub(commit_available);
+        // The commit limit can be below physical RAM when no pagefile is configured
+        let physical = total_phys.min(commit_limit);
+        let pagefile = commit_limit.saturating_sub(physical);
+
+        CommitBreakdown {
+            used_gb: used as f64 / GB,
+            limit_gb: commit_limit as f64 / GB,
+            physical_gb: physical as f64 / GB,
+            pagefile_gb: pagefile as f64 / GB,
+        }
+    }
+
+    /// Commit usage as a percentage of the limit
+    pub fn percent(&self) -> f64 {
+        if self.limit_gb > 0.0 {
+            (self.used_gb / self.limit_gb) * 100.0
+        } else {
+            0.0
+        }
+    }
+
+    /// Pressure level for the current usage
+    pub fn pressure(&self) -> MemoryPressure {
+        MemoryPressure::from_percent(self.percent())
+    }
+}
+
+/// Query (total physical, commit limit, commit available) in bytes
+#[cfg(target_os = "windows")]
+fn query_commit_counters() -> Option<(u64, u64, u64)> {
+    #[repr(C)]
+    #[allow(non_snake_case, clippy::upper_case_acronyms)]
+    struct MEMORYSTATUSEX {
+        dwLength: u32,
+        dwMemoryLoad: u32,
+        ullTotalPhys: u64,
+        ullAvailPhys: u64,
+        ullTotalPageFile: u64,
+        ullAvailPageFile: u64,
+        ullTotalVirtual: u64,
+        ullAvailVirtual: u64,
+        ullAvailExtendedVirtual: u64,
+    }
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GlobalMemoryStatusEx(lpBuffer: *mut MEMORYSTATUSEX) -> i32;
+    }
+
+    let mut status = MEMORYSTATUSEX {
+        dwLength: std::mem::size_of::<MEMORYSTATUSEX>() as u32,
+        dwMemoryLoad: 0,
+        ullTotalPhys: 0,
+        ullAvailPhys: 0,
+        ullTotalPageFile: 0,
+        ullAvailPageFile: 0,
+        ullTotalVirtual: 0,
+        ullAvailVirtual: 0,
+        ullAvailExtendedVirtual: 0,
+    };
+
+    // ullTotalPageFile/ullAvailPageFile are the system commit limit and available commit
+    let ok = unsafe { GlobalMemoryStatusEx(&mut status) };
+    if ok == 0 {
+        return None;
+    }
+
+    Some((status.ullTotalPhys, status.ullTotalPageFile, status.ullAvailPageFile))
+}
+
+/// Non-Windows fallback - commit counters are not available
+#[cfg(not(target_os = "windows"))]
+fn query_commit_counters() -> Option<(u64, u64, u64)> {
+    None
+}
+
+/// Get private bytes (commit charged to the process) for a PID
+#[cfg(target_os = "windows")]
+fn query_private_bytes(pid: u32) -> Option<u64> {
+    #[repr(C)]
+    #[allow(non_snake_case)]
+    struct PROCESS_MEMORY_COUNTERS_EX {
+        cb: u32,
+        PageFaultCount: u32,
+        PeakWorkingSetSize: usize,
+        WorkingSetSize: usize,
+        QuotaPeakPagedPoolUsage: usize,
+        QuotaPagedPoolUsage: usize,
+        QuotaPeakNonPagedPoolUsage: usize,
+        QuotaNonPagedPoolUsage: usize,
+        PagefileUsage: usize,
+        PeakPagefileUsage: usize,
+        PrivateUsage: usize,
+    }
+
+    const PROCESS_QUERY_LIMITED_INFORMATION: u32 = 0x1000;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn OpenProcess(dwDesiredAccess: u32, bInheritHandle: i32, dwProcessId: u32) -> *mut std::ffi::c_void;
+        fn CloseHandle(hObject: *mut std::ffi::c_void) -> i32;
+        fn K32GetProcessMemoryInfo(
+            Process: *mut std::ffi::c_void,
+            ppsmemCounters: *mut PROCESS_MEMORY_COUNTERS_EX,
+            cb: u32,
+        ) -> i32;
+    }
+
+    unsafe {
+        let handle = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, 0, pid);
+        if handle.is_null() {
+            return None;
+        }
+
+        let mut counters: PROCESS_MEMORY_COUNTERS_EX = std::mem::zeroed();
+        counters.cb = std::mem::size_of::<PROCESS_MEMORY_COUNTERS_EX>() as u32;
+        let ok = K32GetProcessMemoryInfo(handle, &mut counters, counters.cb);
+        CloseHandle(handle);
+
+        if ok == 0 {
+            None
+        } else {
+            Some(counters.PrivateUsage as u64)
+        }
+    }
+}
+
+/// Non-Windows fallback - private bytes are not available
+#[cfg(not(target_os = "windows"))]
+fn query_private_bytes(_pid: u32) -> Option<u64> {
+    None
+}
+
+/// Get the top private-bytes consumers (falls back to working set when private bytes can't be read)
+pub fn get_top_by_private_bytes(limit: usize) -> Vec<ProcessInfo> {
+    let mut sys = System::new();
+    sys.refresh_processes(ProcessesToUpdate::All, true);
+
+    let mut processes: Vec<ProcessInfo> = sys
+        .processes()
+        .iter()
+        .map(|(pid, proc)| {
+            let bytes = query_private_bytes(pid.as_u32()).unwrap_or_else(|| proc.memory());
+            ProcessInfo {
+                pid: pid.as_u32(),
+                name: proc.name().to_string_lossy().to_string(),
+                memory_mb: bytes as f64 / 1024.0 / 1024.0,
+                cpu_percent: 0.0,
+                handle_count: 0,
+                thread_count: 0,
+                status: format!("{:?}", proc.status()),
+            }
+        })
+        .collect();
+
+    processes.sort_by(|a, b| b.memory_mb.partial_cmp(&a.memory_mb).unwrap_or(std::cmp::Ordering::Equal));
+    processes.truncate(limit);
+    fill_counts(&mut processes);
+    processes
+}
+
+/// Get system overview (total memory, CPU count, commit charge, etc.)
 pub fn get_system_info() -> SystemOverview {
     let sys = System::new_all();
-    
+
+    let commit = query_commit_counters()
+        .map(|(phys, limit, avail)| CommitBreakdown::from_bytes(phys, limit, avail));
+
     SystemOverview {
         total_memory_gb: sys.total_memory() as f64 / 1024.0 / 1024.0 / 1024.0,
         used_memory_gb: sys.used_memory() as f64 / 1024.0 / 1024.0 / 1024.0,
         cpu_count: sys.cpus().len(),
         process_count: sys.processes().len(),
+        commit,
+        uptime_secs: System::uptime(),
+        boot_time: System::boot_time(),
     }
 }
 
@@ -147,5 +533,82 @@ pub struct SystemOverview {
     pub used_memory_gb: f64,
     pub cpu_count: usize,
     pub process_count: usize,
+    /// Commit charge breakdown (None when unavailable)
+    pub commit: Option<CommitBreakdown>,
+    /// Seconds since the kernel last booted (Fast Startup shutdowns don't reset this)
+    pub uptime_secs: u64,
+    /// Last boot time as a Unix timestamp
+    pub boot_time: u64,
 }
 
+impl SystemOverview {
+    /// Uptime formatted as "3d 4h" / "4h 12m"
+    pub fn uptime_display(&self) -> String {
+        let days = self.uptime_secs / 86_400;
+        let hours = (self.uptime_secs % 86_400) / 3600;
+        let minutes = (self.uptime_secs % 3600) / 60;
+        if days > 0 {
+            format!("{}d {}h", days, hours)
+        } else {
+            format!("{}h {}m", hours, minutes)
+        }
+    }
+
+    /// Last boot time in local time
+    pub fn boot_time_display(&self) -> String {
+        chrono::DateTime::from_timestamp(self.boot_time as i64, 0)
+            .map(|t| t.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_else(|| "Unknown".to_string())
+    }
+
+    /// Uptime is long enough that a reboot is worth suggesting
+    pub fn is_long_uptime(&self) -> bool {
+        self.uptime_secs > LONG_UPTIME_DAYS * 86_400
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GB: u64 = 1024 * 1024 * 1024;
+
+    #[test]
+    fn pressure_thresholds_are_inclusive() {
+        assert_eq!(MemoryPressure::from_percent(0.0), MemoryPressure::Normal);
+        assert_eq!(MemoryPressure::from_percent(84.9), MemoryPressure::Normal);
+        assert_eq!(MemoryPressure::from_percent(COMMIT_WARNING_PERCENT), MemoryPressure::Warning);
+        assert_eq!(MemoryPressure::from_percent(94.9), MemoryPressure::Warning);
+        assert_eq!(MemoryPressure::from_percent(COMMIT_DANGER_PERCENT), MemoryPressure::Danger);
+        assert_eq!(MemoryPressure::from_percent(120.0), MemoryPressure::Danger);
+    }
+
+    #[test]
+    fn breakdown_splits_limit_into_ram_and_pagefile() {
+        let b = CommitBreakdown::from_bytes(16 * GB, 24 * GB, 6 * GB);
+        assert_eq!(b.used_gb, 18.0);
+        assert_eq!(b.limit_gb, 24.0);
+        assert_eq!(b.physical_gb, 16.0);
+        assert_eq!(b.pagefile_gb, 8.0);
+        assert_eq!(b.percent(), 75.0);
+        assert_eq!(b.pressure(), MemoryPressure::Normal);
+    }
+
+    #[test]
+    fn breakdown_without_pagefile_caps_physical_at_limit() {
+        // No pagefile: the commit limit sits slightly below installed RAM
+        let b = CommitBreakdown::from_bytes(16 * GB, 15 * GB, GB / 2);
+        assert_eq!(b.physical_gb, 15.0);
+        assert_eq!(b.pagefile_gb, 0.0);
+        assert_eq!(b.used_gb, 14.5);
+        assert_eq!(b.pressure(), MemoryPressure::Danger);
+    }
+
+    #[test]
+    fn breakdown_tolerates_available_above_limit_and_zero_limit() {
+        let b = CommitBreakdown::from_bytes(8 * GB, 8 * GB, 9 * GB);
+        assert_eq!(b.used_gb, 0.0);
+        assert_eq!(CommitBreakdown::from_bytes(0, 0, 0).percent(), 0.0);
+        assert_eq!(CommitBreakdown::default().pressure(), MemoryPressure::Normal);
+    }
+}
@@ -0,0 +1,150 @@
+//! Read-only registry access
+//!
+//! Minimal wrappers over the Win32 registry API for checking keys and reading values.
+
+/// Registry root key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hive {
+    LocalMachine,
+    CurrentUser,
+}
+
+#[cfg(target_os = "windows")]
+use windows::core::HSTRING;
+#[cfg(target_os = "windows")]
+use windows::Win32::Foundation::ERROR_SUCCESS;
+#[cfg(target_os = "windows")]
+use windows::Win32::System::Registry::{
+    RegCloseKey, RegOpenKeyExW, RegQueryValueExW, HKEY, HKEY_CURRENT_USER, HKEY_LOCAL_MACHINE, KEY_READ,
+    KEY_WOW64_64KEY, REG_DWORD, REG_EXPAND_SZ, REG_MULTI_SZ, REG_SZ, REG_VALUE_TYPE,
+};
+
+/// Open key handle that is closed on drop
+#[cfg(target_os = "windows")]
+struct Key(HKEY);
+
+#[cfg(target_os = "windows")]
+impl Key {
+    fn open(hive: Hive, path: &str) -> Option<Key> {
+        let root = match hive {
+            Hive::LocalMachine => HKEY_LOCAL_MACHINE,
+            Hive::CurrentUser => HKEY_CURRENT_USER,
+        };
+        let mut handle = HKEY::default();
+
+        let result = unsafe { RegOpenKeyExW(root, &HSTRING::from(path), 0, KEY_READ | KEY_WOW64_64KEY, &mut handle) };
+        if result == ERROR_SUCCESS {
+            Some(Key(handle))
+        } else {
+            None
+        }
+    }
+
+    /// Read the raw value type and bytes
+    fn query(&self, name: &str) -> Option<(REG_VALUE_TYPE, Vec<u8>)> {
+        let name = HSTRING::from(name);
+        let mut value_type = REG_VALUE_TYPE::default();
+        let mut size = 0u32;
+
+        unsafe {
+            let result = RegQueryValueExW(self.0, &name, None, Some(&mut value_type), None, Some(&mut size));
+            if result != ERROR_SUCCESS {
+                return None;
+            }
+
+            let mut data = vec![0u8; size as usize];
+            let result = RegQueryValueExW(
+                self.0,
+                &name,
+                None,
+                Some(&mut value_type),
+                Some(data.as_mut_ptr()),
+                Some(&mut size),
+            );
+            if result != ERROR_SUCCESS {
+                return None;
+            }
+            data.truncate(size as usize);
+            Some((value_type, data))
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+impl Drop for Key {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = RegCloseKey(self.0);
+        }
+    }
+}
+
+/// Decode registry UTF-16 bytes into strings split on nulls (empty entries dropped)
+#[cfg(target_os = "windows")]
+fn decode_strings(data: &[u8]) -> Vec<String> {
+    let wide: Vec<u16> = data
+        .chunks_exact(2)
+        .map(|c| u16::from_le_bytes([c[0], c[1]]))
+        .collect();
+    wide.split(|c| *c == 0)
+        .filter(|s| !s.is_empty())
+        .map(String::from_utf16_lossy)
+        .collect()
+}
+
+/// Check if a key exists
+#[cfg(target_os = "windows")]
+pub fn key_exists(hive: Hive, path: &str) -> bool {
+    Key::open(hive, path).is_some()
+}
+
+/// Read a REG_SZ/REG_EXPAND_SZ value (first entry of a REG_MULTI_SZ)
+#[cfg(target_os = "windows")]
+pub fn read_string(hive: Hive, path: &str, name: &str) -> Option<String> {
+    read_multi_string(hive, path, name).and_then(|v| v.into_iter().next())
+}
+
+/// Read a REG_MULTI_SZ value (REG_SZ values come back as a single entry)
+#[cfg(target_os = "windows")]
+pub fn read_multi_string(hive: Hive, path: &str, name: &str) -> Option<Vec<String>> {
+    let (value_type, data) = Key::open(hive, path)?.query(name)?;
+    match value_type {
+        REG_SZ | REG_EXPAND_SZ | REG_MULTI_SZ => Some(decode_strings(&data)),
+        _ => None,
+    }
+}
+
+/// Read a REG_DWORD value
+#[cfg(target_os = "windows")]
+pub fn read_dword(hive: Hive, path: &str, name: &str) -> Option<u32> {
+    let (value_type, data) = Key::open(hive, path)?.query(name)?;
+    if value_type == REG_DWORD && data.len() >= 4 {
+        Some(u32::from_le_bytes([data[0], data[1], data[2], data[3]]))
+    } else {
+        None
+    }
+}
+
+/// Non-Windows fallback - no registry
+#[cfg(not(target_os = "windows"))]
+pub fn key_exists(_hive: Hive, _path: &str) -> bool {
+    false
+}
+
+/// Non-Windows fallback - no registry
+#[cfg(not(target_os = "windows"))]
+pub fn read_string(_hive: Hive, _path: &str, _name: &str) -> Option<String> {
+    None
+}
+
+/// Non-Windows fallback - no registry
+#[cfg(not(target_os = "windows"))]
+pub fn read_multi_string(_hive: Hive, _path: &str, _name: &str) -> Option<Vec<String>> {
+    None
+}
+
+/// Non-Windows fallback - no registry
+#[cfg(not(target_os = "windows"))]
+pub fn read_dword(_hive: Hive, _path: &str, _name: &str) -> Option<u32> {
+    None
+}
@@ -0,0 +1,78 @@
+//! System Restore utilities
+//!
+//! Create restore points before risky changes and check whether System Protection is on.
+
+use crate::state::{CommandOutput, DangerLevel};
+use crate::system::command::run_powershell;
+use crate::system::registry::{self, Hive};
+
+const SYSTEM_RESTORE_KEY: &str = r"SOFTWARE\Microsoft\Windows NT\CurrentVersion\SystemRestore";
+
+/// Result of trying to create a restore point
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RestorePointOutcome {
+    /// A new restore point was created
+    Created,
+    /// Windows skipped creation because one was made in the last 24 hours
+    Throttled,
+    /// System Protection is turned off, so no restore point can be created
+    ProtectionDisabled,
+    /// Anything else (not elevated, VSS errors, ...)
+    Failed(String),
+}
+
+impl RestorePointOutcome {
+    /// Whether it's reasonable to continue with the risky action afterwards
+    pub fn safe_to_continue(&self) -> bool {
+        matches!(self, RestorePointOutcome::Created | RestorePointOutcome::Throttled)
+    }
+
+    /// User-readable description
+    pub fn message(&self) -> String {
+        match self {
+            RestorePointOutcome::Created => t!("restore.created"),
+            RestorePointOutcome::Throttled => t!("restore.throttled"),
+            RestorePointOutcome::ProtectionDisabled => t!("restore.protection-disabled"),
+            RestorePointOutcome::Failed(e) => t!("restore.failed", error = e),
+        }
+    }
+}
+
+/// Check whether System Protection (System Restore) is enabled
+///
+/// Windows keeps this as a machine-wide flag that is set when protection is on for the system drive.
+pub fn protection_enabled() -> bool {
+    registry::read_dword(Hive::LocalMachine, SYSTEM_RESTORE_KEY, "RPSessionInterval")
+        .map(|v| v != 0)
+        .unwrap_or(false)
+}
+
+/// Create a restore point (requires admin, can take a minute)
+pub async fn create_restore_point(description: &str) -> (RestorePointOutcome, CommandOutput) {
+    // Redirect the warning stream so the 24h throttle message is captured
+    let cmd = format!(
+        "Checkpoint-Computer -Description '{}' -RestorePointType MODIFY_SETTINGS -ErrorAction Stop 3>&1",
+        description.replace('\'', "''")
+    );
+    let output = run_powershell(&cmd).await.requires_admin().with_danger(DangerLevel::Modifying);
+
+    let combined = format!("{}\n{}", output.stdout, output.stderr).to_lowercase();
+    let outcome = if combined.contains("1440 minutes") || combined.contains("already been created") {
+        RestorePointOutcome::Throttled
+    } else if combined.contains("disabled") || combined.contains("turned off") {
+        RestorePointOutcome::ProtectionDisabled
+    } else if output.succeeded() {
+        RestorePointOutcome::Created
+    } else {
+        let detail = output
+            .stderr
+            .lines()
+            .map(str::trim)
+            .find(|l| !l.is_empty())
+            .unwrap_or("unknown error")
+            .to_string();
+        RestorePointOutcome::Failed(detail)
+    };
+
+    (outcome, output)
+}
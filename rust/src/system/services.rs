@@ -1,98 +1,1115 @@
 //! Windows services utilities
 //!
 //! List, start, and stop Windows services.
+//!
+//! Everything goes through PowerShell, except on machines where PowerShell is blocked
+//! (AppLocker, ConstrainedLanguage mode): once Get-Service fails there, listing and
+//! start/stop switch to sc.exe.
+
+use crate::state::{BindingSource, CommandOutput, DangerLevel, Protocol, ProtocolFilter};
+use crate::system::command::{run_command, run_powershell};
+use crate::system::ports;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
 
-use crate::state::CommandOutput;
-use crate::system::command::run_powershell;
 
+/// Services whose stop takes down RPC, networking, security, or the user session
+const CRITICAL_SERVICES: &[&str] = &[
+    "RpcSs", "RpcEptMapper", "DcomLaunch", "LSM", "SamSs", "EventLog", "PlugPlay",
+    "Power", "ProfSvc", "Winmgmt", "BFE", "MpsSvc", "WinDefend", "CryptSvc", "Dhcp",
+    "Dnscache", "nsi", "NlaSvc", "LanmanWorkstation", "gpsvc", "Schedule", "BrokerInfrastructure",
+    "CoreMessagingRegistrar", "SystemEventsBroker", "UserManager", "Audiosrv",
+];
+
+/// Check if stopping this service is likely to break the machine
+pub fn is_critical_service(name: &str) -> bool {
+    CRITICAL_SERVICES.iter().any(|s| s.eq_ignore_ascii_case(name))
+}
 
 /// Service info from Get-Service
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
 pub struct ServiceInfo {
     pub name: String,
     pub display_name: String,
     pub status: String,
     pub start_type: String,
+    /// Logon account from Win32_Service (LocalSystem, NT AUTHORITY\NetworkService, DOMAIN\user, ...)
+    #[serde(default)]
+    pub account: String,
+    /// Hosting process from Win32_Service (0 while the service isn't running)
+    #[serde(default)]
+    pub process_id: u32,
 }
 
-/// List all Windows services
-pub async fn list_services() -> (Vec<ServiceInfo>, CommandOutput) {
-    let cmd = r#"Get-Service | Select-Object Name, DisplayName, Status, StartType | ConvertTo-Csv -NoTypeInformation"#;
-    let output = run_powershell(cmd).await;
-    
-    let mut services = Vec::new();
-    
-    if output.exit_code == 0 {
-        // Parse CSV output (skip header line)
-        for line in output.stdout.lines().skip(1) {
-            // CSV format: "Name","DisplayName","Status","StartType"
-            let parts: Vec<&str> = line.split(',')
-                .map(|s| s.trim_matches('"').trim())
-                .collect();
-            
-            if parts.len() >= 4 {
-                services.push(ServiceInfo {
-                    name: parts[0].to_string(),
-                    display_name: parts[1].to_string(),
-                    status: parts[2].to_string(),
-                    start_type: parts[3].to_string(),
-                });
+/// Account every "LocalSystem" service runs as; what security reviews ask about
+pub const LOCAL_SYSTEM: &str = "LocalSystem";
+
+impl ServiceInfo {
+    pub fn runs_as_local_system(&self) -> bool {
+        self.account.eq_ignore_ascii_case(LOCAL_SYSTEM)
+    }
+}
+
+/// Get-Service columns, with the Status/StartType enums as text (ConvertTo-Json would write numbers);
+/// the Account and ProcessId columns need `$cim`, see `CIM_ALL` and `CIM_MATCHING`
+const SERVICE_COLUMNS: &str = "Name, DisplayName, @{Name='Status'; Expression={[string]$_.Status}}, \
+     @{Name='StartType'; Expression={[string]$_.StartType}}, @{Name='Account'; Expression={[string]$cim[$_.Name].StartName}}, \
+     @{Name='ProcessId'; Expression={[int]$cim[$_.Name].ProcessId}}";
+
+/// Builds `$cim` (service name -> Win32_Service) with one CIM query for every service
+const CIM_ALL: &str = "$cim = @{}; Get-CimInstance Win32_Service | ForEach-Object { $cim[$_.Name] = $_ };";
+
+/// Builds `$cim` for the services in `$svcs` only, one CIM query each (for short lists)
+const CIM_MATCHING: &str = "$cim = @{}; $svcs | ForEach-Object { \
+     $cim[$_.Name] = Get-CimInstance Win32_Service -Filter ('Name=''' + $_.Name + '''') };";
+
+/// Set when the last full listing had to use sc.exe because Get-Service failed
+static SC_FALLBACK: AtomicBool = AtomicBool::new(false);
+
+/// Services are managed through sc.exe because PowerShell is unavailable
+pub fn using_sc_fallback() -> bool {
+    SC_FALLBACK.load(Ordering::Relaxed)
+}
+
+/// List all Windows services, falling back to `sc queryex` when Get-Service yields nothing
+///
+/// The sc.exe listing has no start types; fetch them per service with `get_start_type_sc`.
+pub async fn list_services() -> (Vec<ServiceInfo>, Vec<CommandOutput>) {
+    let cmd = format!("{} Get-Service | Select-Object {} | ConvertTo-Json -Compress", CIM_ALL, SERVICE_COLUMNS);
+    let output = run_powershell(&cmd).await;
+    let services = parse_services_json(&output);
+    if !services.is_empty() {
+        SC_FALLBACK.store(false, Ordering::Relaxed);
+        return (services, vec![output]);
+    }
+
+    tracing::warn!(
+        "Get-Service returned no services (exit code {}), falling back to sc.exe: {}",
+        output.exit_code,
+        output.stderr.trim()
+    );
+    let sc_output = run_command("sc.exe", &["queryex", "type=", "service", "state=", "all", "bufsize=", "262144"]).await;
+    let services = parse_sc_query(&sc_output.stdout);
+    SC_FALLBACK.store(!services.is_empty(), Ordering::Relaxed);
+    (services, vec![output, sc_output])
+}
+
+/// Parse `sc query`/`sc queryex` output: SERVICE_NAME starts a block, DISPLAY_NAME, STATE
+/// and (queryex only) PID follow
+fn parse_sc_query(text: &str) -> Vec<ServiceInfo> {
+    let mut services: Vec<ServiceInfo> = Vec::new();
+    for line in text.lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        match key.trim() {
+            "SERVICE_NAME" => services.push(ServiceInfo {
+                name: value.to_string(),
+                ..Default::default()
+            }),
+            "DISPLAY_NAME" => {
+                if let Some(svc) = services.last_mut() {
+                    svc.display_name = value.to_string();
+                }
+            }
+            "STATE" => {
+                if let Some(svc) = services.last_mut() {
+                    svc.status = sc_state_name(value).to_string();
+                }
+            }
+            "PID" => {
+                if let Some(svc) = services.last_mut() {
+                    svc.process_id = value.parse().unwrap_or(0);
+                }
+            }
+            _ => {}
+        }
+    }
+    services
+}
+
+/// Get-Service spelling of an sc.exe state ("4  RUNNING" -> "Running")
+fn sc_state_name(value: &str) -> &str {
+    match value.split_whitespace().next() {
+        Some("1") => "Stopped",
+        Some("2") => "StartPending",
+        Some("3") => "StopPending",
+        Some("4") => "Running",
+        Some("5") => "ContinuePending",
+        Some("6") => "PausePending",
+        Some("7") => "Paused",
+        _ => value,
+    }
+}
+
+/// Start type of one service from `sc qc`, in Get-Service spelling (None if it couldn't be read)
+pub async fn get_start_type_sc(name: &str) -> (Option<String>, CommandOutput) {
+    let output = run_command("sc.exe", &["qc", name]).await;
+    let start_type = output
+        .stdout
+        .lines()
+        .filter_map(|line| line.split_once(':'))
+        .find(|(key, _)| key.trim() == "START_TYPE")
+        .and_then(|(_, value)| {
+            let delayed = value.contains("DELAYED");
+            match value.split_whitespace().next()? {
+                "0" => Some("Boot"),
+                "1" => Some("System"),
+                "2" if delayed => Some("AutomaticDelayedStart"),
+                "2" => Some("Automatic"),
+                "3" => Some("Manual"),
+                "4" => Some("Disabled"),
+                _ => None,
             }
+        })
+        .map(str::to_string);
+    (start_type, output)
+}
+
+/// One service from `sc queryex` plus `sc qc`, for when PowerShell is unavailable
+async fn query_service_sc(name: &str) -> (Vec<ServiceInfo>, CommandOutput) {
+    let output = run_command("sc.exe", &["queryex", name]).await;
+    let mut services = parse_sc_query(&output.stdout);
+    for svc in services.iter_mut() {
+        if let (Some(start_type), _) = get_start_type_sc(&svc.name).await {
+            svc.start_type = start_type;
         }
     }
-    
     (services, output)
 }
 
-/// List services filtered by name pattern
-pub async fn list_services_filtered(filter: &str) -> (Vec<ServiceInfo>, CommandOutput) {
+/// List services whose name matches a `Get-Service -Name` wildcard pattern (e.g. "dnscache", "docker*")
+///
+/// In sc.exe fallback mode the pattern must be an exact service name.
+pub async fn list_services_filtered(pattern: &str) -> (Vec<ServiceInfo>, CommandOutput) {
+    if using_sc_fallback() {
+        return query_service_sc(pattern).await;
+    }
     let cmd = format!(
-        r#"Get-Service | Where-Object {{ $_.Name -like '*{}*' -or $_.DisplayName -like '*{}*' }} | Select-Object Name, DisplayName, Status, StartType | ConvertTo-Csv -NoTypeInformation"#,
-        filter, filter
+        "$svcs = @(Get-Service -Name '{}' -ErrorAction SilentlyContinue); {} $svcs | Select-Object {} | ConvertTo-Json -Compress",
+        pattern.replace('\'', "''"),
+        CIM_MATCHING,
+        SERVICE_COLUMNS
     );
     let output = run_powershell(&cmd).await;
-    
-    let mut services = Vec::new();
-    
-    if output.exit_code == 0 {
-        for line in output.stdout.lines().skip(1) {
-            let parts: Vec<&str> = line.split(',')
-                .map(|s| s.trim_matches('"').trim())
-                .collect();
-            
-            if parts.len() >= 4 {
-                services.push(ServiceInfo {
-                    name: parts[0].to_string(),
-                    display_name: parts[1].to_string(),
-                    status: parts[2].to_string(),
-                    start_type: parts[3].to_string(),
-                });
-            }
+    (parse_services_json(&output), output)
+}
+
+/// Current status of the named services (names that aren't installed are left out)
+pub async fn get_services(names: &[String]) -> (Vec<ServiceInfo>, CommandOutput) {
+    if using_sc_fallback() {
+        let mut services = Vec::new();
+        let mut last = CommandOutput::default();
+        for name in names {
+            let (found, output) = query_service_sc(name).await;
+            services.extend(found);
+            last = output;
+        }
+        return (services, last);
+    }
+    let names = names
+        .iter()
+        .map(|name| format!("'{}'", name.replace('\'', "''")))
+        .collect::<Vec<_>>()
+        .join(",");
+    let cmd = format!(
+        "$svcs = @(Get-Service -Name {} -ErrorAction SilentlyContinue); {} $svcs | Select-Object {} | ConvertTo-Json -Compress",
+        names, CIM_MATCHING, SERVICE_COLUMNS
+    );
+    let output = run_powershell(&cmd).await;
+    (parse_services_json(&output), output)
+}
+
+/// ConvertTo-Json writes a bare object when the pipeline has a single service
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OneOrMany {
+    Many(Vec<ServiceInfo>),
+    One(ServiceInfo),
+}
+
+/// Parse `Get-Service | ConvertTo-Json` output (no output at all when nothing matched)
+fn parse_services_json(output: &CommandOutput) -> Vec<ServiceInfo> {
+    let json = output.stdout.trim();
+    if output.exit_code != 0 || json.is_empty() {
+        return Vec::new();
+    }
+
+    match serde_json::from_str::<OneOrMany>(json) {
+        Ok(OneOrMany::Many(services)) => services,
+        Ok(OneOrMany::One(service)) => vec![service],
+        Err(e) => {
+            tracing::warn!("Failed to parse Get-Service output: {}", e);
+            Vec::new()
         }
     }
-    
-    (services, output)
 }
 
 /// Start a Windows service (requires admin)
 pub async fn start_service(name: &str) -> CommandOutput {
-    let cmd = format!("Start-Service -Name '{}'", name);
-    run_powershell(&cmd).await
+    let output = if using_sc_fallback() {
+        run_command("sc.exe", &["start", name]).await
+    } else {
+        run_powershell(&format!("Start-Service -Name '{}'", name)).await
+    };
+    output.requires_admin().with_danger(DangerLevel::Modifying)
 }
 
 /// Stop a Windows service (requires admin)
 pub async fn stop_service(name: &str) -> CommandOutput {
     let cmd = format!("Stop-Service -Name '{}' -Force", name);
-    run_powershell(&cmd).await
+    let danger = if is_critical_service(name) {
+        DangerLevel::Destructive
+    } else {
+        DangerLevel::Modifying
+    };
+    let output = if using_sc_fallback() {
+        run_command("sc.exe", &["stop", name]).await
+    } else {
+        run_powershell(&cmd).await
+    };
+    output.requires_admin().with_danger(danger)
+}
+
+/// Running services that depend on `name`, in the order to stop them
+///
+/// `DependentServices` and `sc enumdepend` both list transitive dependents in reverse
+/// start order, so a dependent's own dependents come before it.
+pub async fn get_running_dependents(name: &str) -> (Result<Vec<String>, String>, CommandOutput) {
+    if using_sc_fallback() {
+        let output = run_command("sc.exe", &["enumdepend", name, "65536"]).await;
+        if !output.succeeded() {
+            let error = output.stdout.lines().map(str::trim).find(|l| l.contains("FAILED"));
+            return (Err(error.unwrap_or("sc enumdepend failed").to_string()), output);
+        }
+        let names = parse_sc_query(&output.stdout)
+            .into_iter()
+            .filter(|svc| svc.status == "Running")
+            .map(|svc| svc.name)
+            .collect();
+        return (Ok(names), output);
+    }
+    let cmd = format!(
+        "ConvertTo-Json -Compress -InputObject @((Get-Service -Name '{}' -ErrorAction Stop).DependentServices | \
+         Where-Object {{ $_.Status -eq 'Running' }} | ForEach-Object {{ $_.Name }})",
+        name.replace('\'', "''")
+    );
+    let output = run_powershell(&cmd).await;
+    if !output.succeeded() {
+        let error = output.stderr.lines().map(str::trim).find(|l| !l.is_empty());
+        return (Err(error.unwrap_or("Service query failed").to_string()), output);
+    }
+    let result = serde_json::from_str::<Vec<String>>(output.stdout.trim())
+        .map_err(|e| format!("Failed to parse dependent services: {}", e));
+    (result, output)
 }
 
-/// Restart a Windows service (requires admin)
-pub async fn restart_service(name: &str) -> CommandOutput {
-    let cmd = format!("Restart-Service -Name '{}' -Force", name);
+/// What a restart stops and starts again around the target
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RestartPlan {
+    pub name: String,
+    /// Running dependents in stop order; they are started again in reverse
+    pub dependents: Vec<String>,
+}
+
+/// Look up the running dependents a restart of `name` has to take down with it
+pub async fn plan_restart(name: &str) -> (Result<RestartPlan, String>, CommandOutput) {
+    let (dependents, output) = get_running_dependents(name).await;
+    let plan = dependents.map(|dependents| RestartPlan {
+        name: name.to_string(),
+        dependents,
+    });
+    (plan, output)
+}
+
+/// How a restart chain went
+#[derive(Debug, Clone, Default)]
+pub struct RestartChain {
+    /// One output per stop/start step, then a summary
+    pub outputs: Vec<CommandOutput>,
+    /// Where each service the chain touched ended up
+    pub waits: Vec<StatusWait>,
+    /// Step that aborted the chain ("stop W32Time"); None when every step worked
+    pub failed_step: Option<String>,
+    /// Dependents that were stopped and did not start again
+    pub not_restored: Vec<String>,
+}
+
+impl RestartChain {
+    pub fn succeeded(&self) -> bool {
+        self.failed_step.is_none() && self.not_restored.is_empty()
+    }
+
+    /// Stop or start one service and wait for it to settle; false if it didn't get there
+    async fn step(&mut self, name: &str, desired: &str, timeout: Duration) -> bool {
+        let output = if desired == "Stopped" {
+            stop_service(name).await
+        } else {
+            start_service(name).await
+        };
+        self.outputs.push(output);
+        let wait = wait_for_status(name, desired, timeout).await;
+        let reached = wait.reached;
+        self.waits.retain(|w| w.name != name);
+        self.waits.push(wait);
+        reached
+    }
+
+    /// Output-panel entry listing where every service ended up
+    fn summary(&self, plan: &RestartPlan) -> CommandOutput {
+        let mut lines: Vec<String> = self.waits.iter().map(|w| format!("{}: {}", w.name, w.status)).collect();
+        if let Some(step) = &self.failed_step {
+            lines.push(t!("services.chain-aborted", step = step));
+        }
+        if !self.not_restored.is_empty() {
+            lines.push(t!("services.chain-left-stopped", services = self.not_restored.join(", ")));
+        }
+        CommandOutput {
+            command: t!("services.chain-command", name = plan.name, dependents = plan.dependents.len()),
+            stdout: lines.join("\n"),
+            exit_code: if self.succeeded() { 0 } else { 1 },
+            ..Default::default()
+        }
+    }
+}
+
+/// Restart a Windows service together with its running dependents (requires admin)
+///
+/// Restart-Service fails or leaves dependents stopped, and sc.exe has no restart at all, so
+/// this stops the dependents in `plan` order, stops and starts the target, then starts the
+/// dependents in reverse, waiting up to `timeout` for each step. The first step that fails
+/// aborts the chain; the services already stopped are still started again.
+pub async fn restart_service(plan: &RestartPlan, timeout: Duration) -> RestartChain {
+    let mut chain = RestartChain::default();
+    let mut stopped: Vec<&str> = Vec::new();
+    for name in plan.dependents.iter().chain(std::iter::once(&plan.name)) {
+        if !chain.step(name, "Stopped", timeout).await {
+            chain.failed_step = Some(format!("stop {}", name));
+            break;
+        }
+        stopped.push(name);
+    }
+    for name in stopped.into_iter().rev() {
+        if chain.step(name, "Running", timeout).await {
+            continue;
+        }
+        if name == plan.name {
+            chain.failed_step.get_or_insert_with(|| format!("start {}", name));
+        } else {
+            chain.not_restored.push(name.to_string());
+        }
+    }
+    let summary = chain.summary(plan);
+    chain.outputs.push(summary);
+    chain
+}
+
+/// How long to wait for a service to settle after start/stop when not configured
+pub const DEFAULT_STATUS_TIMEOUT_SECS: u64 = 30;
+
+/// How often `wait_for_status` re-queries the service
+const STATUS_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Where a service ended up after `wait_for_status`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StatusWait {
+    pub name: String,
+    /// Last status Get-Service reported (empty if the service couldn't be queried)
+    pub status: String,
+    /// The desired status was reached
+    pub reached: bool,
+    /// Hosting process, looked up when the wait timed out
+    pub pid: Option<u32>,
+}
+
+impl StatusWait {
+    /// Still in StartPending/StopPending/... when the wait gave up
+    pub fn is_stuck(&self) -> bool {
+        !self.reached && self.status.ends_with("Pending")
+    }
+}
+
+/// Poll Get-Service until `name` reports `desired` ("Running", "Stopped") or `timeout` passes
+///
+/// Start-Service/Stop-Service can return while the service is still pending. Gives up
+/// early when the service settles in some other state (e.g. it crashed right after starting).
+pub async fn wait_for_status(name: &str, desired: &str, timeout: Duration) -> StatusWait {
+    let deadline = Instant::now() + timeout;
+    loop {
+        let (services, _) = list_services_filtered(name).await;
+        let status = services
+            .into_iter()
+            .find(|s| s.name.eq_ignore_ascii_case(name))
+            .map(|s| s.status)
+            .unwrap_or_default();
+        let mut wait = StatusWait {
+            name: name.to_string(),
+            reached: status == desired,
+            status,
+            pid: None,
+        };
+        if wait.reached || !wait.status.ends_with("Pending") {
+            return wait;
+        }
+        if Instant::now() >= deadline {
+            wait.pid = get_service_pid(name).await;
+            return wait;
+        }
+        tokio::time::sleep(STATUS_POLL_INTERVAL).await;
+    }
+}
+
+/// PID of the process hosting a service (None when it isn't running)
+pub async fn get_service_pid(name: &str) -> Option<u32> {
+    if using_sc_fallback() {
+        let output = run_command("sc.exe", &["queryex", name]).await;
+        return output
+            .stdout
+            .lines()
+            .filter_map(|line| line.split_once(':'))
+            .find(|(key, _)| key.trim() == "PID")
+            .and_then(|(_, value)| value.trim().parse::<u32>().ok())
+            .filter(|&pid| pid != 0);
+    }
+    let cmd = format!(
+        "(Get-CimInstance Win32_Service -Filter 'Name=''{}''').ProcessId",
+        name.replace('\'', "''")
+    );
+    let output = run_powershell(&cmd).await;
+    output.stdout.trim().parse::<u32>().ok().filter(|&pid| pid != 0)
+}
+
+/// A process and the services it hosts, from `tasklist /svc`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ServiceHost {
+    pub image: String,
+    pub pid: u32,
+    pub services: Vec<String>,
+}
+
+impl ServiceHost {
+    /// Hosts more than one service, so killing it takes the others down too
+    pub fn is_shared(&self) -> bool {
+        self.services.len() > 1
+    }
+}
+
+/// Parse `tasklist /svc /fo csv /nh` lines like `"svchost.exe","1234","BFE,mpssvc"`
+///
+/// Processes without services list `N/A`; the "INFO: No tasks..." line is skipped.
+pub fn parse_tasklist_svc(text: &str) -> Vec<ServiceHost> {
+    text.lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.trim().strip_prefix('"')?.strip_suffix('"')?.split("\",\"").collect();
+            let [image, pid, services] = fields.as_slice() else {
+                return None;
+            };
+            Some(ServiceHost {
+                image: image.to_string(),
+                pid: pid.parse().ok()?,
+                services: services
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|name| !name.is_empty() && *name != "N/A")
+                    .map(str::to_string)
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
+/// Process `pid` and every service it hosts (None when tasklist doesn't know the PID)
+pub async fn get_service_host(pid: u32) -> (Option<ServiceHost>, CommandOutput) {
+    let filter = format!("PID eq {}", pid);
+    let output = run_command("tasklist", &["/svc", "/fi", &filter, "/fo", "csv", "/nh"]).await;
+    let host = parse_tasklist_svc(&output.stdout).into_iter().find(|host| host.pid == pid);
+    (host, output)
+}
+
+/// Windows error for a service whose account could not log on (usually a changed password)
+pub const ERROR_SERVICE_LOGON_FAILED: u32 = 1069;
+
+/// A start failed because the service account couldn't log on
+///
+/// Checks the start command's own error first, then the exit code the service control
+/// manager recorded for the service (`sc queryex`).
+pub async fn is_logon_failure(name: &str, start_output: &CommandOutput) -> bool {
+    let text = format!("{} {}", start_output.stdout, start_output.stderr).to_lowercase();
+    if text.contains(&ERROR_SERVICE_LOGON_FAILED.to_string()) || text.contains("logon failure") {
+        return true;
+    }
+    let output = run_command("sc.exe", &["queryex", name]).await;
+    output
+        .stdout
+        .lines()
+        .filter_map(|line| line.split_once(':'))
+        .filter(|(key, _)| matches!(key.trim(), "WIN32_EXIT_CODE" | "SERVICE_EXIT_CODE"))
+        .filter_map(|(_, value)| value.split_whitespace().next()?.parse::<u32>().ok())
+        .any(|code| code == ERROR_SERVICE_LOGON_FAILED)
+}
+
+/// Start type means the service should be running after boot
+pub fn is_automatic(start_type: &str) -> bool {
+    matches!(start_type, "Automatic" | "AutomaticDelayedStart")
+}
+
+/// Services set to start automatically that aren't running - usually what broke after an update
+///
+/// Trigger-start services are still in here; the caller drops them with `has_start_trigger`,
+/// which costs an sc.exe call per service.
+pub fn audit_auto_not_running(services: &[ServiceInfo]) -> Vec<ServiceInfo> {
+    services
+        .iter()
+        .filter(|svc| is_automatic(&svc.start_type) && svc.status != "Running")
+        .cloned()
+        .collect()
+}
+
+/// Outcome of the Automatic-but-not-running audit
+#[derive(Debug, Clone, Default)]
+pub struct AutoAudit {
+    /// Automatic services that aren't running and have no start trigger
+    pub not_running: Vec<ServiceInfo>,
+    /// Automatic and not running, but trigger-started, so left out of `not_running`
+    pub trigger_start: Vec<String>,
+}
+
+/// Service registered start triggers (`sc qtriggerinfo`)
+///
+/// Windows starts such services on an event (device arrival, network, policy) and stops them
+/// again when idle, so being stopped is normal even with an Automatic start type.
+pub async fn has_start_trigger(name: &str) -> bool {
+    let output = run_command("sc.exe", &["qtriggerinfo", name]).await;
+    output.succeeded() && output.stdout.contains("START SERVICE")
+}
+
+/// Start types offered when changing a service (Boot/System are driver-only)
+pub const START_TYPES: &[&str] = &["Automatic", "AutomaticDelayedStart", "Manual", "Disabled"];
+
+/// Change a service's start type (requires admin)
+///
+/// Delayed start goes through `sc config`, since Windows PowerShell's Set-Service
+/// doesn't accept AutomaticDelayedStart; in sc.exe fallback mode every start type does.
+pub async fn set_start_type(name: &str, start_type: &str) -> CommandOutput {
+    let sc_start = match start_type {
+        "AutomaticDelayedStart" => Some("delayed-auto"),
+        "Automatic" => Some("auto"),
+        "Manual" => Some("demand"),
+        "Disabled" => Some("disabled"),
+        _ => None,
+    };
+    let output = if let Some(sc_start) = sc_start.filter(|_| using_sc_fallback() || start_type == "AutomaticDelayedStart") {
+        run_command("sc.exe", &["config", name, "start=", sc_start]).await
+    } else {
+        let cmd = format!("Set-Service -Name '{}' -StartupType {}", name.replace('\'', "''"), start_type);
+        run_powershell(&cmd).await
+    };
+    let danger = if start_type == "Disabled" && is_critical_service(name) {
+        DangerLevel::Destructive
+    } else {
+        DangerLevel::Modifying
+    };
+    output.requires_admin().with_danger(danger)
+}
+
+/// True when a re-queried start type shows `requested` took effect
+///
+/// Windows PowerShell reports delayed-start services as plain "Automatic".
+pub fn start_type_matches(reported: &str, requested: &str) -> bool {
+    reported == requested || (requested == "AutomaticDelayedStart" && reported == "Automatic")
+}
+
+/// Names of the services hosted by process `pid` (empty for a regular process)
+pub async fn find_service_by_pid(pid: u32) -> Vec<String> {
+    let cmd = format!(
+        "ConvertTo-Json -Compress -InputObject @(Get-CimInstance Win32_Service -Filter 'ProcessId={}' | ForEach-Object {{ $_.Name }})",
+        pid
+    );
+    let output = run_powershell(&cmd).await;
+    if !output.succeeded() {
+        tracing::warn!("Service lookup for PID {} failed: {}", pid, output.stderr.trim());
+        return Vec::new();
+    }
+    let mut names = serde_json::from_str::<Vec<String>>(output.stdout.trim()).unwrap_or_default();
+    names.sort_by_key(|name| name.to_lowercase());
+    names
+}
+
+/// A process listening on a port, with the services it hosts
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PortOwner {
+    pub pid: u32,
+    pub process_name: String,
+    pub protocol: Protocol,
+    /// Kernel listener (PID 4), typically http.sys serving IIS/WinRM/WCF URL reservations
+    pub is_system: bool,
+    /// Service names hosted by the process (empty when it isn't a service)
+    pub services: Vec<String>,
+}
+
+/// Which processes listen on `port` and which services each of them hosts
+pub async fn find_services_by_port(port: u16) -> Result<Vec<PortOwner>, String> {
+    let result = ports::list_bindings_for_ports(&[port], ProtocolFilter::Both).await;
+    if result.scan_failed() {
+        return Err(result.socket_error.unwrap_or_else(|| "Could not read the socket table".to_string()));
+    }
+
+    let mut owners: Vec<PortOwner> = Vec::new();
+    for binding in result.bindings.iter().filter(|b| b.is_listener && b.source == BindingSource::Windows) {
+        if owners.iter().any(|o| o.pid == binding.pid) {
+            continue;
+        }
+        let services = if binding.is_system { Vec::new() } else { find_service_by_pid(binding.pid).await };
+        owners.push(PortOwner {
+            pid: binding.pid,
+            process_name: binding.process_name.clone(),
+            protocol: binding.protocol,
+            is_system: binding.is_system,
+            services,
+        });
+    }
+    Ok(owners)
+}
+
+/// What the service control manager does when a service fails
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FailureActionKind {
+    #[default]
+    None,
+    Restart,
+    RunCommand,
+    Reboot,
+}
+
+impl FailureActionKind {
+    /// Action name in `sc failure actions=` syntax
+    fn sc_name(&self) -> &'static str {
+        match self {
+            FailureActionKind::None => "",
+            FailureActionKind::Restart => "restart",
+            FailureActionKind::RunCommand => "run",
+            FailureActionKind::Reboot => "reboot",
+        }
+    }
+}
+
+/// One entry of a service's failure actions (first, second, subsequent failures)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FailureAction {
+    pub kind: FailureActionKind,
+    pub delay_ms: u64,
+}
+
+/// Recovery settings of a service, as reported by `sc qfailure`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FailureActions {
+    /// Seconds without failures after which the failure count resets
+    pub reset_period_secs: u64,
+    pub reboot_message: String,
+    /// Program run by a RunCommand action
+    pub command: String,
+    /// Actions for the first, second, and subsequent failures (empty = take no action)
+    pub actions: Vec<FailureAction>,
+}
+
+/// Recovery settings offered by the detail card editor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailurePreset {
+    /// Restart after one minute, for the first three failures; the count resets after a day
+    RestartThreeTimes,
+    TakeNoAction,
+}
+
+impl FailurePreset {
+    pub const ALL: [FailurePreset; 2] = [FailurePreset::RestartThreeTimes, FailurePreset::TakeNoAction];
+
+    pub fn actions(&self) -> FailureActions {
+        match self {
+            FailurePreset::RestartThreeTimes => FailureActions {
+                reset_period_secs: 86400,
+                actions: vec![
+                    FailureAction {
+                        kind: FailureActionKind::Restart,
+                        delay_ms: 60_000,
+                    };
+                    3
+                ],
+                ..Default::default()
+            },
+            FailurePreset::TakeNoAction => FailureActions::default(),
+        }
+    }
+
+    /// The preset `current` matches, if any
+    pub fn matching(current: &FailureActions) -> Option<FailurePreset> {
+        Self::ALL.into_iter().find(|preset| {
+            let wanted = preset.actions();
+            wanted.actions == current.actions && wanted.reset_period_secs == current.reset_period_secs
+        })
+    }
+}
+
+/// Read a service's recovery settings with `sc qfailure`
+pub async fn get_failure_actions(name: &str) -> (Result<FailureActions, String>, CommandOutput) {
+    let output = run_command("sc.exe", &["qfailure", name]).await;
+    if !output.succeeded() {
+        let error = output.stdout.lines().chain(output.stderr.lines()).map(str::trim).find(|l| !l.is_empty() && !l.starts_with("[SC]"));
+        return (Err(error.unwrap_or("sc qfailure failed").to_string()), output);
+    }
+    (Ok(parse_failure_actions(&output.stdout)), output)
+}
+
+/// Parse `sc qfailure` output; the action list continues on lines without a label
+fn parse_failure_actions(text: &str) -> FailureActions {
+    let mut result = FailureActions::default();
+    let mut in_actions = false;
+    for line in text.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        // Continued action lines have no label ("RESTART -- Delay = 60000 milliseconds.")
+        let (key, value) = match line.split_once(':') {
+            Some((key, value)) if !key.contains("--") => (key.trim(), value.trim()),
+            _ => {
+                if in_actions {
+                    result.actions.extend(parse_failure_action(line));
+                }
+                continue;
+            }
+        };
+        in_actions = key == "FAILURE_ACTIONS";
+        if key.starts_with("RESET_PERIOD") {
+            result.reset_period_secs = value.parse().unwrap_or(0);
+        } else if key == "REBOOT_MESSAGE" {
+            result.reboot_message = value.to_string();
+        } else if key == "COMMAND_LINE" {
+            result.command = value.to_string();
+        } else if in_actions {
+            result.actions.extend(parse_failure_action(value));
+        }
+    }
+    result
+}
+
+/// Parse one "RESTART -- Delay = 60000 milliseconds." entry
+fn parse_failure_action(text: &str) -> Option<FailureAction> {
+    let (kind, delay) = text.split_once("--")?;
+    let kind = match kind.trim() {
+        "RESTART" => FailureActionKind::Restart,
+        "RUN PROCESS" => FailureActionKind::RunCommand,
+        "REBOOT" => FailureActionKind::Reboot,
+        _ => FailureActionKind::None,
+    };
+    let delay_ms = delay
+        .chars()
+        .skip_while(|c| !c.is_ascii_digit())
+        .take_while(char::is_ascii_digit)
+        .collect::<String>()
+        .parse()
+        .unwrap_or(0);
+    Some(FailureAction { kind, delay_ms })
+}
+
+/// Replace a service's recovery settings with `sc failure` (requires admin)
+pub async fn set_failure_actions(name: &str, settings: &FailureActions) -> CommandOutput {
+    let actions = settings
+        .actions
+        .iter()
+        .map(|a| format!("{}/{}", a.kind.sc_name(), a.delay_ms))
+        .collect::<Vec<_>>()
+        .join("/");
+    let reset = settings.reset_period_secs.to_string();
+    let mut args = vec!["failure", name, "reset=", reset.as_str(), "actions=", actions.as_str()];
+    if !settings.command.is_empty() {
+        args.extend(["command=", settings.command.as_str()]);
+    }
+    run_command("sc.exe", &args).await.requires_admin().with_danger(DangerLevel::Modifying)
+}
+
+/// Win32_Service details plus dependencies, for the service detail card
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ServiceDetails {
+    pub name: String,
+    pub display_name: String,
+    pub description: String,
+    /// "Running", "Stopped", "Start Pending", ...
+    pub state: String,
+    /// "Auto", "Manual", "Disabled"; "Auto (Delayed)" for delayed start
+    pub start_mode: String,
+    /// Command line the service runs, as registered
+    pub binary_path: String,
+    /// Run-as account ("LocalSystem", "NT AUTHORITY\NetworkService", ...)
+    pub account: String,
+    /// Hosting process (None when stopped)
+    pub pid: Option<u32>,
+    /// Win32 exit code of the last stop (1066 means see `service_exit_code`)
+    pub exit_code: u32,
+    pub service_exit_code: u32,
+    /// Services this one needs running
+    pub depends_on: Vec<String>,
+    /// Services that need this one running
+    pub dependents: Vec<String>,
+}
+
+impl ServiceDetails {
+    /// The executable in `binary_path`, without quotes or arguments
+    pub fn executable(&self) -> &str {
+        let path = self.binary_path.trim();
+        if let Some(quoted) = path.strip_prefix('"') {
+            return quoted.split('"').next().unwrap_or(quoted);
+        }
+        match path.to_lowercase().find(".exe") {
+            Some(i) => &path[..i + 4],
+            None => path.split_whitespace().next().unwrap_or(path),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawServiceDetails {
+    name: String,
+    #[serde(default)]
+    display_name: Option<String>,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    state: Option<String>,
+    #[serde(default)]
+    start_mode: Option<String>,
+    #[serde(default)]
+    delayed_auto_start: Option<bool>,
+    #[serde(default)]
+    path_name: Option<String>,
+    #[serde(default)]
+    start_name: Option<String>,
+    #[serde(default)]
+    process_id: Option<u32>,
+    #[serde(default)]
+    exit_code: Option<u32>,
+    #[serde(default)]
+    service_specific_exit_code: Option<u32>,
+    #[serde(default)]
+    depends_on: Option<Vec<String>>,
+    #[serde(default)]
+    dependents: Option<Vec<String>>,
+}
+
+/// Query Win32_Service and Get-Service for one service and parse the result
+pub async fn get_service_details_parsed(name: &str) -> (Result<ServiceDetails, String>, CommandOutput) {
+    let name = name.replace('\'', "''");
+    let cmd = format!(
+        "$s = Get-CimInstance Win32_Service -Filter 'Name=''{name}'''; \
+         if (-not $s) {{ Write-Error 'Service {name} was not found'; exit 1 }}; \
+         $g = Get-Service -Name '{name}'; \
+         [pscustomobject]@{{ Name = $s.Name; DisplayName = $s.DisplayName; Description = $s.Description; \
+         State = $s.State; StartMode = $s.StartMode; DelayedAutoStart = $s.DelayedAutoStart; PathName = $s.PathName; \
+         StartName = $s.StartName; ProcessId = $s.ProcessId; ExitCode = $s.ExitCode; \
+         ServiceSpecificExitCode = $s.ServiceSpecificExitCode; \
+         DependsOn = @($g.ServicesDependedOn | ForEach-Object {{ $_.Name }}); \
+         Dependents = @($g.DependentServices | ForEach-Object {{ $_.Name }}) }} | ConvertTo-Json -Compress"
+    );
+    let output = run_powershell(&cmd).await;
+    if !output.succeeded() || output.stdout.trim().is_empty() {
+        let error = output.stderr.lines().map(str::trim).find(|l| !l.is_empty());
+        return (Err(error.unwrap_or("Service query failed").to_string()), output);
+    }
+
+    let result = serde_json::from_str::<RawServiceDetails>(output.stdout.trim())
+        .map(|raw| {
+            let start_mode = raw.start_mode.unwrap_or_default();
+            ServiceDetails {
+                start_mode: if raw.delayed_auto_start == Some(true) && start_mode == "Auto" {
+                    "Auto (Delayed)".to_string()
+                } else {
+                    start_mode
+                },
+                name: raw.name,
+                display_name: raw.display_name.unwrap_or_default(),
+                description: raw.description.unwrap_or_default(),
+                state: raw.state.unwrap_or_default(),
+                binary_path: raw.path_name.unwrap_or_default(),
+                account: raw.start_name.unwrap_or_default(),
+                pid: raw.process_id.filter(|&pid| pid != 0),
+                exit_code: raw.exit_code.unwrap_or(0),
+                service_exit_code: raw.service_specific_exit_code.unwrap_or(0),
+                depends_on: raw.depends_on.unwrap_or_default(),
+                dependents: raw.dependents.unwrap_or_default(),
+            }
+        })
+        .map_err(|e| format!("Failed to parse service details: {}", e));
+    (result, output)
+}
+
+/// Registry key holding a service's configuration
+pub fn registry_key(name: &str) -> String {
+    format!(r"HKEY_LOCAL_MACHINE\SYSTEM\CurrentControlSet\Services\{}", name)
+}
+
+/// Open regedit at the service's key by setting the `LastKey` it starts at
+///
+/// An already running regedit only comes to the front and keeps its current key.
+pub async fn open_registry_key(name: &str) -> Vec<CommandOutput> {
+    let last_key = format!(r"Computer\{}", registry_key(name));
+    let set = run_command(
+        "reg.exe",
+        &[
+            "add",
+            r"HKCU\Software\Microsoft\Windows\CurrentVersion\Applets\Regedit",
+            "/v",
+            "LastKey",
+            "/t",
+            "REG_SZ",
+            "/d",
+            &last_key,
+            "/f",
+        ],
+    )
+    .await;
+    if !set.succeeded() {
+        return vec![set];
+    }
+    // `start` goes through ShellExecute, which shows the UAC prompt regedit asks for
+    let launch = run_command("cmd", &["/c", "start", "", "regedit.exe"]).await;
+    vec![set, launch]
+}
+
+/// How many Service Control Manager events `get_recent_events` searches
+pub const RECENT_EVENTS_SCANNED: usize = 50;
+
+/// Recent Service Control Manager events in the System log that mention the service
+///
+/// SCM messages name services by display name, so both names are matched.
+pub async fn get_recent_events(name: &str, display_name: &str) -> CommandOutput {
+    let cmd = format!(
+        "Get-WinEvent -FilterHashtable @{{LogName='System'; ProviderName='Service Control Manager'}} -MaxEvents {} | \
+         Where-Object {{ $_.Message.IndexOf('{}', [StringComparison]::OrdinalIgnoreCase) -ge 0 -or \
+         $_.Message.IndexOf('{}', [StringComparison]::OrdinalIgnoreCase) -ge 0 }} | \
+         Format-List TimeCreated, Id, LevelDisplayName, Message | Out-String -Width 200",
+        RECENT_EVENTS_SCANNED,
+        name.replace('\'', "''"),
+        display_name.replace('\'', "''")
+    );
     run_powershell(&cmd).await
 }
 
-/// Get detailed info about a specific service
+/// Check a name and binary path before `sc create`
+pub fn validate_new_service(name: &str, bin_path: &str) -> Result<(), String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Service name cannot be empty".to_string());
+    }
+    if name.chars().any(|c| c == '/' || c == '\\' || c == '"' || c.is_control()) {
+        return Err("Service name cannot contain slashes, quotes or control characters".to_string());
+    }
+    if bin_path.trim().is_empty() {
+        return Err("Binary path cannot be empty".to_string());
+    }
+    Ok(())
+}
+
+/// Register a new service with `sc create` (requires admin)
+///
+/// A bare executable path is quoted, so a path with spaces doesn't become an
+/// unquoted service path that Windows resolves piece by piece.
+pub async fn create_service(name: &str, display_name: &str, bin_path: &str, start_type: &str) -> Result<CommandOutput, String> {
+    validate_new_service(name, bin_path)?;
+    let name = name.trim();
+    let bin_path = bin_path.trim();
+    let bin_path = if !bin_path.starts_with('"') && bin_path.contains(' ') && std::path::Path::new(bin_path).is_file() {
+        format!("\"{}\"", bin_path)
+    } else {
+        bin_path.to_string()
+    };
+    let display_name = match display_name.trim() {
+        "" => name,
+        display_name => display_name,
+    };
+    let sc_start = match start_type {
+        "AutomaticDelayedStart" => "delayed-auto",
+        "Automatic" => "auto",
+        "Disabled" => "disabled",
+        _ => "demand",
+    };
+    // Running it again only fails with "already exists", so there is no re-run
+    Ok(run_command(
+        "sc.exe",
+        &["create", name, "binPath=", &bin_path, "DisplayName=", display_name, "start=", sc_start],
+    )
+    .await
+    .requires_admin()
+    .with_danger(DangerLevel::Modifying)
+    .without_replay())
+}
+
+/// Executable path a service runs, from `sc qc`
+pub async fn get_binary_path(name: &str) -> (Option<String>, CommandOutput) {
+    let output = run_command("sc.exe", &["qc", name]).await;
+    let path = output
+        .stdout
+        .lines()
+        .filter_map(|line| line.split_once(':'))
+        .find(|(key, _)| key.trim() == "BINARY_PATH_NAME")
+        .map(|(_, value)| value.trim().to_string());
+    (path, output)
+}
+
+/// Binary path lives in the Windows directory (System32, SysWOW64, drivers, ...)
+///
+/// Drivers report paths like `\SystemRoot\System32\...` or `System32\...`, which are
+/// relative to the Windows directory too.
+pub fn is_windows_path(bin_path: &str) -> bool {
+    let path = bin_path.trim().trim_start_matches('"').replace('/', "\\").to_lowercase();
+    let windir = std::env::var("SystemRoot")
+        .or_else(|_| std::env::var("windir"))
+        .unwrap_or_else(|_| r"C:\Windows".to_string())
+        .to_lowercase();
+    path.starts_with(&format!("{}\\", windir.trim_end_matches('\\')))
+        || path.starts_with("%systemroot%\\")
+        || path.starts_with("%windir%\\")
+        || path.starts_with("\\systemroot\\")
+        || path.starts_with("system32\\")
+        || (path.starts_with("\\??\\") && path.contains("\\windows\\"))
+}
+
+/// Why a service must not be deleted from here, or `None` when it may be
+pub fn delete_refusal(name: &str, bin_path: &str) -> Option<String> {
+    if is_critical_service(name) {
+        return Some(format!("{} is a critical Windows service", name));
+    }
+    if is_windows_path(bin_path) {
+        return Some(format!("{} runs from the Windows directory ({})", name, bin_path.trim()));
+    }
+    None
+}
+
+/// Remove a service with `sc delete` (requires admin)
+///
+/// Re-checks the protect list against the binary path `sc qc` reports, so a Windows
+/// component can't be deleted even if the caller skipped the check.
+pub async fn delete_service(name: &str) -> Result<CommandOutput, String> {
+    let (bin_path, output) = get_binary_path(name).await;
+    let Some(bin_path) = bin_path else {
+        return Ok(output);
+    };
+    if let Some(reason) = delete_refusal(name, &bin_path) {
+        return Err(reason);
+    }
+    Ok(run_command("sc.exe", &["delete", name])
+        .await
+        .requires_admin()
+        .with_danger(DangerLevel::Destructive)
+        .without_replay())
+}
+
+/// Windows error code from an sc.exe failure line such as `[SC] DeleteService FAILED 1072:`
+pub fn sc_error_code(output: &CommandOutput) -> Option<u32> {
+    let text = format!("{}\n{}", output.stdout, output.stderr);
+    let (_, rest) = text.split_once("FAILED ")?;
+    rest.split(|c: char| !c.is_ascii_digit()).next()?.parse().ok()
+}
+
+/// Get detailed info about a specific service (Format-List text)
 pub async fn get_service_details(name: &str) -> CommandOutput {
     let cmd = format!(
         r#"Get-Service -Name '{}' | Format-List Name, DisplayName, Status, StartType, DependentServices, ServicesDependedOn"#,
@@ -100,3 +1117,103 @@ pub async fn get_service_details(name: &str) -> CommandOutput {
     );
     run_powershell(&cmd).await
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn output(stdout: &str) -> CommandOutput {
+        CommandOutput { stdout: stdout.to_string(), ..Default::default() }
+    }
+
+    #[test]
+    fn services_json_keeps_commas_and_quotes_in_display_names() {
+        let json = r#"[{"Name":"AppMgmt","DisplayName":"Application Management","Status":"Stopped","StartType":"Manual","Account":"LocalSystem","ProcessId":0},{"Name":"acme-agent","DisplayName":"ACME \"Sentinel\" Agent, v2","Status":"Running","StartType":"Automatic","Account":"NT AUTHORITY\\LocalService","ProcessId":4120}]"#;
+        let services = parse_services_json(&output(json));
+        assert_eq!(services.len(), 2);
+        assert_eq!(services[1].name, "acme-agent");
+        assert_eq!(services[1].display_name, r#"ACME "Sentinel" Agent, v2"#);
+        assert_eq!(services[1].account, r"NT AUTHORITY\LocalService");
+        assert_eq!(services[1].process_id, 4120);
+        assert!(services[0].runs_as_local_system());
+    }
+
+    #[test]
+    fn services_json_accepts_a_single_object() {
+        let json = r#"{"Name":"Dnscache","DisplayName":"DNS Client","Status":"Running","StartType":"Automatic"}"#;
+        let services = parse_services_json(&output(json));
+        assert_eq!(services.len(), 1);
+        assert_eq!((services[0].name.as_str(), services[0].status.as_str()), ("Dnscache", "Running"));
+        assert_eq!((services[0].account.as_str(), services[0].process_id), ("", 0));
+    }
+
+    #[test]
+    fn services_json_is_empty_for_no_output_or_failure() {
+        assert!(parse_services_json(&output("")).is_empty());
+        assert!(parse_services_json(&output("not json")).is_empty());
+        let json = r#"{"Name":"x","DisplayName":"x","Status":"Running","StartType":"Manual"}"#;
+        let failed = CommandOutput { exit_code: 1, ..output(json) };
+        assert!(parse_services_json(&failed).is_empty());
+    }
+
+    /// `tasklist /svc /fo csv /nh` rows for a shared svchost, a single-service one, and a plain process
+    const TASKLIST_SVC: &str = r#"
+"svchost.exe","1184","BFE,mpssvc"
+"svchost.exe","2240","Dnscache"
+"explorer.exe","6512","N/A"
+"#;
+
+    fn host(image: &str, pid: u32, services: &[&str]) -> ServiceHost {
+        ServiceHost { image: image.to_string(), pid, services: services.iter().map(|s| s.to_string()).collect() }
+    }
+
+    #[test]
+    fn tasklist_svc_splits_shared_hosts_and_drops_na() {
+        let hosts = parse_tasklist_svc(TASKLIST_SVC);
+        assert_eq!(
+            hosts,
+            vec![
+                host("svchost.exe", 1184, &["BFE", "mpssvc"]),
+                host("svchost.exe", 2240, &["Dnscache"]),
+                host("explorer.exe", 6512, &[]),
+            ]
+        );
+        assert!(hosts[0].is_shared());
+        assert!(!hosts[1].is_shared() && !hosts[2].is_shared());
+    }
+
+    #[test]
+    fn tasklist_svc_skips_the_no_tasks_line() {
+        assert!(parse_tasklist_svc("INFO: No tasks are running which match the specified criteria.\r\n").is_empty());
+        assert!(parse_tasklist_svc("").is_empty());
+    }
+
+    #[test]
+    fn audit_flags_only_automatic_services_that_are_not_running() {
+        let mut services = Vec::new();
+        for start_type in ["Automatic", "AutomaticDelayedStart", "Manual", "Disabled"] {
+            for status in ["Running", "Stopped"] {
+                services.push(ServiceInfo {
+                    name: format!("{}-{}", start_type, status),
+                    start_type: start_type.to_string(),
+                    status: status.to_string(),
+                    ..Default::default()
+                });
+            }
+        }
+        let flagged: Vec<String> = audit_auto_not_running(&services).into_iter().map(|s| s.name).collect();
+        assert_eq!(flagged, vec!["Automatic-Stopped", "AutomaticDelayedStart-Stopped"]);
+    }
+
+    #[test]
+    fn audit_counts_pending_states_as_not_running() {
+        let services = [ServiceInfo {
+            name: "wuauserv".to_string(),
+            start_type: "Automatic".to_string(),
+            status: "StartPending".to_string(),
+            ..Default::default()
+        }];
+        assert_eq!(audit_auto_not_running(&services).len(), 1);
+        assert!(audit_auto_not_running(&[]).is_empty());
+    }
+}
@@ -0,0 +1,116 @@
+//! Persisted user settings
+//!
+//! Small JSON settings file stored under `%APPDATA%\SanitySuite`.
+
+use serde::{Deserialize, Serialize};
+use crate::i18n::Locale;
+use crate::system::network::{self, UrlCheck, WolTarget};
+use crate::system::ports::PortPreset;
+use crate::system::services;
+use std::fs;
+use std::path::PathBuf;
+
+/// Settings that survive application restarts
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct AppSettings {
+    /// When on, destructive actions are disabled
+    pub safe_mode: bool,
+    /// URLs shown on the Network page health-check dashboard
+    pub url_checks: Vec<UrlCheck>,
+    /// UI language
+    pub language: Locale,
+    /// Named port sets for the Ports page "Scan Preset" action
+    pub port_presets: Vec<PortPreset>,
+    /// Service queried by the Network page "Public IP" action
+    pub public_ip_endpoint: String,
+    /// Saved Wake-on-LAN targets on the Network page
+    pub wol_targets: Vec<WolTarget>,
+    /// Compose files used on the Docker page, most recent first
+    pub recent_compose_paths: Vec<String>,
+    /// Where the Docker page writes logs of watched containers that exit (empty = default folder)
+    pub exit_capture_dir: String,
+    /// Seconds the Services page waits for a started/stopped service to reach its final state
+    pub service_wait_secs: u64,
+    /// Services starred on the Services page, shown in the favorites strip
+    pub favorite_services: Vec<String>,
+}
+
+/// How many compose file paths the Docker page remembers
+pub const MAX_RECENT_COMPOSE_PATHS: usize = 5;
+
+impl AppSettings {
+    /// Move `path` to the front of the recent compose files, dropping the oldest past the limit
+    pub fn remember_compose_path(&mut self, path: &str) {
+        self.recent_compose_paths.retain(|p| !p.eq_ignore_ascii_case(path));
+        self.recent_compose_paths.insert(0, path.to_string());
+        self.recent_compose_paths.truncate(MAX_RECENT_COMPOSE_PATHS);
+    }
+
+    /// Folder for exit captures: the configured one, or `%APPDATA%\SanitySuite\captures`
+    pub fn exit_capture_dir(&self) -> PathBuf {
+        if self.exit_capture_dir.trim().is_empty() {
+            settings_dir().unwrap_or_else(std::env::temp_dir).join("captures")
+        } else {
+            PathBuf::from(self.exit_capture_dir.trim())
+        }
+    }
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        Self {
+            safe_mode: true,
+            url_checks: Vec::new(),
+            language: Locale::default(),
+            port_presets: vec![PortPreset {
+                name: "Dev stack".to_string(),
+                ports: vec![80, 443, 3000, 5173, 5432, 6379],
+            }],
+            public_ip_endpoint: network::DEFAULT_PUBLIC_IP_ENDPOINT.to_string(),
+            wol_targets: Vec::new(),
+            recent_compose_paths: Vec::new(),
+            exit_capture_dir: String::new(),
+            service_wait_secs: services::DEFAULT_STATUS_TIMEOUT_SECS,
+            favorite_services: Vec::new(),
+        }
+    }
+}
+
+/// Directory holding the settings file (`%APPDATA%\SanitySuite`)
+pub fn settings_dir() -> Option<PathBuf> {
+    dirs::config_dir().map(|d| d.join("SanitySuite"))
+}
+
+/// Full path of the settings file
+pub fn settings_path() -> Option<PathBuf> {
+    settings_dir().map(|d| d.join("settings.json"))
+}
+
+/// Load settings from disk, falling back to defaults if missing or unreadable
+pub fn load() -> AppSettings {
+    let Some(path) = settings_path() else {
+        return AppSettings::default();
+    };
+
+    match fs::read_to_string(&path) {
+        Ok(content) => serde_json::from_str(&content).unwrap_or_else(|e| {
+            tracing::warn!("Ignoring invalid settings file {}: {}", path.display(), e);
+            AppSettings::default()
+        }),
+        Err(_) => AppSettings::default(),
+    }
+}
+
+/// Write settings to disk
+pub fn save(settings: &AppSettings) -> Result<(), String> {
+    let dir = settings_dir().ok_or("Could not determine settings directory")?;
+    fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+
+    let json = serde_json::to_string_pretty(settings)
+        .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+
+    let path = dir.join("settings.json");
+    fs::write(&path, json).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
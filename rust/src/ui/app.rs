@@ -2,13 +2,15 @@
 
 use dioxus::prelude::*;
 
+use crate::i18n::{self, Locale};
 use crate::state::{
     CurrentPage, DiskPageState, DockerPageState, HostsPageState, 
     NetworkPageState, PortsPageState, ProcessesPageState, ServicesPageState
 };
 use crate::system;
+use crate::system::settings;
 
-use super::components::Sidebar;
+use super::components::{ConfirmDialog, Sidebar};
 use super::pages::{DiskPage, DockerPage, HostsPage, NetworkPage, PortsPage, ProcessesPage, ServicesPage};
 use super::STYLES;
 
@@ -18,17 +20,28 @@ pub fn App() -> Element {
     // Global navigation state
     let mut current_page = use_signal(|| CurrentPage::Ports);
     let is_admin = use_signal(|| system::admin::is_elevated());
+    let mut reboot_pending = use_signal(system::health::pending_reboot);
+
+    // Persisted settings (safe mode, etc.)
+    let mut app_settings = use_signal(|| {
+        let loaded = settings::load();
+        i18n::set_locale(loaded.language);
+        loaded
+    });
+    let mut confirm_unsafe = use_signal(|| false);
 
     // Lifted page state - persists across tab switches
     let ports_state = use_signal(PortsPageState::new);
-    let network_state = use_signal(NetworkPageState::default);
+    let network_state = use_signal(NetworkPageState::new);
     let docker_state = use_signal(DockerPageState::new);
-    let services_state = use_signal(ServicesPageState::default);
+    let services_state = use_signal(|| ServicesPageState::new(app_settings.peek().favorite_services.clone()));
     let processes_state = use_signal(ProcessesPageState::new);
     let hosts_state = use_signal(HostsPageState::default);
     let disk_state = use_signal(DiskPageState::default);
 
     // Provide state to child components via context
+    use_context_provider(|| current_page);
+    use_context_provider(|| app_settings);
     use_context_provider(|| ports_state);
     use_context_provider(|| network_state);
     use_context_provider(|| docker_state);
@@ -37,29 +50,86 @@ pub fn App() -> Element {
     use_context_provider(|| hosts_state);
     use_context_provider(|| disk_state);
 
+    // Apply and persist a safe mode change
+    let mut set_safe_mode = move |enabled: bool| {
+        app_settings.write().safe_mode = enabled;
+        if let Err(e) = settings::save(&app_settings()) {
+            tracing::error!("Failed to save settings: {}", e);
+        }
+    };
+
+    // Turning safe mode on is instant; turning it off needs confirmation
+    let on_toggle_safe_mode = move |_| {
+        if app_settings().safe_mode {
+            confirm_unsafe.set(true);
+        } else {
+            set_safe_mode(true);
+        }
+    };
+
+    // Apply and persist a language change
+    let on_change_locale = move |locale: Locale| {
+        i18n::set_locale(locale);
+        app_settings.write().language = locale;
+        if let Err(e) = settings::save(&app_settings()) {
+            tracing::error!("Failed to save settings: {}", e);
+        }
+    };
+
+    let safe_mode = app_settings().safe_mode;
+    let locale = app_settings().language;
+
     rsx! {
         // Inject styles
         style { {STYLES} }
 
-        // App container
-        div { class: "app-container",
-            // Sidebar navigation
-            Sidebar {
-                current_page: current_page(),
-                is_admin: is_admin(),
-                on_navigate: move |page| current_page.set(page),
+        if confirm_unsafe() {
+            ConfirmDialog {
+                title: t!("app.confirm-unsafe.title"),
+                message: t!("app.confirm-unsafe.message"),
+                confirm_label: t!("app.confirm-unsafe.confirm"),
+                danger: true,
+                on_confirm: move |_| {
+                    confirm_unsafe.set(false);
+                    set_safe_mode(false);
+                },
+                on_cancel: move |_| confirm_unsafe.set(false),
             }
+        }
+
+        // App container, keyed by language so every page re-renders its strings on a switch
+        for locale in [locale] {
+            div { key: "{locale.code()}", class: "app-container",
+                // Sidebar navigation
+                Sidebar {
+                    current_page: current_page(),
+                    is_admin: is_admin(),
+                    safe_mode: safe_mode,
+                    reboot_pending: reboot_pending(),
+                    on_navigate: move |page| current_page.set(page),
+                    on_toggle_safe_mode: on_toggle_safe_mode,
+                    on_check_reboot: move |_| reboot_pending.set(system::health::pending_reboot()),
+                    locale,
+                    on_change_locale,
+                }
+
+                // Main content area
+                main { class: "main-content",
+                    if !safe_mode {
+                        div { class: "safe-mode-banner",
+                            {t!("app.safe-mode-off-banner")}
+                        }
+                    }
 
-            // Main content area
-            main { class: "main-content",
-                match current_page() {
-                    CurrentPage::Ports => rsx! { PortsPage { is_admin: is_admin() } },
-                    CurrentPage::Network => rsx! { NetworkPage { is_admin: is_admin() } },
-                    CurrentPage::Docker => rsx! { DockerPage {} },
-                    CurrentPage::Services => rsx! { ServicesPage { is_admin: is_admin() } },
-                    CurrentPage::Processes => rsx! { ProcessesPage { is_admin: is_admin() } },
-                    CurrentPage::Hosts => rsx! { HostsPage { is_admin: is_admin() } },
-                    CurrentPage::Disk => rsx! { DiskPage { is_admin: is_admin() } },
+                    match current_page() {
+                        CurrentPage::Ports => rsx! { PortsPage { is_admin: is_admin() } },
+                        CurrentPage::Network => rsx! { NetworkPage { is_admin: is_admin() } },
+                        CurrentPage::Docker => rsx! { DockerPage { is_admin: is_admin() } },
+                        CurrentPage::Services => rsx! { ServicesPage { is_admin: is_admin() } },
+                        CurrentPage::Processes => rsx! { ProcessesPage { is_admin: is_admin() } },
+                        CurrentPage::Hosts => rsx! { HostsPage { is_admin: is_admin() } },
+                        CurrentPage::Disk => rsx! { DiskPage { is_admin: is_admin() } },
+                    }
                 }
             }
         }
@@ -0,0 +1,52 @@
+//! Danger-aware action button
+//!
+//! Every action that touches the system declares a `DangerLevel`; destructive
+//! actions are disabled while safe mode is on.
+
+use dioxus::prelude::*;
+
+use crate::state::DangerLevel;
+use crate::system::settings::AppSettings;
+
+/// Button that is automatically disabled in safe mode when its action is destructive
+#[component]
+pub fn ActionButton(
+    /// Danger classification of the action
+    danger: DangerLevel,
+    /// CSS class for the button
+    #[props(into)]
+    class: String,
+    /// Disabled for reasons other than safe mode (running, not admin, ...)
+    #[props(default)]
+    disabled: bool,
+    /// Tooltip shown when the action is allowed
+    #[props(into, default)]
+    title: String,
+    /// Click handler
+    onclick: EventHandler<MouseEvent>,
+    children: Element,
+) -> Element {
+    let settings: Signal<AppSettings> = use_context();
+    let blocked = settings().safe_mode && danger.blocked_in_safe_mode();
+
+    let title = if blocked {
+        t!("safe-mode.locked-hint")
+    } else {
+        title
+    };
+    let class = if blocked {
+        format!("{} safe-mode-locked", class)
+    } else {
+        class
+    };
+
+    rsx! {
+        button {
+            class: "{class}",
+            disabled: disabled || blocked,
+            title: "{title}",
+            onclick: move |e| onclick.call(e),
+            {children}
+        }
+    }
+}
@@ -0,0 +1,8 @@
+//! Clipboard access for table and report exports
+
+/// Copy export text to the clipboard
+pub fn copy_to_clipboard(text: &str) -> Result<(), String> {
+    arboard::Clipboard::new()
+        .and_then(|mut clipboard| clipboard.set_text(text))
+        .map_err(|e| format!("Failed to copy to clipboard: {}", e))
+}
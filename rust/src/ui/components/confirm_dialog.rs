@@ -0,0 +1,57 @@
+//! Confirmation dialog component
+//!
+//! Modal overlay asking the user to confirm an action before it runs.
+
+use dioxus::prelude::*;
+
+/// Modal confirmation dialog
+#[component]
+pub fn ConfirmDialog(
+    /// Dialog title
+    #[props(into)]
+    title: String,
+    /// Explanation of what will happen
+    #[props(into)]
+    message: String,
+    /// Label for the confirm button
+    #[props(into, default = t!("common.confirm"))]
+    confirm_label: String,
+    /// Style the confirm button as dangerous
+    #[props(default)]
+    danger: bool,
+    /// Disable the confirm button (e.g. while a preview is loading)
+    #[props(default)]
+    confirm_disabled: bool,
+    /// Called when the user confirms
+    on_confirm: EventHandler<()>,
+    /// Called when the user cancels or clicks outside the dialog
+    on_cancel: EventHandler<()>,
+    /// Extra options shown between the message and the buttons
+    #[props(default)]
+    children: Element,
+) -> Element {
+    rsx! {
+        div { class: "modal-overlay",
+            onclick: move |_| on_cancel.call(()),
+            div { class: "modal",
+                onclick: move |e| e.stop_propagation(),
+                h3 { class: "modal-title", "{title}" }
+                p { class: "modal-message", "{message}" }
+                {children}
+                div { class: "modal-actions",
+                    button {
+                        class: "btn btn-ghost",
+                        onclick: move |_| on_cancel.call(()),
+                        {t!("common.cancel")}
+                    }
+                    button {
+                        class: if danger { "btn btn-danger" } else { "btn btn-primary" },
+                        disabled: confirm_disabled,
+                        onclick: move |_| on_confirm.call(()),
+                        "{confirm_label}"
+                    }
+                }
+            }
+        }
+    }
+}
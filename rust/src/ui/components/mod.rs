@@ -1,9 +1,19 @@
 //! Reusable UI components
 
+mod action_button;
+mod clipboard;
+mod confirm_dialog;
 mod output_panel;
+mod restore_point_option;
 mod sidebar;
 mod sortable_table;
+mod virtual_rows;
 
+pub use action_button::ActionButton;
+pub use clipboard::copy_to_clipboard;
+pub use confirm_dialog::ConfirmDialog;
 pub use output_panel::OutputPanel;
+pub use restore_point_option::RestorePointOption;
 pub use sidebar::Sidebar;
 pub use sortable_table::{SortableHeader, StaticHeader, SortDirection};
+pub use virtual_rows::{RowWindow, VirtualScroll, VirtualSpacer, VIRTUAL_ROW_HEIGHT};
@@ -4,6 +4,64 @@
 
 use dioxus::prelude::*;
 use crate::state::CommandOutput;
+use crate::system::command;
+use crate::ui::components::ActionButton;
+
+/// A single command output with its re-run control
+#[component]
+fn OutputEntry(
+    output: CommandOutput,
+    is_admin: bool,
+    on_rerun: Option<EventHandler<CommandOutput>>,
+    line_class: Option<Callback<String, String>>,
+) -> Element {
+    let mut rerunning = use_signal(|| false);
+
+    let replay = output.replay.clone();
+    let danger = replay.as_ref().map(|r| r.danger).unwrap_or_default();
+    let needs_admin = replay.as_ref().is_some_and(|r| r.requires_admin);
+    let (disabled, title) = match (&replay, on_rerun) {
+        (None, _) | (_, None) => (true, t!("output.rerun-unavailable")),
+        _ if needs_admin && !is_admin => (true, t!("common.requires-admin")),
+        _ => (rerunning(), t!("output.rerun-hint")),
+    };
+
+    let on_click = move |_| {
+        let (Some(replay), Some(handler)) = (replay.clone(), on_rerun) else {
+            return;
+        };
+        rerunning.set(true);
+        spawn(async move {
+            let output = command::replay(&replay).await;
+            handler.call(output);
+            rerunning.set(false);
+        });
+    };
+
+    rsx! {
+        div { class: "output-entry",
+            div { class: "output-entry-header",
+                ActionButton {
+                    danger,
+                    class: "btn btn-ghost btn-sm",
+                    disabled,
+                    title,
+                    onclick: on_click,
+                    if rerunning() { {t!("output.rerunning")} } else { {t!("output.rerun")} }
+                }
+            }
+            if let Some(line_class) = line_class {
+                pre { class: "output-text",
+                    for line in output.display_output().lines() {
+                        span { class: line_class.call(line.to_string()), "{line}\n" }
+                    }
+                }
+            } else {
+                pre { class: "output-text", "{output.display_output()}" }
+            }
+        }
+    }
+}
 
 /// Reusable output panel that displays command output with copy functionality
 #[component]
@@ -12,6 +70,18 @@ pub fn OutputPanel(
     outputs: Vec<CommandOutput>,
     /// Callback when clear is clicked
     on_clear: EventHandler<()>,
+    /// Whether the app is running as Administrator (gates admin-only re-runs)
+    #[props(default)]
+    is_admin: bool,
+    /// Receives the output of a re-run; re-run buttons are disabled without it
+    #[props(default)]
+    on_rerun: Option<EventHandler<CommandOutput>>,
+    /// CSS class for each output line (empty for none), e.g. to highlight failures
+    #[props(default)]
+    line_class: Option<Callback<String, String>>,
+    /// Keep the panel scrolled to the newest line while output is streaming in
+    #[props(default)]
+    follow: bool,
 ) -> Element {
     // Track expanded state
     let mut is_expanded = use_signal(|| false);
@@ -53,36 +123,36 @@ pub fn OutputPanel(
                 div { class: "output-panel-expanded",
                     onclick: move |e| e.stop_propagation(),
                     div { class: "output-panel-header",
-                        span { class: "output-panel-title", "📋 Output (Reading Mode)" }
+                        span { class: "output-panel-title", {t!("output.title-reading")} }
                         div { class: "output-panel-actions",
                             button {
                                 class: "btn btn-ghost btn-sm",
-                                title: "Copy to clipboard",
+                                title: t!("output.copy-hint"),
                                 onclick: on_copy_expanded,
-                                "📋 Copy"
+                                {t!("output.copy")}
                             }
                             button {
                                 class: "btn btn-ghost btn-sm",
                                 onclick: move |_| {
                                     on_clear.call(());
                                 },
-                                "Clear"
+                                {t!("common.clear")}
                             }
                             button {
                                 class: "btn btn-primary btn-sm",
                                 onclick: move |_| is_expanded.set(false),
-                                "✕ Close"
+                                {t!("output.close")}
                             }
                         }
                     }
                     div { class: "output-panel-content-expanded",
                         if outputs_for_expanded.is_empty() {
                             div { class: "output-panel-empty",
-                                "Run a command to see output here"
+                                {t!("output.empty")}
                             }
                         } else {
                             for output in outputs_for_expanded.iter() {
-                                pre { class: "output-text", "{output.display_output()}" }
+                                OutputEntry { output: output.clone(), is_admin, on_rerun, line_class }
                             }
                         }
                     }
@@ -94,35 +164,42 @@ pub fn OutputPanel(
         div { class: "output-panel-container",
             div { class: "output-panel-fixed",
                 div { class: "output-panel-header",
-                    span { class: "output-panel-title", "📋 Output" }
+                    span { class: "output-panel-title", {t!("output.title")} }
                     div { class: "output-panel-actions",
                         button {
                             class: "btn btn-ghost btn-sm",
-                            title: "Expand to reading mode",
+                            title: t!("output.expand-hint"),
                             onclick: move |_| is_expanded.set(true),
-                            "⛶ Expand"
+                            {t!("output.expand")}
                         }
                         button {
                             class: "btn btn-ghost btn-sm",
-                            title: "Copy to clipboard",
+                            title: t!("output.copy-hint"),
                             onclick: on_copy,
-                            "📋 Copy"
+                            {t!("output.copy")}
                         }
                         button {
                             class: "btn btn-ghost btn-sm",
                             onclick: move |_| on_clear.call(()),
-                            "Clear"
+                            {t!("common.clear")}
                         }
                     }
                 }
-                div { class: "output-panel-content",
+                div { class: if follow { "output-panel-content output-panel-follow" } else { "output-panel-content" },
                     if outputs.is_empty() {
                         div { class: "output-panel-empty",
-                            "Run a command to see output here"
+                            {t!("output.empty")}
+                        }
+                    } else if follow {
+                        // Single child so the reversed flex column only anchors scrolling to the bottom
+                        div {
+                            for output in outputs.iter() {
+                                OutputEntry { output: output.clone(), is_admin, on_rerun, line_class }
+                            }
                         }
                     } else {
                         for output in outputs.iter() {
-                            pre { class: "output-text", "{output.display_output()}" }
+                            OutputEntry { output: output.clone(), is_admin, on_rerun, line_class }
                         }
                     }
                 }
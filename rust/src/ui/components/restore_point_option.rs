@@ -0,0 +1,37 @@
+//! Restore point opt-in checkbox
+//!
+//! Shown in confirmation dialogs of risky actions.
+
+use dioxus::prelude::*;
+
+use crate::system::restore;
+
+/// "Create restore point first" checkbox that explains when System Protection is off
+#[component]
+pub fn RestorePointOption(
+    /// Current checkbox state
+    checked: bool,
+    /// Called with the new checkbox state
+    onchange: EventHandler<bool>,
+) -> Element {
+    let protection_enabled = use_hook(restore::protection_enabled);
+
+    rsx! {
+        div { class: "restore-point-option",
+            label { class: "checkbox-label",
+                input {
+                    r#type: "checkbox",
+                    checked: checked && protection_enabled,
+                    disabled: !protection_enabled,
+                    onchange: move |e| onchange.call(e.checked()),
+                }
+                {t!("restore-option.label")}
+            }
+            if protection_enabled {
+                span { class: "muted", {t!("restore-option.hint")} }
+            } else {
+                span { class: "muted", {t!("restore-option.unavailable")} }
+            }
+        }
+    }
+}
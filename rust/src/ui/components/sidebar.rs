@@ -1,13 +1,15 @@
 //! Sidebar navigation component
 
 use dioxus::prelude::*;
+use crate::i18n::Locale;
 use crate::state::CurrentPage;
+use crate::system::health::RebootIndicator;
 
 /// Navigation item definition
 pub struct NavItem {
     pub id: CurrentPage,
     pub icon: &'static str,
-    pub label: &'static str,
+    pub label: String,
 }
 
 /// Sidebar component with navigation
@@ -15,43 +17,55 @@ pub struct NavItem {
 pub fn Sidebar(
     current_page: CurrentPage,
     is_admin: bool,
+    safe_mode: bool,
+    reboot_pending: Vec<RebootIndicator>,
     on_navigate: EventHandler<CurrentPage>,
+    on_toggle_safe_mode: EventHandler<()>,
+    on_check_reboot: EventHandler<()>,
+    locale: Locale,
+    on_change_locale: EventHandler<Locale>,
 ) -> Element {
+    let reboot_labels = reboot_pending
+        .iter()
+        .map(|i| t!(i.label_key()))
+        .collect::<Vec<_>>()
+        .join(", ");
+
     let nav_items = vec![
         NavItem {
             id: CurrentPage::Ports,
             icon: "🔌",
-            label: "Ports",
+            label: t!("nav.ports"),
         },
         NavItem {
             id: CurrentPage::Network,
             icon: "🌐",
-            label: "Network",
+            label: t!("nav.network"),
         },
         NavItem {
             id: CurrentPage::Docker,
             icon: "🐳",
-            label: "Docker",
+            label: t!("nav.docker"),
         },
         NavItem {
             id: CurrentPage::Services,
             icon: "⚙️",
-            label: "Services",
+            label: t!("nav.services"),
         },
         NavItem {
             id: CurrentPage::Processes,
             icon: "📊",
-            label: "Processes",
+            label: t!("nav.processes"),
         },
         NavItem {
             id: CurrentPage::Hosts,
             icon: "📝",
-            label: "Hosts",
+            label: t!("nav.hosts"),
         },
         NavItem {
             id: CurrentPage::Disk,
             icon: "💾",
-            label: "Disk",
+            label: t!("nav.disk"),
         },
     ];
 
@@ -74,14 +88,53 @@ pub fn Sidebar(
                 }
             }
 
-            // Footer with admin status
+            // Footer with reboot status, safe mode toggle, admin status and language
             div { class: "sidebar-footer",
+                button {
+                    class: if reboot_pending.is_empty() { "reboot-status clear" } else { "reboot-status pending" },
+                    title: t!("sidebar.reboot-check-again"),
+                    onclick: move |_| on_check_reboot.call(()),
+                    if reboot_pending.is_empty() {
+                        {t!("sidebar.reboot-clear")}
+                    } else {
+                        {t!("sidebar.reboot-pending", reasons = reboot_labels)}
+                    }
+                }
+                button {
+                    class: if safe_mode { "safe-mode-toggle on" } else { "safe-mode-toggle off" },
+                    title: if safe_mode {
+                        t!("sidebar.safe-mode-on-hint")
+                    } else {
+                        t!("sidebar.safe-mode-off-hint")
+                    },
+                    onclick: move |_| on_toggle_safe_mode.call(()),
+                    if safe_mode { {t!("sidebar.safe-mode-on")} } else { {t!("sidebar.safe-mode-off")} }
+                }
                 div {
                     class: if is_admin { "admin-badge elevated" } else { "admin-badge standard" },
                     if is_admin {
-                        "🛡️ Administrator"
+                        {t!("sidebar.admin")}
                     } else {
-                        "👤 Standard User"
+                        {t!("sidebar.standard-user")}
+                    }
+                }
+                label { class: "language-select",
+                    span { class: "muted", {t!("sidebar.language")} }
+                    select {
+                        class: "input",
+                        value: "{locale.code()}",
+                        onchange: move |e| {
+                            if let Some(locale) = Locale::from_code(&e.value()) {
+                                on_change_locale.call(locale);
+                            }
+                        },
+                        for option in Locale::ALL {
+                            option {
+                                value: "{option.code()}",
+                                selected: option == locale,
+                                "{option.native_name()}"
+                            }
+                        }
                     }
                 }
             }
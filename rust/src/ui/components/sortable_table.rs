@@ -13,6 +13,15 @@ pub enum SortDirection {
 }
 
 impl SortDirection {
+    /// Direction for a table controller's `sort_ascending` flag
+    pub fn from_ascending(ascending: bool) -> Self {
+        if ascending {
+            SortDirection::Ascending
+        } else {
+            SortDirection::Descending
+        }
+    }
+
     /// Toggle between ascending and descending
     pub fn toggle(self) -> Self {
         match self {
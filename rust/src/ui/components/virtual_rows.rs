@@ -0,0 +1,134 @@
+//! Windowed rendering for long tables
+//!
+//! Only the rows inside the scroll container's viewport, plus a buffer, are rendered.
+//! Spacer rows above and below stand in for the rest, so the scrollbar keeps the size of
+//! the full table. Rows need a fixed height: `.virtual-rows td` pins it to
+//! `VIRTUAL_ROW_HEIGHT`.
+
+use std::ops::Range;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+/// Height of one row in a windowed table, in pixels (`.virtual-rows td` in styles.rs)
+pub const VIRTUAL_ROW_HEIGHT: f64 = 36.0;
+
+/// Rows rendered above and below the viewport so fast scrolling doesn't show blank space
+const BUFFER_ROWS: usize = 10;
+
+/// Scroll position and viewport height of a windowed table's container
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RowWindow {
+    pub scroll_top: f64,
+    pub viewport_height: f64,
+}
+
+impl Default for RowWindow {
+    fn default() -> Self {
+        Self {
+            scroll_top: 0.0,
+            viewport_height: 600.0,
+        }
+    }
+}
+
+/// Rows to render and the heights of the spacers standing in for the others
+#[derive(Debug, Clone, PartialEq)]
+pub struct VisibleRows {
+    pub range: Range<usize>,
+    pub above: f64,
+    pub below: f64,
+}
+
+impl RowWindow {
+    /// The slice of a `total`-row table to render at the current scroll position
+    pub fn visible(&self, total: usize) -> VisibleRows {
+        let per_view = (self.viewport_height / VIRTUAL_ROW_HEIGHT).ceil() as usize;
+        let first = (self.scroll_top.max(0.0) / VIRTUAL_ROW_HEIGHT) as usize;
+        let end = (first + per_view + BUFFER_ROWS).min(total);
+        // A filter that shrank the table can leave the scroll position past the end
+        let start = first.saturating_sub(BUFFER_ROWS).min(end);
+        VisibleRows {
+            range: start..end,
+            above: start as f64 * VIRTUAL_ROW_HEIGHT,
+            below: (total - end) as f64 * VIRTUAL_ROW_HEIGHT,
+        }
+    }
+}
+
+/// Scroll container of a windowed table; keeps `window` in step with the scroll position
+///
+/// Starts at `window`'s offset, so a page can restore the position it saved.
+#[component]
+pub fn VirtualScroll(
+    /// Scroll state the page renders rows from (see `RowWindow::visible`)
+    mut window: Signal<RowWindow>,
+    /// Element id, for scrolling to a row from script
+    id: String,
+    /// Extra classes (height limits and borders come from the page's own class)
+    #[props(into, default)]
+    class: String,
+    /// The table
+    children: Element,
+) -> Element {
+    let mut container: Signal<Option<Rc<MountedData>>> = use_signal(|| None);
+
+    // Scroll events carry no position in Dioxus 0.6, so read it back from the element
+    let measure = move || {
+        spawn(async move {
+            let Some(element) = container.peek().clone() else {
+                return;
+            };
+            let (Ok(offset), Ok(rect)) = (element.get_scroll_offset().await, element.get_client_rect().await) else {
+                return;
+            };
+            let next = RowWindow {
+                scroll_top: offset.y,
+                viewport_height: rect.height(),
+            };
+            if *window.peek() != next {
+                window.set(next);
+            }
+        });
+    };
+
+    let script_id = serde_json::to_string(&id).unwrap_or_default();
+
+    rsx! {
+        div {
+            id: "{id}",
+            class: "virtual-rows {class}",
+            onmounted: move |e| {
+                container.set(Some(e.data()));
+                // A window that starts scrolled (state kept across pages) puts the container back
+                // there; the scroll event that follows measures it
+                let top = window.peek().scroll_top;
+                if top > 0.0 {
+                    document::eval(&format!("document.getElementById({})?.scrollTo(0, {})", script_id, top));
+                } else {
+                    measure();
+                }
+            },
+            onscroll: move |_| measure(),
+            {children}
+        }
+    }
+}
+
+/// Spacer row standing in for the rows a windowed table doesn't render
+#[component]
+pub fn VirtualSpacer(
+    /// Combined height of the skipped rows
+    height: f64,
+    /// Number of table columns
+    colspan: usize,
+) -> Element {
+    if height <= 0.0 {
+        return rsx! {};
+    }
+    rsx! {
+        tr { class: "virtual-spacer",
+            td { colspan: "{colspan}", style: "height: {height}px" }
+        }
+    }
+}
@@ -1,12 +1,15 @@
 //! Disk page component
 //!
-//! Drive space, temp folders, cleanup.
+//! Drive space, temp folders, cleanup, System Restore, Defender exclusions.
 
 use dioxus::prelude::*;
 
-use crate::state::{DiskPageState, DiskSortColumn};
-use crate::system::disk;
-use crate::ui::components::{SortableHeader, StaticHeader, SortDirection};
+use crate::state::{CommandOutput, DangerLevel, DiskPageState, DiskSortColumn};
+use crate::system::command::pick_folder;
+use crate::system::defender::{self, ExclusionKind};
+use crate::system::integrity::{self, IntegrityTool};
+use crate::system::{disk, restore};
+use crate::ui::components::{ActionButton, ConfirmDialog, OutputPanel, SortableHeader, StaticHeader, SortDirection};
 
 fn update_disk_sort(mut state: Signal<DiskPageState>, column: DiskSortColumn) {
     let mut s = state.write();
@@ -21,16 +24,96 @@ fn update_disk_sort(mut state: Signal<DiskPageState>, column: DiskSortColumn) {
     }
 }
 
+/// Reload Defender status and exclusions
+fn refresh_defender(mut state: Signal<DiskPageState>) {
+    spawn(async move {
+        let (status, _) = defender::get_status().await;
+        let (exclusions, _) = defender::list_exclusions().await;
+        let mut s = state.write();
+        s.defender_status = Some(status);
+        s.defender_exclusions = Some(exclusions);
+    });
+}
+
+/// Run sfc or DISM, streaming output into the panel and progress into the bar
+fn run_integrity(mut state: Signal<DiskPageState>, tool: IntegrityTool) {
+    let cancel = crate::system::command::CancelFlag::default();
+    {
+        let mut s = state.write();
+        s.is_running = true;
+        s.running_action = tool.label().to_string();
+        s.integrity_tool = Some(tool);
+        s.integrity_progress = None;
+        s.integrity_cancel = cancel.clone();
+        s.status_message = t!("disk.integrity-running", tool = tool.label());
+        s.status_type = String::new();
+    }
+
+    spawn(async move {
+        // Progress redraws replace the previous progress line instead of piling up
+        let mut lines: Vec<String> = Vec::new();
+        let mut last_was_progress = false;
+
+        let (verdict, mut output) = integrity::run(tool, cancel, |line| {
+            let progress = integrity::parse_progress(line);
+            if progress.is_some() && last_was_progress {
+                lines.pop();
+            }
+            last_was_progress = progress.is_some();
+            lines.push(line.trim().to_string());
+
+            let mut s = state.write();
+            if progress.is_some() {
+                s.integrity_progress = progress;
+            }
+            s.command_outputs = vec![CommandOutput {
+                command: tool.label().to_string(),
+                stdout: lines.join("\n"),
+                ..Default::default()
+            }];
+        })
+        .await;
+
+        // Keep the collapsed output plus the verdict as the record of this run
+        let summary = verdict.summary(tool);
+        output.stdout = format!("{}\n\n{}\n", lines.join("\n"), t!("disk.integrity-result", summary = summary));
+
+        let mut s = state.write();
+        s.command_outputs = vec![output];
+        s.integrity_tool = None;
+        s.integrity_progress = None;
+        s.is_running = false;
+        s.running_action = String::new();
+        s.status_message = summary;
+        s.status_type = verdict.status_type().to_string();
+    });
+}
+
 /// Disk page
 #[component]
 pub fn DiskPage(is_admin: bool) -> Element {
     let mut state: Signal<DiskPageState> = use_context();
+    let protection_enabled = use_hook(restore::protection_enabled);
+
+    // Leaving the page drops its tasks (which kills sfc/DISM); clear the running state with them
+    use_drop(move || {
+        let mut s = state.write();
+        if s.is_running {
+            s.integrity_cancel.store(true, std::sync::atomic::Ordering::Relaxed);
+            s.integrity_tool = None;
+            s.integrity_progress = None;
+            s.is_running = false;
+            s.running_action = String::new();
+            s.status_message = String::new();
+            s.status_type = String::new();
+        }
+    });
 
     // Refresh disk info
     let on_refresh = move |_| {
         state.write().is_running = true;
         state.write().running_action = "Refresh".to_string();
-        state.write().status_message = "Scanning drives...".to_string();
+        state.write().status_message = t!("disk.scanning");
         state.write().status_type = String::new();
 
         spawn(async move {
@@ -42,7 +125,7 @@ pub fn DiskPage(is_admin: bool) -> Element {
             s.temp_folders = temps;
             s.is_running = false;
             s.running_action = String::new();
-            s.status_message = format!("Found {} drives, {} temp folders", s.disks.len(), s.temp_folders.len());
+            s.status_message = t!("disk.found", drives = s.disks.len(), folders = s.temp_folders.len());
             s.status_type = "success".to_string();
         });
     };
@@ -51,7 +134,7 @@ pub fn DiskPage(is_admin: bool) -> Element {
     let mut on_clean_temp = move |path: String| {
         state.write().is_running = true;
         state.write().running_action = "Clean".to_string();
-        state.write().status_message = format!("Cleaning {}...", path);
+        state.write().status_message = t!("disk.cleaning", path = path);
         state.write().status_type = String::new();
 
         spawn(async move {
@@ -61,7 +144,7 @@ pub fn DiskPage(is_admin: bool) -> Element {
                     let temps = disk::get_temp_folder_sizes();
                     let mut s = state.write();
                     s.temp_folders = temps;
-                    s.status_message = format!("Deleted {} files ({:.1} MB)", count, size);
+                    s.status_message = t!("disk.cleaned", count = count, size = format!("{:.1}", size));
                     s.status_type = "success".to_string();
                 }
                 Err(e) => {
@@ -76,7 +159,105 @@ pub fn DiskPage(is_admin: bool) -> Element {
 
 
 
+    // Create a restore point on demand
+    let on_create_restore_point = move |_| {
+        state.write().is_running = true;
+        state.write().running_action = "Restore Point".to_string();
+        state.write().status_message = t!("disk.restore-creating");
+        state.write().status_type = String::new();
+
+        spawn(async move {
+            let (outcome, _) = restore::create_restore_point(&t!("disk.restore-description")).await;
+            let mut s = state.write();
+            s.status_message = outcome.message();
+            s.status_type = match outcome {
+                restore::RestorePointOutcome::Created => "success",
+                restore::RestorePointOutcome::Throttled => "warning",
+                _ => "error",
+            }
+            .to_string();
+            s.is_running = false;
+            s.running_action = String::new();
+        });
+    };
+
+    // Pick a folder to exclude; the add itself waits for confirmation
+    let on_pick_exclusion = move |_| {
+        spawn(async move {
+            if let Some(path) = pick_folder(&t!("disk.exclusion-pick")).await {
+                state.write().pending_exclusion = Some(path);
+            }
+        });
+    };
+
+    // Add the confirmed exclusion
+    let on_add_exclusion = move |_| {
+        let Some(path) = state.write().pending_exclusion.take() else {
+            return;
+        };
+        state.write().is_running = true;
+        state.write().running_action = "Defender".to_string();
+        state.write().status_message = t!("disk.exclusion-adding", path = path);
+        state.write().status_type = String::new();
+
+        spawn(async move {
+            let output = defender::add_exclusion(ExclusionKind::Path, &path).await;
+            {
+                let mut s = state.write();
+                s.is_running = false;
+                s.running_action = String::new();
+                if output.succeeded() {
+                    s.status_message = t!("disk.exclusion-added", path = path);
+                    s.status_type = "success".to_string();
+                } else {
+                    s.status_message = t!("disk.exclusion-add-failed", error = output.stderr.trim());
+                    s.status_type = "error".to_string();
+                }
+            }
+            refresh_defender(state);
+        });
+    };
+
+    // Remove an exclusion
+    let mut on_remove_exclusion = move |(kind, value): (ExclusionKind, String)| {
+        state.write().is_running = true;
+        state.write().running_action = "Defender".to_string();
+        state.write().status_message = t!("disk.exclusion-removing", path = value);
+        state.write().status_type = String::new();
+
+        spawn(async move {
+            let output = defender::remove_exclusion(kind, &value).await;
+            {
+                let mut s = state.write();
+                s.is_running = false;
+                s.running_action = String::new();
+                if output.succeeded() {
+                    s.status_message = t!("disk.exclusion-removed", path = value);
+                    s.status_type = "success".to_string();
+                } else {
+                    s.status_message = t!("disk.exclusion-remove-failed", error = output.stderr.trim());
+                    s.status_type = "error".to_string();
+                }
+            }
+            refresh_defender(state);
+        });
+    };
+
+    // Clear output handler
+    let mut on_clear = move |_| {
+        state.write().command_outputs = Vec::new();
+    };
+
+    let restore_hint = if !is_admin {
+        t!("common.requires-admin")
+    } else if !protection_enabled {
+        t!("disk.protection-off-hint")
+    } else {
+        String::new()
+    };
+
     let current_state = state();
+    let outputs_for_panel = current_state.command_outputs.clone();
 
     // Sort temp folders based on current sort state
     let mut sorted_folders = current_state.temp_folders.clone();
@@ -101,8 +282,8 @@ pub fn DiskPage(is_admin: bool) -> Element {
 
     rsx! {
         header { class: "page-header",
-            h1 { class: "page-title", "💾 Disk" }
-            p { class: "page-subtitle", "Drive space and temp folder management" }
+            h1 { class: "page-title", {t!("disk.title")} }
+            p { class: "page-subtitle", {t!("disk.subtitle")} }
         }
 
         div { class: "page-split-layout",
@@ -114,7 +295,7 @@ pub fn DiskPage(is_admin: bool) -> Element {
                             class: "btn btn-primary",
                             disabled: current_state.is_running,
                             onclick: on_refresh,
-                            if current_state.running_action == "Refresh" { "Scanning..." } else { "🔄 Refresh" }
+                            if current_state.running_action == "Refresh" { {t!("disk.scanning-short")} } else { {t!("disk.refresh")} }
                         }
                     }
                 }
@@ -122,7 +303,7 @@ pub fn DiskPage(is_admin: bool) -> Element {
                 // Drives
                 if !current_state.disks.is_empty() {
                     div { class: "section",
-                        h3 { class: "section-title", "Drives" }
+                        h3 { class: "section-title", {t!("disk.drives")} }
                         div { class: "stats-grid",
                             for disk_info in current_state.disks.iter() {
                                 {
@@ -137,7 +318,12 @@ pub fn DiskPage(is_admin: bool) -> Element {
                                         div { class: "{usage_class}",
                                             span { class: "stat-value", "{disk_info.mount_point}" }
                                             span { class: "stat-label", 
-                                                "{disk_info.available_gb:.1} GB free / {disk_info.total_gb:.1} GB ({disk_info.usage_percent:.0}%)"
+                                                {t!(
+                                                    "disk.drive-usage",
+                                                    free = format!("{:.1}", disk_info.available_gb),
+                                                    total = format!("{:.1}", disk_info.total_gb),
+                                                    percent = format!("{:.0}", disk_info.usage_percent),
+                                                )}
                                             }
                                             div { class: "progress-bar",
                                                 div { 
@@ -156,32 +342,32 @@ pub fn DiskPage(is_admin: bool) -> Element {
                 // Temp folders
                 if !current_state.temp_folders.is_empty() {
                     div { class: "section",
-                        h3 { class: "section-title", "Temp Folders" }
+                        h3 { class: "section-title", {t!("disk.temp-folders")} }
                         table { class: "data-table",
                             thead {
                                 tr {
                                     SortableHeader {
                                         column: DiskSortColumn::Folder,
-                                        label: "Folder".to_string(),
+                                        label: t!("disk.col.folder"),
                                         current_sort: current_state.sort_column,
                                         direction: sort_dir,
                                         on_sort: move |col| update_disk_sort(state, col),
                                     }
                                     SortableHeader {
                                         column: DiskSortColumn::Size,
-                                        label: "Size".to_string(),
+                                        label: t!("disk.col.size"),
                                         current_sort: current_state.sort_column,
                                         direction: sort_dir,
                                         on_sort: move |col| update_disk_sort(state, col),
                                     }
                                     SortableHeader {
                                         column: DiskSortColumn::Files,
-                                        label: "Files".to_string(),
+                                        label: t!("disk.col.files"),
                                         current_sort: current_state.sort_column,
                                         direction: sort_dir,
                                         on_sort: move |col| update_disk_sort(state, col),
                                     }
-                                    StaticHeader { label: "Actions".to_string() }
+                                    StaticHeader { label: t!("disk.col.actions") }
                                 }
                             }
                             tbody {
@@ -196,11 +382,12 @@ pub fn DiskPage(is_admin: bool) -> Element {
                                                 td { class: "mono {size_class}", "{folder.size_mb:.1} MB" }
                                                 td { class: "mono muted", "{folder.file_count}" }
                                                 td {
-                                                    button {
+                                                    ActionButton {
+                                                        danger: DangerLevel::Destructive,
                                                         class: "btn btn-danger btn-sm",
                                                         disabled: current_state.is_running,
                                                         onclick: move |_| on_clean_temp(path_for_click.clone()),
-                                                        "🧹 Clean"
+                                                        {t!("disk.clean")}
                                                     }
                                                 }
                                             }
@@ -212,18 +399,185 @@ pub fn DiskPage(is_admin: bool) -> Element {
 
                         if !is_admin {
                             div { class: "status-bar warning", style: "margin-top: 1rem;",
-                                "⚠️ Some folders (Windows\\Temp, Prefetch) require Administrator to clean"
+                                {t!("disk.clean-admin-warning")}
+                            }
+                        }
+                    }
+                }
+
+                // System file integrity
+                div { class: "section",
+                    h3 { class: "section-title", {t!("disk.integrity")} }
+                    div { class: "action-bar",
+                        ActionButton {
+                            danger: DangerLevel::Modifying,
+                            class: "btn btn-secondary",
+                            disabled: current_state.is_running || !is_admin,
+                            title: if !is_admin { t!("common.requires-admin") } else { t!("disk.sfc-hint") },
+                            onclick: move |_| run_integrity(state, IntegrityTool::Sfc),
+                            "🩺 sfc /scannow"
+                        }
+                        ActionButton {
+                            danger: DangerLevel::Modifying,
+                            class: "btn btn-secondary",
+                            disabled: current_state.is_running || !is_admin,
+                            title: if !is_admin { t!("common.requires-admin") } else { t!("disk.dism-hint") },
+                            onclick: move |_| run_integrity(state, IntegrityTool::Dism),
+                            "🧰 DISM RestoreHealth"
+                        }
+                        if current_state.integrity_tool.is_some() {
+                            button {
+                                class: "btn btn-ghost",
+                                onclick: move |_| {
+                                    state.peek().integrity_cancel.store(true, std::sync::atomic::Ordering::Relaxed);
+                                },
+                                {t!("disk.integrity-cancel")}
+                            }
+                        }
+                    }
+                    if let Some(tool) = current_state.integrity_tool {
+                        {
+                            let progress = current_state.integrity_progress.unwrap_or(0.0);
+                            rsx! {
+                                div { class: "integrity-progress",
+                                    span { class: "muted", "{tool.label()}: {progress:.1}%" }
+                                    div { class: "progress-bar",
+                                        div {
+                                            class: "progress-fill",
+                                            style: "width: {progress}%",
+                                        }
+                                    }
+                                }
                             }
                         }
                     }
                 }
 
+                // System Restore
+                div { class: "section",
+                    h3 { class: "section-title", {t!("disk.restore")} }
+                    div { class: "action-bar",
+                        ActionButton {
+                            danger: DangerLevel::Modifying,
+                            class: "btn btn-secondary",
+                            disabled: current_state.is_running || !is_admin || !protection_enabled,
+                            title: restore_hint,
+                            onclick: on_create_restore_point,
+                            if current_state.running_action == "Restore Point" { {t!("disk.restore-creating-short")} } else { {t!("disk.restore-create")} }
+                        }
+                        span { class: "muted",
+                            if protection_enabled { {t!("disk.protection-on")} } else { {t!("disk.protection-off")} }
+                        }
+                    }
+                }
+
+                // Defender exclusions
+                div { class: "section",
+                    h3 { class: "section-title", {t!("disk.defender")} }
+                    div { class: "action-bar",
+                        button {
+                            class: "btn btn-secondary",
+                            disabled: current_state.is_running,
+                            onclick: move |_| refresh_defender(state),
+                            {t!("disk.defender-check")}
+                        }
+                        ActionButton {
+                            danger: DangerLevel::Destructive,
+                            class: "btn btn-secondary",
+                            disabled: current_state.is_running || !is_admin,
+                            title: if !is_admin { t!("common.requires-admin") } else { t!("disk.exclusion-add-hint") },
+                            onclick: on_pick_exclusion,
+                            {t!("disk.exclusion-add")}
+                        }
+                    }
+
+                    match &current_state.defender_status {
+                        Some(Ok(status)) => rsx! {
+                            div { class: "stats-grid",
+                                div { class: if status.is_scanning() { "stat-card stat-card-warning" } else { "stat-card" },
+                                    span { class: "stat-value",
+                                        if status.is_scanning() { {t!("disk.realtime.scanning")} } else if status.is_passive() { {t!("disk.realtime.passive")} } else { {t!("disk.realtime.off")} }
+                                    }
+                                    span { class: "stat-label", {t!("disk.realtime")} }
+                                }
+                                div { class: "stat-card",
+                                    span { class: "stat-value", "{status.running_mode}" }
+                                    span { class: "stat-label", {t!("disk.running-mode")} }
+                                }
+                            }
+                            if status.is_passive() {
+                                p { class: "muted", {t!("disk.defender-passive")} }
+                            }
+                        },
+                        Some(Err(e)) => rsx! {
+                            div { class: "status-bar warning", "{e}" }
+                        },
+                        None => rsx! {
+                            p { class: "muted", {t!("disk.defender-intro")} }
+                        },
+                    }
+
+                    match &current_state.defender_exclusions {
+                        Some(Ok(exclusions)) if exclusions.paths.is_empty() && exclusions.processes.is_empty() => rsx! {
+                            p { class: "muted", {t!("disk.exclusions-none")} }
+                        },
+                        Some(Ok(exclusions)) => rsx! {
+                            table { class: "data-table",
+                                thead {
+                                    tr {
+                                        th { {t!("disk.col.type")} }
+                                        th { {t!("disk.col.exclusion")} }
+                                        th { "" }
+                                    }
+                                }
+                                tbody {
+                                    for (kind, value) in exclusions.paths.iter().map(|p| (ExclusionKind::Path, p.clone()))
+                                        .chain(exclusions.processes.iter().map(|p| (ExclusionKind::Process, p.clone())))
+                                    {
+                                        tr {
+                                            td { class: "muted", {t!(kind.label_key())} }
+                                            td { class: "mono", "{value}" }
+                                            td {
+                                                ActionButton {
+                                                    danger: DangerLevel::Modifying,
+                                                    class: "btn btn-ghost btn-sm",
+                                                    disabled: current_state.is_running || !is_admin,
+                                                    onclick: {
+                                                        let value = value.clone();
+                                                        move |_| on_remove_exclusion((kind, value.clone()))
+                                                    },
+                                                    {t!("common.remove")}
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        },
+                        Some(Err(e)) if current_state.defender_status.as_ref().is_some_and(|s| s.is_ok()) => rsx! {
+                            p { class: "muted", "{e}" }
+                        },
+                        _ => rsx! {},
+                    }
+                }
+
+                if let Some(path) = current_state.pending_exclusion.clone() {
+                    ConfirmDialog {
+                        title: t!("disk.exclusion-confirm-title"),
+                        message: t!("disk.exclusion-confirm", path = path),
+                        confirm_label: t!("disk.exclusion-confirm-button"),
+                        danger: true,
+                        on_confirm: on_add_exclusion,
+                        on_cancel: move |_| state.write().pending_exclusion = None,
+                    }
+                }
+
                 // Empty state
                 if current_state.disks.is_empty() && current_state.temp_folders.is_empty() {
                     div { class: "section",
                         div { class: "empty-state",
                             div { class: "empty-state-icon", "💾" }
-                            p { class: "empty-state-text", "Click 'Refresh' to scan drives and temp folders" }
+                            p { class: "empty-state-text", {t!("disk.empty")} }
                         }
                     }
                 }
@@ -235,6 +589,14 @@ pub fn DiskPage(is_admin: bool) -> Element {
                     }
                 }
             }
+
+            // Fixed output panel at bottom
+            OutputPanel {
+                outputs: outputs_for_panel,
+                on_clear: move |_| on_clear(()),
+                is_admin,
+                on_rerun: move |output| state.write().command_outputs.push(output),
+            }
         }
     }
 }
@@ -4,158 +4,1254 @@
 
 use dioxus::prelude::*;
 
-use crate::state::DockerPageState;
-use crate::system::docker;
+use crate::state::{
+    CommandOutput, ContainerStatsSortColumn, ContainersSortColumn, CurrentPage, DangerLevel, DockerPageState,
+    ExitWatch, ImagesSortColumn, NetworkPageState, PortsPageState, TableColumn,
+};
+use crate::system::command::{pick_folder, pick_open_file, reveal_in_explorer};
+use crate::system::docker::{self, CheckState, ContainerInfo, ContainerStats, ImageInfo, PortConflict, PrunePreview};
+use crate::system::{network, notify};
+use crate::system::settings::{self, AppSettings};
+use crate::ui::components::{ActionButton, ConfirmDialog, SortableHeader, SortDirection, StaticHeader};
 
-/// Docker page with container management and diagnostics
-#[component]
-pub fn DockerPage() -> Element {
-    // Get persistent state from context
-    let mut state: Signal<DockerPageState> = use_context();
+/// Container table columns, shared by headers, filtering, and sorting
+const CONTAINER_COLUMNS: &[TableColumn<ContainerInfo, ContainersSortColumn>] = &[
+    TableColumn {
+        id: ContainersSortColumn::Name,
+        label: "Name",
+        label_key: "docker.container-col.name",
+        text: |c| c.names.join(", "),
+        compare: |a, b| a.name().to_lowercase().cmp(&b.name().to_lowercase()),
+        descending_first: false,
+    },
+    TableColumn {
+        id: ContainersSortColumn::Image,
+        label: "Image",
+        label_key: "docker.container-col.image",
+        text: |c| c.image.clone(),
+        compare: |a, b| a.image.to_lowercase().cmp(&b.image.to_lowercase()),
+        descending_first: false,
+    },
+    TableColumn {
+        id: ContainersSortColumn::Status,
+        label: "Status",
+        label_key: "docker.container-col.status",
+        text: |c| c.status.clone(),
+        // Running containers first, then by state name
+        compare: |a, b| (!a.is_running(), &a.state).cmp(&(!b.is_running(), &b.state)),
+        descending_first: false,
+    },
+    TableColumn {
+        id: ContainersSortColumn::Health,
+        label: "Health",
+        label_key: "docker.container-col.health",
+        text: |c| c.health().unwrap_or_default().to_string(),
+        // Unhealthy first, containers without a healthcheck last
+        compare: |a, b| health_rank(a).cmp(&health_rank(b)),
+        descending_first: false,
+    },
+    TableColumn {
+        id: ContainersSortColumn::Ports,
+        label: "Ports",
+        label_key: "docker.container-col.ports",
+        text: |c| c.ports.join(", "),
+        compare: |a, b| a.ports.cmp(&b.ports),
+        descending_first: false,
+    },
+    TableColumn {
+        id: ContainersSortColumn::Id,
+        label: "ID",
+        label_key: "docker.container-col.id",
+        text: |c| c.id.clone(),
+        compare: |a, b| a.id.cmp(&b.id),
+        descending_first: false,
+    },
+];
 
-    // Check if Docker is available
-    let docker_available = docker::is_docker_available();
+/// Sort key for the Health column
+fn health_rank(container: &ContainerInfo) -> u8 {
+    match container.health() {
+        Some("unhealthy") => 0,
+        Some("starting") => 1,
+        Some(_) => 2,
+        None => 3,
+    }
+}
 
-    // Refresh container list
-    let refresh_containers = move || {
-        spawn(async move {
-            let names = docker::get_container_names().await;
-            state.write().container_names = names;
-        });
+/// Stats table columns (CPU and memory sort largest-first)
+const STATS_COLUMNS: &[TableColumn<ContainerStats, ContainerStatsSortColumn>] = &[
+    TableColumn {
+        id: ContainerStatsSortColumn::Name,
+        label: "Container",
+        label_key: "docker.stats-col.container",
+        text: |s| s.name.clone(),
+        compare: |a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+        descending_first: false,
+    },
+    TableColumn {
+        id: ContainerStatsSortColumn::Cpu,
+        label: "CPU %",
+        label_key: "docker.stats-col.cpu",
+        text: |s| format!("{:.2}%", s.cpu_percent),
+        compare: |a, b| a.cpu_percent.total_cmp(&b.cpu_percent),
+        descending_first: true,
+    },
+    TableColumn {
+        id: ContainerStatsSortColumn::Memory,
+        label: "Memory",
+        label_key: "docker.stats-col.memory",
+        text: |s| format!("{} / {} ({:.2}%)", s.mem_usage, s.mem_limit, s.mem_percent),
+        compare: |a, b| a.mem_bytes.cmp(&b.mem_bytes),
+        descending_first: true,
+    },
+    TableColumn {
+        id: ContainerStatsSortColumn::NetIo,
+        label: "Net I/O",
+        label_key: "docker.stats-col.net-io",
+        text: |s| s.net_io.clone(),
+        compare: |a, b| docker::io_total_bytes(&a.net_io).cmp(&docker::io_total_bytes(&b.net_io)),
+        descending_first: true,
+    },
+    TableColumn {
+        id: ContainerStatsSortColumn::BlockIo,
+        label: "Block I/O",
+        label_key: "docker.stats-col.block-io",
+        text: |s| s.block_io.clone(),
+        compare: |a, b| docker::io_total_bytes(&a.block_io).cmp(&docker::io_total_bytes(&b.block_io)),
+        descending_first: true,
+    },
+    TableColumn {
+        id: ContainerStatsSortColumn::Pids,
+        label: "PIDs",
+        label_key: "docker.stats-col.pids",
+        text: |s| s.pids.to_string(),
+        compare: |a, b| a.pids.cmp(&b.pids),
+        descending_first: true,
+    },
+];
+
+/// Image table columns, shared by headers, filtering, and sorting
+const IMAGE_COLUMNS: &[TableColumn<ImageInfo, ImagesSortColumn>] = &[
+    TableColumn {
+        id: ImagesSortColumn::Repository,
+        label: "Repository",
+        label_key: "docker.image-col.repository",
+        text: |i| i.repository.clone(),
+        compare: |a, b| a.repository.to_lowercase().cmp(&b.repository.to_lowercase()),
+        descending_first: false,
+    },
+    TableColumn {
+        id: ImagesSortColumn::Tag,
+        label: "Tag",
+        label_key: "docker.image-col.tag",
+        text: |i| i.tag.clone(),
+        compare: |a, b| a.tag.cmp(&b.tag),
+        descending_first: false,
+    },
+    TableColumn {
+        id: ImagesSortColumn::Id,
+        label: "ID",
+        label_key: "docker.image-col.id",
+        text: |i| i.id.clone(),
+        compare: |a, b| a.id.cmp(&b.id),
+        descending_first: false,
+    },
+    TableColumn {
+        id: ImagesSortColumn::Size,
+        label: "Size",
+        label_key: "docker.image-col.size",
+        text: |i| i.size.clone(),
+        compare: |a, b| a.size_bytes.cmp(&b.size_bytes),
+        descending_first: true,
+    },
+    TableColumn {
+        id: ImagesSortColumn::Created,
+        label: "Created",
+        label_key: "docker.image-col.created",
+        text: |i| i.created_since.clone(),
+        compare: |a, b| a.created_at.cmp(&b.created_at),
+        descending_first: true,
+    },
+];
+
+/// Seconds between stats samples while auto-refresh is on
+const STATS_REFRESH_SECS: u64 = 3;
+
+/// CPU/memory percentage shown in the warning color
+const STATS_WARNING_PERCENT: f64 = 80.0;
+
+/// Per-row container lifecycle actions
+#[derive(Clone, Copy, PartialEq)]
+enum ContainerAction {
+    Start,
+    Stop,
+    Restart,
+    /// `docker rm`, with `-f` to remove a running container
+    Remove { force: bool },
+}
+
+impl ContainerAction {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Start => "Start",
+            Self::Stop => "Stop",
+            Self::Restart => "Restart",
+            Self::Remove { .. } => "Remove",
+        }
+    }
+
+    /// Message key of the in-progress status
+    fn progress_key(self) -> &'static str {
+        match self {
+            Self::Start => "docker.container-starting",
+            Self::Stop => "docker.container-stopping",
+            Self::Restart => "docker.container-restarting",
+            Self::Remove { .. } => "docker.container-removing",
+        }
+    }
+
+    /// Message key of the success status
+    fn done_key(self) -> &'static str {
+        match self {
+            Self::Start => "docker.container-started",
+            Self::Stop => "docker.container-stopped",
+            Self::Restart => "docker.container-restarted",
+            Self::Remove { .. } => "docker.container-removed",
+        }
+    }
+
+    /// Message key of the failure status
+    fn failed_key(self) -> &'static str {
+        match self {
+            Self::Start => "docker.container-start-failed",
+            Self::Stop => "docker.container-stop-failed",
+            Self::Restart => "docker.container-restart-failed",
+            Self::Remove { .. } => "docker.container-remove-failed",
+        }
+    }
+}
+
+/// Reload the container table
+fn refresh_containers(mut state: Signal<DockerPageState>) {
+    state.write().loading_containers = true;
+    spawn(async move {
+        let (result, _) = docker::list_containers().await;
+        let mut s = state.write();
+        match result {
+            Ok(containers) => {
+                s.containers = containers;
+                s.containers_error = None;
+            }
+            Err(e) => s.containers_error = Some(e),
+        }
+        s.loading_containers = false;
+    });
+}
+
+/// Volume commands whose output goes to the panel
+#[derive(Clone, PartialEq)]
+enum VolumeAction {
+    Inspect(String),
+    Remove(String),
+    Prune,
+}
+
+/// Docker Compose commands run against the compose file input
+#[derive(Clone, Copy, PartialEq)]
+enum ComposeAction {
+    /// config --quiet only
+    Validate,
+    /// validate, then pull and up -d
+    Refresh,
+    Ps,
+    Logs,
+    Down,
+}
+
+impl ComposeAction {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Validate => "Compose Validate",
+            Self::Refresh => "Compose",
+            Self::Ps => "Compose PS",
+            Self::Logs => "Compose Logs",
+            Self::Down => "Compose Down",
+        }
+    }
+}
+
+/// Run a compose action, remembering the compose file in the recent list
+fn run_compose(mut state: Signal<DockerPageState>, mut app_settings: Signal<AppSettings>, action: ComposeAction) {
+    let path = state().compose_path.trim().to_string();
+    let service = state().compose_service.trim().to_string();
+    let tail_lines = state().log_tail_lines;
+
+    if !path.is_empty() {
+        app_settings.write().remember_compose_path(&path);
+        if let Err(e) = settings::save(&app_settings()) {
+            state.write().status_message = e;
+            state.write().status_type = "error".to_string();
+            return;
+        }
+    }
+
+    {
+        let mut s = state.write();
+        s.is_running = true;
+        s.running_action = action.label().to_string();
+        s.status_message = match action {
+            ComposeAction::Validate => t!("docker.compose-validating"),
+            ComposeAction::Refresh => t!("docker.compose-refreshing"),
+            ComposeAction::Ps => t!("docker.compose-ps-running"),
+            ComposeAction::Logs => t!("docker.compose-logs-running"),
+            ComposeAction::Down => t!("docker.compose-down-running"),
+        };
+        s.status_type = String::new();
+    }
+
+    spawn(async move {
+        let path = Some(path.as_str()).filter(|p| !p.is_empty());
+
+        // Pull & Up only runs against a file that passes `config`
+        if matches!(action, ComposeAction::Validate | ComposeAction::Refresh) {
+            let (check, check_outputs) = docker::compose_config_check(path).await;
+            let passed = check.passed;
+            let mut s = state.write();
+            s.compose_check = Some(check);
+            if !passed || action == ComposeAction::Validate {
+                s.command_outputs = check_outputs;
+                s.is_running = false;
+                s.running_action = String::new();
+                s.status_message = match (passed, action) {
+                    (true, _) => t!("docker.compose-valid"),
+                    (false, ComposeAction::Refresh) => t!("docker.compose-invalid-refresh"),
+                    (false, _) => t!("docker.compose-invalid"),
+                };
+                s.status_type = if passed { "success" } else { "error" }.to_string();
+                return;
+            }
+        }
+
+        let outputs = match action {
+            ComposeAction::Validate => Vec::new(),
+            ComposeAction::Refresh => docker::docker_compose_refresh(path).await,
+            ComposeAction::Ps => vec![docker::docker_compose_ps(path).await],
+            ComposeAction::Logs => {
+                let service = Some(service.as_str()).filter(|s| !s.is_empty());
+                vec![docker::docker_compose_logs(path, service, tail_lines).await]
+            }
+            ComposeAction::Down => vec![docker::docker_compose_down(path).await],
+        };
+        let all_success = outputs.iter().all(|o| o.succeeded());
+
+        {
+            let mut s = state.write();
+            s.command_outputs = outputs;
+            s.is_running = false;
+            s.running_action = String::new();
+
+            let (done, failed) = match action {
+                ComposeAction::Validate => (t!("docker.compose-valid"), t!("docker.compose-invalid")),
+                ComposeAction::Refresh => (t!("docker.compose-refreshed"), t!("docker.compose-refresh-failed")),
+                ComposeAction::Ps => (t!("docker.compose-ps-done"), t!("docker.compose-ps-failed")),
+                ComposeAction::Logs => (t!("docker.compose-logs-done"), t!("docker.compose-logs-failed")),
+                ComposeAction::Down => (t!("docker.compose-down-done"), t!("docker.compose-down-failed")),
+            };
+            if all_success {
+                s.status_message = done;
+                s.status_type = "success".to_string();
+            } else {
+                s.status_message = failed;
+                s.status_type = "error".to_string();
+            }
+        }
+        if matches!(action, ComposeAction::Refresh | ComposeAction::Down) {
+            refresh_containers(state);
+        }
+    });
+}
+
+/// Copy between the host and a container; the output is appended to the panel
+fn run_copy(mut state: Signal<DockerPageState>) {
+    let s = state();
+    let container = s.copy_container.clone();
+    let container_path = s.copy_container_path.trim().to_string();
+    let host_path = s.copy_host_path.trim().to_string();
+    let to_container = s.copy_to_container;
+
+    let missing = if container.is_empty() {
+        Some(t!("docker.copy-no-container"))
+    } else if container_path.is_empty() {
+        Some(t!("docker.copy-no-container-path"))
+    } else if host_path.is_empty() {
+        Some(t!("docker.copy-no-host-path"))
+    } else {
+        None
     };
+    if let Some(message) = missing {
+        state.write().status_message = message;
+        state.write().status_type = "warning".to_string();
+        return;
+    }
 
-    // Initial container refresh
-    use_effect(move || {
-        if docker_available {
-            refresh_containers();
+    {
+        let mut s = state.write();
+        s.is_running = true;
+        s.running_action = "Copy".to_string();
+        s.status_message = if to_container {
+            t!("docker.copy-to-running", host_path = host_path, container = container, container_path = container_path)
+        } else {
+            t!("docker.copy-from-running", host_path = host_path, container = container, container_path = container_path)
+        };
+        s.status_type = String::new();
+    }
+
+    spawn(async move {
+        let output = if to_container {
+            docker::copy_to(&container, &host_path, &container_path).await
+        } else {
+            docker::copy_from(&container, &container_path, &host_path).await
+        };
+        let success = output.succeeded();
+        let error = docker::explain_copy_error(&output.stderr, &container, &container_path);
+
+        let mut s = state.write();
+        s.command_outputs.push(output);
+        s.is_running = false;
+        s.running_action = String::new();
+
+        if success {
+            s.status_message = if to_container {
+                t!("docker.copied-to", host_path = host_path, container = container)
+            } else {
+                t!("docker.copied-from", container_path = container_path, host_path = host_path)
+            };
+            s.status_type = "success".to_string();
+        } else {
+            s.status_message = t!("docker.copy-failed", error = error);
+            s.status_type = "error".to_string();
         }
     });
+}
 
-    // Docker Info handler
-    let on_docker_info = move |_| {
-        state.write().is_running = true;
-        state.write().running_action = "Info".to_string();
-        state.write().status_message = "Getting Docker info...".to_string();
-        state.write().status_type = String::new();
+/// Reload the image list (all images, or dangling only)
+fn refresh_images(mut state: Signal<DockerPageState>) {
+    let dangling_only = state().images_dangling_only;
+    state.write().loading_images = true;
+    spawn(async move {
+        let (result, _) = docker::list_images(dangling_only).await;
+        let mut s = state.write();
+        match result {
+            Ok(images) => {
+                s.images = Some(images);
+                s.images_error = None;
+            }
+            Err(e) => s.images_error = Some(e),
+        }
+        s.loading_images = false;
+    });
+}
 
-        spawn(async move {
-            let output = docker::docker_info().await;
-            let success = output.succeeded();
-            
+/// Reload the volume list (with sizes)
+fn refresh_volumes(mut state: Signal<DockerPageState>) {
+    state.write().loading_volumes = true;
+    spawn(async move {
+        let (result, _) = docker::list_volumes().await;
+        let mut s = state.write();
+        match result {
+            Ok(volumes) => {
+                s.volumes = Some(volumes);
+                s.volumes_error = None;
+            }
+            Err(e) => s.volumes_error = Some(e),
+        }
+        s.loading_volumes = false;
+    });
+}
+
+/// Watch the picked container until it exits, then capture its logs to the capture folder
+///
+/// Runs with `spawn_forever` so an overnight watch survives leaving the Docker page.
+fn start_exit_watch(mut state: Signal<DockerPageState>, app_settings: Signal<AppSettings>) {
+    let container = state().exit_watch_container.clone();
+    if container.is_empty() || state().exit_watches.iter().any(|w| w.container == container) {
+        return;
+    }
+    let dir = app_settings().exit_capture_dir();
+    let cancel = crate::system::command::CancelFlag::default();
+    {
+        let mut s = state.write();
+        s.exit_watches.push(ExitWatch { container: container.clone(), since: chrono::Local::now(), cancel: cancel.clone() });
+        s.status_message = t!("docker.watch-started", container = container, dir = dir.display());
+        s.status_type = "info".to_string();
+    }
+
+    spawn_forever(async move {
+        let result = docker::watch_container_exit(&container, &dir, cancel.clone()).await;
+        state.write().exit_watches.retain(|w| !std::sync::Arc::ptr_eq(&w.cancel, &cancel));
+
+        match result {
+            Ok(None) => {}
+            Ok(Some(path)) => {
+                let message = t!("docker.watch-exited-saved", container = container, path = path.display());
+                {
+                    let mut s = state.write();
+                    s.exit_captures.push((container.clone(), path));
+                    s.status_message = message.clone();
+                    s.status_type = "warning".to_string();
+                }
+                if let Err(e) = notify::show_toast(&t!("docker.watch-exited", container = container), &message).await {
+                    tracing::warn!("Failed to show notification: {}", e);
+                }
+            }
+            Err(e) => {
+                let mut s = state.write();
+                s.status_message = t!("docker.watch-stopped", container = container, error = e);
+                s.status_type = "error".to_string();
+            }
+        }
+    });
+}
+
+/// Re-read which registries have stored credentials
+fn refresh_registry_logins(mut state: Signal<DockerPageState>) {
+    let mut s = state.write();
+    match docker::get_login_status() {
+        Ok(logins) => {
+            s.registry_logins = Some(logins);
+            s.registry_logins_error = None;
+        }
+        Err(e) => s.registry_logins_error = Some(e),
+    }
+}
+
+/// Log in to the form's registry, piping the password to `docker login --password-stdin`
+fn run_login(mut state: Signal<DockerPageState>, password: String) {
+    let (registry, username) = {
+        let s = state();
+        (s.login_registry.trim().to_string(), s.login_username.trim().to_string())
+    };
+    {
+        let mut s = state.write();
+        s.is_running = true;
+        s.running_action = "Login".to_string();
+        s.status_message = t!("docker.login-running", registry = registry);
+        s.status_type = String::new();
+    }
+
+    spawn(async move {
+        let output = docker::docker_login(&registry, &username, &password).await;
+        let success = output.succeeded();
+        let error = output.stderr.lines().map(str::trim).find(|l| !l.is_empty()).map(String::from);
+
+        {
             let mut s = state.write();
             s.command_outputs = vec![output];
             s.is_running = false;
             s.running_action = String::new();
-
             if success {
-                s.status_message = "Docker daemon is running".to_string();
+                s.login_open = false;
+                s.status_message = t!("docker.login-done", registry = registry, username = username);
                 s.status_type = "success".to_string();
             } else {
-                s.status_message = "Docker daemon connection failed".to_string();
+                s.status_message = t!("docker.login-failed", registry = registry, error = error.unwrap_or_default());
                 s.status_type = "error".to_string();
             }
-        });
+        }
+        refresh_registry_logins(state);
+    });
+}
+
+/// Locate Docker Desktop's WSL data disk and measure it against `docker system df`
+fn refresh_vhdx_info(mut state: Signal<DockerPageState>) {
+    state.write().loading_vhdx = true;
+    spawn(async move {
+        let (result, _) = docker::get_wsl_vhdx_info().await;
+        let mut s = state.write();
+        match result {
+            Ok(info) => {
+                s.vhdx_info = Some(info);
+                s.vhdx_error = None;
+            }
+            Err(e) => {
+                s.vhdx_info = None;
+                s.vhdx_error = Some(e);
+            }
+        }
+        s.loading_vhdx = false;
+    });
+}
+
+/// Shut down WSL and compact the data disk, reporting the size before and after
+fn compact_vhdx(mut state: Signal<DockerPageState>, path: std::path::PathBuf) {
+    {
+        let mut s = state.write();
+        s.is_running = true;
+        s.running_action = "Compact VHDX".to_string();
+        s.status_message = t!("docker.vhdx-compacting");
+        s.status_type = String::new();
+    }
+
+    spawn(async move {
+        let (result, outputs) = docker::compact_vhdx(&path).await;
+
+        {
+            let mut s = state.write();
+            s.command_outputs = outputs;
+            s.is_running = false;
+            s.running_action = String::new();
+            match result {
+                Ok((before, after)) => {
+                    s.status_message = t!(
+                        "docker.vhdx-compacted",
+                        path = path.display(),
+                        before = docker::format_size(before),
+                        after = docker::format_size(after),
+                        freed = docker::format_size(before.saturating_sub(after))
+                    );
+                    s.status_type = "success".to_string();
+                }
+                Err(e) => {
+                    s.status_message = t!("docker.vhdx-compact-failed", error = e);
+                    s.status_type = "error".to_string();
+                }
+            }
+        }
+        refresh_vhdx_info(state);
+    });
+}
+
+/// Inspect, remove, or prune volumes; changes refresh the volume list afterwards
+fn run_volume_action(mut state: Signal<DockerPageState>, action: VolumeAction) {
+    let (running, progress) = match &action {
+        VolumeAction::Inspect(name) => (format!("Inspect {}", name), t!("docker.volume-inspecting", name = name)),
+        VolumeAction::Remove(name) => (format!("Remove volume {}", name), t!("docker.volume-removing", name = name)),
+        VolumeAction::Prune => ("Volume Prune".to_string(), t!("docker.volume-pruning")),
     };
+    {
+        let mut s = state.write();
+        s.is_running = true;
+        s.running_action = running;
+        s.status_message = progress;
+        s.status_type = String::new();
+    }
 
-    // List Containers handler
-    let on_list_containers = move |_| {
-        state.write().is_running = true;
-        state.write().running_action = "Containers".to_string();
-        state.write().status_message = "Listing containers...".to_string();
-        state.write().status_type = String::new();
+    spawn(async move {
+        let output = match &action {
+            VolumeAction::Inspect(name) => docker::inspect_volume(name).await,
+            VolumeAction::Remove(name) => docker::remove_volume(name).await,
+            VolumeAction::Prune => docker::volume_prune().await,
+        };
+        let success = output.succeeded();
 
-        spawn(async move {
-            let output = docker::docker_ps_all().await;
-            let success = output.succeeded();
-            
-            // Also refresh container names
-            let names = docker::get_container_names().await;
-            
+        {
             let mut s = state.write();
             s.command_outputs = vec![output];
-            s.container_names = names;
             s.is_running = false;
             s.running_action = String::new();
 
+            let (done, failed) = match &action {
+                VolumeAction::Inspect(name) => (t!("docker.volume-inspected", name = name), t!("docker.volume-inspect-failed", name = name)),
+                VolumeAction::Remove(name) => (t!("docker.volume-removed", name = name), t!("docker.volume-remove-failed", name = name)),
+                VolumeAction::Prune => (t!("docker.volume-pruned"), t!("docker.volume-prune-failed")),
+            };
             if success {
-                s.status_message = "Container list refreshed".to_string();
+                s.status_message = done;
                 s.status_type = "success".to_string();
             } else {
-                s.status_message = "Failed to list containers".to_string();
+                s.status_message = failed;
                 s.status_type = "error".to_string();
             }
-        });
-    };
+        }
+        if !matches!(action, VolumeAction::Inspect(_)) {
+            refresh_volumes(state);
+        }
+    });
+}
 
-    // List Images handler
-    let on_list_images = move |_| {
-        state.write().is_running = true;
-        state.write().running_action = "Images".to_string();
-        state.write().status_message = "Listing images...".to_string();
-        state.write().status_type = String::new();
+/// Reload Docker networks, and the host routing table (shared with the Network page) if it isn't loaded yet
+fn refresh_networks(mut state: Signal<DockerPageState>, mut network_state: Signal<NetworkPageState>) {
+    state.write().loading_networks = true;
+    spawn(async move {
+        if network_state.peek().routes.is_empty() {
+            if let (Ok(routes), _) = network::get_route_table().await {
+                network_state.write().routes = routes;
+            }
+        }
+        let (result, _) = docker::list_networks().await;
+        let mut s = state.write();
+        match result {
+            Ok(networks) => {
+                s.networks = Some(networks);
+                s.networks_error = None;
+            }
+            Err(e) => s.networks_error = Some(e),
+        }
+        s.loading_networks = false;
+    });
+}
 
-        spawn(async move {
-            let output = docker::docker_images().await;
-            let success = output.succeeded();
-            
+/// Walk the engine checklist (CLI, pipe, service, Docker Desktop, WSL 2)
+fn diagnose_engine(mut state: Signal<DockerPageState>) {
+    state.write().diagnosing_engine = true;
+    spawn(async move {
+        let (checks, _) = docker::diagnose_engine().await;
+        let mut s = state.write();
+        s.engine_checks = Some(checks);
+        s.diagnosing_engine = false;
+    });
+}
+
+/// Restart com.docker.service, then re-run the checklist
+fn restart_docker_service(mut state: Signal<DockerPageState>) {
+    {
+        let mut s = state.write();
+        s.is_running = true;
+        s.running_action = "Restart Service".to_string();
+        s.status_message = t!("docker.service-restarting");
+        s.status_type = String::new();
+    }
+
+    spawn(async move {
+        let chain = docker::restart_docker_service().await;
+        let success = chain.succeeded();
+
+        {
+            let mut s = state.write();
+            s.command_outputs = chain.outputs;
+            s.is_running = false;
+            s.running_action = String::new();
+
+            if success {
+                s.status_message = t!("docker.service-restarted");
+                s.status_type = "success".to_string();
+            } else {
+                s.status_message = t!("docker.service-restart-failed");
+                s.status_type = "error".to_string();
+            }
+        }
+        diagnose_engine(state);
+    });
+}
+
+/// Cross-check published container ports against host listeners
+fn check_port_conflicts(mut state: Signal<DockerPageState>) {
+    state.write().checking_port_conflicts = true;
+    spawn(async move {
+        let (result, _) = docker::port_conflict_scan().await;
+        let mut s = state.write();
+        match result {
+            Ok(conflicts) => {
+                s.port_conflicts = Some(conflicts);
+                s.port_conflicts_error = None;
+            }
+            Err(e) => s.port_conflicts_error = Some(e),
+        }
+        s.checking_port_conflicts = false;
+    });
+}
+
+/// Sample `docker stats` once
+fn refresh_stats(mut state: Signal<DockerPageState>) {
+    state.write().loading_stats = true;
+    spawn(async move {
+        let (result, _) = docker::container_stats().await;
+        let mut s = state.write();
+        match result {
+            Ok(stats) => {
+                s.stats = Some(stats);
+                s.stats_error = None;
+            }
+            Err(e) => s.stats_error = Some(e),
+        }
+        s.loading_stats = false;
+    });
+}
+
+/// Start, stop, restart, or remove a container, then refresh the table so Status is current
+fn run_container_action(mut state: Signal<DockerPageState>, action: ContainerAction, container: String) {
+    {
+        let mut s = state.write();
+        s.is_running = true;
+        s.running_action = format!("{} {}", action.label(), container);
+        s.status_message = t!(action.progress_key(), container = container);
+        s.status_type = String::new();
+    }
+
+    spawn(async move {
+        let output = match action {
+            ContainerAction::Start => docker::docker_start(&container).await,
+            ContainerAction::Stop => docker::docker_stop(&container).await,
+            ContainerAction::Restart => docker::docker_restart(&container).await,
+            ContainerAction::Remove { force } => docker::docker_rm(&container, force).await,
+        };
+        let success = output.succeeded();
+
+        {
             let mut s = state.write();
             s.command_outputs = vec![output];
             s.is_running = false;
             s.running_action = String::new();
 
             if success {
-                s.status_message = "Image list refreshed".to_string();
+                s.status_message = t!(action.done_key(), container = container);
+                s.status_type = "success".to_string();
+                if matches!(action, ContainerAction::Remove { .. }) && s.selected_container == container {
+                    s.selected_container = String::new();
+                }
+                if s.container_details.as_ref().is_some_and(|d| d.name == container) {
+                    s.container_details = None;
+                }
+                if s.health_log.as_ref().is_some_and(|(name, _)| *name == container) {
+                    s.health_log = None;
+                }
+            } else {
+                s.status_message = t!(action.failed_key(), container = container);
+                s.status_type = "error".to_string();
+            }
+        }
+        refresh_containers(state);
+    });
+}
+
+/// Fetch the last N log lines of a container into the output panel
+fn fetch_logs(mut state: Signal<DockerPageState>, container: String) {
+    let tail_lines = state().log_tail_lines;
+    {
+        let mut s = state.write();
+        s.selected_container = container.clone();
+        s.is_running = true;
+        s.running_action = format!("Logs {}", container);
+        s.status_message = t!("docker.logs-running", container = container);
+        s.status_type = String::new();
+    }
+
+    spawn(async move {
+        let output = docker::docker_logs_timestamps(&container, tail_lines).await;
+        let success = output.succeeded();
+
+        let mut s = state.write();
+        s.command_outputs = vec![output];
+        s.is_running = false;
+        s.running_action = String::new();
+
+        if success {
+            s.status_message = t!("docker.logs-done", container = container);
+            s.status_type = "success".to_string();
+        } else {
+            s.status_message = t!("docker.logs-failed", container = container);
+            s.status_type = "error".to_string();
+        }
+    });
+}
+
+/// Toggle the parsed inspect card for a container
+fn toggle_details(mut state: Signal<DockerPageState>, container: String) {
+    if state().container_details.as_ref().is_some_and(|d| d.name == container) {
+        state.write().container_details = None;
+        return;
+    }
+    {
+        let mut s = state.write();
+        s.selected_container = container.clone();
+        s.is_running = true;
+        s.running_action = format!("Details {}", container);
+        s.status_message = t!("docker.inspecting", container = container);
+        s.status_type = String::new();
+    }
+
+    spawn(async move {
+        let (result, output) = docker::inspect_container_parsed(&container).await;
+
+        let mut s = state.write();
+        s.command_outputs = vec![output];
+        s.is_running = false;
+        s.running_action = String::new();
+        match result {
+            Ok(details) => {
+                s.container_details = Some(details);
+                s.reveal_secrets = false;
+                s.env_drift = None;
+                s.env_drift_error = None;
+                s.status_message = t!("docker.inspected", container = container);
+                s.status_type = "success".to_string();
+            }
+            Err(e) => {
+                s.container_details = None;
+                s.status_message = t!("docker.inspect-failed", container = container, error = e);
+                s.status_type = "error".to_string();
+            }
+        }
+    });
+}
+
+/// Set a container's restart policy, then re-inspect so the details card shows the new value
+fn set_restart_policy(mut state: Signal<DockerPageState>, container: String, policy: String) {
+    {
+        let mut s = state.write();
+        s.is_running = true;
+        s.running_action = format!("Restart policy {}", container);
+        s.status_message = t!("docker.policy-setting", container = container, policy = policy);
+        s.status_type = String::new();
+    }
+
+    spawn(async move {
+        let output = docker::update_restart_policy(&container, &policy).await;
+        let success = output.succeeded();
+        let (details, inspect) = docker::inspect_container_parsed(&container).await;
+
+        let mut s = state.write();
+        s.command_outputs = vec![output, inspect];
+        s.is_running = false;
+        s.running_action = String::new();
+        let has_healthcheck = details.as_ref().map(|d| d.has_healthcheck).unwrap_or(true);
+        if let Ok(details) = details {
+            s.container_details = Some(details);
+        }
+
+        if !success {
+            s.status_message = t!("docker.policy-failed", container = container);
+            s.status_type = "error".to_string();
+        } else if policy == "always" && !has_healthcheck {
+            s.status_message = t!("docker.policy-always-no-healthcheck", container = container);
+            s.status_type = "warning".to_string();
+        } else {
+            s.status_message = t!("docker.policy-set", container = container, policy = policy);
+            s.status_type = "success".to_string();
+        }
+    });
+}
+
+/// Compare the details container's env with its compose service
+fn check_env_drift(mut state: Signal<DockerPageState>, container: String) {
+    let compose_path = state().compose_path.trim().to_string();
+    {
+        let mut s = state.write();
+        s.is_running = true;
+        s.running_action = format!("Env drift {}", container);
+        s.status_message = t!("docker.drift-checking", container = container);
+        s.status_type = String::new();
+    }
+
+    spawn(async move {
+        let path = Some(compose_path.as_str()).filter(|p| !p.is_empty());
+        let (result, outputs) = docker::env_drift(&container, path).await;
+
+        let mut s = state.write();
+        s.command_outputs = outputs;
+        s.is_running = false;
+        s.running_action = String::new();
+        match result {
+            Ok(drift) => {
+                if drift.diffs.is_empty() {
+                    s.status_message = t!("docker.drift-none", container = container, service = drift.service);
+                    s.status_type = "success".to_string();
+                } else {
+                    s.status_message = t!(
+                        "docker.drift-found",
+                        container = container,
+                        service = drift.service,
+                        count = drift.diffs.len()
+                    );
+                    s.status_type = "warning".to_string();
+                }
+                s.env_drift = Some(drift);
+                s.env_drift_error = None;
+            }
+            Err(e) => {
+                s.status_message = t!("docker.drift-failed", container = container);
+                s.status_type = "error".to_string();
+                s.env_drift = None;
+                s.env_drift_error = Some(e);
+            }
+        }
+    });
+}
+
+/// Recreate a drifted container from its compose service, then compare again
+fn recreate_from_compose(mut state: Signal<DockerPageState>, drift: docker::EnvDrift) {
+    {
+        let mut s = state.write();
+        s.is_running = true;
+        s.running_action = format!("Recreate {}", drift.container);
+        s.status_message = t!("docker.recreating", service = drift.service);
+        s.status_type = String::new();
+    }
+
+    spawn(async move {
+        let output = docker::docker_compose_recreate(drift.compose_path.as_deref(), &drift.service).await;
+        let success = output.succeeded();
+        let (details, inspect) = docker::inspect_container_parsed(&drift.container).await;
+        let (after, mut diff_outputs) = if success {
+            docker::env_drift(&drift.container, drift.compose_path.as_deref()).await
+        } else {
+            (Err(String::new()), Vec::new())
+        };
+
+        {
+            let mut s = state.write();
+            s.command_outputs = vec![output, inspect];
+            s.command_outputs.append(&mut diff_outputs);
+            s.is_running = false;
+            s.running_action = String::new();
+            if let Ok(details) = details {
+                s.container_details = Some(details);
+            }
+            match (success, after) {
+                (false, _) => {
+                    s.status_message = t!("docker.recreate-failed", service = drift.service);
+                    s.status_type = "error".to_string();
+                }
+                (true, Ok(after)) if !after.diffs.is_empty() => {
+                    s.status_message = t!("docker.recreated-drift", container = drift.container, count = after.diffs.len());
+                    s.status_type = "warning".to_string();
+                    s.env_drift = Some(after);
+                }
+                (true, after) => {
+                    s.status_message = t!("docker.recreated", container = drift.container, service = drift.service);
+                    s.status_type = "success".to_string();
+                    s.env_drift = after.ok();
+                }
+            }
+        }
+        refresh_containers(state);
+    });
+}
+
+/// Toggle the healthcheck probe log for a container
+fn toggle_health_log(mut state: Signal<DockerPageState>, container: String) {
+    if state().health_log.as_ref().is_some_and(|(name, _)| *name == container) {
+        state.write().health_log = None;
+        return;
+    }
+    {
+        let mut s = state.write();
+        s.selected_container = container.clone();
+        s.is_running = true;
+        s.running_action = format!("Health {}", container);
+        s.status_message = t!("docker.health-reading", container = container);
+        s.status_type = String::new();
+    }
+
+    spawn(async move {
+        let (result, output) = docker::get_health(&container).await;
+
+        let mut s = state.write();
+        s.command_outputs = vec![output];
+        s.is_running = false;
+        s.running_action = String::new();
+        match result {
+            Ok(Some(health)) => {
+                s.status_message = t!("docker.health-status", container = container, status = health.status);
+                s.status_type = if health.status == "unhealthy" { "warning" } else { "success" }.to_string();
+                s.health_log = Some((container, health));
+            }
+            Ok(None) => {
+                s.health_log = None;
+                s.status_message = t!("docker.health-none", container = container);
+                s.status_type = "info".to_string();
+            }
+            Err(e) => {
+                s.health_log = None;
+                s.status_message = t!("docker.health-failed", container = container, error = e);
+                s.status_type = "error".to_string();
+            }
+        }
+    });
+}
+
+/// Open an interactive shell in the container in a separate terminal window
+fn open_shell(mut state: Signal<DockerPageState>, container: String) {
+    {
+        let mut s = state.write();
+        s.selected_container = container.clone();
+        s.is_running = true;
+        s.running_action = format!("Shell {}", container);
+        s.status_message = t!("docker.shell-opening", container = container);
+        s.status_type = String::new();
+    }
+
+    spawn(async move {
+        let result = docker::open_shell(&container).await;
+
+        let mut s = state.write();
+        s.is_running = false;
+        s.running_action = String::new();
+        match result {
+            Ok(message) => {
+                s.status_message = message;
                 s.status_type = "success".to_string();
+            }
+            Err(e) => {
+                s.status_message = e;
+                s.status_type = "error".to_string();
+            }
+        }
+    });
+}
+
+/// Stream a container's logs into the output panel until stopped
+fn start_log_follow(mut state: Signal<DockerPageState>, container: String) {
+    let tail_lines = state().log_tail_lines;
+    let cancel = crate::system::command::CancelFlag::default();
+    {
+        let mut s = state.write();
+        s.selected_container = container.clone();
+        s.following_logs = Some(container.clone());
+        s.log_follow_cancel = cancel.clone();
+        s.log_lines.clear();
+        s.status_message = t!("docker.follow-running", container = container);
+        s.status_type = "info".to_string();
+    }
+
+    spawn(async move {
+        let mut output = docker::follow_logs(&container, tail_lines, cancel.clone(), |lines| {
+            state.write().push_log_lines(lines);
+        })
+        .await;
+        let stopped = cancel.load(std::sync::atomic::Ordering::Relaxed);
+
+        let mut s = state.write();
+        output.stdout = s.log_lines.iter().map(String::as_str).collect::<Vec<_>>().join("\n");
+        s.command_outputs = vec![output];
+        s.following_logs = None;
+        s.log_lines.clear();
+        if stopped {
+            s.status_message = t!("docker.follow-stopped", container = container);
+            s.status_type = "info".to_string();
+        } else {
+            s.status_message = t!("docker.follow-ended", container = container);
+            s.status_type = "warning".to_string();
+        }
+    });
+}
+
+/// Docker page with container management and diagnostics
+#[component]
+pub fn DockerPage(is_admin: bool) -> Element {
+    // Get persistent state from context
+    let mut state: Signal<DockerPageState> = use_context();
+    let network_state: Signal<NetworkPageState> = use_context();
+    let mut app_settings: Signal<AppSettings> = use_context();
+    let mut ports_state: Signal<PortsPageState> = use_context();
+    let mut current_page: Signal<CurrentPage> = use_context();
+
+    // Check if Docker is available
+    let docker_available = docker::is_docker_available();
+
+    // Container awaiting remove confirmation, and whether to force-remove it
+    let mut pending_remove: Signal<Option<ContainerInfo>> = use_signal(|| None);
+    let mut force_remove = use_signal(|| false);
+
+    let mut confirm_compose_down = use_signal(|| false);
+
+    // Image awaiting remove confirmation, and whether to force-remove it
+    let mut pending_image_remove: Signal<Option<ImageInfo>> = use_signal(|| None);
+    let mut force_image_remove = use_signal(|| false);
+
+    // System prune confirmation: open flag, dry-run preview (None while loading), and --volumes
+    let mut confirm_system_prune = use_signal(|| false);
+    let mut prune_preview: Signal<Option<Result<PrunePreview, String>>> = use_signal(|| None);
+    let mut prune_volumes = use_signal(|| false);
+
+    // Build cache prune confirmation, with the cache (size, reclaimable) once loaded
+    let mut confirm_cache_prune = use_signal(|| false);
+    let mut cache_usage: Signal<Option<Result<(String, String), String>>> = use_signal(|| None);
+
+    // Data disk compaction confirmation, and the "stops all WSL distros" acknowledgement
+    let mut confirm_vhdx_compact = use_signal(|| false);
+    let mut vhdx_acknowledged = use_signal(|| false);
+
+    // Volume awaiting remove confirmation, and whether a volume prune is awaiting confirmation
+    let mut pending_volume_remove: Signal<Option<String>> = use_signal(|| None);
+    let mut confirm_volume_prune = use_signal(|| false);
+
+    // Initial container refresh
+    use_hook(move || {
+        if docker_available {
+            refresh_containers(state);
+            refresh_registry_logins(state);
+        }
+    });
+
+    // Registry login password: kept only until the login runs
+    let mut login_password = use_signal(String::new);
+
+    // Remove handler - refuses up front when a container still mounts the volume
+    let on_remove_volume = move |name: String| {
+        spawn(async move {
+            let users = docker::volume_users(&name).await;
+            if users.is_empty() {
+                pending_volume_remove.set(Some(name));
             } else {
-                s.status_message = "Failed to list images".to_string();
-                s.status_type = "error".to_string();
+                let mut s = state.write();
+                s.status_message = t!("docker.volume-in-use", name = name, containers = users.join(", "));
+                s.status_type = "warning".to_string();
             }
         });
     };
 
-    // Disk Usage handler
-    let on_disk_usage = move |_| {
+    // Network prune handler
+    let on_network_prune = move |_| {
         state.write().is_running = true;
-        state.write().running_action = "Disk".to_string();
-        state.write().status_message = "Getting disk usage...".to_string();
+        state.write().running_action = "Network Prune".to_string();
+        state.write().status_message = t!("docker.network-pruning");
         state.write().status_type = String::new();
 
         spawn(async move {
-            let output = docker::docker_disk_usage().await;
+            let output = docker::network_prune().await;
             let success = output.succeeded();
-            
-            let mut s = state.write();
-            s.command_outputs = vec![output];
-            s.is_running = false;
-            s.running_action = String::new();
 
-            if success {
-                s.status_message = "Disk usage retrieved".to_string();
-                s.status_type = "success".to_string();
-            } else {
-                s.status_message = "Failed to get disk usage".to_string();
-                s.status_type = "error".to_string();
+            {
+                let mut s = state.write();
+                s.command_outputs = vec![output];
+                s.is_running = false;
+                s.running_action = String::new();
+
+                if success {
+                    s.status_message = t!("docker.network-pruned");
+                    s.status_type = "success".to_string();
+                } else {
+                    s.status_message = t!("docker.network-prune-failed");
+                    s.status_type = "error".to_string();
+                }
             }
+            refresh_networks(state, network_state);
         });
     };
 
-    // Container Logs handler
-    let on_get_logs = move |_| {
-        let container = state().selected_container.clone();
-        if container.is_empty() {
-            state.write().status_message = "Select a container first".to_string();
-            state.write().status_type = "warning".to_string();
-            return;
+    // Re-sample stats while auto-refresh is on (stops when the page is left)
+    use_future(move || async move {
+        loop {
+            tokio::time::sleep(tokio::time::Duration::from_secs(STATS_REFRESH_SECS)).await;
+            let s = state.peek();
+            if docker_available && s.stats_auto_refresh && !s.loading_stats {
+                drop(s);
+                refresh_stats(state);
+            }
+        }
+    });
+
+    // Leaving the page drops the follow task (which kills docker logs -f); clear the follow state with it
+    use_drop(move || {
+        let mut s = state.write();
+        if s.following_logs.take().is_some() {
+            s.log_follow_cancel.store(true, std::sync::atomic::Ordering::Relaxed);
+            s.log_lines.clear();
+            s.status_message = String::new();
+            s.status_type = String::new();
         }
+    });
 
-        let tail_lines = state().log_tail_lines;
+    // Docker Info handler
+    let on_docker_info = move |_| {
         state.write().is_running = true;
-        state.write().running_action = "Logs".to_string();
-        state.write().status_message = format!("Getting logs for {}...", container);
+        state.write().running_action = "Info".to_string();
+        state.write().status_message = t!("docker.info-running");
         state.write().status_type = String::new();
 
         spawn(async move {
-            let output = docker::docker_logs_follow(&container, tail_lines).await;
+            let output = docker::docker_info().await;
             let success = output.succeeded();
             
             let mut s = state.write();
@@ -164,31 +1260,85 @@ pub fn DockerPage() -> Element {
             s.running_action = String::new();
 
             if success {
-                s.status_message = format!("Logs retrieved for {}", container);
+                s.status_message = t!("docker.daemon-running");
                 s.status_type = "success".to_string();
+                s.engine_checks = None;
             } else {
-                s.status_message = format!("Failed to get logs for {}", container);
+                s.status_message = t!("docker.daemon-failed");
                 s.status_type = "error".to_string();
+                drop(s);
+                diagnose_engine(state);
             }
         });
     };
 
-    // Restart Container handler
-    let on_restart_container = move |_| {
-        let container = state().selected_container.clone();
-        if container.is_empty() {
-            state.write().status_message = "Select a container first".to_string();
-            state.write().status_type = "warning".to_string();
-            return;
+    // Launch Docker Desktop from Program Files
+    let on_start_desktop = move |_| {
+        let mut s = state.write();
+        match docker::start_docker_desktop() {
+            Ok(message) => {
+                s.status_message = message;
+                s.status_type = "success".to_string();
+            }
+            Err(e) => {
+                s.status_message = e;
+                s.status_type = "error".to_string();
+            }
+        }
+    };
+
+    // Remove Image handler - a "must force" conflict re-opens the dialog with force ticked
+    let mut on_remove_image = move |(image, force): (ImageInfo, bool)| {
+        let reference = image.reference();
+        {
+            let mut s = state.write();
+            s.is_running = true;
+            s.running_action = format!("Remove image {}", reference);
+            s.status_message = t!("docker.image-removing", image = reference);
+            s.status_type = String::new();
         }
 
+        spawn(async move {
+            let output = docker::remove_image(&reference, force).await;
+            let success = output.succeeded();
+            let needs_force = !force && docker::image_remove_needs_force(&output.stderr);
+            let error = output.stderr.lines().map(str::trim).find(|l| !l.is_empty()).unwrap_or_default().to_string();
+
+            {
+                let mut s = state.write();
+                s.command_outputs = vec![output];
+                s.is_running = false;
+                s.running_action = String::new();
+
+                if success {
+                    s.status_message = t!("docker.image-removed", image = reference);
+                    s.status_type = "success".to_string();
+                } else if needs_force {
+                    s.status_message = t!("docker.image-needs-force", image = reference);
+                    s.status_type = "warning".to_string();
+                } else {
+                    s.status_message = t!("docker.image-remove-failed", image = reference, error = error);
+                    s.status_type = "error".to_string();
+                }
+            }
+            if needs_force {
+                force_image_remove.set(true);
+                pending_image_remove.set(Some(image));
+            } else if success {
+                refresh_images(state);
+            }
+        });
+    };
+
+    // Disk Usage handler
+    let on_disk_usage = move |_| {
         state.write().is_running = true;
-        state.write().running_action = "Restart".to_string();
-        state.write().status_message = format!("Restarting {}...", container);
+        state.write().running_action = "Disk".to_string();
+        state.write().status_message = t!("docker.disk-usage-running");
         state.write().status_type = String::new();
 
         spawn(async move {
-            let output = docker::docker_restart(&container).await;
+            let output = docker::docker_disk_usage().await;
             let success = output.succeeded();
             
             let mut s = state.write();
@@ -197,10 +1347,10 @@ pub fn DockerPage() -> Element {
             s.running_action = String::new();
 
             if success {
-                s.status_message = format!("Container {} restarted", container);
+                s.status_message = t!("docker.disk-usage-done");
                 s.status_type = "success".to_string();
             } else {
-                s.status_message = format!("Failed to restart {}", container);
+                s.status_message = t!("docker.disk-usage-failed");
                 s.status_type = "error".to_string();
             }
         });
@@ -210,112 +1360,196 @@ pub fn DockerPage() -> Element {
     let on_pull_image = move |_| {
         let image = state().image_input.clone();
         if image.is_empty() {
-            state.write().status_message = "Enter an image name first".to_string();
+            state.write().status_message = t!("docker.pull-no-image");
             state.write().status_type = "warning".to_string();
             return;
         }
 
-        state.write().is_running = true;
-        state.write().running_action = "Pull".to_string();
-        state.write().status_message = format!("Pulling {}...", image);
-        state.write().status_type = String::new();
+        let cancel = crate::system::command::CancelFlag::default();
+        {
+            let mut s = state.write();
+            s.is_running = true;
+            s.running_action = "Pull".to_string();
+            s.pull_cancel = cancel.clone();
+            s.status_message = t!("docker.pulling", image = image);
+            s.status_type = String::new();
+        }
 
         spawn(async move {
-            let output = docker::docker_pull(&image).await;
+            let mut lines: Vec<String> = Vec::new();
+            let mut progress = docker::PullProgress::default();
+            let output = docker::docker_pull_streaming(&image, cancel.clone(), |line| {
+                lines.push(line.to_string());
+                progress.update(line);
+                let mut s = state.write();
+                s.command_outputs = vec![CommandOutput {
+                    command: format!("docker pull {}", image),
+                    stdout: lines.join("\n"),
+                    ..Default::default()
+                }];
+                if progress.total() > 0 {
+                    s.status_message = t!(
+                        "docker.pulling-progress",
+                        image = image,
+                        done = progress.completed(),
+                        total = progress.total()
+                    );
+                }
+            })
+            .await;
+            let stopped = cancel.load(std::sync::atomic::Ordering::Relaxed);
             let success = output.succeeded();
-            
+            let auth_error = !success && docker::is_pull_auth_error(&output.stderr);
+
             let mut s = state.write();
             s.command_outputs = vec![output];
             s.is_running = false;
             s.running_action = String::new();
 
-            if success {
-                s.status_message = format!("Successfully pulled {}", image);
+            if stopped {
+                s.status_message = t!("docker.pull-cancelled", image = image);
+                s.status_type = "info".to_string();
+            } else if success {
+                s.status_message = t!("docker.pulled", image = image);
                 s.status_type = "success".to_string();
+            } else if auth_error {
+                let registry = docker::registry_of(&image);
+                let logged_in = s
+                    .registry_logins
+                    .as_ref()
+                    .is_some_and(|logins| logins.iter().any(|l| l.display_name() == registry));
+                s.status_message = if logged_in {
+                    t!("docker.pull-denied", registry = registry, image = image)
+                } else {
+                    t!("docker.pull-auth-required", registry = registry, image = image)
+                };
+                s.status_type = "error".to_string();
+                s.login_registry = registry;
+                s.login_open = true;
             } else {
-                s.status_message = format!("Failed to pull {}", image);
+                s.status_message = t!("docker.pull-failed", image = image);
                 s.status_type = "error".to_string();
             }
+            let images_loaded = s.images.is_some();
+            drop(s);
+            if success && images_loaded {
+                refresh_images(state);
+            }
         });
     };
 
-    let on_compose_refresh = move |_| {
-        let path = state().compose_path.clone();
-
+    // Image Prune handler
+    let on_image_prune = move |_| {
         state.write().is_running = true;
-        state.write().running_action = "Compose".to_string();
-        state.write().status_message = "Running docker compose pull && up -d...".to_string();
+        state.write().running_action = "Prune".to_string();
+        state.write().status_message = t!("docker.image-pruning");
         state.write().status_type = String::new();
 
         spawn(async move {
-            let path_owned = if path.is_empty() { None } else { Some(path.clone()) };
-            let outputs = docker::docker_compose_refresh(path_owned.as_deref()).await;
-            let all_success = outputs.iter().all(|o| o.succeeded());
+            let output = docker::docker_image_prune().await;
+            let success = output.succeeded();
             
             let mut s = state.write();
-            s.command_outputs = outputs;
+            s.command_outputs = vec![output];
             s.is_running = false;
             s.running_action = String::new();
 
-            if all_success {
-                s.status_message = "Compose refresh completed".to_string();
+            if success {
+                s.status_message = t!("docker.image-pruned");
                 s.status_type = "success".to_string();
             } else {
-                s.status_message = "Compose refresh had errors".to_string();
+                s.status_message = t!("docker.image-prune-failed");
                 s.status_type = "error".to_string();
             }
+            let images_loaded = s.images.is_some();
+            drop(s);
+            if images_loaded {
+                refresh_images(state);
+            }
         });
     };
 
-    // Image Prune handler
-    let on_image_prune = move |_| {
+    // Build Cache Prune: open the confirmation and load the current cache size into it
+    let on_cache_prune = move |_| {
+        let keep = state().cache_keep_storage.trim().to_string();
+        if !keep.is_empty() && docker::parse_size(&keep).is_none() {
+            state.write().status_message = t!("docker.cache-size-invalid", value = keep);
+            state.write().status_type = "warning".to_string();
+            return;
+        }
+        cache_usage.set(None);
+        confirm_cache_prune.set(true);
+        spawn(async move {
+            let (usage, _) = docker::build_cache_usage().await;
+            cache_usage.set(Some(usage));
+        });
+    };
+
+    // Build Cache Prune handler (after confirmation); shows disk usage afterwards
+    let mut run_cache_prune = move || {
+        let keep = Some(state().cache_keep_storage.trim().to_string()).filter(|k| !k.is_empty());
         state.write().is_running = true;
-        state.write().running_action = "Prune".to_string();
-        state.write().status_message = "Pruning unused images...".to_string();
+        state.write().running_action = "Cache Prune".to_string();
+        state.write().status_message = t!("docker.cache-pruning");
         state.write().status_type = String::new();
 
         spawn(async move {
-            let output = docker::docker_image_prune().await;
+            let output = docker::builder_prune(keep).await;
             let success = output.succeeded();
-            
+            let usage = docker::docker_disk_usage().await;
+
             let mut s = state.write();
-            s.command_outputs = vec![output];
+            s.command_outputs = vec![output, usage];
             s.is_running = false;
             s.running_action = String::new();
 
             if success {
-                s.status_message = "Unused images pruned".to_string();
+                s.status_message = t!("docker.cache-pruned");
                 s.status_type = "success".to_string();
             } else {
-                s.status_message = "Image prune failed".to_string();
+                s.status_message = t!("docker.cache-prune-failed");
                 s.status_type = "error".to_string();
             }
         });
     };
 
-    // System Prune handler
+    // System Prune: open the confirmation and load the dry-run preview into it
     let on_system_prune = move |_| {
+        prune_preview.set(None);
+        prune_volumes.set(false);
+        confirm_system_prune.set(true);
+        spawn(async move {
+            let (preview, _) = docker::prune_preview().await;
+            prune_preview.set(Some(preview));
+        });
+    };
+
+    // System Prune handler (after confirmation)
+    let mut run_system_prune = move |volumes: bool| {
         state.write().is_running = true;
         state.write().running_action = "System Prune".to_string();
-        state.write().status_message = "Pruning Docker system...".to_string();
+        state.write().status_message = t!("docker.system-pruning");
         state.write().status_type = String::new();
 
         spawn(async move {
-            let output = docker::docker_system_prune().await;
+            let output = docker::docker_system_prune(volumes).await;
             let success = output.succeeded();
-            
-            let mut s = state.write();
-            s.command_outputs = vec![output];
-            s.is_running = false;
-            s.running_action = String::new();
 
-            if success {
-                s.status_message = "System prune completed".to_string();
-                s.status_type = "success".to_string();
-            } else {
-                s.status_message = "System prune failed".to_string();
-                s.status_type = "error".to_string();
+            {
+                let mut s = state.write();
+                s.command_outputs = vec![output];
+                s.is_running = false;
+                s.running_action = String::new();
+
+                if success {
+                    s.status_message = t!("docker.system-pruned");
+                    s.status_type = "success".to_string();
+                } else {
+                    s.status_message = t!("docker.system-prune-failed");
+                    s.status_type = "error".to_string();
+                }
             }
+            refresh_containers(state);
         });
     };
 
@@ -329,13 +1563,28 @@ pub fn DockerPage() -> Element {
 
     // Read current state
     let current_state = state();
-    let outputs_for_panel = current_state.command_outputs.clone();
+    let host_subnets = network::local_subnets(&network_state().routes);
+    let stats = current_state.stats.clone().unwrap_or_default();
+    let visible_stats = current_state.stats_table.visible_rows(&stats, STATS_COLUMNS);
+    let images = current_state.images.clone().unwrap_or_default();
+    let visible_images = current_state.image_table.visible_rows(&images, IMAGE_COLUMNS);
+
+    // While following, the panel shows the live buffer instead of the last command output
+    let outputs_for_panel = match &current_state.following_logs {
+        Some(container) => vec![CommandOutput {
+            command: format!("docker logs -f {}", container),
+            stdout: current_state.log_lines.iter().map(String::as_str).collect::<Vec<_>>().join("\n"),
+            ..Default::default()
+        }],
+        None => current_state.command_outputs.clone(),
+    };
+    let visible_containers = current_state.container_table.visible_rows(&current_state.containers, CONTAINER_COLUMNS);
 
     rsx! {
         // Page header
         header { class: "page-header",
-            h1 { class: "page-title", "🐳 Docker" }
-            p { class: "page-subtitle", "Container management and diagnostics" }
+            h1 { class: "page-title", {t!("docker.title")} }
+            p { class: "page-subtitle", {t!("docker.subtitle")} }
         }
 
         // Split layout
@@ -344,178 +1593,1431 @@ pub fn DockerPage() -> Element {
             div { class: "page-controls",
                 if !docker_available {
                     div { class: "status-bar error",
-                        "❌ Docker not found in PATH. Install Docker Desktop or add docker to your PATH."
+                        {t!("docker.not-found")}
+                    }
+                } else {
+                    // Quick Info Section
+                    div { class: "section",
+                        h3 { class: "section-title", {t!("docker.quick-info")} }
+                        div { class: "quick-actions",
+                            ActionButton {
+                                danger: DangerLevel::ReadOnly,
+                                class: "quick-action-btn",
+                                disabled: current_state.is_running,
+                                onclick: on_docker_info,
+                                span { class: "quick-action-icon", "ℹ️" }
+                                span { class: "quick-action-label",
+                                    if current_state.running_action == "Info" { {t!("docker.running")} } else { {t!("docker.info")} }
+                                }
+                            }
+                            ActionButton {
+                                danger: DangerLevel::ReadOnly,
+                                class: "quick-action-btn",
+                                disabled: current_state.is_running,
+                                onclick: on_disk_usage,
+                                span { class: "quick-action-icon", "💾" }
+                                span { class: "quick-action-label",
+                                    if current_state.running_action == "Disk" { {t!("docker.running")} } else { {t!("docker.disk-usage")} }
+                                }
+                            }
+                        }
+                    }
+
+                    // Engine checklist (after docker info failed)
+                    if let Some(checks) = &current_state.engine_checks {
+                        div { class: "section",
+                            div { class: "section-header",
+                                h3 { class: "section-title", {t!("docker.engine-checklist")} }
+                                div { class: "action-bar-group",
+                                    button {
+                                        class: "btn btn-ghost btn-sm",
+                                        disabled: current_state.diagnosing_engine,
+                                        onclick: move |_| diagnose_engine(state),
+                                        if current_state.diagnosing_engine { {t!("docker.checking")} } else { {t!("docker.recheck")} }
+                                    }
+                                    button {
+                                        class: "btn btn-ghost btn-sm",
+                                        onclick: move |_| state.write().engine_checks = None,
+                                        "✕"
+                                    }
+                                }
+                            }
+                            ul { class: "engine-checklist",
+                                for check in checks.iter() {
+                                    li {
+                                        span { class: "engine-check-icon",
+                                            match check.state {
+                                                CheckState::Ok => "✅",
+                                                CheckState::Warning => "⚠️",
+                                                CheckState::Failed => "❌",
+                                            }
+                                        }
+                                        span { class: "engine-check-label", "{check.label}" }
+                                        span { class: "muted", "{check.detail}" }
+                                    }
+                                }
+                            }
+                            div { class: "action-bar",
+                                ActionButton {
+                                    danger: DangerLevel::Modifying,
+                                    class: "btn btn-secondary",
+                                    disabled: current_state.is_running || !is_admin,
+                                    title: if !is_admin { t!("common.requires-admin") } else { "Restart-Service com.docker.service".to_string() },
+                                    onclick: move |_| restart_docker_service(state),
+                                    if current_state.running_action == "Restart Service" { {t!("docker.restarting")} } else { {t!("docker.service-restart")} }
+                                }
+                                ActionButton {
+                                    danger: DangerLevel::Modifying,
+                                    class: "btn btn-primary",
+                                    disabled: docker::docker_desktop_exe().is_none(),
+                                    title: if docker::docker_desktop_exe().is_none() { t!("docker.desktop-not-found") } else { t!("docker.desktop-launch-hint") },
+                                    onclick: on_start_desktop,
+                                    {t!("docker.desktop-start")}
+                                }
+                            }
+                        }
+                    }
+
+                    // Containers Section
+                    div { class: "section",
+                        div { class: "section-header",
+                            h3 { class: "section-title",
+                                {t!("docker.containers", shown = visible_containers.len(), total = current_state.containers.len())}
+                            }
+                            div { class: "action-bar-group",
+                                if let Some(container) = &current_state.following_logs {
+                                    span { class: "badge badge-success", {t!("docker.following", container = container)} }
+                                    button {
+                                        class: "btn btn-danger btn-sm",
+                                        onclick: move |_| {
+                                            state.peek().log_follow_cancel.store(true, std::sync::atomic::Ordering::Relaxed);
+                                        },
+                                        {t!("docker.stop")}
+                                    }
+                                }
+                                button {
+                                    class: "btn btn-ghost btn-sm",
+                                    disabled: current_state.loading_containers,
+                                    onclick: move |_| refresh_containers(state),
+                                    if current_state.loading_containers { {t!("common.loading")} } else { {t!("docker.refresh")} }
+                                }
+                            }
+                        }
+                        div { class: "action-bar",
+                            input {
+                                r#type: "text",
+                                class: "input",
+                                placeholder: t!("docker.containers-filter"),
+                                value: "{current_state.container_table.filter}",
+                                oninput: move |e| state.write().container_table.filter = e.value(),
+                            }
+                            div { class: "action-bar-group",
+                                label { {t!("docker.log-tail")} }
+                                input {
+                                    r#type: "number",
+                                    class: "input input-number",
+                                    value: "{current_state.log_tail_lines}",
+                                    min: 10,
+                                    max: 1000,
+                                    oninput: move |e| {
+                                        if let Ok(v) = e.value().parse::<u32>() {
+                                            state.write().log_tail_lines = v;
+                                        }
+                                    },
+                                }
+                            }
+                        }
+                        if let Some(error) = &current_state.containers_error {
+                            div { class: "status-bar error", "{error}" }
+                        }
+                        if !current_state.containers.is_empty() {
+                            table { class: "data-table",
+                                thead {
+                                    tr {
+                                        for column in CONTAINER_COLUMNS {
+                                            SortableHeader {
+                                                column: column.id,
+                                                label: t!(column.label_key),
+                                                current_sort: current_state.container_table.sort_column,
+                                                direction: SortDirection::from_ascending(current_state.container_table.sort_ascending),
+                                                on_sort: move |col| state.write().container_table.toggle_sort(col, CONTAINER_COLUMNS),
+                                            }
+                                        }
+                                        StaticHeader { label: "".to_string() }
+                                    }
+                                }
+                                tbody {
+                                    for container in visible_containers.iter() {
+                                        {
+                                            let name = container.name().to_string();
+                                            let running = container.is_running();
+                                            let busy = |action: &str| current_state.running_action == format!("{} {}", action, name);
+                                            let (start_busy, stop_busy, restart_busy, logs_busy, shell_busy) =
+                                                (busy("Start"), busy("Stop"), busy("Restart"), busy("Logs"), busy("Shell"));
+                                            let (start_name, stop_name, restart_name, logs_name, follow_name, shell_name) =
+                                                (name.clone(), name.clone(), name.clone(), name.clone(), name.clone(), name.clone());
+                                            let details_name = name.clone();
+                                            let health_name = name.clone();
+                                            let health = container.health();
+                                            let details_open = current_state.container_details.as_ref().is_some_and(|d| d.name == name);
+                                            let remove_target = container.clone();
+                                            rsx! {
+                                                tr {
+                                                    class: match (current_state.selected_container == name, health == Some("unhealthy")) {
+                                                        (true, true) => "selected error",
+                                                        (true, false) => "selected",
+                                                        (false, true) => "error",
+                                                        (false, false) => "",
+                                                    },
+                                                    td { class: "mono",
+                                                        for container_name in container.names.iter() {
+                                                            div { "{container_name}" }
+                                                        }
+                                                    }
+                                                    td { class: "mono", "{container.image}" }
+                                                    td { class: if running { "success" } else { "muted" }, "{container.status}" }
+                                                    td {
+                                                        match health {
+                                                            Some("unhealthy") => rsx! { span { class: "badge badge-error", {t!("docker.health.unhealthy")} } },
+                                                            Some("starting") => rsx! { span { class: "badge badge-warning", {t!("docker.health.starting")} } },
+                                                            Some(h) => rsx! { span { class: "badge badge-success", "{h}" } },
+                                                            None => rsx! { span { class: "muted", "—" } },
+                                                        }
+                                                    }
+                                                    td { class: "mono",
+                                                        for port in container.ports.iter() {
+                                                            div { "{port}" }
+                                                        }
+                                                    }
+                                                    td { class: "mono muted", "{container.id}" }
+                                                    td { class: "container-actions",
+                                                        if running {
+                                                            ActionButton {
+                                                                danger: DangerLevel::Modifying,
+                                                                class: "btn btn-ghost btn-sm",
+                                                                disabled: current_state.is_running,
+                                                                onclick: move |_| run_container_action(state, ContainerAction::Stop, stop_name.clone()),
+                                                                if stop_busy { {t!("docker.stopping")} } else { {t!("docker.action.stop")} }
+                                                            }
+                                                        } else {
+                                                            ActionButton {
+                                                                danger: DangerLevel::Modifying,
+                                                                class: "btn btn-ghost btn-sm",
+                                                                disabled: current_state.is_running,
+                                                                onclick: move |_| run_container_action(state, ContainerAction::Start, start_name.clone()),
+                                                                if start_busy { {t!("docker.starting")} } else { {t!("docker.action.start")} }
+                                                            }
+                                                        }
+                                                        ActionButton {
+                                                            danger: DangerLevel::Modifying,
+                                                            class: "btn btn-ghost btn-sm",
+                                                            disabled: current_state.is_running,
+                                                            onclick: move |_| run_container_action(state, ContainerAction::Restart, restart_name.clone()),
+                                                            if restart_busy { {t!("docker.restarting")} } else { {t!("docker.action.restart")} }
+                                                        }
+                                                        ActionButton {
+                                                            danger: DangerLevel::ReadOnly,
+                                                            class: "btn btn-ghost btn-sm",
+                                                            disabled: current_state.is_running,
+                                                            title: t!("docker.logs-hint", lines = current_state.log_tail_lines),
+                                                            onclick: move |_| fetch_logs(state, logs_name.clone()),
+                                                            if logs_busy { {t!("common.loading")} } else { {t!("docker.action.logs")} }
+                                                        }
+                                                        ActionButton {
+                                                            danger: DangerLevel::ReadOnly,
+                                                            class: "btn btn-ghost btn-sm",
+                                                            disabled: current_state.is_running || current_state.following_logs.is_some(),
+                                                            title: t!("docker.follow-hint"),
+                                                            onclick: move |_| start_log_follow(state, follow_name.clone()),
+                                                            {t!("docker.action.follow")}
+                                                        }
+                                                        ActionButton {
+                                                            danger: DangerLevel::Modifying,
+                                                            class: "btn btn-ghost btn-sm",
+                                                            disabled: current_state.is_running || !running,
+                                                            title: if running { t!("docker.shell-hint") } else { t!("docker.shell-not-running") },
+                                                            onclick: move |_| open_shell(state, shell_name.clone()),
+                                                            if shell_busy { {t!("docker.opening")} } else { {t!("docker.action.shell")} }
+                                                        }
+                                                        ActionButton {
+                                                            danger: DangerLevel::ReadOnly,
+                                                            class: if details_open { "btn btn-secondary btn-sm" } else { "btn btn-ghost btn-sm" },
+                                                            disabled: current_state.is_running,
+                                                            title: t!("docker.details-hint"),
+                                                            onclick: move |_| toggle_details(state, details_name.clone()),
+                                                            if busy("Details") { {t!("common.loading")} } else { {t!("docker.action.details")} }
+                                                        }
+                                                        if health.is_some() {
+                                                            ActionButton {
+                                                                danger: DangerLevel::ReadOnly,
+                                                                class: "btn btn-ghost btn-sm",
+                                                                disabled: current_state.is_running,
+                                                                title: t!("docker.health-log-hint"),
+                                                                onclick: move |_| toggle_health_log(state, health_name.clone()),
+                                                                if busy("Health") { {t!("common.loading")} } else { {t!("docker.action.health-log")} }
+                                                            }
+                                                        }
+                                                        ActionButton {
+                                                            danger: DangerLevel::Destructive,
+                                                            class: "btn btn-ghost btn-sm",
+                                                            disabled: current_state.is_running,
+                                                            onclick: move |_| {
+                                                                force_remove.set(remove_target.is_running());
+                                                                pending_remove.set(Some(remove_target.clone()));
+                                                            },
+                                                            if busy("Remove") { {t!("docker.removing")} } else { {t!("docker.action.remove")} }
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        } else if current_state.containers_error.is_none() && !current_state.loading_containers {
+                            p { class: "muted", {t!("docker.containers-empty")} }
+                        }
+
+                        if let Some((name, health)) = &current_state.health_log {
+                            div { class: "card container-details-card",
+                                div { class: "adapter-card-header",
+                                    span { class: "card-title", {t!("docker.health-log-title", name = name)} }
+                                    div { class: "action-bar-group",
+                                        span {
+                                            class: match health.status.as_str() {
+                                                "unhealthy" => "badge badge-error",
+                                                "starting" => "badge badge-warning",
+                                                _ => "badge badge-success",
+                                            },
+                                            "{health.status}"
+                                        }
+                                        if health.failing_streak > 0 {
+                                            span { class: "muted", {t!("docker.health-streak", count = health.failing_streak)} }
+                                        }
+                                        button {
+                                            class: "btn btn-ghost btn-sm",
+                                            onclick: move |_| state.write().health_log = None,
+                                            "✕"
+                                        }
+                                    }
+                                }
+                                if health.log.is_empty() {
+                                    p { class: "muted", {t!("docker.health-no-probes")} }
+                                }
+                                for probe in health.log.iter().rev() {
+                                    div { class: "health-probe",
+                                        div { class: "health-probe-header",
+                                            span { class: if probe.exit_code == 0 { "badge badge-success" } else { "badge badge-error" },
+                                                {t!("docker.health-exit", code = probe.exit_code)}
+                                            }
+                                            span { class: "mono muted", {probe.start.get(..19).unwrap_or(&probe.start).replace('T', " ")} }
+                                        }
+                                        if !probe.output.trim().is_empty() {
+                                            pre { class: "mono", "{probe.output.trim_end()}" }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+
+                        if let Some(details) = &current_state.container_details {
+                            div { class: "card container-details-card",
+                                div { class: "adapter-card-header",
+                                    span { class: "card-title mono", "{details.name}" }
+                                    div { class: "action-bar-group",
+                                        span {
+                                            class: match details.health.as_deref() {
+                                                Some("unhealthy") => "badge badge-error",
+                                                Some("starting") => "badge badge-warning",
+                                                _ if details.status == "running" => "badge badge-success",
+                                                _ => "badge badge-muted",
+                                            },
+                                            match &details.health {
+                                                Some(health) => format!("{} ({})", details.status, health),
+                                                None => details.status.clone(),
+                                            }
+                                        }
+                                        button {
+                                            class: "btn btn-ghost btn-sm",
+                                            onclick: move |_| state.write().container_details = None,
+                                            "✕"
+                                        }
+                                    }
+                                }
+                                dl { class: "adapter-details",
+                                    dt { {t!("docker.details.image")} }
+                                    dd { class: "mono", "{details.image}" }
+                                    dt { {t!("docker.details.restart-policy")} }
+                                    dd {
+                                        {
+                                            let container = details.name.clone();
+                                            let current = details.restart_policy.split(':').next().unwrap_or_default().to_string();
+                                            rsx! {
+                                                select {
+                                                    class: "input",
+                                                    disabled: current_state.is_running,
+                                                    onchange: move |e| {
+                                                        if e.value() != current {
+                                                            set_restart_policy(state, container.clone(), e.value());
+                                                        }
+                                                    },
+                                                    for policy in docker::RESTART_POLICIES {
+                                                        option {
+                                                            value: "{policy}",
+                                                            selected: details.restart_policy.split(':').next() == Some(*policy),
+                                                            "{policy}"
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        if details.restart_policy.starts_with("on-failure:") {
+                                            span { class: "muted mono", " ({details.restart_policy})" }
+                                        }
+                                        if details.restart_policy == "always" && !details.has_healthcheck {
+                                            div { class: "error", {t!("docker.details.no-healthcheck")} }
+                                        }
+                                    }
+                                    if details.status != "running" && details.status != "created" {
+                                        dt { {t!("docker.details.exit-code")} }
+                                        dd { class: if details.exit_code == 0 { "mono" } else { "mono error" },
+                                            "{details.exit_code}"
+                                            if !details.finished_at.starts_with("0001-") {
+                                                span { class: "muted", " " {t!("docker.details.exited-at", time = details.finished_at)} }
+                                            }
+                                        }
+                                    } else if !details.started_at.starts_with("0001-") {
+                                        dt { {t!("docker.details.started")} }
+                                        dd { class: "mono", "{details.started_at}" }
+                                    }
+                                    dt { {t!("docker.details.ports")} }
+                                    dd { class: "mono",
+                                        if details.ports.is_empty() {
+                                            span { class: "muted", {t!("docker.details.none")} }
+                                        }
+                                        for port in details.ports.iter() {
+                                            div {
+                                                match (&port.host_ip, port.host_port) {
+                                                    (Some(ip), Some(host_port)) => format!("{}:{} → {}", ip, host_port, port.container_port),
+                                                    (None, Some(host_port)) => format!("{} → {}", host_port, port.container_port),
+                                                    _ => t!("docker.details.not-published", port = port.container_port),
+                                                }
+                                            }
+                                        }
+                                    }
+                                    if let Some(service) = &details.compose_service {
+                                        dt { {t!("docker.details.compose-service")} }
+                                        dd { class: "mono",
+                                            "{service}"
+                                            if let Some(file) = &details.compose_file {
+                                                span { class: "muted", " ({file})" }
+                                            }
+                                        }
+                                    }
+                                    dt { {t!("docker.details.mounts")} }
+                                    dd { class: "mono",
+                                        if details.mounts.is_empty() {
+                                            span { class: "muted", {t!("docker.details.none")} }
+                                        }
+                                        for mount in details.mounts.iter() {
+                                            div {
+                                                "{mount.source} → {mount.destination}"
+                                                span { class: "muted",
+                                                    " ({mount.kind}"
+                                                    if mount.read_only { ", " {t!("docker.details.read-only")} }
+                                                    ")"
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                                div { class: "adapter-card-header",
+                                    span { class: "card-title", {t!("docker.details.environment", count = details.env.len())} }
+                                    if details.env.iter().any(|(name, _)| docker::is_secret_env(name)) {
+                                        label { class: "checkbox-label",
+                                            input {
+                                                r#type: "checkbox",
+                                                checked: current_state.reveal_secrets,
+                                                onchange: move |e| state.write().reveal_secrets = e.checked(),
+                                            }
+                                            {t!("docker.details.reveal-secrets")}
+                                        }
+                                    }
+                                }
+                                dl { class: "adapter-details",
+                                    for (name, value) in details.env.iter() {
+                                        dt { class: "mono", "{name}" }
+                                        dd { class: "mono",
+                                            if docker::is_secret_env(name) && !current_state.reveal_secrets {
+                                                span { class: "muted", "••••••••" }
+                                            } else {
+                                                "{value}"
+                                            }
+                                        }
+                                    }
+                                }
+                                if details.compose_service.is_some() {
+                                    div { class: "adapter-card-header",
+                                        span { class: "card-title", {t!("docker.drift")} }
+                                        {
+                                            let container = details.name.clone();
+                                            let checking = current_state.running_action == format!("Env drift {}", details.name);
+                                            rsx! {
+                                                ActionButton {
+                                                    danger: DangerLevel::ReadOnly,
+                                                    class: "btn btn-ghost btn-sm",
+                                                    disabled: current_state.is_running,
+                                                    title: t!("docker.drift-hint"),
+                                                    onclick: move |_| check_env_drift(state, container.clone()),
+                                                    if checking { {t!("docker.drift-comparing")} } else { {t!("docker.drift-compare")} }
+                                                }
+                                            }
+                                        }
+                                    }
+                                    if let Some(error) = &current_state.env_drift_error {
+                                        div { class: "status-bar error", "{error}" }
+                                    }
+                                    if let Some(drift) = current_state.env_drift.as_ref().filter(|d| d.container == details.name) {
+                                        if drift.diffs.is_empty() {
+                                            p { class: "muted", {t!("docker.drift-empty", service = drift.service)} }
+                                        } else {
+                                            dl { class: "adapter-details",
+                                                for diff in drift.diffs.iter() {
+                                                    dt { class: "mono",
+                                                        "{diff.name} "
+                                                        span {
+                                                            class: match diff.change {
+                                                                docker::EnvChange::Changed => "badge badge-warning",
+                                                                docker::EnvChange::Added => "badge badge-success",
+                                                                docker::EnvChange::Removed => "badge badge-error",
+                                                            },
+                                                            {t!(diff.change.label_key())}
+                                                        }
+                                                    }
+                                                    dd { class: "mono",
+                                                        match (&diff.container_value, &diff.compose_value) {
+                                                            (Some(running), Some(declared)) => format!("{} → {}", running, declared),
+                                                            (None, Some(declared)) => t!("docker.drift-not-set", declared = declared),
+                                                            (Some(running), None) => t!("docker.drift-not-declared", running = running),
+                                                            (None, None) => String::new(),
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                            {
+                                                let drift = drift.clone();
+                                                rsx! {
+                                                    div { class: "action-bar-group",
+                                                        ActionButton {
+                                                            danger: DangerLevel::Modifying,
+                                                            class: "btn btn-warning btn-sm",
+                                                            disabled: current_state.is_running,
+                                                            title: format!("docker compose up -d --force-recreate {}", drift.service),
+                                                            onclick: move |_| recreate_from_compose(state, drift.clone()),
+                                                            if current_state.running_action == format!("Recreate {}", details.name) {
+                                                                {t!("docker.recreating-short")}
+                                                            } else {
+                                                                {t!("docker.recreate")}
+                                                            }
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    // Published ports vs. host listeners
+                    div { class: "section",
+                        div { class: "section-header",
+                            h3 { class: "section-title", {t!("docker.conflicts")} }
+                            button {
+                                class: "btn btn-ghost btn-sm",
+                                disabled: current_state.checking_port_conflicts,
+                                title: t!("docker.conflicts-hint"),
+                                onclick: move |_| check_port_conflicts(state),
+                                if current_state.checking_port_conflicts { {t!("docker.checking")} } else { {t!("docker.conflicts-check")} }
+                            }
+                        }
+                        if let Some(error) = &current_state.port_conflicts_error {
+                            div { class: "status-bar error", "{error}" }
+                        }
+                        if let Some(conflicts) = &current_state.port_conflicts {
+                            if conflicts.is_empty() {
+                                p { class: "muted", {t!("docker.conflicts-empty")} }
+                            }
+                            div { class: "port-conflict-list",
+                                for conflict in conflicts.iter() {
+                                    {
+                                        let port = conflict.port();
+                                        let (title, detail) = match conflict {
+                                            PortConflict::HostProcess { container, port, process_name, pid } => (
+                                                t!("docker.conflict-title", container = container, port = port),
+                                                t!("docker.conflict-process", process = process_name, pid = pid, port = port),
+                                            ),
+                                            PortConflict::OtherContainer { container, port, holder } => (
+                                                t!("docker.conflict-title", container = container, port = port),
+                                                t!("docker.conflict-container", holder = holder, port = port),
+                                            ),
+                                            PortConflict::OrphanedDockerPort { port, process_name, pid } => (
+                                                t!("docker.conflict-orphan-title", port = port),
+                                                t!("docker.conflict-orphan", process = process_name, pid = pid, port = port),
+                                            ),
+                                        };
+                                        rsx! {
+                                            div { class: "card port-conflict-card",
+                                                div { class: "adapter-card-header",
+                                                    span { class: "card-title", "⚠️ {title}" }
+                                                    button {
+                                                        class: "btn btn-ghost btn-sm",
+                                                        title: t!("docker.conflict-scan-hint"),
+                                                        onclick: move |_| {
+                                                            {
+                                                                let mut ports = ports_state.write();
+                                                                ports.port_input = port;
+                                                                ports.range_mode = false;
+                                                            }
+                                                            current_page.set(CurrentPage::Ports);
+                                                        },
+                                                        {t!("docker.conflict-scan")}
+                                                    }
+                                                }
+                                                p { class: "muted", "{detail}" }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    // Resource usage (docker stats)
+                    div { class: "section",
+                        div { class: "section-header",
+                            h3 { class: "section-title", {t!("docker.stats")} }
+                            div { class: "action-bar-group",
+                                label { class: "checkbox-label",
+                                    input {
+                                        r#type: "checkbox",
+                                        checked: current_state.stats_auto_refresh,
+                                        onchange: move |e| {
+                                            state.write().stats_auto_refresh = e.checked();
+                                            if e.checked() && state.peek().stats.is_none() {
+                                                refresh_stats(state);
+                                            }
+                                        },
+                                    }
+                                    {t!("docker.stats-auto-refresh", secs = STATS_REFRESH_SECS)}
+                                }
+                                button {
+                                    class: "btn btn-ghost btn-sm",
+                                    disabled: current_state.loading_stats,
+                                    onclick: move |_| refresh_stats(state),
+                                    if current_state.loading_stats && current_state.stats.is_none() { {t!("common.loading")} } else { {t!("docker.stats-sample")} }
+                                }
+                            }
+                        }
+                        if let Some(error) = &current_state.stats_error {
+                            div { class: "status-bar error", "{error}" }
+                        }
+                        if current_state.stats.is_some() {
+                            if stats.is_empty() {
+                                p { class: "muted", {t!("docker.stats-empty")} }
+                            } else {
+                                table { class: "data-table",
+                                    thead {
+                                        tr {
+                                            for column in STATS_COLUMNS {
+                                                SortableHeader {
+                                                    column: column.id,
+                                                    label: t!(column.label_key),
+                                                    current_sort: current_state.stats_table.sort_column,
+                                                    direction: SortDirection::from_ascending(current_state.stats_table.sort_ascending),
+                                                    on_sort: move |col| state.write().stats_table.toggle_sort(col, STATS_COLUMNS),
+                                                }
+                                            }
+                                        }
+                                    }
+                                    tbody {
+                                        for row in visible_stats.iter() {
+                                            tr {
+                                                td { class: "mono", "{row.name}" }
+                                                td { class: if row.cpu_percent > STATS_WARNING_PERCENT { "mono warning" } else { "mono" },
+                                                    "{row.cpu_percent:.2}%"
+                                                }
+                                                td { class: if row.mem_percent > STATS_WARNING_PERCENT { "mono warning" } else { "mono" },
+                                                    "{row.mem_usage} / {row.mem_limit} ({row.mem_percent:.2}%)"
+                                                }
+                                                td { class: "mono", "{row.net_io}" }
+                                                td { class: "mono", "{row.block_io}" }
+                                                td { class: "mono", "{row.pids}" }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
                     }
-                } else {
-                    // Quick Info Section
+
+                    // Images
                     div { class: "section",
-                        h3 { class: "section-title", "Quick Info" }
-                        div { class: "quick-actions",
-                            button {
-                                class: "quick-action-btn",
-                                disabled: current_state.is_running,
-                                onclick: on_docker_info,
-                                span { class: "quick-action-icon", "ℹ️" }
-                                span { class: "quick-action-label",
-                                    if current_state.running_action == "Info" { "Running..." } else { "Docker Info" }
+                        div { class: "section-header",
+                            h3 { class: "section-title",
+                                if let Some(images) = &current_state.images {
+                                    {t!("docker.images-count", shown = visible_images.len(), total = images.len())}
+                                } else {
+                                    {t!("docker.images")}
                                 }
                             }
                             button {
-                                class: "quick-action-btn",
-                                disabled: current_state.is_running,
-                                onclick: on_list_containers,
-                                span { class: "quick-action-icon", "📦" }
-                                span { class: "quick-action-label",
-                                    if current_state.running_action == "Containers" { "Running..." } else { "List Containers" }
+                                class: "btn btn-ghost btn-sm",
+                                disabled: current_state.loading_images,
+                                onclick: move |_| refresh_images(state),
+                                if current_state.loading_images { {t!("common.loading")} } else if current_state.images.is_some() { {t!("docker.refresh")} } else { {t!("docker.images-list")} }
+                            }
+                        }
+                        if let Some(error) = &current_state.images_error {
+                            div { class: "status-bar error", "{error}" }
+                        }
+                        if let Some(images) = &current_state.images {
+                            p { class: "muted",
+                                title: t!("docker.images-reclaim-hint"),
+                                {t!("docker.images-reclaim", size = docker::format_size(docker::dangling_size_bytes(images)), count = images.iter().filter(|i| i.is_dangling()).count())}
+                            }
+                            div { class: "action-bar",
+                                input {
+                                    r#type: "text",
+                                    class: "input",
+                                    placeholder: t!("docker.images-filter"),
+                                    value: "{current_state.image_table.filter}",
+                                    oninput: move |e| state.write().image_table.filter = e.value(),
+                                }
+                                label { class: "checkbox-label",
+                                    input {
+                                        r#type: "checkbox",
+                                        checked: current_state.images_dangling_only,
+                                        onchange: move |e| {
+                                            state.write().images_dangling_only = e.checked();
+                                            refresh_images(state);
+                                        },
+                                    }
+                                    {t!("docker.images-dangling-only")}
+                                }
+                            }
+                            if images.is_empty() {
+                                p { class: "muted",
+                                    if current_state.images_dangling_only { {t!("docker.images-no-dangling")} } else { {t!("docker.images-empty")} }
+                                }
+                            } else {
+                                table { class: "data-table",
+                                    thead {
+                                        tr {
+                                            for column in IMAGE_COLUMNS {
+                                                SortableHeader {
+                                                    column: column.id,
+                                                    label: t!(column.label_key),
+                                                    current_sort: current_state.image_table.sort_column,
+                                                    direction: SortDirection::from_ascending(current_state.image_table.sort_ascending),
+                                                    on_sort: move |col| state.write().image_table.toggle_sort(col, IMAGE_COLUMNS),
+                                                }
+                                            }
+                                            StaticHeader { label: "".to_string() }
+                                        }
+                                    }
+                                    tbody {
+                                        for image in visible_images.iter() {
+                                            {
+                                                let remove_target = image.clone();
+                                                let removing = current_state.running_action == format!("Remove image {}", image.reference());
+                                                rsx! {
+                                                    tr { class: if image.is_dangling() { "muted" } else { "" },
+                                                        td { class: "mono", "{image.repository}" }
+                                                        td { class: "mono", "{image.tag}" }
+                                                        td { class: "mono", "{image.id}" }
+                                                        td { class: "mono", "{image.size}" }
+                                                        td { title: "{image.created_at}", "{image.created_since}" }
+                                                        td { class: "container-actions",
+                                                            ActionButton {
+                                                                danger: DangerLevel::Destructive,
+                                                                class: "btn btn-ghost btn-sm",
+                                                                disabled: current_state.is_running,
+                                                                onclick: move |_| {
+                                                                    force_image_remove.set(false);
+                                                                    pending_image_remove.set(Some(remove_target.clone()));
+                                                                },
+                                                                if removing { {t!("docker.removing")} } else { {t!("docker.action.remove")} }
+                                                            }
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
                                 }
                             }
+                        }
+                    }
+
+                    // Volumes
+                    div { class: "section",
+                        div { class: "section-header",
+                            h3 { class: "section-title", {t!("docker.volumes")} }
                             button {
-                                class: "quick-action-btn",
+                                class: "btn btn-ghost btn-sm",
+                                disabled: current_state.loading_volumes,
+                                title: t!("docker.volumes-hint"),
+                                onclick: move |_| refresh_volumes(state),
+                                if current_state.loading_volumes { {t!("common.loading")} } else if current_state.volumes.is_some() { {t!("docker.refresh")} } else { {t!("docker.volumes-list")} }
+                            }
+                        }
+                        if let Some(error) = &current_state.volumes_error {
+                            div { class: "status-bar error", "{error}" }
+                        }
+                        if let Some(volumes) = &current_state.volumes {
+                            if volumes.is_empty() {
+                                p { class: "muted", {t!("docker.volumes-empty")} }
+                            } else {
+                                table { class: "data-table",
+                                    thead {
+                                        tr {
+                                            StaticHeader { label: t!("docker.volume-col.name") }
+                                            StaticHeader { label: t!("docker.volume-col.driver") }
+                                            StaticHeader { label: t!("docker.volume-col.size") }
+                                            StaticHeader { label: t!("docker.volume-col.containers") }
+                                            StaticHeader { label: "".to_string() }
+                                        }
+                                    }
+                                    tbody {
+                                        for volume in volumes.iter() {
+                                            {
+                                                let inspect_name = volume.name.clone();
+                                                let remove_name = volume.name.clone();
+                                                rsx! {
+                                                    tr {
+                                                        td { class: "mono", title: "{volume.name}", "{volume.name}" }
+                                                        td { "{volume.driver}" }
+                                                        td { class: "mono", {volume.size.clone().unwrap_or_else(|| "?".to_string())} }
+                                                        td { class: if volume.links == 0 { "muted" } else { "" }, "{volume.links}" }
+                                                        td { class: "container-actions",
+                                                            ActionButton {
+                                                                danger: DangerLevel::ReadOnly,
+                                                                class: "btn btn-ghost btn-sm",
+                                                                disabled: current_state.is_running,
+                                                                onclick: move |_| run_volume_action(state, VolumeAction::Inspect(inspect_name.clone())),
+                                                                {t!("docker.volume-inspect")}
+                                                            }
+                                                            ActionButton {
+                                                                danger: DangerLevel::Destructive,
+                                                                class: "btn btn-ghost btn-sm",
+                                                                disabled: current_state.is_running,
+                                                                onclick: move |_| on_remove_volume(remove_name.clone()),
+                                                                {t!("docker.action.remove")}
+                                                            }
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        div { class: "quick-actions",
+                            ActionButton {
+                                danger: DangerLevel::Destructive,
+                                class: "quick-action-btn quick-action-warning",
                                 disabled: current_state.is_running,
-                                onclick: on_list_images,
-                                span { class: "quick-action-icon", "🖼️" }
+                                onclick: move |_| confirm_volume_prune.set(true),
+                                title: t!("docker.volume-prune-hint"),
+                                span { class: "quick-action-icon", "🧺" }
                                 span { class: "quick-action-label",
-                                    if current_state.running_action == "Images" { "Running..." } else { "List Images" }
+                                    if current_state.running_action == "Volume Prune" { {t!("docker.pruning")} } else { {t!("docker.volume-prune")} }
                                 }
                             }
+                        }
+                    }
+
+                    // Networks
+                    div { class: "section",
+                        div { class: "section-header",
+                            h3 { class: "section-title", {t!("docker.networks")} }
                             button {
+                                class: "btn btn-ghost btn-sm",
+                                disabled: current_state.loading_networks,
+                                onclick: move |_| refresh_networks(state, network_state),
+                                if current_state.loading_networks { {t!("common.loading")} } else if current_state.networks.is_some() { {t!("docker.refresh")} } else { {t!("docker.networks-list")} }
+                            }
+                        }
+                        if let Some(error) = &current_state.networks_error {
+                            div { class: "status-bar error", "{error}" }
+                        }
+                        if let Some(networks) = &current_state.networks {
+                            table { class: "data-table",
+                                thead {
+                                    tr {
+                                        StaticHeader { label: t!("docker.network-col.name") }
+                                        StaticHeader { label: t!("docker.network-col.driver") }
+                                        StaticHeader { label: t!("docker.network-col.subnet") }
+                                        StaticHeader { label: t!("docker.network-col.containers") }
+                                    }
+                                }
+                                tbody {
+                                    for net in networks.iter() {
+                                        {
+                                            // Host subnets this network collides with - traffic to them stays inside Docker
+                                            let overlaps: Vec<String> = net
+                                                .subnets
+                                                .iter()
+                                                .flat_map(|subnet| {
+                                                    host_subnets
+                                                        .iter()
+                                                        .filter(|(host, _)| network::ipv4_subnets_overlap(subnet, host))
+                                                        .map(move |(host, interface)| t!("docker.network-overlap", subnet = subnet, host = host, interface = interface))
+                                                })
+                                                .collect();
+                                            rsx! {
+                                                tr {
+                                                    td { class: "mono",
+                                                        "{net.name} "
+                                                        if net.is_prunable() {
+                                                            span { class: "badge badge-muted", title: t!("docker.network-unused-hint"), {t!("docker.network-unused")} }
+                                                        }
+                                                    }
+                                                    td { "{net.driver}" }
+                                                    td { class: "mono",
+                                                        {net.subnets.join(", ")}
+                                                        for overlap in overlaps.iter() {
+                                                            div { class: "warning", title: t!("docker.network-overlap-hint"), "⚠️ {overlap}" }
+                                                        }
+                                                    }
+                                                    td { class: if net.containers.is_empty() { "muted" } else { "" },
+                                                        if net.containers.is_empty() { "—" } else { {net.containers.join(", ")} }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        div { class: "quick-actions",
+                            ActionButton {
+                                danger: DangerLevel::Destructive,
                                 class: "quick-action-btn",
                                 disabled: current_state.is_running,
-                                onclick: on_disk_usage,
-                                span { class: "quick-action-icon", "💾" }
+                                onclick: on_network_prune,
+                                title: t!("docker.network-prune-hint"),
+                                span { class: "quick-action-icon", "🕸️" }
                                 span { class: "quick-action-label",
-                                    if current_state.running_action == "Disk" { "Running..." } else { "Disk Usage" }
+                                    if current_state.running_action == "Network Prune" { {t!("docker.pruning")} } else { {t!("docker.network-prune")} }
                                 }
                             }
                         }
                     }
 
-                    // Container Actions Section
+                    // Image Pull Section
                     div { class: "section",
-                        h3 { class: "section-title", "Container Actions" }
+                        h3 { class: "section-title", {t!("docker.pull")} }
                         div { class: "action-bar",
                             div { class: "action-bar-group",
-                                label { "Container:" }
-                                select {
+                                label { {t!("docker.pull-image")} }
+                                input {
+                                    r#type: "text",
                                     class: "input",
-                                    value: "{current_state.selected_container}",
-                                    onchange: move |e| {
-                                        state.write().selected_container = e.value();
+                                    placeholder: "nginx:latest",
+                                    value: "{current_state.image_input}",
+                                    oninput: move |e| {
+                                        state.write().image_input = e.value();
                                     },
-                                    option { value: "", "Select container..." }
-                                    for name in current_state.container_names.iter() {
-                                        option { value: "{name}", "{name}" }
-                                    }
                                 }
                             }
 
-                            div { class: "action-bar-group",
-                                label { "Tail:" }
-                                input {
-                                    r#type: "number",
-                                    class: "input input-number",
-                                    value: "{current_state.log_tail_lines}",
-                                    min: 10,
-                                    max: 1000,
-                                    oninput: move |e| {
-                                        if let Ok(v) = e.value().parse::<u32>() {
-                                            state.write().log_tail_lines = v;
+                            ActionButton {
+                                danger: DangerLevel::Modifying,
+                                class: "btn btn-primary",
+                                disabled: current_state.is_running || current_state.image_input.is_empty(),
+                                onclick: on_pull_image,
+                                if current_state.running_action == "Pull" { {t!("docker.pulling-short")} } else { {t!("docker.pull-button")} }
+                            }
+                            if current_state.running_action == "Pull" {
+                                button {
+                                    class: "btn btn-ghost",
+                                    onclick: move |_| {
+                                        state.peek().pull_cancel.store(true, std::sync::atomic::Ordering::Relaxed);
+                                    },
+                                    {t!("docker.stop")}
+                                }
+                            }
+                            button {
+                                class: "btn btn-ghost",
+                                disabled: current_state.is_running,
+                                title: t!("docker.login-hint"),
+                                onclick: move |_| {
+                                    let mut s = state.write();
+                                    s.login_open = !s.login_open;
+                                    if s.login_registry.is_empty() {
+                                        s.login_registry = docker::registry_of(&s.image_input);
+                                    }
+                                },
+                                {t!("docker.login-open")}
+                            }
+                        }
+                        div { class: "registry-logins",
+                            span { class: "muted", {t!("docker.logged-in")} " " }
+                            if let Some(error) = &current_state.registry_logins_error {
+                                span { class: "error", "{error}" }
+                            } else {
+                                match current_state.registry_logins.as_deref() {
+                                    None | Some([]) => rsx! { span { class: "muted", {t!("docker.no-credentials")} } },
+                                    Some(logins) => rsx! {
+                                        for login in logins.iter() {
+                                            span {
+                                                class: "badge badge-muted mono",
+                                                title: t!("docker.credentials-hint", registry = login.registry, store = login.store),
+                                                "{login.display_name()}"
+                                            }
                                         }
                                     },
                                 }
                             }
+                        }
+                        if current_state.login_open {
+                            div { class: "action-bar registry-login-form",
+                                input {
+                                    r#type: "text",
+                                    class: "input",
+                                    placeholder: t!("docker.login-registry"),
+                                    value: "{current_state.login_registry}",
+                                    oninput: move |e| state.write().login_registry = e.value(),
+                                }
+                                input {
+                                    r#type: "text",
+                                    class: "input",
+                                    placeholder: t!("docker.login-username"),
+                                    value: "{current_state.login_username}",
+                                    oninput: move |e| state.write().login_username = e.value(),
+                                }
+                                input {
+                                    r#type: "password",
+                                    class: "input",
+                                    placeholder: t!("docker.login-password"),
+                                    value: "{login_password}",
+                                    oninput: move |e| login_password.set(e.value()),
+                                }
+                                ActionButton {
+                                    danger: DangerLevel::Modifying,
+                                    class: "btn btn-primary",
+                                    disabled: current_state.is_running
+                                        || current_state.login_registry.trim().is_empty()
+                                        || current_state.login_username.trim().is_empty()
+                                        || login_password().is_empty(),
+                                    title: t!("docker.login-submit-hint"),
+                                    onclick: move |_| {
+                                        let password = login_password();
+                                        login_password.set(String::new());
+                                        run_login(state, password);
+                                    },
+                                    if current_state.running_action == "Login" { {t!("docker.logging-in")} } else { {t!("docker.login-submit")} }
+                                }
+                                button {
+                                    class: "btn btn-ghost",
+                                    onclick: move |_| {
+                                        login_password.set(String::new());
+                                        state.write().login_open = false;
+                                    },
+                                    {t!("common.cancel")}
+                                }
+                            }
+                        }
+                    }
 
+                    // Capture logs of a flaky container when it exits
+                    div { class: "section",
+                        h3 { class: "section-title", {t!("docker.exit-capture")} }
+                        div { class: "action-bar",
+                            select {
+                                class: "input",
+                                value: "{current_state.exit_watch_container}",
+                                onchange: move |e| state.write().exit_watch_container = e.value(),
+                                option { value: "", {t!("docker.container-pick")} }
+                                for container in current_state.containers.iter() {
+                                    option {
+                                        value: "{container.name()}",
+                                        selected: container.name() == current_state.exit_watch_container,
+                                        "{container.name()} ({container.state})"
+                                    }
+                                }
+                            }
                             button {
                                 class: "btn btn-primary",
-                                disabled: current_state.is_running || current_state.selected_container.is_empty(),
-                                onclick: on_get_logs,
-                                if current_state.running_action == "Logs" { "Loading..." } else { "Get Logs" }
+                                disabled: current_state.exit_watch_container.is_empty()
+                                    || current_state.exit_watches.iter().any(|w| w.container == current_state.exit_watch_container),
+                                title: t!("docker.exit-watch-hint", secs = docker::EXIT_WATCH_POLL.as_secs()),
+                                onclick: move |_| start_exit_watch(state, app_settings),
+                                {t!("docker.exit-watch")}
                             }
-
+                        }
+                        div { class: "action-bar",
+                            label { {t!("docker.exit-save-to")} }
+                            span { class: "mono muted", "{app_settings().exit_capture_dir().display()}" }
                             button {
-                                class: "btn btn-secondary",
-                                disabled: current_state.is_running || current_state.selected_container.is_empty(),
-                                onclick: on_restart_container,
-                                if current_state.running_action == "Restart" { "Restarting..." } else { "Restart" }
+                                class: "btn btn-ghost btn-sm",
+                                onclick: move |_| {
+                                    spawn(async move {
+                                        if let Some(dir) = pick_folder(&t!("docker.exit-folder-pick")).await {
+                                            app_settings.write().exit_capture_dir = dir;
+                                            if let Err(e) = settings::save(&app_settings()) {
+                                                state.write().status_message = e;
+                                                state.write().status_type = "error".to_string();
+                                            }
+                                        }
+                                    });
+                                },
+                                {t!("docker.exit-folder")}
+                            }
+                        }
+                        for watch in current_state.exit_watches.iter() {
+                            {
+                                let cancel = watch.cancel.clone();
+                                let since = watch.since.format("%Y-%m-%d %H:%M").to_string();
+                                rsx! {
+                                    div { class: "exit-watch",
+                                        span { class: "badge badge-warning", {t!("docker.exit-watching")} }
+                                        span { class: "mono", "{watch.container}" }
+                                        span { class: "muted", {t!("docker.exit-since", since = since)} }
+                                        button {
+                                            class: "btn btn-ghost btn-sm",
+                                            onclick: move |_| cancel.store(true, std::sync::atomic::Ordering::Relaxed),
+                                            {t!("docker.stop")}
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        for (container, path) in current_state.exit_captures.iter() {
+                            {
+                                let file = path.display().to_string();
+                                rsx! {
+                                    div { class: "exit-watch",
+                                        span { class: "badge badge-error", {t!("docker.exit-exited")} }
+                                        span { class: "mono", "{container}" }
+                                        span { class: "mono muted", "{file}" }
+                                        button {
+                                            class: "btn btn-ghost btn-sm",
+                                            onclick: move |_| {
+                                                if let Err(e) = reveal_in_explorer(&file) {
+                                                    state.write().status_message = e;
+                                                    state.write().status_type = "error".to_string();
+                                                }
+                                            },
+                                            {t!("docker.exit-show")}
+                                        }
+                                    }
+                                }
                             }
                         }
                     }
 
-                    // Image Pull Section
+                    // Copy files (docker cp)
                     div { class: "section",
-                        h3 { class: "section-title", "Pull Image" }
+                        h3 { class: "section-title", {t!("docker.copy")} }
+                        div { class: "action-bar",
+                            select {
+                                class: "input",
+                                value: "{current_state.copy_container}",
+                                onchange: move |e| state.write().copy_container = e.value(),
+                                option { value: "", {t!("docker.container-pick")} }
+                                for container in current_state.containers.iter() {
+                                    option {
+                                        value: "{container.name()}",
+                                        selected: container.name() == current_state.copy_container,
+                                        "{container.name()}"
+                                    }
+                                }
+                            }
+                            select {
+                                class: "input",
+                                onchange: move |e| state.write().copy_to_container = e.value() == "to",
+                                option { value: "from", selected: !current_state.copy_to_container, {t!("docker.copy-from")} }
+                                option { value: "to", selected: current_state.copy_to_container, {t!("docker.copy-to")} }
+                            }
+                        }
                         div { class: "action-bar",
                             div { class: "action-bar-group",
-                                label { "Image:" }
+                                label { {t!("docker.copy-container-path")} }
                                 input {
                                     r#type: "text",
                                     class: "input",
-                                    placeholder: "nginx:latest",
-                                    value: "{current_state.image_input}",
-                                    oninput: move |e| {
-                                        state.write().image_input = e.value();
+                                    placeholder: "/etc/nginx/nginx.conf",
+                                    value: "{current_state.copy_container_path}",
+                                    oninput: move |e| state.write().copy_container_path = e.value(),
+                                }
+                            }
+                            div { class: "action-bar-group",
+                                label { {t!("docker.copy-host-path")} }
+                                input {
+                                    r#type: "text",
+                                    class: "input url-input",
+                                    placeholder: if current_state.copy_to_container { t!("docker.copy-source-placeholder") } else { t!("docker.copy-destination-placeholder") },
+                                    value: "{current_state.copy_host_path}",
+                                    oninput: move |e| state.write().copy_host_path = e.value(),
+                                }
+                                button {
+                                    class: "btn btn-ghost",
+                                    title: t!("docker.copy-pick-file-hint"),
+                                    onclick: move |_| {
+                                        spawn(async move {
+                                            if let Some(path) = pick_open_file(&t!("docker.copy-pick-file"), &t!("docker.copy-file-filter")).await {
+                                                state.write().copy_host_path = path;
+                                            }
+                                        });
+                                    },
+                                    {t!("docker.copy-file")}
+                                }
+                                button {
+                                    class: "btn btn-ghost",
+                                    title: t!("docker.copy-pick-folder-hint"),
+                                    onclick: move |_| {
+                                        spawn(async move {
+                                            if let Some(path) = pick_folder(&t!("docker.copy-pick-folder")).await {
+                                                state.write().copy_host_path = path;
+                                            }
+                                        });
                                     },
+                                    {t!("docker.copy-folder")}
                                 }
                             }
-
-                            button {
+                            ActionButton {
+                                danger: DangerLevel::Modifying,
                                 class: "btn btn-primary",
-                                disabled: current_state.is_running || current_state.image_input.is_empty(),
-                                onclick: on_pull_image,
-                                if current_state.running_action == "Pull" { "Pulling..." } else { "Pull" }
+                                disabled: current_state.is_running,
+                                onclick: move |_| run_copy(state),
+                                if current_state.running_action == "Copy" { {t!("docker.copying")} } else { {t!("docker.copy-button")} }
                             }
                         }
                     }
 
                     // Compose Section
                     div { class: "section",
-                        h3 { class: "section-title", "Docker Compose" }
+                        h3 { class: "section-title", {t!("docker.compose")} }
                         div { class: "action-bar",
                             div { class: "action-bar-group",
-                                label { "Compose file (optional):" }
+                                label { {t!("docker.compose-file")} }
                                 input {
                                     r#type: "text",
-                                    class: "input",
+                                    class: "input url-input",
                                     placeholder: "docker-compose.yml",
                                     value: "{current_state.compose_path}",
                                     oninput: move |e| {
-                                        state.write().compose_path = e.value();
+                                        let mut s = state.write();
+                                        s.compose_path = e.value();
+                                        s.compose_check = None;
+                                    },
+                                }
+                                button {
+                                    class: "btn btn-ghost",
+                                    onclick: move |_| {
+                                        spawn(async move {
+                                            if let Some(path) = pick_open_file(
+                                                &t!("docker.compose-pick"),
+                                                &t!("docker.compose-filter"),
+                                            )
+                                            .await
+                                            {
+                                                let mut s = state.write();
+                                                s.compose_path = path;
+                                                s.compose_check = None;
+                                            }
+                                        });
                                     },
+                                    {t!("docker.compose-browse")}
+                                }
+                                if !app_settings().recent_compose_paths.is_empty() {
+                                    select {
+                                        class: "input",
+                                        title: t!("docker.compose-recent-hint"),
+                                        onchange: move |e| {
+                                            if !e.value().is_empty() {
+                                                let mut s = state.write();
+                                                s.compose_path = e.value();
+                                                s.compose_check = None;
+                                            }
+                                        },
+                                        option { value: "", {t!("docker.compose-recent")} }
+                                        for path in app_settings().recent_compose_paths {
+                                            option { value: "{path}", selected: false, "{path}" }
+                                        }
+                                    }
+                                }
+                                ActionButton {
+                                    danger: DangerLevel::ReadOnly,
+                                    class: "btn btn-secondary",
+                                    disabled: current_state.is_running,
+                                    onclick: move |_| run_compose(state, app_settings, ComposeAction::Validate),
+                                    title: t!("docker.compose-validate-hint"),
+                                    if current_state.running_action == "Compose Validate" { {t!("docker.compose-validating-short")} } else { {t!("docker.compose-validate")} }
                                 }
                             }
-
-                            button {
+                        }
+                        if let Some(check) = &current_state.compose_check {
+                            if let Some(error) = &check.error {
+                                div { class: "status-bar error compose-check", "❌ {error}" }
+                            } else if check.warnings.is_empty() {
+                                div { class: "status-bar success compose-check", "✅ " {t!("docker.compose-valid")} }
+                            }
+                            for warning in check.warnings.iter() {
+                                div { class: "status-bar warning compose-check", "⚠️ {warning}" }
+                            }
+                        }
+                        div { class: "action-bar",
+                            ActionButton {
+                                danger: DangerLevel::Modifying,
                                 class: "btn btn-primary",
                                 disabled: current_state.is_running,
-                                onclick: on_compose_refresh,
-                                title: "Pull latest images and restart containers",
-                                if current_state.running_action == "Compose" { "Running..." } else { "Pull & Up" }
+                                onclick: move |_| run_compose(state, app_settings, ComposeAction::Refresh),
+                                title: t!("docker.compose-up-hint"),
+                                if current_state.running_action == "Compose" { {t!("docker.running")} } else { {t!("docker.compose-up")} }
+                            }
+                            ActionButton {
+                                danger: DangerLevel::ReadOnly,
+                                class: "btn btn-secondary",
+                                disabled: current_state.is_running,
+                                onclick: move |_| run_compose(state, app_settings, ComposeAction::Ps),
+                                title: t!("docker.compose-ps-hint"),
+                                if current_state.running_action == "Compose PS" { {t!("docker.running")} } else { {t!("docker.compose-ps")} }
+                            }
+                            div { class: "action-bar-group",
+                                input {
+                                    r#type: "text",
+                                    class: "input",
+                                    placeholder: t!("docker.compose-service"),
+                                    value: "{current_state.compose_service}",
+                                    oninput: move |e| state.write().compose_service = e.value(),
+                                }
+                                ActionButton {
+                                    danger: DangerLevel::ReadOnly,
+                                    class: "btn btn-secondary",
+                                    disabled: current_state.is_running,
+                                    onclick: move |_| run_compose(state, app_settings, ComposeAction::Logs),
+                                    title: t!("docker.compose-logs-hint", lines = current_state.log_tail_lines),
+                                    if current_state.running_action == "Compose Logs" { {t!("common.loading")} } else { {t!("docker.compose-logs")} }
+                                }
+                            }
+                            ActionButton {
+                                danger: DangerLevel::Modifying,
+                                class: "btn btn-danger",
+                                disabled: current_state.is_running,
+                                onclick: move |_| confirm_compose_down.set(true),
+                                title: t!("docker.compose-down-hint"),
+                                if current_state.running_action == "Compose Down" { {t!("docker.running")} } else { {t!("docker.compose-down")} }
                             }
                         }
                     }
 
                     // Cleanup Section  
                     div { class: "section",
-                        h3 { class: "section-title", "Cleanup" }
+                        h3 { class: "section-title", {t!("docker.cleanup")} }
                         div { class: "quick-actions",
-                            button {
+                            ActionButton {
+                                danger: DangerLevel::Destructive,
                                 class: "quick-action-btn",
                                 disabled: current_state.is_running,
                                 onclick: on_image_prune,
-                                title: "Remove dangling/unused images",
+                                title: t!("docker.image-prune-hint"),
                                 span { class: "quick-action-icon", "🧹" }
                                 span { class: "quick-action-label",
-                                    if current_state.running_action == "Prune" { "Pruning..." } else { "Image Prune" }
+                                    if current_state.running_action == "Prune" { {t!("docker.pruning")} } else { {t!("docker.image-prune")} }
                                 }
                             }
-                            button {
+                            ActionButton {
+                                danger: DangerLevel::Destructive,
                                 class: "quick-action-btn quick-action-warning",
                                 disabled: current_state.is_running,
                                 onclick: on_system_prune,
-                                title: "⚠️ Remove all unused containers, networks, images, and cache",
+                                title: t!("docker.system-prune-hint"),
                                 span { class: "quick-action-icon", "🗑️" }
                                 span { class: "quick-action-label",
-                                    if current_state.running_action == "System Prune" { "Pruning..." } else { "System Prune" }
+                                    if current_state.running_action == "System Prune" { {t!("docker.pruning")} } else { {t!("docker.system-prune")} }
+                                }
+                            }
+                        }
+                        div { class: "action-bar",
+                            div { class: "action-bar-group",
+                                label { {t!("docker.cache-keep")} }
+                                input {
+                                    r#type: "text",
+                                    class: "input input-number",
+                                    placeholder: t!("docker.cache-keep-placeholder"),
+                                    title: t!("docker.cache-keep-hint"),
+                                    value: "{current_state.cache_keep_storage}",
+                                    oninput: move |e| state.write().cache_keep_storage = e.value(),
+                                }
+                            }
+                            ActionButton {
+                                danger: DangerLevel::Destructive,
+                                class: "btn btn-secondary",
+                                disabled: current_state.is_running,
+                                onclick: on_cache_prune,
+                                title: t!("docker.cache-prune-hint"),
+                                if current_state.running_action == "Cache Prune" { {t!("docker.pruning")} } else { {t!("docker.cache-prune")} }
+                            }
+                        }
+                    }
+
+                    // Docker Desktop's WSL 2 data disk (ext4.vhdx) never shrinks on its own
+                    div { class: "section",
+                        div { class: "section-header",
+                            h3 { class: "section-title", {t!("docker.vhdx")} }
+                            button {
+                                class: "btn btn-ghost btn-sm",
+                                disabled: current_state.loading_vhdx,
+                                title: t!("docker.vhdx-check-hint"),
+                                onclick: move |_| refresh_vhdx_info(state),
+                                if current_state.loading_vhdx { {t!("docker.checking")} } else { {t!("docker.vhdx-check")} }
+                            }
+                        }
+                        if let Some(error) = &current_state.vhdx_error {
+                            div { class: "status-bar error", "{error}" }
+                        }
+                        if let Some(info) = &current_state.vhdx_info {
+                            dl { class: "adapter-details",
+                                dt { {t!("docker.vhdx.file")} }
+                                dd { class: "mono", "{info.path.display()}" }
+                                dt { {t!("docker.vhdx.distro")} }
+                                dd { class: "mono",
+                                    match &info.distro {
+                                        Some(distro) => distro.clone(),
+                                        None => t!("docker.vhdx.not-registered"),
+                                    }
+                                }
+                                dt { {t!("docker.vhdx.size")} }
+                                dd { "{docker::format_size(info.size_bytes)}" }
+                                dt { {t!("docker.vhdx.used")} }
+                                dd {
+                                    match info.docker_used_bytes {
+                                        Some(used) => docker::format_size(used),
+                                        None => t!("docker.vhdx.used-unknown"),
+                                    }
+                                }
+                                if let Some(reclaimable) = info.reclaimable_bytes() {
+                                    dt { {t!("docker.vhdx.reclaimable")} }
+                                    dd { class: if reclaimable > 0 { "warning" } else { "" }, {t!("docker.vhdx.about", size = docker::format_size(reclaimable))} }
+                                }
+                            }
+                            div { class: "action-bar",
+                                ActionButton {
+                                    danger: DangerLevel::Destructive,
+                                    class: "btn btn-warning",
+                                    disabled: current_state.is_running || !is_admin,
+                                    title: if !is_admin { t!("common.requires-admin") } else { t!("docker.vhdx-compact-hint") },
+                                    onclick: move |_| {
+                                        vhdx_acknowledged.set(false);
+                                        confirm_vhdx_compact.set(true);
+                                    },
+                                    if current_state.running_action == "Compact VHDX" { {t!("docker.vhdx-compacting-short")} } else { {t!("docker.vhdx-compact")} }
                                 }
                             }
                         }
@@ -528,13 +3030,219 @@ pub fn DockerPage() -> Element {
                             "{current_state.status_message}"
                         }
                     }
+
+                    if let Some(name) = pending_volume_remove() {
+                        ConfirmDialog {
+                            title: t!("docker.volume-remove-title", name = name),
+                            message: t!("docker.volume-remove-message"),
+                            confirm_label: t!("docker.volume-remove-confirm"),
+                            danger: true,
+                            on_confirm: move |_| {
+                                pending_volume_remove.set(None);
+                                run_volume_action(state, VolumeAction::Remove(name.clone()));
+                            },
+                            on_cancel: move |_| pending_volume_remove.set(None),
+                        }
+                    }
+
+                    if let Some(image) = pending_image_remove() {
+                        ConfirmDialog {
+                            title: t!("docker.image-remove-title"),
+                            message: t!("docker.image-remove-message", image = image.reference(), id = image.id, size = image.size),
+                            confirm_label: t!("common.remove"),
+                            danger: true,
+                            on_confirm: move |_| {
+                                pending_image_remove.set(None);
+                                on_remove_image((image.clone(), force_image_remove()));
+                            },
+                            on_cancel: move |_| pending_image_remove.set(None),
+                            label { class: "checkbox-label",
+                                input {
+                                    r#type: "checkbox",
+                                    checked: force_image_remove(),
+                                    onchange: move |e| force_image_remove.set(e.checked()),
+                                }
+                                {t!("docker.image-remove-force")}
+                            }
+                        }
+                    }
+
+                    if confirm_cache_prune() {
+                        ConfirmDialog {
+                            title: t!("docker.cache-prune-title"),
+                            message: if current_state.cache_keep_storage.trim().is_empty() {
+                                t!("docker.cache-prune-all")
+                            } else {
+                                t!("docker.cache-prune-keep", keep = current_state.cache_keep_storage.trim())
+                            },
+                            confirm_label: t!("docker.cache-prune-confirm"),
+                            danger: true,
+                            confirm_disabled: !matches!(cache_usage(), Some(Ok(_))),
+                            on_confirm: move |_| {
+                                confirm_cache_prune.set(false);
+                                run_cache_prune();
+                            },
+                            on_cancel: move |_| confirm_cache_prune.set(false),
+                            match cache_usage() {
+                                None => rsx! { p { class: "muted", {t!("docker.cache-reading")} } },
+                                Some(Err(e)) => rsx! { div { class: "status-bar error", {t!("docker.cache-read-failed", error = e)} } },
+                                Some(Ok((size, reclaimable))) => rsx! {
+                                    dl { class: "adapter-details prune-preview",
+                                        dt { {t!("docker.prune.build-cache")} }
+                                        dd { "{size}" }
+                                        dt { {t!("docker.prune.reclaimable")} }
+                                        dd { "{reclaimable}" }
+                                    }
+                                },
+                            }
+                        }
+                    }
+
+                    if confirm_vhdx_compact() {
+                        if let Some(info) = current_state.vhdx_info.clone() {
+                            ConfirmDialog {
+                                title: t!("docker.vhdx-confirm-title"),
+                                message: t!("docker.vhdx-confirm", path = info.path.display()),
+                                confirm_label: t!("docker.vhdx-confirm-label"),
+                                danger: true,
+                                confirm_disabled: !vhdx_acknowledged(),
+                                on_confirm: move |_| {
+                                    confirm_vhdx_compact.set(false);
+                                    compact_vhdx(state, info.path.clone());
+                                },
+                                on_cancel: move |_| confirm_vhdx_compact.set(false),
+                                label { class: "checkbox-label",
+                                    input {
+                                        r#type: "checkbox",
+                                        checked: vhdx_acknowledged(),
+                                        onchange: move |e| vhdx_acknowledged.set(e.checked()),
+                                    }
+                                    {t!("docker.vhdx-acknowledge")}
+                                }
+                            }
+                        }
+                    }
+
+                    if confirm_system_prune() {
+                        ConfirmDialog {
+                            title: t!("docker.system-prune-title"),
+                            message: t!("docker.system-prune-message"),
+                            confirm_label: t!("docker.system-prune-confirm"),
+                            danger: true,
+                            confirm_disabled: !matches!(prune_preview(), Some(Ok(_))),
+                            on_confirm: move |_| {
+                                confirm_system_prune.set(false);
+                                run_system_prune(prune_volumes());
+                            },
+                            on_cancel: move |_| confirm_system_prune.set(false),
+                            match prune_preview() {
+                                None => rsx! { p { class: "muted", {t!("docker.prune-previewing")} } },
+                                Some(Err(e)) => rsx! { div { class: "status-bar error", {t!("docker.prune-preview-failed", error = e)} } },
+                                Some(Ok(preview)) => rsx! {
+                                    dl { class: "adapter-details prune-preview",
+                                        dt { {t!("docker.prune.stopped-containers")} }
+                                        dd {
+                                            "{preview.stopped_containers.len()}"
+                                            if !preview.stopped_containers.is_empty() {
+                                                span { class: "muted mono", {format!(" ({})", preview.stopped_containers.join(", "))} }
+                                            }
+                                        }
+                                        dt { {t!("docker.prune.dangling-images")} }
+                                        dd { {t!("docker.prune.dangling-size", count = preview.dangling_images, size = docker::format_size(preview.dangling_image_bytes))} }
+                                        dt { {t!("docker.prune.unused-networks")} }
+                                        dd {
+                                            "{preview.unused_networks.len()}"
+                                            if !preview.unused_networks.is_empty() {
+                                                span { class: "muted mono", {format!(" ({})", preview.unused_networks.join(", "))} }
+                                            }
+                                        }
+                                        dt { {t!("docker.prune.build-cache")} }
+                                        dd { "{preview.build_cache}" }
+                                        if prune_volumes() {
+                                            dt { {t!("docker.prune.volumes")} }
+                                            dd { class: "warning", {t!("docker.prune.volumes-size", size = preview.volumes_reclaimable)} }
+                                        }
+                                    }
+                                },
+                            }
+                            label { class: "checkbox-label",
+                                input {
+                                    r#type: "checkbox",
+                                    checked: prune_volumes(),
+                                    onchange: move |e| prune_volumes.set(e.checked()),
+                                }
+                                {t!("docker.system-prune-volumes")}
+                            }
+                        }
+                    }
+
+                    if confirm_compose_down() {
+                        ConfirmDialog {
+                            title: t!("docker.compose-down-title"),
+                            message: if current_state.compose_path.trim().is_empty() {
+                                t!("docker.compose-down-default")
+                            } else {
+                                t!("docker.compose-down-file", path = current_state.compose_path.trim())
+                            },
+                            confirm_label: t!("docker.compose-down-confirm"),
+                            danger: true,
+                            on_confirm: move |_| {
+                                confirm_compose_down.set(false);
+                                run_compose(state, app_settings, ComposeAction::Down);
+                            },
+                            on_cancel: move |_| confirm_compose_down.set(false),
+                        }
+                    }
+
+                    if confirm_volume_prune() {
+                        ConfirmDialog {
+                            title: t!("docker.volume-prune-title"),
+                            message: t!("docker.volume-prune-message"),
+                            confirm_label: t!("docker.volume-prune-confirm"),
+                            danger: true,
+                            on_confirm: move |_| {
+                                confirm_volume_prune.set(false);
+                                run_volume_action(state, VolumeAction::Prune);
+                            },
+                            on_cancel: move |_| confirm_volume_prune.set(false),
+                        }
+                    }
+
+                    if let Some(container) = pending_remove() {
+                        ConfirmDialog {
+                            title: t!("docker.container-remove-title", name = container.name()),
+                            message: if container.is_running() {
+                                t!("docker.container-remove-running", name = container.name())
+                            } else {
+                                t!("docker.container-remove-stopped", image = container.image)
+                            },
+                            confirm_label: if force_remove() { t!("docker.container-force-remove") } else { t!("common.remove") },
+                            danger: true,
+                            on_confirm: move |_| {
+                                pending_remove.set(None);
+                                let name = container.name().to_string();
+                                run_container_action(state, ContainerAction::Remove { force: force_remove() }, name);
+                            },
+                            on_cancel: move |_| pending_remove.set(None),
+                            label { class: "checkbox-label",
+                                input {
+                                    r#type: "checkbox",
+                                    checked: force_remove(),
+                                    onchange: move |e| force_remove.set(e.checked()),
+                                }
+                                {t!("docker.container-remove-force")}
+                            }
+                        }
+                    }
                 }
             }
 
             // Fixed output panel at bottom
             crate::ui::components::OutputPanel {
                 outputs: outputs_for_panel,
+                follow: current_state.following_logs.is_some(),
                 on_clear: move |_| on_clear(()),
+                on_rerun: move |output| state.write().command_outputs.push(output),
             }
         }
     }
@@ -4,9 +4,9 @@
 
 use dioxus::prelude::*;
 
-use crate::state::{HostsPageState, HostsSortColumn};
+use crate::state::{DangerLevel, HostsPageState, HostsSortColumn};
 use crate::system::hosts;
-use crate::ui::components::{SortableHeader, StaticHeader, SortDirection};
+use crate::ui::components::{ActionButton, SortableHeader, StaticHeader, SortDirection};
 
 fn update_host_sort(mut state: Signal<HostsPageState>, column: HostsSortColumn) {
     let mut s = state.write();
@@ -26,7 +26,7 @@ pub fn HostsPage(is_admin: bool) -> Element {
     // Load hosts file
     let on_load = move |_| {
         state.write().is_running = true;
-        state.write().status_message = "Loading hosts file...".to_string();
+        state.write().status_message = t!("hosts.loading");
         state.write().status_type = String::new();
 
         spawn(async move {
@@ -36,7 +36,7 @@ pub fn HostsPage(is_admin: bool) -> Element {
                     s.entries = entries;
                     s.raw_content = raw;
                     s.is_running = false;
-                    s.status_message = format!("Loaded {} entries", s.entries.len());
+                    s.status_message = t!("hosts.loaded", count = s.entries.len());
                     s.status_type = "success".to_string();
                 }
                 Err(e) => {
@@ -55,13 +55,13 @@ pub fn HostsPage(is_admin: bool) -> Element {
         let hostname = state().new_hostname.clone();
 
         if ip.is_empty() || hostname.is_empty() {
-            state.write().status_message = "Enter both IP and hostname".to_string();
+            state.write().status_message = t!("hosts.enter-both");
             state.write().status_type = "warning".to_string();
             return;
         }
 
         state.write().is_running = true;
-        state.write().status_message = format!("Adding {} {}...", ip, hostname);
+        state.write().status_message = t!("hosts.adding", ip = ip, hostname = hostname);
 
         spawn(async move {
             match hosts::add_host_entry(&ip, &hostname, None) {
@@ -73,7 +73,7 @@ pub fn HostsPage(is_admin: bool) -> Element {
                         s.raw_content = raw;
                         s.new_ip = String::new();
                         s.new_hostname = String::new();
-                        s.status_message = format!("Added {} -> {}", ip, hostname);
+                        s.status_message = t!("hosts.added", ip = ip, hostname = hostname);
                         s.status_type = "success".to_string();
                     }
                 }
@@ -90,13 +90,13 @@ pub fn HostsPage(is_admin: bool) -> Element {
     let on_toggle = move |_| {
         let hostname = state().selected_hostname.clone();
         if hostname.is_empty() {
-            state.write().status_message = "Select an entry first".to_string();
+            state.write().status_message = t!("hosts.select-first");
             state.write().status_type = "warning".to_string();
             return;
         }
 
         state.write().is_running = true;
-        state.write().status_message = format!("Toggling {}...", hostname);
+        state.write().status_message = t!("hosts.toggling", hostname = hostname);
 
         spawn(async move {
             match hosts::toggle_host_entry(&hostname) {
@@ -106,8 +106,11 @@ pub fn HostsPage(is_admin: bool) -> Element {
                         let mut s = state.write();
                         s.entries = entries;
                         s.raw_content = raw;
-                        let status = if enabled { "enabled" } else { "disabled" };
-                        s.status_message = format!("{} is now {}", hostname, status);
+                        s.status_message = if enabled {
+                            t!("hosts.now-enabled", hostname = hostname)
+                        } else {
+                            t!("hosts.now-disabled", hostname = hostname)
+                        };
                         s.status_type = "success".to_string();
                     }
                 }
@@ -124,13 +127,13 @@ pub fn HostsPage(is_admin: bool) -> Element {
     let on_remove = move |_| {
         let hostname = state().selected_hostname.clone();
         if hostname.is_empty() {
-            state.write().status_message = "Select an entry first".to_string();
+            state.write().status_message = t!("hosts.select-first");
             state.write().status_type = "warning".to_string();
             return;
         }
 
         state.write().is_running = true;
-        state.write().status_message = format!("Removing {}...", hostname);
+        state.write().status_message = t!("hosts.removing", hostname = hostname);
 
         spawn(async move {
             match hosts::remove_host_entry(&hostname) {
@@ -141,7 +144,7 @@ pub fn HostsPage(is_admin: bool) -> Element {
                         s.entries = entries;
                         s.raw_content = raw;
                         s.selected_hostname = String::new();
-                        s.status_message = format!("Removed {}", hostname);
+                        s.status_message = t!("hosts.removed", hostname = hostname);
                         s.status_type = "success".to_string();
                     }
                 }
@@ -185,8 +188,8 @@ pub fn HostsPage(is_admin: bool) -> Element {
 
     rsx! {
         header { class: "page-header",
-            h1 { class: "page-title", "📝 Hosts" }
-            p { class: "page-subtitle", "Edit the Windows hosts file" }
+            h1 { class: "page-title", {t!("hosts.title")} }
+            p { class: "page-subtitle", {t!("hosts.subtitle")} }
         }
 
         div { class: "page-split-layout",
@@ -198,21 +201,21 @@ pub fn HostsPage(is_admin: bool) -> Element {
                             class: "btn btn-primary",
                             disabled: current_state.is_running,
                             onclick: on_load,
-                            "🔄 Load Hosts File"
+                            {t!("hosts.load")}
                         }
 
                         if !is_admin {
-                            span { class: "muted", "⚠️ Editing requires Administrator" }
+                            span { class: "muted", {t!("hosts.admin-warning")} }
                         }
                     }
                 }
 
                 // Add entry section
                 div { class: "section",
-                    h3 { class: "section-title", "Add Entry" }
+                    h3 { class: "section-title", {t!("hosts.add-entry")} }
                     div { class: "action-bar",
                         div { class: "action-bar-group",
-                            label { "IP:" }
+                            label { {t!("hosts.ip-label")} }
                             input {
                                 r#type: "text",
                                 class: "input",
@@ -225,7 +228,7 @@ pub fn HostsPage(is_admin: bool) -> Element {
                         }
 
                         div { class: "action-bar-group",
-                            label { "Hostname:" }
+                            label { {t!("hosts.hostname-label")} }
                             input {
                                 r#type: "text",
                                 class: "input",
@@ -237,11 +240,12 @@ pub fn HostsPage(is_admin: bool) -> Element {
                             }
                         }
 
-                        button {
+                        ActionButton {
+                            danger: DangerLevel::Modifying,
                             class: "btn btn-primary",
                             disabled: current_state.is_running || !is_admin || current_state.new_ip.is_empty() || current_state.new_hostname.is_empty(),
                             onclick: on_add,
-                            "➕ Add"
+                            {t!("hosts.add")}
                         }
                     }
                 }
@@ -249,7 +253,7 @@ pub fn HostsPage(is_admin: bool) -> Element {
                 // Entries list
                 if !current_state.entries.is_empty() {
                     div { class: "section",
-                        h3 { class: "section-title", "Entries ({current_state.entries.len()})" }
+                        h3 { class: "section-title", {t!("hosts.entries", count = current_state.entries.len())} }
                         div { class: "service-list",
                             table { class: "data-table",
                                 thead {
@@ -257,26 +261,26 @@ pub fn HostsPage(is_admin: bool) -> Element {
                                         StaticHeader { label: "".to_string() }
                                         SortableHeader {
                                             column: HostsSortColumn::Status,
-                                            label: "Status".to_string(),
+                                            label: t!("hosts.col.status"),
                                             current_sort: current_state.sort_column,
                                             direction: sort_dir,
                                             on_sort: move |col| update_host_sort(state, col),
                                         }
                                         SortableHeader {
                                             column: HostsSortColumn::Ip,
-                                            label: "IP".to_string(),
+                                            label: t!("hosts.col.ip"),
                                             current_sort: current_state.sort_column,
                                             direction: sort_dir,
                                             on_sort: move |col| update_host_sort(state, col),
                                         }
                                         SortableHeader {
                                             column: HostsSortColumn::Hostname,
-                                            label: "Hostname".to_string(),
+                                            label: t!("hosts.col.hostname"),
                                             current_sort: current_state.sort_column,
                                             direction: sort_dir,
                                             on_sort: move |col| update_host_sort(state, col),
                                         }
-                                        StaticHeader { label: "Comment".to_string() }
+                                        StaticHeader { label: t!("hosts.col.comment") }
                                     }
                                 }
                                 tbody {
@@ -317,20 +321,22 @@ pub fn HostsPage(is_admin: bool) -> Element {
                         // Entry actions
                         if !current_state.selected_hostname.is_empty() {
                             div { class: "action-bar", style: "margin-top: 1rem;",
-                                span { class: "mono", "Selected: {current_state.selected_hostname}" }
+                                span { class: "mono", {t!("hosts.selected", hostname = current_state.selected_hostname)} }
                                 
-                                button {
+                                ActionButton {
+                                    danger: DangerLevel::Modifying,
                                     class: "btn btn-secondary",
                                     disabled: current_state.is_running || !is_admin,
                                     onclick: on_toggle,
-                                    "🔀 Toggle"
+                                    {t!("hosts.toggle")}
                                 }
 
-                                button {
+                                ActionButton {
+                                    danger: DangerLevel::Destructive,
                                     class: "btn btn-danger",
                                     disabled: current_state.is_running || !is_admin,
                                     onclick: on_remove,
-                                    "🗑️ Remove"
+                                    {t!("hosts.remove")}
                                 }
                             }
                         }
@@ -339,7 +345,7 @@ pub fn HostsPage(is_admin: bool) -> Element {
                     div { class: "section",
                         div { class: "empty-state",
                             div { class: "empty-state-icon", "📝" }
-                            p { class: "empty-state-text", "Click 'Load Hosts File' to view entries" }
+                            p { class: "empty-state-text", {t!("hosts.empty")} }
                         }
                     }
                 }
@@ -4,20 +4,554 @@
 
 use dioxus::prelude::*;
 
-use crate::state::NetworkPageState;
-use crate::system::network;
+use crate::state::{
+    CommandOutput, CurrentPage, DangerLevel, DnsCacheSortColumn, NetworkPageState, PortsPageState, RoutesSortColumn,
+    TableColumn,
+};
+use crate::system::command::{pick_save_file, reveal_in_explorer};
+use crate::system::network::{
+    self, DnsCacheRecord, HopTimeouts, NetworkCategory, ProbeResult, RouteEntry, UrlCheck, WolTarget,
+};
+use crate::system::ports::ConnectOutcome;
+use crate::system::restore;
+use crate::system::settings::{self, AppSettings};
+use crate::ui::components::{
+    ActionButton, ConfirmDialog, RestorePointOption, SortableHeader, SortDirection, StaticHeader,
+};
+
+/// Seconds between URL check passes when auto-refresh is on
+const URL_AUTO_REFRESH_SECS: u64 = 30;
+
+/// Seconds between adapter throughput samples
+const ADAPTER_STATS_INTERVAL_SECS: u64 = 2;
+
+/// Echo request counts offered in the ping count selector
+const PING_COUNTS: [u32; 5] = [1, 4, 10, 20, 50];
+
+/// How long Port Test waits for name resolution and for the connect
+const PORT_TEST_TIMEOUT_MS: u64 = 3000;
+
+/// Certificates expiring within this many days are flagged
+const CERT_EXPIRY_WARNING_DAYS: i64 = 14;
+
+/// Latency monitor: one echo request per interval, each waiting up to the timeout
+const LATENCY_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+const LATENCY_TIMEOUT_MS: u32 = 1000;
+
+/// Port Test results kept for comparison
+const PORT_TEST_HISTORY_LEN: usize = 10;
+
+/// Hop limits offered in the traceroute selector
+const TRACE_MAX_HOPS: [u32; 4] = [10, 15, 30, 64];
+
+/// Routing table columns
+const ROUTE_COLUMNS: &[TableColumn<RouteEntry, RoutesSortColumn>] = &[
+    TableColumn {
+        id: RoutesSortColumn::Destination,
+        label: "Destination",
+        label_key: "network.route-col.destination",
+        text: |r| r.destination.clone(),
+        compare: |a, b| {
+            let key = |r: &RouteEntry| (r.ipv6, r.destination.parse::<std::net::IpAddr>().ok(), r.prefix_len);
+            key(a).cmp(&key(b))
+        },
+        descending_first: false,
+    },
+    TableColumn {
+        id: RoutesSortColumn::Netmask,
+        label: "Netmask",
+        label_key: "network.route-col.netmask",
+        text: |r| r.netmask(),
+        compare: |a, b| a.prefix_len.cmp(&b.prefix_len),
+        descending_first: false,
+    },
+    TableColumn {
+        id: RoutesSortColumn::Gateway,
+        label: "Gateway",
+        label_key: "network.route-col.gateway",
+        text: |r| if r.is_on_link() { "On-link".to_string() } else { r.gateway.clone() },
+        compare: |a, b| a.gateway.cmp(&b.gateway),
+        descending_first: false,
+    },
+    TableColumn {
+        id: RoutesSortColumn::Interface,
+        label: "Interface",
+        label_key: "network.route-col.interface",
+        text: |r| r.interface.clone(),
+        compare: |a, b| a.interface.to_lowercase().cmp(&b.interface.to_lowercase()),
+        descending_first: false,
+    },
+    TableColumn {
+        id: RoutesSortColumn::Metric,
+        label: "Metric",
+        label_key: "network.route-col.metric",
+        text: |r| r.metric.to_string(),
+        compare: |a, b| a.metric.cmp(&b.metric),
+        descending_first: false,
+    },
+];
+
+/// DNS cache columns
+const DNS_CACHE_COLUMNS: &[TableColumn<DnsCacheRecord, DnsCacheSortColumn>] = &[
+    TableColumn {
+        id: DnsCacheSortColumn::Name,
+        label: "Name",
+        label_key: "network.dns-col.name",
+        text: |r| r.name.clone(),
+        compare: |a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+        descending_first: false,
+    },
+    TableColumn {
+        id: DnsCacheSortColumn::Type,
+        label: "Type",
+        label_key: "network.dns-col.type",
+        text: |r| r.record_type.clone(),
+        compare: |a, b| a.record_type.cmp(&b.record_type),
+        descending_first: false,
+    },
+    TableColumn {
+        id: DnsCacheSortColumn::Data,
+        label: "Data",
+        label_key: "network.dns-col.data",
+        text: |r| r.data.clone(),
+        compare: |a, b| a.data.cmp(&b.data),
+        descending_first: false,
+    },
+    TableColumn {
+        id: DnsCacheSortColumn::Ttl,
+        label: "TTL",
+        label_key: "network.dns-col.ttl",
+        text: |r| format!("{}s", r.ttl),
+        compare: |a, b| a.ttl.cmp(&b.ttl),
+        descending_first: true,
+    },
+    TableColumn {
+        id: DnsCacheSortColumn::Section,
+        label: "Section",
+        label_key: "network.dns-col.section",
+        text: |r| r.section.clone(),
+        compare: |a, b| a.section.cmp(&b.section),
+        descending_first: false,
+    },
+];
+
+/// Output line class that highlights traceroute hops with unanswered probes
+fn trace_line_class(line: String) -> String {
+    match network::parse_trace_hop(&line) {
+        Some(HopTimeouts::All) => "output-line-error",
+        Some(HopTimeouts::Some) => "output-line-warning",
+        _ => "",
+    }
+    .to_string()
+}
+
+/// Check every saved URL and store the results
+fn run_url_checks(mut state: Signal<NetworkPageState>, app_settings: Signal<AppSettings>) {
+    let checks = app_settings().url_checks.clone();
+    if checks.is_empty() || state().checking_urls {
+        return;
+    }
+    state.write().checking_urls = true;
+
+    spawn(async move {
+        let results = network::check_urls(&checks).await;
+        let mut s = state.write();
+        s.url_results = results;
+        s.checking_urls = false;
+    });
+}
+
+/// Create a restore point before a risky action
+///
+/// On failure the page is reset and the reason shown, and the action should not run.
+async fn restore_point_first(mut state: Signal<NetworkPageState>, description: &str) -> Result<CommandOutput, ()> {
+    state.write().status_message = t!("network.restore-creating");
+
+    let (outcome, output) = restore::create_restore_point(description).await;
+    if outcome.safe_to_continue() {
+        return Ok(output);
+    }
+
+    let mut s = state.write();
+    s.command_outputs = vec![output];
+    s.is_running = false;
+    s.running_action = String::new();
+    s.status_message = t!("network.restore-cancelled", reason = outcome.message());
+    s.status_type = "error".to_string();
+    Err(())
+}
+
+/// Format a duration as h:mm:ss or m:ss
+fn format_elapsed(secs: u64) -> String {
+    if secs >= 3600 {
+        format!("{}:{:02}:{:02}", secs / 3600, (secs % 3600) / 60, secs % 60)
+    } else {
+        format!("{}:{:02}", secs / 60, secs % 60)
+    }
+}
+
+/// Format a byte count with a binary unit (B, KB, MB, GB)
+fn format_bytes(bytes: f64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut value = bytes;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{:.0} {}", value, UNITS[unit])
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+/// Ping `target` with the page's count/continuous options, streaming replies into the output panel
+fn run_ping(mut state: Signal<NetworkPageState>, target: String) {
+    if let Err(e) = network::validate_host(&target) {
+        state.write().status_message = e;
+        state.write().status_type = "warning".to_string();
+        return;
+    }
+    let count = state().ping_count;
+    let continuous = state().ping_continuous;
+    let cancel = crate::system::command::CancelFlag::default();
+    {
+        let mut s = state.write();
+        s.is_running = true;
+        s.running_action = "Ping".to_string();
+        s.ping_cancel = cancel.clone();
+        s.status_message = if continuous {
+            t!("network.pinging-continuous", target = target)
+        } else {
+            t!("network.pinging", target = target)
+        };
+        s.status_type = String::new();
+    }
+
+    spawn(async move {
+        let mut lines: Vec<String> = Vec::new();
+        let output = network::ping_host(&target, count, continuous, cancel.clone(), |line| {
+            lines.push(line.to_string());
+            state.write().command_outputs = vec![CommandOutput {
+                command: format!("ping {}", target),
+                stdout: lines.join("\n"),
+                ..Default::default()
+            }];
+        })
+        .await;
+        let stopped = cancel.load(std::sync::atomic::Ordering::Relaxed);
+        let success = output.succeeded();
+
+        let mut s = state.write();
+        s.command_outputs = vec![output];
+        s.is_running = false;
+        s.running_action = String::new();
+
+        if stopped {
+            s.status_message = t!("network.ping-stopped", target = target);
+            s.status_type = "info".to_string();
+        } else if success {
+            s.status_message = t!("network.ping-ok", target = target);
+            s.status_type = "success".to_string();
+        } else {
+            s.status_message = t!("network.ping-failed", target = target);
+            s.status_type = "error".to_string();
+        }
+    });
+}
+
+/// Reload the IPv4 and IPv6 routing tables
+fn refresh_routes(mut state: Signal<NetworkPageState>) {
+    state.write().loading_routes = true;
+    spawn(async move {
+        let (routes, _) = network::get_route_table().await;
+        let mut s = state.write();
+        match routes {
+            Ok(routes) => {
+                s.routes = routes;
+                s.routes_error = None;
+            }
+            Err(e) => s.routes_error = Some(e),
+        }
+        s.loading_routes = false;
+    });
+}
+
+/// Reload the DNS client cache
+fn refresh_dns_cache(mut state: Signal<NetworkPageState>) {
+    state.write().loading_dns_cache = true;
+    spawn(async move {
+        let (records, _) = network::get_dns_cache().await;
+        let mut s = state.write();
+        match records {
+            Ok(records) => {
+                s.dns_cache = Some(records);
+                s.dns_cache_error = None;
+            }
+            Err(e) => s.dns_cache_error = Some(e),
+        }
+        s.loading_dns_cache = false;
+    });
+}
+
+/// Reload the Public/Private category of each connected network
+fn refresh_profiles(mut state: Signal<NetworkPageState>) {
+    state.write().loading_profiles = true;
+    spawn(async move {
+        let (profiles, _) = network::get_connection_profiles().await;
+        let mut s = state.write();
+        match profiles {
+            Ok(profiles) => {
+                s.connection_profiles = profiles;
+                s.profiles_error = None;
+            }
+            Err(e) => s.profiles_error = Some(e),
+        }
+        s.loading_profiles = false;
+    });
+}
+
+/// Start or stop the latency monitor
+///
+/// The loop is spawned on the root scope so it keeps sampling while another page is open.
+fn toggle_latency_monitor(mut state: Signal<NetworkPageState>) {
+    if state.peek().latency_monitoring {
+        state.peek().latency_cancel.store(true, std::sync::atomic::Ordering::Relaxed);
+        state.write().latency_monitoring = false;
+        return;
+    }
+
+    let host = state().ping_target.trim().to_string();
+    if let Err(e) = network::validate_host(&host) {
+        state.write().status_message = e;
+        state.write().status_type = "warning".to_string();
+        return;
+    }
+    let cancel = crate::system::command::CancelFlag::default();
+    {
+        let mut s = state.write();
+        s.latency_monitoring = true;
+        s.latency_host = host.clone();
+        s.latency_samples.clear();
+        s.latency_cancel = cancel.clone();
+    }
+
+    spawn_forever(async move {
+        use std::sync::atomic::Ordering;
+        while !cancel.load(Ordering::Relaxed) {
+            let started = std::time::Instant::now();
+            let sample = network::ping_once(&host, LATENCY_TIMEOUT_MS).await;
+            if cancel.load(Ordering::Relaxed) {
+                break;
+            }
+            state.write().push_latency_sample(sample);
+            tokio::time::sleep(LATENCY_INTERVAL.saturating_sub(started.elapsed())).await;
+        }
+    });
+}
+
+/// Reload the network adapter list
+fn refresh_adapters(mut state: Signal<NetworkPageState>) {
+    state.write().loading_adapters = true;
+    spawn(async move {
+        let adapters = network::get_network_adapters().await;
+        let mut s = state.write();
+        match adapters {
+            Ok(adapters) => {
+                s.adapters = adapters;
+                s.adapters_error = None;
+            }
+            Err(e) => s.adapters_error = Some(e),
+        }
+        s.loading_adapters = false;
+    });
+}
+
+/// Reload WinHTTP and per-user proxy settings
+fn refresh_proxy(mut state: Signal<NetworkPageState>) {
+    state.write().loading_proxy = true;
+    spawn(async move {
+        let proxy = network::get_proxy_settings().await;
+        let mut s = state.write();
+        match proxy {
+            Ok(proxy) => {
+                s.proxy = Some(proxy);
+                s.proxy_error = None;
+            }
+            Err(e) => s.proxy_error = Some(e),
+        }
+        s.loading_proxy = false;
+    });
+}
+
+/// Send a magic packet and report the outcome in the status bar
+fn wake(mut state: Signal<NetworkPageState>, label: &str, mac: &str, broadcast: Option<&str>) {
+    let mut s = state.write();
+    match network::send_wol(mac, broadcast) {
+        Ok(()) => {
+            s.status_message = t!("network.wol-sent", target = label, broadcast = broadcast.unwrap_or("255.255.255.255"));
+            s.status_type = "success".to_string();
+        }
+        Err(e) => {
+            s.status_message = e;
+            s.status_type = "error".to_string();
+        }
+    }
+}
+
+/// Persist settings, reporting failures in the status bar
+fn save_settings(mut state: Signal<NetworkPageState>, app_settings: Signal<AppSettings>) {
+    if let Err(e) = settings::save(&app_settings()) {
+        state.write().status_message = e;
+        state.write().status_type = "error".to_string();
+    }
+}
 
 /// Network page with quick fixes and adapter info
 #[component]
 pub fn NetworkPage(is_admin: bool) -> Element {
     // Get persistent state from context
     let mut state: Signal<NetworkPageState> = use_context();
+    let mut app_settings: Signal<AppSettings> = use_context();
+    let mut ports_state: Signal<PortsPageState> = use_context();
+    let mut current_page: Signal<CurrentPage> = use_context();
+
+    let mut now = use_signal(std::time::SystemTime::now);
+    // Risky action awaiting confirmation, and whether to create a restore point first
+    let mut confirm_action = use_signal(|| None::<&'static str>);
+    let mut with_restore_point = use_signal(|| false);
+    // Also reset IPv6 when resetting the TCP/IP stack
+    // Adapter awaiting restart confirmation
+    let mut pending_adapter_restart = use_signal(|| None::<String>);
+    // Connection (interface alias) and category awaiting confirmation
+    let mut pending_profile = use_signal(|| None::<(String, NetworkCategory)>);
+    let mut reset_ipv6 = use_signal(|| true);
+
+    // Leaving the page drops its tasks (which kills ping and tracert); clear the running state with them
+    use_drop(move || {
+        let mut s = state.write();
+        if s.is_running {
+            s.ping_cancel.store(true, std::sync::atomic::Ordering::Relaxed);
+            s.trace_cancel.store(true, std::sync::atomic::Ordering::Relaxed);
+            s.is_running = false;
+            s.running_action = String::new();
+            s.status_message = String::new();
+            s.status_type = String::new();
+        }
+    });
+
+    // Detect a capture left running (possibly from a previous session) when the page opens
+    use_hook(move || {
+        spawn(async move {
+            let (status, _) = network::capture_status().await;
+            let mut s = state.write();
+            s.capture_running = status.running;
+            if let Some(file) = status.trace_file {
+                if status.running && s.capture_started.is_none() {
+                    s.capture_started = std::fs::metadata(&file).and_then(|m| m.created()).ok();
+                }
+                s.capture_file = file;
+            }
+            if !status.running {
+                s.capture_started = None;
+            }
+        });
+    });
+
+    // Load adapters, proxy settings, routes, and connection profiles the first time the page opens
+    use_hook(move || {
+        if state.peek().adapters.is_empty() {
+            refresh_adapters(state);
+        }
+        if state.peek().proxy.is_none() {
+            refresh_proxy(state);
+        }
+        if state.peek().routes.is_empty() {
+            refresh_routes(state);
+        }
+        if state.peek().connection_profiles.is_empty() {
+            refresh_profiles(state);
+        }
+    });
+
+    // Tick the recording indicator once a second
+    use_future(move || async move {
+        loop {
+            tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+            if state.peek().capture_running {
+                now.set(std::time::SystemTime::now());
+            }
+        }
+    });
+
+    // Sample adapter throughput every 2 seconds while the page is mounted
+    use_future(move || async move {
+        loop {
+            let stats = tokio::task::spawn_blocking(network::get_adapter_stats).await.unwrap_or_default();
+            state.write().adapter_stats = stats;
+            tokio::time::sleep(tokio::time::Duration::from_secs(ADAPTER_STATS_INTERVAL_SECS)).await;
+        }
+    });
+
+    // Periodic URL re-check while auto-refresh is on
+    use_future(move || async move {
+        loop {
+            tokio::time::sleep(tokio::time::Duration::from_secs(URL_AUTO_REFRESH_SECS)).await;
+            if state.peek().url_auto_refresh {
+                run_url_checks(state, app_settings);
+            }
+        }
+    });
+
+    // Add a URL check from the input fields
+    let on_add_url = move |_| {
+        let name = state().new_url_name.trim().to_string();
+        let url = state().new_url.trim().to_string();
+        let status_text = state().new_url_status.trim().to_string();
+
+        if !url.starts_with("http://") && !url.starts_with("https://") {
+            state.write().status_message = t!("network.url-scheme");
+            state.write().status_type = "warning".to_string();
+            return;
+        }
+        let expected_status = if status_text.is_empty() {
+            None
+        } else {
+            match status_text.parse::<u16>() {
+                Ok(code) if (100..600).contains(&code) => Some(code),
+                _ => {
+                    state.write().status_message = t!("network.url-status-invalid");
+                    state.write().status_type = "warning".to_string();
+                    return;
+                }
+            }
+        };
+        if app_settings().url_checks.iter().any(|c| c.url == url) {
+            state.write().status_message = t!("network.url-duplicate", url = url);
+            state.write().status_type = "warning".to_string();
+            return;
+        }
+
+        let name = if name.is_empty() { url.clone() } else { name };
+        app_settings.write().url_checks.push(UrlCheck { name, url, expected_status });
+        save_settings(state, app_settings);
+
+        let mut s = state.write();
+        s.new_url_name = String::new();
+        s.new_url = String::new();
+        s.new_url_status = String::new();
+        s.url_results = Vec::new();
+    };
+
+    // Check all URLs now
+    let on_check_urls = move |_| run_url_checks(state, app_settings);
 
     // Flush DNS handler
     let on_flush_dns = move |_| {
         state.write().is_running = true;
         state.write().running_action = "Flush DNS".to_string();
-        state.write().status_message = "Flushing DNS cache...".to_string();
+        state.write().status_message = t!("network.dns-flushing");
         state.write().status_type = String::new();
 
         spawn(async move {
@@ -30,12 +564,19 @@ pub fn NetworkPage(is_admin: bool) -> Element {
             s.running_action = String::new();
 
             if success {
-                s.status_message = "DNS cache flushed successfully".to_string();
+                s.status_message = t!("network.dns-flushed");
                 s.status_type = "success".to_string();
             } else {
-                s.status_message = "Failed to flush DNS cache".to_string();
+                s.status_message = t!("network.dns-flush-failed");
                 s.status_type = "error".to_string();
             }
+
+            // Show what survived the flush (hosts file entries are reloaded immediately)
+            let cache_shown = s.dns_cache.is_some();
+            drop(s);
+            if cache_shown {
+                refresh_dns_cache(state);
+            }
         });
     };
 
@@ -43,7 +584,7 @@ pub fn NetworkPage(is_admin: bool) -> Element {
     let on_renew_ip = move |_| {
         state.write().is_running = true;
         state.write().running_action = "Renew IP".to_string();
-        state.write().status_message = "Releasing and renewing IP...".to_string();
+        state.write().status_message = t!("network.ip-renewing");
         state.write().status_type = String::new();
 
         spawn(async move {
@@ -56,117 +597,708 @@ pub fn NetworkPage(is_admin: bool) -> Element {
             s.running_action = String::new();
 
             if all_success {
-                s.status_message = "IP renewed successfully".to_string();
+                s.status_message = t!("network.ip-renewed");
                 s.status_type = "success".to_string();
             } else {
-                s.status_message = "IP renewal completed with issues".to_string();
+                s.status_message = t!("network.ip-renew-issues");
                 s.status_type = "warning".to_string();
             }
         });
     };
 
-    // Winsock Reset handler
-    let on_winsock_reset = move |_| {
+    // Winsock Reset handler (runs after the confirm dialog)
+    let mut on_winsock_reset = move |with_restore_point: bool| {
+        confirm_action.set(None);
         state.write().is_running = true;
         state.write().running_action = "Winsock Reset".to_string();
-        state.write().status_message = "Resetting Winsock...".to_string();
+        state.write().status_message = t!("network.winsock-resetting");
         state.write().status_type = String::new();
 
         spawn(async move {
+            let mut outputs = Vec::new();
+            if with_restore_point {
+                match restore_point_first(state, &t!("network.restore-before-winsock")).await {
+                    Ok(output) => outputs.push(output),
+                    Err(()) => return,
+                }
+                state.write().status_message = t!("network.winsock-resetting");
+            }
+
             let output = network::winsock_reset().await;
             let success = output.succeeded();
-            
+            outputs.push(output);
+
             let mut s = state.write();
-            s.command_outputs = vec![output];
+            s.command_outputs = outputs;
             s.is_running = false;
             s.running_action = String::new();
 
             if success {
-                s.status_message = "Winsock reset successfully. Reboot may be required.".to_string();
+                s.status_message = t!("network.winsock-reset");
+                s.status_type = "success".to_string();
+            } else {
+                s.status_message = t!("network.winsock-failed");
+                s.status_type = "error".to_string();
+            }
+        });
+    };
+
+    // TCP/IP stack reset handler (runs after the confirm dialog)
+    let mut on_ip_reset = move |with_restore_point: bool| {
+        confirm_action.set(None);
+        state.write().is_running = true;
+        state.write().running_action = "TCP/IP Reset".to_string();
+        state.write().status_message = t!("network.ip-resetting");
+        state.write().status_type = String::new();
+
+        spawn(async move {
+            let mut outputs = Vec::new();
+            if with_restore_point {
+                match restore_point_first(state, &t!("network.restore-before-ip")).await {
+                    Ok(output) => outputs.push(output),
+                    Err(()) => return,
+                }
+                state.write().status_message = t!("network.ip-resetting");
+            }
+
+            let reset = network::ip_stack_reset(reset_ipv6()).await;
+            let all_success = reset.iter().all(|o| o.succeeded());
+            outputs.extend(reset);
+
+            let mut s = state.write();
+            s.command_outputs = outputs;
+            s.is_running = false;
+            s.running_action = String::new();
+
+            if all_success {
+                s.status_message = t!("network.ip-reset");
                 s.status_type = "success".to_string();
             } else {
-                s.status_message = "Winsock reset failed. Run as Administrator.".to_string();
+                s.status_message = t!("network.ip-reset-failed");
                 s.status_type = "error".to_string();
             }
         });
     };
 
-    // Restart HTTP Service handler (clears orphaned HTTP sockets)
-    let on_restart_http = move |_| {
+    // Restart HTTP Service handler (clears orphaned HTTP sockets, runs after the confirm dialog)
+    let mut on_restart_http = move |with_restore_point: bool| {
+        confirm_action.set(None);
         state.write().is_running = true;
         state.write().running_action = "Restart HTTP".to_string();
-        state.write().status_message = "Restarting HTTP service (http.sys)...".to_string();
+        state.write().status_message = t!("network.http-restarting");
         state.write().status_type = String::new();
 
         spawn(async move {
-            let outputs = network::restart_http_service().await;
-            let all_success = outputs.iter().all(|o| o.succeeded());
-            
+            let mut outputs = Vec::new();
+            if with_restore_point {
+                match restore_point_first(state, &t!("network.restore-before-http")).await {
+                    Ok(output) => outputs.push(output),
+                    Err(()) => return,
+                }
+                state.write().status_message = t!("network.http-restarting");
+            }
+
+            let restart = network::restart_http_service().await;
+            let all_success = restart.iter().all(|o| o.succeeded());
+            outputs.extend(restart);
+
             let mut s = state.write();
             s.command_outputs = outputs;
             s.is_running = false;
             s.running_action = String::new();
 
-            if all_success {
-                s.status_message = "HTTP service restarted. Orphaned HTTP sockets should be cleared.".to_string();
+            if all_success {
+                s.status_message = t!("network.http-restarted");
+                s.status_type = "success".to_string();
+            } else {
+                s.status_message = t!("network.http-restart-failed");
+                s.status_type = "error".to_string();
+            }
+        });
+    };
+
+    // Ping handler - replies stream into the output panel
+    let on_ping = move |_| {
+        let target = state().ping_target.trim().to_string();
+        run_ping(state, target);
+    };
+
+    // Ping the default gateway - if it doesn't answer, the problem is on the local network
+    let on_ping_gateway = move |_| {
+        let Some(gateway) = network::default_gateway(&state().routes).map(str::to_string) else {
+            return;
+        };
+        state.write().ping_target = gateway.clone();
+        run_ping(state, gateway);
+    };
+
+    // Disable and re-enable an adapter (runs after the confirm dialog)
+    let on_restart_adapter = move |_| {
+        let Some(name) = pending_adapter_restart() else {
+            return;
+        };
+        pending_adapter_restart.set(None);
+        state.write().is_running = true;
+        state.write().running_action = "Restart Adapter".to_string();
+        state.write().status_message = t!("network.adapter-restarting", name = name);
+        state.write().status_type = String::new();
+
+        spawn(async move {
+            let outputs = network::restart_adapter(&name).await;
+            let disabled = outputs.first().is_some_and(|o| o.succeeded());
+            let all_success = outputs.len() == 2 && outputs.iter().all(|o| o.succeeded());
+
+            {
+                let mut s = state.write();
+                s.command_outputs = outputs;
+                s.is_running = false;
+                s.running_action = String::new();
+
+                if all_success {
+                    s.status_message = t!("network.adapter-restarted", name = name);
+                    s.status_type = "success".to_string();
+                } else if disabled {
+                    s.status_message = t!("network.adapter-stuck-disabled", name = name);
+                    s.status_type = "error".to_string();
+                } else {
+                    s.status_message = t!("network.adapter-restart-failed", name = name);
+                    s.status_type = "error".to_string();
+                }
+            }
+            refresh_adapters(state);
+        });
+    };
+
+    // Switch a connection between Public and Private (runs after the confirm dialog)
+    let on_set_profile = move |_| {
+        let Some((alias, category)) = pending_profile() else {
+            return;
+        };
+        pending_profile.set(None);
+        state.write().is_running = true;
+        state.write().running_action = "Set Profile".to_string();
+        state.write().status_message = t!("network.profile-setting", alias = alias, category = t!(category.label_key()));
+        state.write().status_type = String::new();
+
+        spawn(async move {
+            let output = network::set_connection_profile(&alias, category).await;
+            let success = output.succeeded();
+
+            {
+                let mut s = state.write();
+                s.command_outputs = vec![output];
+                s.is_running = false;
+                s.running_action = String::new();
+
+                if success {
+                    s.status_message = t!("network.profile-set", alias = alias, category = t!(category.label_key()));
+                    s.status_type = "success".to_string();
+                } else {
+                    s.status_message = t!("network.profile-failed", alias = alias);
+                    s.status_type = "error".to_string();
+                }
+            }
+            refresh_profiles(state);
+        });
+    };
+
+    // Reset WinHTTP proxy handler
+    let on_reset_winhttp_proxy = move |_| {
+        state.write().is_running = true;
+        state.write().running_action = "Reset WinHTTP Proxy".to_string();
+        state.write().status_message = t!("network.winhttp-resetting");
+        state.write().status_type = String::new();
+
+        spawn(async move {
+            let output = network::reset_winhttp_proxy().await;
+            let success = output.succeeded();
+
+            {
+                let mut s = state.write();
+                s.command_outputs = vec![output];
+                s.is_running = false;
+                s.running_action = String::new();
+
+                if success {
+                    s.status_message = t!("network.winhttp-reset");
+                    s.status_type = "success".to_string();
+                } else {
+                    s.status_message = t!("network.winhttp-failed");
+                    s.status_type = "error".to_string();
+                }
+            }
+            refresh_proxy(state);
+        });
+    };
+
+    // Disable user proxy handler
+    let on_disable_user_proxy = move |_| {
+        state.write().is_running = true;
+        state.write().running_action = "Disable User Proxy".to_string();
+        state.write().status_message = t!("network.user-proxy-disabling");
+        state.write().status_type = String::new();
+
+        spawn(async move {
+            let output = network::disable_user_proxy().await;
+            let success = output.succeeded();
+
+            {
+                let mut s = state.write();
+                s.command_outputs = vec![output];
+                s.is_running = false;
+                s.running_action = String::new();
+
+                if success {
+                    s.status_message = t!("network.user-proxy-disabled");
+                    s.status_type = "success".to_string();
+                } else {
+                    s.status_message = t!("network.user-proxy-failed");
+                    s.status_type = "error".to_string();
+                }
+            }
+            refresh_proxy(state);
+        });
+    };
+
+    // Remote port test handler - results are prepended so several targets can be compared
+    let on_port_test = move |_| {
+        let host = state().port_test_host.trim().to_string();
+        if let Err(e) = network::validate_host(&host) {
+            state.write().status_message = e;
+            state.write().status_type = "warning".to_string();
+            return;
+        }
+        let port = match state().port_test_port.trim().parse::<u16>() {
+            Ok(port) if port > 0 => port,
+            _ => {
+                state.write().status_message = t!("network.port-invalid");
+                state.write().status_type = "warning".to_string();
+                return;
+            }
+        };
+        state.write().testing_port = true;
+
+        spawn(async move {
+            let test = network::test_remote_port(&host, port, PORT_TEST_TIMEOUT_MS).await;
+            let (message, status_type) = match &test.outcome {
+                ConnectOutcome::Connected => (t!("network.port-test-ok", host = host, port = port, ms = test.elapsed_ms), "success"),
+                ConnectOutcome::Refused => (t!("network.port-test-refused", host = host, port = port), "error"),
+                ConnectOutcome::TimedOut => (t!("network.port-test-timeout", host = host, port = port, ms = PORT_TEST_TIMEOUT_MS), "warning"),
+                ConnectOutcome::Failed(e) => (e.clone(), "error"),
+            };
+
+            let mut s = state.write();
+            s.port_test_results.insert(0, test);
+            s.port_test_results.truncate(PORT_TEST_HISTORY_LEN);
+            s.testing_port = false;
+            s.status_message = message;
+            s.status_type = status_type.to_string();
+        });
+    };
+
+    // Diagnose DNS handler - runs each check in turn and shows a checklist
+    let on_diagnose_dns = move |_| {
+        let host = state().dns_test_host.trim().to_string();
+        if let Err(e) = network::validate_host(&host) {
+            state.write().status_message = e;
+            state.write().status_type = "warning".to_string();
+            return;
+        }
+        state.write().is_running = true;
+        state.write().running_action = "Diagnose DNS".to_string();
+        state.write().status_message = t!("network.dns-diagnosing", host = host);
+        state.write().status_type = String::new();
+        state.write().dns_diagnosis = Vec::new();
+
+        spawn(async move {
+            let (steps, outputs) = network::diagnose_dns(&host).await;
+            let failed = steps.iter().filter(|s| !s.passed).count();
+
+            let mut s = state.write();
+            s.command_outputs = outputs;
+            s.dns_diagnosis = steps;
+            s.is_running = false;
+            s.running_action = String::new();
+
+            if failed == 0 {
+                s.status_message = t!("network.dns-healthy");
+                s.status_type = "success".to_string();
+            } else {
+                s.status_message = t!("network.dns-failed-checks", count = failed);
+                s.status_type = "warning".to_string();
+            }
+        });
+    };
+
+    // Wake the MAC in the form
+    let on_wake = move |_| {
+        let mac = state().wol_mac.trim().to_string();
+        let broadcast = state().wol_broadcast.trim().to_string();
+        wake(state, &mac, &mac, Some(broadcast.as_str()).filter(|b| !b.is_empty()));
+    };
+
+    // Save the form as a Wake-on-LAN target
+    let on_save_wol = move |_| {
+        let mac = state().wol_mac.trim().to_string();
+        let broadcast = state().wol_broadcast.trim().to_string();
+        if let Err(e) = network::parse_mac(&mac) {
+            state.write().status_message = e;
+            state.write().status_type = "warning".to_string();
+            return;
+        }
+        if !broadcast.is_empty() && broadcast.parse::<std::net::Ipv4Addr>().is_err() {
+            state.write().status_message = t!("network.wol-broadcast-invalid", value = broadcast);
+            state.write().status_type = "warning".to_string();
+            return;
+        }
+        if app_settings().wol_targets.iter().any(|t| network::parse_mac(&t.mac).ok() == network::parse_mac(&mac).ok()) {
+            state.write().status_message = t!("network.wol-duplicate", mac = mac);
+            state.write().status_type = "warning".to_string();
+            return;
+        }
+
+        let name = state().wol_name.trim().to_string();
+        let name = if name.is_empty() { mac.clone() } else { name };
+        app_settings.write().wol_targets.push(WolTarget {
+            name,
+            mac,
+            broadcast: Some(broadcast).filter(|b| !b.is_empty()),
+        });
+        save_settings(state, app_settings);
+
+        let mut s = state.write();
+        s.wol_name = String::new();
+        s.wol_mac = String::new();
+        s.wol_broadcast = String::new();
+    };
+
+    // Traceroute handler - hops stream into the output panel as tracert reports them
+    let on_trace = move |_| {
+        let target = state().ping_target.trim().to_string();
+        if let Err(e) = network::validate_host(&target) {
+            state.write().status_message = e;
+            state.write().status_type = "warning".to_string();
+            return;
+        }
+        let max_hops = state().trace_max_hops;
+        let cancel = crate::system::command::CancelFlag::default();
+        {
+            let mut s = state.write();
+            s.is_running = true;
+            s.running_action = "Traceroute".to_string();
+            s.trace_cancel = cancel.clone();
+            s.status_message = t!("network.tracing", target = target, hops = max_hops);
+            s.status_type = String::new();
+        }
+
+        spawn(async move {
+            let mut lines: Vec<String> = Vec::new();
+            let output = network::trace_route(&target, max_hops, cancel.clone(), |line| {
+                lines.push(line.to_string());
+                state.write().command_outputs = vec![CommandOutput {
+                    command: format!("tracert {}", target),
+                    stdout: lines.join("\n"),
+                    ..Default::default()
+                }];
+            })
+            .await;
+            let cancelled = cancel.load(std::sync::atomic::Ordering::Relaxed);
+            let success = output.succeeded();
+
+            let mut s = state.write();
+            s.command_outputs = vec![output];
+            s.is_running = false;
+            s.running_action = String::new();
+
+            if cancelled {
+                s.status_message = t!("network.trace-cancelled", target = target);
+                s.status_type = "info".to_string();
+            } else if success {
+                s.status_message = t!("network.trace-complete", target = target);
+                s.status_type = "success".to_string();
+            } else {
+                s.status_message = t!("network.trace-failed", target = target);
+                s.status_type = "error".to_string();
+            }
+        });
+    };
+
+    // HTTP HEAD handler
+    let on_http_head = move |_| {
+        let url = state().http_url.trim().to_string();
+        if let Err(e) = network::validate_endpoint_url(&url) {
+            state.write().status_message = e;
+            state.write().status_type = "warning".to_string();
+            return;
+        }
+        state.write().is_running = true;
+        state.write().running_action = "HTTP HEAD".to_string();
+        state.write().status_message = t!("network.http-fetching", url = url);
+        state.write().status_type = String::new();
+        state.write().tls_certificate = None;
+
+        spawn(async move {
+            let report = network::http_head(&url).await;
+
+            let mut s = state.write();
+            s.is_running = false;
+            s.running_action = String::new();
+
+            let report = match report {
+                Ok(report) => report,
+                Err(e) => {
+                    s.status_message = e;
+                    s.status_type = "error".to_string();
+                    return;
+                }
+            };
+            s.command_outputs = report.outputs;
+            s.tls_certificate = report.certificate.clone();
+
+            match (&report.certificate, report.succeeded) {
+                (Some(Ok(cert)), _) if cert.errors.is_some() => {
+                    s.status_message = t!("network.cert-problem", error = cert.errors.as_deref().unwrap_or_default());
+                    s.status_type = "error".to_string();
+                }
+                (Some(Ok(cert)), _) if cert.days_left() < 0 => {
+                    s.status_message = t!("network.cert-expired", days = -cert.days_left());
+                    s.status_type = "error".to_string();
+                }
+                (Some(Ok(cert)), true) if cert.days_left() < CERT_EXPIRY_WARNING_DAYS => {
+                    s.status_message = t!("network.cert-expiring", days = cert.days_left());
+                    s.status_type = "warning".to_string();
+                }
+                (_, true) => {
+                    s.status_message = t!("network.http-ok");
+                    s.status_type = "success".to_string();
+                }
+                (_, false) => {
+                    s.status_message = t!("network.http-failed");
+                    s.status_type = "error".to_string();
+                }
+            }
+        });
+    };
+
+    // Network snapshot handler - asks where to save, then runs the bundle with per-command progress
+    let on_snapshot = move |_| {
+        state.write().is_running = true;
+        state.write().running_action = "Snapshot".to_string();
+        state.write().status_message = t!("network.snapshot-choose");
+        state.write().status_type = String::new();
+
+        spawn(async move {
+            let Some(path) = pick_save_file(&network::snapshot_file_name(), &t!("network.snapshot-filter")).await
+            else {
+                let mut s = state.write();
+                s.is_running = false;
+                s.running_action = String::new();
+                s.status_message = t!("network.snapshot-cancelled");
+                s.status_type = "info".to_string();
+                return;
+            };
+
+            state.write().status_message = t!("network.snapshot-progress-start", total = network::SNAPSHOT_STEPS);
+            let outputs = network::capture_snapshot(|done, output| {
+                state.write().status_message =
+                    t!("network.snapshot-progress", done = done, total = network::SNAPSHOT_STEPS, command = output.command);
+            })
+            .await;
+            let written = std::fs::write(&path, network::format_snapshot(&outputs))
+                .map_err(|e| t!("network.snapshot-write-failed", path = path, error = e));
+
+            let mut s = state.write();
+            s.command_outputs = outputs;
+            s.is_running = false;
+            s.running_action = String::new();
+            match written {
+                Ok(()) => {
+                    s.status_message = t!("network.snapshot-saved", path = path);
+                    s.status_type = "success".to_string();
+                    drop(s);
+                    let _ = reveal_in_explorer(&path);
+                }
+                Err(e) => {
+                    s.status_message = e;
+                    s.status_type = "error".to_string();
+                }
+            }
+        });
+    };
+
+    // Public IP handler - shows the address (and location, if the endpoint reports it) in the status bar
+    let on_public_ip = move |_| {
+        let endpoint = app_settings().public_ip_endpoint.trim().to_string();
+        state.write().is_running = true;
+        state.write().running_action = "Public IP".to_string();
+        state.write().status_message = t!("network.public-ip-looking", endpoint = endpoint);
+        state.write().status_type = String::new();
+
+        spawn(async move {
+            let (result, output) = network::get_public_ip(&endpoint).await;
+
+            let mut s = state.write();
+            s.command_outputs = vec![output];
+            s.is_running = false;
+            s.running_action = String::new();
+
+            match result {
+                Ok(public) => {
+                    let mut message = t!("network.public-ip", ip = public.ip);
+                    if let Some(location) = &public.location {
+                        message.push_str(&format!(" - {}", location));
+                    }
+                    if let Some(org) = &public.org {
+                        message.push_str(&format!(" ({})", org));
+                    }
+                    s.status_message = message;
+                    s.status_type = "success".to_string();
+                }
+                Err(e) => {
+                    s.status_message = e;
+                    s.status_type = "error".to_string();
+                }
+            }
+        });
+    };
+
+    // Save the public IP endpoint once editing is done
+    let on_public_ip_endpoint = move |e: Event<FormData>| {
+        let endpoint = e.value().trim().to_string();
+        let endpoint = if endpoint.is_empty() {
+            network::DEFAULT_PUBLIC_IP_ENDPOINT.to_string()
+        } else {
+            endpoint
+        };
+        if let Err(e) = network::validate_endpoint_url(&endpoint) {
+            state.write().status_message = e;
+            state.write().status_type = "warning".to_string();
+            return;
+        }
+        if app_settings().public_ip_endpoint != endpoint {
+            app_settings.write().public_ip_endpoint = endpoint;
+            save_settings(state, app_settings);
+        }
+    };
+
+    // Start packet capture handler
+    let on_start_capture = move |_| {
+        let filter_text = state().capture_ip_filter.trim().to_string();
+        let ipv4_filter = if filter_text.is_empty() {
+            None
+        } else {
+            match filter_text.parse::<std::net::Ipv4Addr>() {
+                Ok(ip) => Some(ip),
+                Err(_) => {
+                    state.write().status_message = t!("network.capture-filter-invalid", value = filter_text);
+                    state.write().status_type = "warning".to_string();
+                    return;
+                }
+            }
+        };
+
+        state.write().is_running = true;
+        state.write().running_action = "Capture".to_string();
+        state.write().status_message = t!("network.capture-starting");
+        state.write().status_type = String::new();
+
+        spawn(async move {
+            // Never start a second capture on top of one that is already running
+            let (status, status_output) = network::capture_status().await;
+            if status.running {
+                let mut s = state.write();
+                s.command_outputs = vec![status_output];
+                s.capture_running = true;
+                if let Some(file) = status.trace_file {
+                    s.capture_file = file;
+                }
+                s.is_running = false;
+                s.running_action = String::new();
+                s.status_message = t!("network.capture-already-running");
+                s.status_type = "warning".to_string();
+                return;
+            }
+
+            let (file, output) = network::start_capture(ipv4_filter).await;
+            let success = output.succeeded();
+
+            let mut s = state.write();
+            s.command_outputs = vec![output];
+            s.is_running = false;
+            s.running_action = String::new();
+
+            if success {
+                s.capture_running = true;
+                s.capture_file = file;
+                s.capture_started = Some(std::time::SystemTime::now());
+                s.status_message = t!("network.capture-started");
                 s.status_type = "success".to_string();
             } else {
-                s.status_message = "HTTP service restart failed. Run as Administrator.".to_string();
+                s.status_message = t!("network.capture-start-failed");
                 s.status_type = "error".to_string();
             }
         });
     };
 
-
-    // Ping handler
-    let on_ping = move |_| {
+    // Stop packet capture handler - always allowed so a leftover capture can be stopped
+    let on_stop_capture = move |_| {
         state.write().is_running = true;
-        state.write().running_action = "Ping".to_string();
-        state.write().status_message = "Pinging 8.8.8.8...".to_string();
+        state.write().running_action = "Stop Capture".to_string();
+        state.write().status_message = t!("network.capture-stopping");
         state.write().status_type = String::new();
 
         spawn(async move {
-            let output = network::ping_host("8.8.8.8").await;
+            let output = network::stop_capture().await;
             let success = output.succeeded();
-            
+
             let mut s = state.write();
             s.command_outputs = vec![output];
             s.is_running = false;
             s.running_action = String::new();
+            s.capture_running = false;
+            s.capture_started = None;
 
             if success {
-                s.status_message = "Ping completed successfully".to_string();
+                s.status_message = t!("network.capture-saved", path = s.capture_file);
                 s.status_type = "success".to_string();
             } else {
-                s.status_message = "Ping failed - network may be down".to_string();
-                s.status_type = "error".to_string();
+                s.status_message = t!("network.capture-stop-failed");
+                s.status_type = "warning".to_string();
             }
         });
     };
 
-    // HTTP HEAD handler
-    let on_http_head = move |_| {
+    // Reveal the capture file in Explorer
+    let on_open_capture_folder = move |_| {
+        if let Err(e) = reveal_in_explorer(&state().capture_file) {
+            state.write().status_message = e;
+            state.write().status_type = "error".to_string();
+        }
+    };
+
+    // Connectivity matrix handler - all four probes run at once
+    let on_connectivity = move |_| {
         state.write().is_running = true;
-        state.write().running_action = "HTTP HEAD".to_string();
-        state.write().status_message = "Fetching headers from microsoft.com...".to_string();
+        state.write().running_action = "Connectivity".to_string();
+        state.write().status_message = t!("network.connectivity-checking");
         state.write().status_type = String::new();
 
         spawn(async move {
-            let output = network::http_head("https://www.microsoft.com").await;
-            let success = output.succeeded();
-            
+            let matrix = network::connectivity_matrix().await;
+            let probes = [&matrix.gateway, &matrix.dns, &matrix.https, &matrix.proxy];
+            let failed = probes.iter().filter(|p| !p.passed).count();
+
             let mut s = state.write();
-            s.command_outputs = vec![output];
             s.is_running = false;
             s.running_action = String::new();
-
-            if success {
-                s.status_message = "HTTP HEAD completed successfully".to_string();
+            if failed == 0 {
+                s.status_message = t!("network.connectivity-ok");
                 s.status_type = "success".to_string();
             } else {
-                s.status_message = "HTTP request failed".to_string();
+                s.status_message = t!("network.connectivity-failed", failed = failed, total = probes.len());
                 s.status_type = "error".to_string();
             }
+            s.connectivity = Some(matrix);
         });
     };
 
@@ -181,98 +1313,1205 @@ pub fn NetworkPage(is_admin: bool) -> Element {
     // Read current state
     let current_state = state();
     let outputs_for_panel = current_state.command_outputs.clone();
+    let url_checks = app_settings().url_checks.clone();
+    let capture_elapsed = current_state
+        .capture_started
+        .and_then(|start| now().duration_since(start).ok())
+        .map(|d| format_elapsed(d.as_secs()));
+
+    let ping_running = current_state.running_action == "Ping";
+    let dns_cache = current_state.dns_cache.clone().unwrap_or_default();
+    let visible_dns = current_state.dns_cache_table.visible_rows(&dns_cache, DNS_CACHE_COLUMNS);
+    let pinned_count = dns_cache.iter().filter(|r| r.from_hosts).count();
+    let visible_routes = current_state.route_table.visible_rows(&current_state.routes, ROUTE_COLUMNS);
+    let gateway = network::default_gateway(&current_state.routes).map(str::to_string);
+    let trace_running = current_state.running_action == "Traceroute";
 
     rsx! {
         // Page header
         header { class: "page-header",
-            h1 { class: "page-title", "🌐 Network Tools" }
-            p { class: "page-subtitle", "Quick fixes and diagnostics for network issues" }
+            h1 { class: "page-title", {t!("network.title")} }
+            p { class: "page-subtitle", {t!("network.subtitle")} }
         }
 
         // Split layout: scrollable controls top, fixed output bottom
         div { class: "page-split-layout",
             // Scrollable controls section
             div { class: "page-controls",
+                // Connectivity matrix: is it DNS, the proxy, or the pipe?
+                div { class: "section",
+                    div { class: "section-header",
+                        h3 { class: "section-title", {t!("network.connectivity")} }
+                        ActionButton {
+                            danger: DangerLevel::ReadOnly,
+                            class: "btn btn-primary btn-sm",
+                            disabled: current_state.is_running,
+                            title: t!("network.connectivity-hint"),
+                            onclick: on_connectivity,
+                            if current_state.running_action == "Connectivity" { {t!("network.checking")} } else { {t!("network.connectivity-check")} }
+                        }
+                    }
+                    if let Some(matrix) = &current_state.connectivity {
+                        div { class: "connectivity-grid",
+                            ConnectivityCell { label: t!("network.probe.gateway"), probe: matrix.gateway.clone(),
+                                ActionButton {
+                                    danger: DangerLevel::Modifying,
+                                    class: "btn btn-secondary btn-sm",
+                                    disabled: current_state.is_running,
+                                    onclick: on_renew_ip,
+                                    {t!("network.renew-ip-button")}
+                                }
+                            }
+                            ConnectivityCell { label: t!("network.probe.dns"), probe: matrix.dns.clone(),
+                                ActionButton {
+                                    danger: DangerLevel::Modifying,
+                                    class: "btn btn-secondary btn-sm",
+                                    disabled: current_state.is_running,
+                                    onclick: on_flush_dns,
+                                    {t!("network.flush-dns-button")}
+                                }
+                            }
+                            ConnectivityCell { label: t!("network.probe.https"), probe: matrix.https.clone(),
+                                ActionButton {
+                                    danger: DangerLevel::Modifying,
+                                    class: "btn btn-secondary btn-sm",
+                                    disabled: current_state.is_running,
+                                    onclick: on_renew_ip,
+                                    {t!("network.renew-ip-button")}
+                                }
+                            }
+                            ConnectivityCell { label: t!("network.probe.proxy"), probe: matrix.proxy.clone(),
+                                ActionButton {
+                                    danger: DangerLevel::Modifying,
+                                    class: "btn btn-secondary btn-sm",
+                                    disabled: current_state.is_running
+                                        || !current_state.proxy.as_ref().is_some_and(|p| p.user_proxy_enabled),
+                                    title: t!("network.user-proxy-disable-hint"),
+                                    onclick: on_disable_user_proxy,
+                                    {t!("network.user-proxy-disable-button")}
+                                }
+                            }
+                        }
+                    }
+                }
+
                 // Quick actions section
                 div { class: "section",
-                    h3 { class: "section-title", "Quick Actions" }
+                    h3 { class: "section-title", {t!("network.quick-actions")} }
                     div { class: "quick-actions",
                         // Flush DNS
-                        button {
+                        ActionButton {
+                            danger: DangerLevel::Modifying,
                             class: "quick-action-btn",
                             disabled: current_state.is_running,
                             onclick: on_flush_dns,
                             span { class: "quick-action-icon", "🗑️" }
                             span { class: "quick-action-label",
-                                if current_state.running_action == "Flush DNS" { "Running..." } else { "Flush DNS" }
+                                if current_state.running_action == "Flush DNS" { {t!("network.running")} } else { {t!("network.action.flush-dns")} }
                             }
                         }
 
                         // Renew IP
-                        button {
+                        ActionButton {
+                            danger: DangerLevel::Modifying,
                             class: "quick-action-btn",
                             disabled: current_state.is_running,
                             onclick: on_renew_ip,
                             span { class: "quick-action-icon", "🔄" }
                             span { class: "quick-action-label",
-                                if current_state.running_action == "Renew IP" { "Running..." } else { "Renew IP" }
+                                if current_state.running_action == "Renew IP" { {t!("network.running")} } else { {t!("network.action.renew-ip")} }
                             }
                         }
 
                         // Winsock Reset (requires admin)
-                        button {
+                        ActionButton {
+                            danger: DangerLevel::Destructive,
                             class: "quick-action-btn",
                             disabled: current_state.is_running || !is_admin,
-                            onclick: on_winsock_reset,
-                            title: if !is_admin { "Requires Administrator" } else { "" },
+                            onclick: move |_| confirm_action.set(Some("Winsock Reset")),
+                            title: if !is_admin { t!("common.requires-admin") } else { String::new() },
                             span { class: "quick-action-icon", "⚡" }
                             span { class: "quick-action-label",
-                                if current_state.running_action == "Winsock Reset" { "Running..." } else { "Winsock Reset" }
+                                if current_state.running_action == "Winsock Reset" { {t!("network.running")} } else { {t!("network.action.winsock")} }
+                            }
+                        }
+
+                        // TCP/IP stack reset (requires admin, reboot to apply)
+                        ActionButton {
+                            danger: DangerLevel::Destructive,
+                            class: if is_admin { "quick-action-btn quick-action-warning" } else { "quick-action-btn" },
+                            disabled: current_state.is_running || !is_admin,
+                            onclick: move |_| confirm_action.set(Some("TCP/IP Reset")),
+                            title: if !is_admin {
+                                t!("common.requires-admin")
+                            } else {
+                                t!("network.tcpip-warning")
+                            },
+                            span { class: "quick-action-icon", "🧯" }
+                            span { class: "quick-action-label",
+                                if current_state.running_action == "TCP/IP Reset" { {t!("network.running")} } else { {t!("network.action.tcpip")} }
                             }
                         }
 
                         // Restart HTTP Service (requires admin) - clears orphaned HTTP sockets
-                        button {
+                        ActionButton {
+                            danger: DangerLevel::Destructive,
                             class: if is_admin { "quick-action-btn quick-action-warning" } else { "quick-action-btn" },
                             disabled: current_state.is_running || !is_admin,
-                            onclick: on_restart_http,
-                            title: if !is_admin { 
-                                "Requires Administrator" 
-                            } else { 
-                                "⚠️ WARNING: Restarts http.sys - will interrupt IIS, WinRM, etc!" 
+                            onclick: move |_| confirm_action.set(Some("Restart HTTP")),
+                            title: if !is_admin {
+                                t!("common.requires-admin")
+                            } else {
+                                t!("network.restart-http-warning")
                             },
                             span { class: "quick-action-icon", "🔁" }
                             span { class: "quick-action-label",
-                                if current_state.running_action == "Restart HTTP" { "Running..." } else { "Restart HTTP" }
+                                if current_state.running_action == "Restart HTTP" { {t!("network.running")} } else { {t!("network.action.restart-http")} }
                             }
                         }
 
-                        // Ping
-                        button {
+                        // Public IP
+                        ActionButton {
+                            danger: DangerLevel::ReadOnly,
                             class: "quick-action-btn",
                             disabled: current_state.is_running,
-                            onclick: on_ping,
-                            span { class: "quick-action-icon", "📡" }
+                            title: t!("network.public-ip-hint"),
+                            onclick: on_public_ip,
+                            span { class: "quick-action-icon", "🛰️" }
                             span { class: "quick-action-label",
-                                if current_state.running_action == "Ping" { "Running..." } else { "Ping 8.8.8.8" }
+                                if current_state.running_action == "Public IP" { {t!("network.running")} } else { {t!("network.action.public-ip")} }
                             }
                         }
 
-                        // HTTP HEAD
-                        button {
+                        // Network snapshot bundle for support
+                        ActionButton {
+                            danger: DangerLevel::ReadOnly,
                             class: "quick-action-btn",
                             disabled: current_state.is_running,
-                            onclick: on_http_head,
-                            span { class: "quick-action-icon", "🌍" }
+                            title: t!("network.snapshot-hint"),
+                            onclick: on_snapshot,
+                            span { class: "quick-action-icon", "📦" }
                             span { class: "quick-action-label",
-                                if current_state.running_action == "HTTP HEAD" { "Running..." } else { "HTTP HEAD" }
+                                if current_state.running_action == "Snapshot" { {t!("network.snapshot-capturing")} } else { {t!("network.snapshot-capture")} }
                             }
                         }
+
+                    }
+
+                    div { class: "action-bar",
+                        label { class: "muted", {t!("network.public-ip-endpoint")} }
+                        input {
+                            class: "input url-input",
+                            r#type: "text",
+                            placeholder: network::DEFAULT_PUBLIC_IP_ENDPOINT,
+                            title: t!("network.public-ip-endpoint-hint"),
+                            value: "{app_settings().public_ip_endpoint}",
+                            onchange: on_public_ip_endpoint,
+                        }
                     }
 
                     if !is_admin {
                         div { class: "status-bar warning",
-                            "⚠️ Some actions require Administrator privileges. Run as Admin for full functionality."
+                            {t!("network.admin-warning")}
+                        }
+                    }
+                }
+
+                // Ping and traceroute
+                div { class: "section",
+                    h3 { class: "section-title", {t!("network.ping-trace")} }
+                    div { class: "action-bar",
+                        input {
+                            class: "input",
+                            r#type: "text",
+                            placeholder: t!("network.ping-placeholder"),
+                            value: "{current_state.ping_target}",
+                            disabled: ping_running || trace_running,
+                            oninput: move |e| state.write().ping_target = e.value(),
+                        }
+                        select {
+                            class: "input",
+                            title: t!("network.ping-count-hint"),
+                            value: "{current_state.ping_count}",
+                            disabled: ping_running || current_state.ping_continuous,
+                            onchange: move |e| {
+                                if let Ok(count) = e.value().parse() {
+                                    state.write().ping_count = count;
+                                }
+                            },
+                            for count in PING_COUNTS {
+                                option {
+                                    value: "{count}",
+                                    selected: current_state.ping_count == count,
+                                    "{count}×"
+                                }
+                            }
+                        }
+                        label { class: "checkbox-label",
+                            input {
+                                r#type: "checkbox",
+                                checked: current_state.ping_continuous,
+                                disabled: ping_running,
+                                onchange: move |e| state.write().ping_continuous = e.checked(),
+                            }
+                            {t!("network.ping-continuous")}
+                        }
+                        ActionButton {
+                            danger: DangerLevel::ReadOnly,
+                            class: "btn btn-primary",
+                            disabled: current_state.is_running,
+                            onclick: on_ping,
+                            if ping_running { {t!("network.ping-running")} } else { {t!("network.ping-button")} }
+                        }
+                        if ping_running {
+                            button {
+                                class: "btn btn-ghost",
+                                onclick: move |_| {
+                                    state.peek().ping_cancel.store(true, std::sync::atomic::Ordering::Relaxed);
+                                },
+                                {t!("network.stop")}
+                            }
+                        }
+                    }
+                    div { class: "action-bar",
+                        button {
+                            class: if current_state.latency_monitoring { "btn btn-danger" } else { "btn btn-secondary" },
+                            title: t!("network.latency-hint"),
+                            onclick: move |_| toggle_latency_monitor(state),
+                            if current_state.latency_monitoring { {t!("network.latency-stop")} } else { {t!("network.latency-start")} }
+                        }
+                        if !current_state.latency_samples.is_empty() {
+                            {
+                                let stats = current_state.latency_stats();
+                                let ms = |v: Option<u32>| v.map(|v| format!("{} ms", v)).unwrap_or_else(|| "-".to_string());
+                                let avg = stats.avg_ms.map(|v| format!("{:.1} ms", v)).unwrap_or_else(|| "-".to_string());
+                                rsx! {
+                                    span { class: "muted",
+                                        {t!("network.latency-stats", host = current_state.latency_host, min = ms(stats.min_ms), avg = avg, max = ms(stats.max_ms))}
+                                        " · "
+                                    }
+                                    span { class: if stats.loss_percent > 0.0 { "latency-loss" } else { "muted" },
+                                        {t!("network.latency-loss", percent = format!("{:.1}", stats.loss_percent), samples = current_state.latency_samples.len())}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    if !current_state.latency_samples.is_empty() {
+                        {
+                            let scale = current_state.latency_samples.iter().flatten().copied().max().unwrap_or(1).max(1);
+                            rsx! {
+                                div { class: "latency-strip",
+                                    for sample in current_state.latency_samples.iter() {
+                                        match sample {
+                                            Some(ms) => rsx! {
+                                                div {
+                                                    class: "latency-bar",
+                                                    style: "height: {(*ms).max(1) * 100 / scale}%",
+                                                    title: "{ms} ms",
+                                                }
+                                            },
+                                            None => rsx! {
+                                                div { class: "latency-bar loss", title: t!("network.latency-lost") }
+                                            },
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    div { class: "action-bar",
+                        select {
+                            class: "input",
+                            title: t!("network.trace-hops-hint"),
+                            value: "{current_state.trace_max_hops}",
+                            disabled: trace_running,
+                            onchange: move |e| {
+                                if let Ok(hops) = e.value().parse() {
+                                    state.write().trace_max_hops = hops;
+                                }
+                            },
+                            for hops in TRACE_MAX_HOPS {
+                                option {
+                                    value: "{hops}",
+                                    selected: current_state.trace_max_hops == hops,
+                                    {t!("network.trace-hops", hops = hops)}
+                                }
+                            }
+                        }
+                        ActionButton {
+                            danger: DangerLevel::ReadOnly,
+                            class: "btn btn-secondary",
+                            disabled: current_state.is_running,
+                            title: t!("network.trace-hint"),
+                            onclick: on_trace,
+                            if trace_running { {t!("network.trace-running")} } else { {t!("network.trace-button")} }
+                        }
+                        if trace_running {
+                            button {
+                                class: "btn btn-ghost",
+                                onclick: move |_| {
+                                    state.peek().trace_cancel.store(true, std::sync::atomic::Ordering::Relaxed);
+                                },
+                                {t!("network.trace-cancel")}
+                            }
+                        }
+                    }
+                }
+
+                // Remote port test
+                div { class: "section",
+                    h3 { class: "section-title", {t!("network.port-test")} }
+                    div { class: "action-bar",
+                        input {
+                            class: "input",
+                            r#type: "text",
+                            placeholder: t!("network.port-test-host-placeholder"),
+                            value: "{current_state.port_test_host}",
+                            oninput: move |e| state.write().port_test_host = e.value(),
+                        }
+                        input {
+                            class: "input input-number",
+                            r#type: "text",
+                            placeholder: t!("network.port-test-port-placeholder"),
+                            value: "{current_state.port_test_port}",
+                            oninput: move |e| state.write().port_test_port = e.value(),
+                        }
+                        button {
+                            class: "btn btn-primary",
+                            disabled: current_state.testing_port,
+                            title: t!("network.port-test-hint"),
+                            onclick: on_port_test,
+                            if current_state.testing_port { {t!("network.port-testing")} } else { {t!("network.port-test-button")} }
+                        }
+                        if !current_state.port_test_results.is_empty() {
+                            button {
+                                class: "btn btn-ghost",
+                                onclick: move |_| state.write().port_test_results.clear(),
+                                {t!("common.clear")}
+                            }
+                        }
+                    }
+                    if !current_state.port_test_results.is_empty() {
+                        table { class: "data-table",
+                            thead {
+                                tr {
+                                    th { {t!("network.port-col.result")} }
+                                    th { {t!("network.port-col.target")} }
+                                    th { {t!("network.port-col.resolved")} }
+                                    th { {t!("network.port-col.connect")} }
+                                    th { {t!("network.port-col.tested")} }
+                                }
+                            }
+                            tbody {
+                                for test in current_state.port_test_results.iter() {
+                                    {
+                                        let (badge_class, badge_label, detail) = match &test.outcome {
+                                            ConnectOutcome::Connected => ("url-badge up", t!("network.port-outcome.open"), String::new()),
+                                            ConnectOutcome::Refused => ("url-badge down", t!("network.port-outcome.refused"), String::new()),
+                                            ConnectOutcome::TimedOut => ("url-badge down", t!("network.port-outcome.timeout"), String::new()),
+                                            ConnectOutcome::Failed(e) => ("url-badge unknown", t!("network.port-outcome.error"), e.clone()),
+                                        };
+                                        let resolved = test.resolved.map(|ip| ip.to_string()).unwrap_or_else(|| "-".to_string());
+                                        let elapsed = if test.resolved.is_some() { format!("{} ms", test.elapsed_ms) } else { "-".to_string() };
+                                        rsx! {
+                                            tr { title: "{detail}",
+                                                td { span { class: "{badge_class}", "{badge_label}" } }
+                                                td { class: "mono", "{test.host}:{test.port}" }
+                                                td { class: "mono", "{resolved}" }
+                                                td { class: "mono", "{elapsed}" }
+                                                td { class: "muted", {test.tested_at.format("%H:%M:%S").to_string()} }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // Guided DNS diagnosis
+                div { class: "section",
+                    h3 { class: "section-title", {t!("network.dns-diagnose")} }
+                    div { class: "action-bar",
+                        input {
+                            class: "input",
+                            r#type: "text",
+                            placeholder: t!("network.dns-diagnose-placeholder"),
+                            value: "{current_state.dns_test_host}",
+                            oninput: move |e| state.write().dns_test_host = e.value(),
+                        }
+                        ActionButton {
+                            danger: DangerLevel::ReadOnly,
+                            class: "btn btn-primary",
+                            disabled: current_state.is_running,
+                            title: t!("network.dns-diagnose-hint"),
+                            onclick: on_diagnose_dns,
+                            if current_state.running_action == "Diagnose DNS" { {t!("network.dns-diagnosing-short")} } else { {t!("network.dns-diagnose-button")} }
+                        }
+                    }
+                    if !current_state.dns_diagnosis.is_empty() {
+                        ul { class: "checklist",
+                            for step in current_state.dns_diagnosis.iter() {
+                                li { class: if step.passed { "checklist-item passed" } else { "checklist-item failed" },
+                                    span { class: "checklist-mark", if step.passed { "✓" } else { "✗" } }
+                                    div {
+                                        div { "{step.label}" }
+                                        div { class: "muted", "{step.detail}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // HTTP HEAD with TLS details
+                div { class: "section",
+                    h3 { class: "section-title", {t!("network.http-head")} }
+                    div { class: "action-bar",
+                        input {
+                            class: "input url-input",
+                            r#type: "text",
+                            placeholder: "https://intranet.local/",
+                            value: "{current_state.http_url}",
+                            oninput: move |e| state.write().http_url = e.value(),
+                        }
+                        ActionButton {
+                            danger: DangerLevel::ReadOnly,
+                            class: "btn btn-primary",
+                            disabled: current_state.is_running,
+                            title: t!("network.http-head-hint"),
+                            onclick: on_http_head,
+                            if current_state.running_action == "HTTP HEAD" { {t!("network.http-fetching-short")} } else { {t!("network.http-head-button")} }
+                        }
+                    }
+                    match &current_state.tls_certificate {
+                        Some(Ok(cert)) => {
+                            let days = cert.days_left();
+                            let expiry_class = if days < 0 {
+                                "cert-expiry expired"
+                            } else if days < CERT_EXPIRY_WARNING_DAYS {
+                                "cert-expiry expiring"
+                            } else {
+                                "cert-expiry"
+                            };
+                            let expiry_text = if days < 0 {
+                                t!("network.cert-expired-ago", days = -days)
+                            } else {
+                                t!("network.cert-expires-in", days = days)
+                            };
+                            rsx! {
+                                div { class: "card",
+                                    dl { class: "adapter-details",
+                                        dt { {t!("network.cert.subject")} }
+                                        dd { class: "mono", "{cert.subject}" }
+                                        dt { {t!("network.cert.issuer")} }
+                                        dd { class: "mono", "{cert.issuer}" }
+                                        dt { {t!("network.cert.expires")} }
+                                        dd {
+                                            span { class: "mono", {cert.expires.format("%Y-%m-%d %H:%M UTC").to_string()} }
+                                            span { class: "{expiry_class}", " ({expiry_text})" }
+                                        }
+                                        if let Some(errors) = &cert.errors {
+                                            dt { {t!("network.cert.problems")} }
+                                            dd { class: "cert-expiry expired", "{errors}" }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        Some(Err(e)) => rsx! {
+                            div { class: "status-bar error", {t!("network.cert-read-failed", error = e)} }
+                        },
+                        None => rsx! {},
+                    }
+                }
+
+                // Wake-on-LAN
+                div { class: "section",
+                    h3 { class: "section-title", {t!("network.wol")} }
+                    div { class: "action-bar",
+                        input {
+                            class: "input",
+                            r#type: "text",
+                            placeholder: t!("network.wol-name-placeholder"),
+                            value: "{current_state.wol_name}",
+                            oninput: move |e| state.write().wol_name = e.value(),
+                        }
+                        input {
+                            class: "input mono",
+                            r#type: "text",
+                            placeholder: t!("network.wol-mac-placeholder"),
+                            value: "{current_state.wol_mac}",
+                            oninput: move |e| state.write().wol_mac = e.value(),
+                        }
+                        input {
+                            class: "input",
+                            r#type: "text",
+                            placeholder: t!("network.wol-broadcast-placeholder"),
+                            title: t!("network.wol-broadcast-hint"),
+                            value: "{current_state.wol_broadcast}",
+                            oninput: move |e| state.write().wol_broadcast = e.value(),
+                        }
+                        button {
+                            class: "btn btn-primary",
+                            onclick: on_wake,
+                            {t!("network.wol-wake")}
+                        }
+                        button {
+                            class: "btn btn-secondary",
+                            onclick: on_save_wol,
+                            {t!("network.wol-save")}
+                        }
+                    }
+                    if !app_settings().wol_targets.is_empty() {
+                        table { class: "data-table",
+                            thead {
+                                tr {
+                                    th { {t!("network.wol-col.name")} }
+                                    th { {t!("network.wol-col.mac")} }
+                                    th { {t!("network.wol-col.broadcast")} }
+                                    th { "" }
+                                }
+                            }
+                            tbody {
+                                for target in app_settings().wol_targets {
+                                    {
+                                        let wake_target = target.clone();
+                                        let remove_mac = target.mac.clone();
+                                        rsx! {
+                                            tr {
+                                                td { "{target.name}" }
+                                                td { class: "mono", "{target.mac}" }
+                                                td { class: "mono", {target.broadcast.clone().unwrap_or_else(|| "255.255.255.255".to_string())} }
+                                                td {
+                                                    button {
+                                                        class: "btn btn-ghost btn-sm",
+                                                        onclick: move |_| wake(state, &wake_target.name, &wake_target.mac, wake_target.broadcast.as_deref()),
+                                                        {t!("network.wol-wake")}
+                                                    }
+                                                    button {
+                                                        class: "btn btn-ghost btn-sm",
+                                                        title: t!("common.remove"),
+                                                        onclick: move |_| {
+                                                            app_settings.write().wol_targets.retain(|t| t.mac != remove_mac);
+                                                            save_settings(state, app_settings);
+                                                        },
+                                                        "✕"
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // Packet capture
+                div { class: "section",
+                    h3 { class: "section-title", {t!("network.capture")} }
+                    div { class: "action-bar",
+                        input {
+                            class: "input",
+                            r#type: "text",
+                            placeholder: t!("network.capture-filter-placeholder"),
+                            disabled: current_state.capture_running,
+                            value: "{current_state.capture_ip_filter}",
+                            oninput: move |e| state.write().capture_ip_filter = e.value(),
+                        }
+                        ActionButton {
+                            danger: DangerLevel::Modifying,
+                            class: "btn btn-primary",
+                            disabled: current_state.is_running || current_state.capture_running || !is_admin,
+                            title: if !is_admin { t!("common.requires-admin") } else { String::new() },
+                            onclick: on_start_capture,
+                            if current_state.running_action == "Capture" { {t!("network.capture-starting-short")} } else { {t!("network.capture-start")} }
+                        }
+                        ActionButton {
+                            danger: DangerLevel::Modifying,
+                            class: "btn btn-secondary",
+                            disabled: current_state.is_running || !is_admin,
+                            title: if !is_admin { t!("common.requires-admin") } else { t!("network.capture-stop-hint") },
+                            onclick: on_stop_capture,
+                            if current_state.running_action == "Stop Capture" { {t!("network.capture-stopping-short")} } else { {t!("network.capture-stop")} }
+                        }
+                        if !current_state.capture_running && !current_state.capture_file.is_empty() {
+                            button {
+                                class: "btn btn-ghost",
+                                onclick: on_open_capture_folder,
+                                {t!("network.capture-open-folder")}
+                            }
+                        }
+                    }
+
+                    if current_state.capture_running {
+                        div { class: "capture-indicator",
+                            span { class: "capture-dot" }
+                            match &capture_elapsed {
+                                Some(elapsed) => rsx! { {t!("network.capture-recording", elapsed = elapsed)} },
+                                None => rsx! { {t!("network.capture-recording-previous")} },
+                            }
+                            span { class: "mono muted", " {current_state.capture_file}" }
+                        }
+                    }
+
+                    p { class: "muted",
+                        {t!("network.capture-note", max = network::CAPTURE_MAX_SIZE_MB)}
+                    }
+                }
+
+                // Network adapters
+                div { class: "section",
+                    div { class: "section-header",
+                        h3 { class: "section-title", {t!("network.adapters")} }
+                        button {
+                            class: "btn btn-ghost btn-sm",
+                            disabled: current_state.loading_adapters,
+                            onclick: move |_| refresh_adapters(state),
+                            if current_state.loading_adapters { {t!("common.loading")} } else { {t!("network.refresh")} }
+                        }
+                    }
+                    if let Some(error) = &current_state.adapters_error {
+                        div { class: "status-bar error", "{error}" }
+                    }
+                    div { class: "adapter-grid",
+                        for adapter in current_state.adapters.iter() {
+                            div { class: if adapter.is_up { "card adapter-card" } else { "card adapter-card adapter-down" },
+                                div { class: "adapter-card-header",
+                                    span { class: "card-title", "{adapter.name}" }
+                                    span {
+                                        class: if adapter.is_up { "badge badge-success" } else { "badge badge-muted" },
+                                        if adapter.is_up { {t!("network.adapter-up")} } else { {t!("network.adapter-disconnected")} }
+                                    }
+                                }
+                                p { class: "muted", "{adapter.kind} · {adapter.description}" }
+                                dl { class: "adapter-details",
+                                    if let Some(mac) = &adapter.mac {
+                                        dt { {t!("network.adapter.mac")} }
+                                        dd { class: "mono", "{mac}" }
+                                    }
+                                    if !adapter.addresses.is_empty() {
+                                        dt { {t!("network.adapter.addresses")} }
+                                        dd { class: "mono",
+                                            for address in adapter.addresses.iter() {
+                                                div { "{address}" }
+                                            }
+                                        }
+                                    }
+                                    if !adapter.gateways.is_empty() {
+                                        dt { {t!("network.adapter.gateway")} }
+                                        dd { class: "mono", {adapter.gateways.join(", ")} }
+                                    }
+                                    if !adapter.dns_servers.is_empty() {
+                                        dt { {t!("network.adapter.dns")} }
+                                        dd { class: "mono", {adapter.dns_servers.join(", ")} }
+                                    }
+                                    if let Some(stats) = current_state.adapter_stats.iter().find(|s| s.name == adapter.name) {
+                                        dt { {t!("network.adapter.throughput")} }
+                                        dd { class: "mono",
+                                            "↓ {format_bytes(stats.rx_per_sec)}/s · ↑ {format_bytes(stats.tx_per_sec)}/s"
+                                        }
+                                        dt { {t!("network.adapter.totals")} }
+                                        dd { class: "mono",
+                                            {t!("network.adapter-totals", rx = format_bytes(stats.total_received as f64), rx_packets = stats.packets_received, tx = format_bytes(stats.total_transmitted as f64), tx_packets = stats.packets_transmitted)}
+                                        }
+                                        dt { {t!("network.adapter.errors")} }
+                                        dd {
+                                            class: if stats.has_errors() { "mono adapter-errors" } else { "mono" },
+                                            title: if stats.has_errors() { t!("network.adapter-errors-hint") } else { String::new() },
+                                            "rx {stats.rx_errors} · tx {stats.tx_errors}"
+                                        }
+                                    }
+                                }
+                                div { class: "adapter-card-actions",
+                                    {
+                                        let name = adapter.name.clone();
+                                        rsx! {
+                                            ActionButton {
+                                                danger: DangerLevel::Destructive,
+                                                class: "btn btn-ghost btn-sm",
+                                                disabled: current_state.is_running || !is_admin,
+                                                title: if !is_admin { t!("common.requires-admin") } else { t!("network.adapter-restart-hint") },
+                                                onclick: move |_| pending_adapter_restart.set(Some(name.clone())),
+                                                if current_state.running_action == "Restart Adapter" { {t!("network.adapter-restarting-short")} } else { {t!("network.adapter-restart")} }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // Routing table
+                div { class: "section",
+                    div { class: "section-header",
+                        h3 { class: "section-title", {t!("network.routes", shown = visible_routes.len(), total = current_state.routes.len())} }
+                        button {
+                            class: "btn btn-ghost btn-sm",
+                            disabled: current_state.loading_routes,
+                            onclick: move |_| refresh_routes(state),
+                            if current_state.loading_routes { {t!("common.loading")} } else { {t!("network.refresh")} }
+                        }
+                    }
+                    div { class: "action-bar",
+                        input {
+                            r#type: "text",
+                            class: "input",
+                            placeholder: t!("network.routes-filter"),
+                            value: "{current_state.route_table.filter}",
+                            oninput: move |e| state.write().route_table.filter = e.value(),
+                        }
+                        ActionButton {
+                            danger: DangerLevel::ReadOnly,
+                            class: "btn btn-secondary",
+                            disabled: current_state.is_running || gateway.is_none(),
+                            title: match &gateway {
+                                Some(gateway) => t!("network.ping-gateway-hint", gateway = gateway),
+                                None => t!("network.no-default-gateway"),
+                            },
+                            onclick: on_ping_gateway,
+                            {t!("network.ping-gateway")}
+                        }
+                    }
+                    if let Some(error) = &current_state.routes_error {
+                        div { class: "status-bar error", "{error}" }
+                    }
+                    if !current_state.routes.is_empty() {
+                        table { class: "data-table",
+                            thead {
+                                tr {
+                                    for column in ROUTE_COLUMNS {
+                                        SortableHeader {
+                                            column: column.id,
+                                            label: t!(column.label_key),
+                                            current_sort: current_state.route_table.sort_column,
+                                            direction: SortDirection::from_ascending(current_state.route_table.sort_ascending),
+                                            on_sort: move |col| state.write().route_table.toggle_sort(col, ROUTE_COLUMNS),
+                                        }
+                                    }
+                                }
+                            }
+                            tbody {
+                                for route in visible_routes.iter() {
+                                    tr { class: if route.is_default() { "route-default" } else { "" },
+                                        for column in ROUTE_COLUMNS {
+                                            td { class: "mono", {(column.text)(route)} }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // DNS client cache
+                div { class: "section",
+                    div { class: "section-header",
+                        h3 { class: "section-title",
+                            if current_state.dns_cache.is_some() {
+                                {t!("network.dns-cache-count", shown = visible_dns.len(), total = dns_cache.len())}
+                            } else {
+                                {t!("network.dns-cache")}
+                            }
+                        }
+                        button {
+                            class: "btn btn-ghost btn-sm",
+                            disabled: current_state.loading_dns_cache,
+                            onclick: move |_| refresh_dns_cache(state),
+                            if current_state.loading_dns_cache {
+                                {t!("common.loading")}
+                            } else if current_state.dns_cache.is_some() {
+                                {t!("network.refresh")}
+                            } else {
+                                {t!("network.dns-cache-load")}
+                            }
+                        }
+                    }
+                    if let Some(error) = &current_state.dns_cache_error {
+                        div { class: "status-bar error", "{error}" }
+                    }
+                    if current_state.dns_cache.is_some() {
+                        div { class: "action-bar",
+                            input {
+                                r#type: "text",
+                                class: "input",
+                                placeholder: t!("network.dns-cache-search"),
+                                value: "{current_state.dns_cache_table.filter}",
+                                oninput: move |e| state.write().dns_cache_table.filter = e.value(),
+                            }
+                            if pinned_count > 0 {
+                                span { class: "muted", {t!("network.dns-cache-pinned", count = pinned_count)} }
+                            }
+                        }
+                        if dns_cache.is_empty() {
+                            p { class: "muted", {t!("network.dns-cache-empty")} }
+                        } else {
+                            table { class: "data-table",
+                                thead {
+                                    tr {
+                                        for column in DNS_CACHE_COLUMNS {
+                                            SortableHeader {
+                                                column: column.id,
+                                                label: t!(column.label_key),
+                                                current_sort: current_state.dns_cache_table.sort_column,
+                                                direction: SortDirection::from_ascending(current_state.dns_cache_table.sort_ascending),
+                                                on_sort: move |col| state.write().dns_cache_table.toggle_sort(col, DNS_CACHE_COLUMNS),
+                                            }
+                                        }
+                                        StaticHeader { label: "".to_string() }
+                                    }
+                                }
+                                tbody {
+                                    for record in visible_dns.iter() {
+                                        tr {
+                                            class: if record.from_hosts { "dns-hosts" } else { "" },
+                                            title: if record.from_hosts { t!("network.dns-cache-hosts-hint") } else { String::new() },
+                                            for column in DNS_CACHE_COLUMNS {
+                                                td { class: "mono", {(column.text)(record)} }
+                                            }
+                                            td {
+                                                if record.from_hosts {
+                                                    span { class: "badge badge-warning", {t!("network.dns-cache-hosts")} }
+                                                }
+                                                if record.entry != record.name {
+                                                    span { class: "muted", " " {t!("network.dns-cache-via", entry = record.entry)} }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // Network location (firewall profile) per connection
+                div { class: "section",
+                    div { class: "section-header",
+                        h3 { class: "section-title", {t!("network.profiles")} }
+                        button {
+                            class: "btn btn-ghost btn-sm",
+                            disabled: current_state.loading_profiles,
+                            onclick: move |_| refresh_profiles(state),
+                            if current_state.loading_profiles { {t!("common.loading")} } else { {t!("network.refresh")} }
+                        }
+                    }
+                    if let Some(error) = &current_state.profiles_error {
+                        div { class: "status-bar error", "{error}" }
+                    }
+                    if current_state.connection_profiles.is_empty() && !current_state.loading_profiles {
+                        p { class: "muted", {t!("network.profiles-empty")} }
+                    }
+                    for profile in current_state.connection_profiles.iter() {
+                        {
+                            let alias = profile.interface_alias.clone();
+                            let target = match profile.category {
+                                NetworkCategory::Public => Some(NetworkCategory::Private),
+                                NetworkCategory::Private => Some(NetworkCategory::Public),
+                                NetworkCategory::DomainAuthenticated => None,
+                            };
+                            rsx! {
+                                div { class: "action-bar",
+                                    span { class: "mono", "{profile.interface_alias}" }
+                                    span { class: "muted", "{profile.name}" }
+                                    span {
+                                        class: if profile.category == NetworkCategory::Public { "badge badge-warning" } else { "badge badge-success" },
+                                        {t!(profile.category.label_key())}
+                                    }
+                                    if let Some(target) = target {
+                                        ActionButton {
+                                            danger: DangerLevel::Modifying,
+                                            class: "btn btn-secondary btn-sm",
+                                            disabled: current_state.is_running || !is_admin,
+                                            title: if !is_admin { t!("common.requires-admin") } else { String::new() },
+                                            onclick: move |_| pending_profile.set(Some((alias.clone(), target))),
+                                            {t!("network.profile-make", category = t!(target.label_key()))}
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // Proxy settings
+                div { class: "section",
+                    div { class: "section-header",
+                        h3 { class: "section-title", {t!("network.proxy")} }
+                        button {
+                            class: "btn btn-ghost btn-sm",
+                            disabled: current_state.loading_proxy,
+                            onclick: move |_| refresh_proxy(state),
+                            if current_state.loading_proxy { {t!("common.loading")} } else { {t!("network.refresh")} }
+                        }
+                    }
+                    if let Some(error) = &current_state.proxy_error {
+                        div { class: "status-bar error", "{error}" }
+                    }
+                    if let Some(proxy) = &current_state.proxy {
+                        div { class: "card",
+                            dl { class: "adapter-details",
+                                dt { {t!("network.proxy.winhttp")} }
+                                dd { class: "mono",
+                                    match &proxy.winhttp_proxy {
+                                        Some(server) => rsx! { "{server}" },
+                                        None => rsx! { span { class: "muted", {t!("network.proxy.direct")} } },
+                                    }
+                                }
+                                if let Some(bypass) = &proxy.winhttp_bypass {
+                                    dt { {t!("network.proxy.bypass")} }
+                                    dd { class: "mono", "{bypass}" }
+                                }
+                                dt { {t!("network.proxy.user")} }
+                                dd {
+                                    span {
+                                        class: if proxy.user_proxy_enabled { "badge badge-warning" } else { "badge badge-muted" },
+                                        if proxy.user_proxy_enabled { {t!("network.proxy.enabled")} } else { {t!("network.proxy.disabled")} }
+                                    }
+                                    if let Some(server) = &proxy.user_proxy_server {
+                                        span { class: "mono", " {server}" }
+                                    }
+                                }
+                                if let Some(url) = &proxy.auto_config_url {
+                                    dt { {t!("network.proxy.pac")} }
+                                    dd { class: "mono", "{url}" }
+                                }
+                            }
+                            div { class: "action-bar",
+                                ActionButton {
+                                    danger: DangerLevel::Modifying,
+                                    class: "btn btn-secondary",
+                                    disabled: current_state.is_running || !is_admin || proxy.winhttp_proxy.is_none(),
+                                    title: if !is_admin { t!("common.requires-admin") } else { "netsh winhttp reset proxy".to_string() },
+                                    onclick: on_reset_winhttp_proxy,
+                                    if current_state.running_action == "Reset WinHTTP Proxy" { {t!("network.running")} } else { {t!("network.proxy.reset-winhttp")} }
+                                }
+                                ActionButton {
+                                    danger: DangerLevel::Modifying,
+                                    class: "btn btn-secondary",
+                                    disabled: current_state.is_running || !proxy.user_proxy_enabled,
+                                    title: t!("network.proxy.disable-user-hint"),
+                                    onclick: on_disable_user_proxy,
+                                    if current_state.running_action == "Disable User Proxy" { {t!("network.running")} } else { {t!("network.proxy.disable-user")} }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // URL health checks
+                div { class: "section",
+                    h3 { class: "section-title", {t!("network.url-health")} }
+                    div { class: "action-bar",
+                        input {
+                            class: "input",
+                            r#type: "text",
+                            placeholder: t!("network.url-name-placeholder"),
+                            value: "{current_state.new_url_name}",
+                            oninput: move |e| state.write().new_url_name = e.value(),
+                        }
+                        input {
+                            class: "input url-input",
+                            r#type: "text",
+                            placeholder: "http://localhost:3000/health",
+                            value: "{current_state.new_url}",
+                            oninput: move |e| state.write().new_url = e.value(),
+                        }
+                        input {
+                            class: "input input-number",
+                            r#type: "text",
+                            placeholder: t!("network.url-status-placeholder"),
+                            title: t!("network.url-status-hint"),
+                            value: "{current_state.new_url_status}",
+                            oninput: move |e| state.write().new_url_status = e.value(),
+                        }
+                        button {
+                            class: "btn btn-secondary",
+                            onclick: on_add_url,
+                            {t!("network.url-add")}
+                        }
+                    }
+                    div { class: "action-bar",
+                        button {
+                            class: "btn btn-primary",
+                            disabled: current_state.checking_urls || url_checks.is_empty(),
+                            onclick: on_check_urls,
+                            if current_state.checking_urls { {t!("network.checking")} } else { {t!("network.url-check-all")} }
+                        }
+                        label { class: "checkbox-label",
+                            input {
+                                r#type: "checkbox",
+                                checked: current_state.url_auto_refresh,
+                                onchange: move |e| state.write().url_auto_refresh = e.checked(),
+                            }
+                            {t!("network.url-auto-refresh", secs = URL_AUTO_REFRESH_SECS)}
+                        }
+                    }
+
+                    if url_checks.is_empty() {
+                        p { class: "muted", {t!("network.url-empty")} }
+                    } else {
+                        table { class: "data-table",
+                            thead {
+                                tr {
+                                    th { {t!("network.url-col.status")} }
+                                    th { {t!("network.url-col.name")} }
+                                    th { {t!("network.url-col.url")} }
+                                    th { {t!("network.url-col.code")} }
+                                    th { {t!("network.url-col.latency")} }
+                                    th { "" }
+                                }
+                            }
+                            tbody {
+                                for (index, check) in url_checks.iter().enumerate() {
+                                    {
+                                        let result = current_state.url_results.get(index).filter(|r| r.url == check.url).cloned();
+                                        let url = check.url.clone();
+                                        let remove_url = check.url.clone();
+                                        let expanded = current_state.expanded_url.as_deref() == Some(check.url.as_str());
+                                        let host_port = check.host_port();
+                                        let (badge_class, badge_label) = match &result {
+                                            Some(r) if r.outcome.is_up() => ("url-badge up", t!(r.outcome.label_key())),
+                                            Some(r) => ("url-badge down", t!(r.outcome.label_key())),
+                                            None => ("url-badge unknown", "-".to_string()),
+                                        };
+                                        let code = result.as_ref().and_then(|r| r.status).map(|c| c.to_string()).unwrap_or_else(|| "-".to_string());
+                                        let latency = result.as_ref().map(|r| format!("{} ms", r.latency_ms)).unwrap_or_else(|| "-".to_string());
+                                        let error = result.as_ref().map(|r| r.error.clone()).unwrap_or_default();
+                                        let expected = check.expected_status.map(|c| format!(" {}", t!("network.url-expects", status = c))).unwrap_or_default();
+                                        rsx! {
+                                            tr {
+                                                class: if !error.is_empty() { "url-row clickable" } else { "url-row" },
+                                                onclick: move |_| {
+                                                    let mut s = state.write();
+                                                    s.expanded_url = if s.expanded_url.as_deref() == Some(url.as_str()) { None } else { Some(url.clone()) };
+                                                },
+                                                td { span { class: "{badge_class}", "{badge_label}" } }
+                                                td { "{check.name}" }
+                                                td { class: "mono", "{check.url}{expected}" }
+                                                td { class: "mono", "{code}" }
+                                                td { class: "mono", "{latency}" }
+                                                td {
+                                                    if let Some((_, port)) = host_port {
+                                                        button {
+                                                            class: "btn btn-ghost btn-sm",
+                                                            title: t!("network.url-open-port", port = port),
+                                                            onclick: move |e| {
+                                                                e.stop_propagation();
+                                                                ports_state.write().port_input = port;
+                                                                current_page.set(CurrentPage::Ports);
+                                                            },
+                                                            "🔌 {port}"
+                                                        }
+                                                    }
+                                                    button {
+                                                        class: "btn btn-ghost btn-sm",
+                                                        title: t!("common.remove"),
+                                                        onclick: move |e| {
+                                                            e.stop_propagation();
+                                                            app_settings.write().url_checks.retain(|c| c.url != remove_url);
+                                                            state.write().url_results = Vec::new();
+                                                            save_settings(state, app_settings);
+                                                        },
+                                                        "✕"
+                                                    }
+                                                }
+                                            }
+                                            if expanded && !error.is_empty() {
+                                                tr { class: "url-error-row",
+                                                    td { colspan: "6", class: "mono", "{error}" }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                if let Some(name) = pending_adapter_restart() {
+                    {
+                        // Restarting the adapter that carries the default route drops internet
+                        // access - and the session itself when connected remotely
+                        let carries_default = current_state.routes.iter().any(|r| r.is_default() && r.interface == name)
+                            || current_state.adapters.iter().any(|a| a.name == name && !a.gateways.is_empty());
+                        let remote = network::is_remote_session();
+                        let message = match (carries_default, remote) {
+                            (true, true) => t!("network.adapter-confirm-remote", name = name),
+                            (true, false) => t!("network.adapter-confirm-default", name = name),
+                            _ => t!("network.adapter-confirm", name = name),
+                        };
+                        rsx! {
+                            ConfirmDialog {
+                                title: if carries_default { t!("network.adapter-confirm-title-primary", name = name) } else { t!("network.adapter-confirm-title", name = name) },
+                                message,
+                                confirm_label: t!("network.adapter-confirm-label"),
+                                danger: true,
+                                on_confirm: on_restart_adapter,
+                                on_cancel: move |_| pending_adapter_restart.set(None),
+                            }
+                        }
+                    }
+                }
+
+                if let Some((alias, category)) = pending_profile() {
+                    ConfirmDialog {
+                        title: t!("network.profile-confirm-title", alias = alias, category = t!(category.label_key())),
+                        message: if category == NetworkCategory::Private {
+                            t!("network.profile-confirm-private")
+                        } else {
+                            t!("network.profile-confirm-public")
+                        },
+                        confirm_label: t!("network.profile-make", category = t!(category.label_key())),
+                        danger: category == NetworkCategory::Private,
+                        on_confirm: on_set_profile,
+                        on_cancel: move |_| pending_profile.set(None),
+                    }
+                }
+
+                if let Some(action) = confirm_action() {
+                    ConfirmDialog {
+                        title: match action {
+                            "Winsock Reset" => t!("network.winsock-confirm-title"),
+                            "TCP/IP Reset" => t!("network.tcpip-confirm-title"),
+                            _ => t!("network.restart-http-confirm-title"),
+                        },
+                        message: match action {
+                            "Winsock Reset" => t!("network.winsock-confirm"),
+                            "TCP/IP Reset" => t!("network.tcpip-confirm"),
+                            _ => t!("network.restart-http-confirm"),
+                        },
+                        confirm_label: match action {
+                            "Winsock Reset" => t!("network.action.winsock"),
+                            "TCP/IP Reset" => t!("network.action.tcpip"),
+                            _ => t!("network.action.restart-http"),
+                        },
+                        danger: true,
+                        on_confirm: move |_| {
+                            match action {
+                                "Winsock Reset" => on_winsock_reset(with_restore_point()),
+                                "TCP/IP Reset" => on_ip_reset(with_restore_point()),
+                                _ => on_restart_http(with_restore_point()),
+                            }
+                        },
+                        on_cancel: move |_| confirm_action.set(None),
+                        if action == "TCP/IP Reset" {
+                            label { class: "checkbox-label",
+                                input {
+                                    r#type: "checkbox",
+                                    checked: reset_ipv6(),
+                                    onchange: move |e| reset_ipv6.set(e.checked()),
+                                }
+                                {t!("network.tcpip-ipv6")}
+                            }
+                        }
+                        RestorePointOption {
+                            checked: with_restore_point(),
+                            onchange: move |checked| with_restore_point.set(checked),
                         }
                     }
                 }
@@ -290,6 +2529,29 @@ pub fn NetworkPage(is_admin: bool) -> Element {
             crate::ui::components::OutputPanel {
                 outputs: outputs_for_panel,
                 on_clear: move |_| on_clear(()),
+                is_admin,
+                on_rerun: move |output| state.write().command_outputs.push(output),
+                line_class: trace_line_class,
+            }
+        }
+    }
+}
+
+/// One pass/fail card in the connectivity matrix; `children` (the fix action) shows only on failure
+#[component]
+fn ConnectivityCell(label: String, probe: ProbeResult, children: Element) -> Element {
+    rsx! {
+        div { class: if probe.passed { "card connectivity-cell passed" } else { "card connectivity-cell failed" },
+            div { class: "connectivity-cell-header",
+                span { class: "checklist-mark", if probe.passed { "✓" } else { "✗" } }
+                span { class: "card-title", "{label}" }
+                if let Some(ms) = probe.latency_ms {
+                    span { class: "muted mono", "{ms} ms" }
+                }
+            }
+            p { class: "muted", "{probe.detail}" }
+            if !probe.passed {
+                {children}
             }
         }
     }
@@ -4,9 +4,27 @@
 
 use dioxus::prelude::*;
 
-use crate::state::{BindingSource, PortBinding, PortScanResult, PortsPageState, PortsSortColumn};
-use crate::system::ports;
-use crate::ui::components::{SortableHeader, StaticHeader, SortDirection};
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::time::Duration;
+
+use crate::state::{
+    binding_key, csv_field, BindingSource, CurrentPage, DangerLevel, NetworkPageState, PortBinding, PortScanResult,
+    PortsPageState, PortsScanScope, PortsSortColumn, Protocol, ProtocolFilter, ReportFormat,
+};
+use crate::system::{admin, notify};
+use crate::system::ports::{self, ConnectOutcome, KillError, PortPreset};
+use crate::system::settings::{self, AppSettings};
+use crate::ui::components::{
+    copy_to_clipboard, ActionButton, ConfirmDialog, OutputPanel, SortableHeader, StaticHeader, SortDirection,
+};
+
+/// How long "Test Connect" waits for the listener to accept
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// "Notify when free" polling interval and give-up time
+const FREE_WAIT_POLL: Duration = Duration::from_secs(1);
+const FREE_WAIT_TIMEOUT: Duration = Duration::from_secs(5 * 60);
 
 fn update_port_sort(mut state: Signal<PortsPageState>, column: PortsSortColumn) {
      let mut s = state.write();
@@ -20,102 +38,439 @@ fn update_port_sort(mut state: Signal<PortsPageState>, column: PortsSortColumn)
         }
 }
 
+/// Port bounds of the scan described by the inputs (start == end outside range mode)
+fn scan_bounds(s: &PortsPageState) -> (u16, u16) {
+    if s.range_mode {
+        ports::clamp_range(s.port_input, s.port_end_input)
+    } else {
+        (s.port_input, s.port_input)
+    }
+}
+
+/// Repeat the current scan, e.g. after killing a process
+async fn rescan(mut state: Signal<PortsPageState>) {
+    let scope = state().scope.clone();
+    let result = match scope {
+        PortsScanScope::Inputs => {
+            let (start, end) = scan_bounds(&state());
+            ports::list_bindings_enhanced_range(start, end, state().protocol).await
+        }
+        PortsScanScope::AllListeners => ports::list_all_listeners().await,
+        PortsScanScope::Preset { ports, .. } => ports::list_bindings_for_ports(&ports, state().protocol).await,
+    };
+    let target = scan_target(&state());
+    state.write().record_scan(target, result);
+}
+
+/// Label for what the current scope scans, used by the watch indicator and scan history
+fn scan_target(s: &PortsPageState) -> String {
+    match (&s.scope, scan_bounds(s)) {
+        (PortsScanScope::AllListeners, _) => t!("ports.all-listeners-target"),
+        (PortsScanScope::Preset { name, .. }, _) => t!("ports.preset-target", name = name),
+        (PortsScanScope::Inputs, (start, end)) if start == end => start.to_string(),
+        (PortsScanScope::Inputs, (start, end)) => format!("{}-{}", start, end),
+    }
+}
+
+/// Look up the firewall rules covering `port` and report what was found
+async fn check_firewall(mut state: Signal<PortsPageState>, port: u16) {
+    let rules = ports::get_firewall_rules_for_port(port).await;
+    let mut s = state.write();
+    match &rules {
+        Ok(rules) if rules.iter().any(|r| r.blocks_inbound()) => {
+            s.status_message = t!("ports.firewall-blocked", port = port);
+            s.status_type = "warning".to_string();
+        }
+        Ok(rules) => {
+            s.status_message = t!("ports.firewall-found", count = rules.len(), port = port);
+            s.status_type = "info".to_string();
+        }
+        Err(e) => {
+            s.status_message = t!("ports.firewall-failed", error = e);
+            s.status_type = "error".to_string();
+        }
+    }
+    s.firewall_rules = Some(rules);
+    s.checking_firewall = false;
+}
+
+/// Columns of "Copy Report"
+const REPORT_COLUMNS: [&str; 11] = [
+    "Protocol",
+    "Service",
+    "PID",
+    "Process",
+    "Windows Services",
+    "Path",
+    "Local Address",
+    "Remote Address",
+    "State",
+    "Scope",
+    "Status",
+];
+
+/// One "Copy Report" row, matching `REPORT_COLUMNS`
+fn report_row(b: &PortBinding) -> [String; 11] {
+    [
+        b.protocol.label().to_string(),
+        ports::well_known_service(b.local_port).unwrap_or("-").to_string(),
+        b.pid.to_string(),
+        b.process_name.clone(),
+        if b.services.is_empty() { "-".to_string() } else { b.services.join(", ") },
+        b.exe_path.clone().unwrap_or_else(|| "-".to_string()),
+        b.address(),
+        b.remote_address().unwrap_or_else(|| "-".to_string()),
+        b.state.clone(),
+        b.scope_description().to_string(),
+        b.process_status().to_string(),
+    ]
+}
+
+/// Escape text for a markdown table cell (pipes end the cell, line breaks end the row)
+fn markdown_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace(['\r', '\n'], " ")
+}
+
+/// Markdown table of report rows, padded so the columns also line up as plain text
+///
+/// The PID column is right-aligned.
+fn markdown_table(rows: &[[String; 11]]) -> String {
+    let cells: Vec<Vec<String>> = rows.iter().map(|row| row.iter().map(|c| markdown_cell(c)).collect()).collect();
+    let widths: Vec<usize> = (0..REPORT_COLUMNS.len())
+        .map(|col| {
+            cells
+                .iter()
+                .map(|row| row[col].chars().count())
+                .chain([REPORT_COLUMNS[col].len(), 3])
+                .max()
+                .unwrap_or(3)
+        })
+        .collect();
+    let right_aligned = |col: usize| REPORT_COLUMNS[col] == "PID";
+
+    let line = |row: Vec<String>| {
+        let padded: Vec<String> = row
+            .iter()
+            .enumerate()
+            .map(|(col, cell)| {
+                if right_aligned(col) {
+                    format!("{:>width$}", cell, width = widths[col])
+                } else {
+                    format!("{:<width$}", cell, width = widths[col])
+                }
+            })
+            .collect();
+        format!("| {} |\n", padded.join(" | "))
+    };
+
+    let mut table = line(REPORT_COLUMNS.iter().map(|c| c.to_string()).collect());
+    let separator: Vec<String> = widths
+        .iter()
+        .enumerate()
+        .map(|(col, width)| {
+            if right_aligned(col) {
+                format!("{}:", "-".repeat(width - 1))
+            } else {
+                "-".repeat(*width)
+            }
+        })
+        .collect();
+    table.push_str(&format!("| {} |\n", separator.join(" | ")));
+    for row in cells {
+        table.push_str(&line(row));
+    }
+    table
+}
+
+/// Compare two bindings by a results table column (ascending)
+fn compare_bindings(a: &PortBinding, b: &PortBinding, column: PortsSortColumn) -> Ordering {
+    match column {
+        PortsSortColumn::Source => a.source.description().cmp(b.source.description()),
+        PortsSortColumn::Protocol => a.protocol.label().cmp(b.protocol.label()),
+        PortsSortColumn::Port => a.local_port.cmp(&b.local_port),
+        PortsSortColumn::Pid => a.pid.cmp(&b.pid),
+        PortsSortColumn::Process => a.process_name.to_lowercase().cmp(&b.process_name.to_lowercase()),
+        PortsSortColumn::Address => a.address().cmp(&b.address()),
+        PortsSortColumn::Remote => a.remote_address().cmp(&b.remote_address()),
+        PortsSortColumn::State => a.state.cmp(&b.state),
+        PortsSortColumn::Scope => a.scope_description().cmp(b.scope_description()),
+    }
+}
+
+/// Persist settings, reporting failures in the status bar
+fn save_settings(mut state: Signal<PortsPageState>, app_settings: Signal<AppSettings>) {
+    if let Err(e) = settings::save(&app_settings()) {
+        state.write().status_message = e;
+        state.write().status_type = "error".to_string();
+    }
+}
+
+/// Bindings of the displayed result that pass the filters
+fn visible_bindings(state: &PortsPageState) -> Vec<&PortBinding> {
+    state
+        .displayed_result()
+        .bindings
+        .iter()
+        .filter(|b| binding_visible(state, b))
+        .collect()
+}
+
+/// Whether a binding passes the "listeners only" toggle, the address filters, and the result filter
+fn binding_visible(state: &PortsPageState, b: &PortBinding) -> bool {
+    (b.is_listener || !state.listeners_only)
+        && if b.is_ipv6() { !state.ipv4_only } else { !state.ipv6_only }
+        && (!b.is_loopback || !state.hide_loopback)
+        && (!b.is_link_local() || !state.hide_link_local)
+        && matches_result_filter(b, &state.result_filter)
+}
+
+/// Result filter: every whitespace-separated term must match the port number, process name,
+/// or one of the hosted service names
+fn matches_result_filter(binding: &PortBinding, filter: &str) -> bool {
+    let port = binding.local_port.to_string();
+    let process = binding.process_name.to_lowercase();
+    let services = binding.services.join(", ").to_lowercase();
+    filter
+        .to_lowercase()
+        .split_whitespace()
+        .all(|term| port.contains(term) || process.contains(term) || services.contains(term))
+}
+
 /// Ports page with port scanning and process killing
 #[component]
 pub fn PortsPage(is_admin: bool) -> Element {
     // Get persistent state from context
     let mut state: Signal<PortsPageState> = use_context();
+    let mut app_settings: Signal<AppSettings> = use_context();
+    let mut network_state: Signal<NetworkPageState> = use_context();
+    let mut current_page: Signal<CurrentPage> = use_context();
+
+    // Watch mode: the next scan only starts after the previous one finished,
+    // so a scan slower than the interval never stacks up
+    use_future(move || async move {
+        loop {
+            let interval = state.peek().watch_interval_secs.max(1);
+            tokio::time::sleep(tokio::time::Duration::from_secs(interval)).await;
+            let due = {
+                let s = state.peek();
+                s.watching && !s.is_scanning
+            };
+            if due {
+                rescan(state).await;
+
+                // Watching to wait out TIME_WAIT: stop once the last one expired
+                let cleared = {
+                    let s = state.peek();
+                    s.watch_until_clear && s.scan_result.state_count("TIME_WAIT") == 0
+                };
+                if cleared {
+                    let mut s = state.write();
+                    s.watching = false;
+                    s.watch_until_clear = false;
+                    s.status_message = t!("ports.time-wait-cleared");
+                    s.status_type = "success".to_string();
+                }
+            }
+        }
+    });
+
+    // Leaving the page cancels the loop above; don't resume watching on return
+    use_drop(move || {
+        let mut s = state.write();
+        s.watching = false;
+        s.watch_until_clear = false;
+        s.free_wait_port = None;
+    });
 
     // Scan handler
     let on_scan = move |_| {
-        let port = state().port_input;
+        let (start, end) = scan_bounds(&state());
+        let port = if start == end { start.to_string() } else { format!("{}-{}", start, end) };
+        let protocol = state().protocol;
+        state.write().scope = PortsScanScope::Inputs;
         state.write().is_scanning = true;
-        state.write().status_message = format!("Scanning port {}...", port);
+        state.write().status_message = t!("ports.scanning", port = port);
         state.write().status_type = String::new();
 
         // Run scan in spawn to not block UI (uses enhanced scanner with Docker/WSL detection)
         spawn(async move {
-            let result = ports::list_bindings_enhanced(port).await;
+            let result = ports::list_bindings_enhanced_range(start, end, protocol).await;
             let binding_count = result.bindings.len();
             let has_conflicts = !result.conflict_pids.is_empty();
             let has_orphans = !result.orphan_pids.is_empty();
             let has_docker = !result.docker_bindings.is_empty();
             let has_wsl = !result.wsl_bindings.is_empty();
             let has_shadow = result.shadow_detected;
+            let target = scan_target(&state());
+            // "Free" but reserved: nothing can bind a port inside an excluded range
+            let excluded_range = if start == end && binding_count == 0 && !result.scan_failed() {
+                ports::excluded_range_for_port(start).await
+            } else {
+                None
+            };
 
             let mut s = state.write();
-            s.scan_result = result;
+            s.record_scan(target, result);
             s.is_scanning = false;
+            s.excluded_range = excluded_range.clone().map(|range| (start, range));
 
-            if binding_count == 0 {
-                s.status_message = format!("No listeners detected on port {}", port);
+            if s.scan_result.scan_failed() {
+                s.status_message = t!("ports.scan-failed", port = port);
+                s.status_type = "error".to_string();
+            } else if s.scan_result.is_range() {
+                let occupied = s.scan_result.occupied_ports().len();
+                s.status_message = t!(
+                    "ports.range-summary",
+                    range = port,
+                    occupied = occupied,
+                    free = s.scan_result.port_count() - occupied,
+                    count = binding_count,
+                );
+                s.status_type = if has_shadow || has_orphans || has_conflicts {
+                    "warning".to_string()
+                } else {
+                    "info".to_string()
+                };
+            } else if let Some(range) = &excluded_range {
+                s.status_message = t!(
+                    "ports.excluded-range",
+                    port = port,
+                    start = range.start(),
+                    end = range.end(),
+                );
+                s.status_type = "warning".to_string();
+            } else if binding_count == 0 {
+                s.status_message = t!("ports.no-listeners", port = port);
                 s.status_type = "success".to_string();
             } else if has_shadow {
-                s.status_message = format!(
-                    "⚠️ Shadow binding detected on port {} - port in use but source unknown!",
-                    port
-                );
+                s.status_message = t!("ports.shadow-detected", port = port);
                 s.status_type = "warning".to_string();
             } else if has_docker || has_wsl {
                 let mut sources = Vec::new();
                 if has_docker { sources.push("Docker"); }
                 if has_wsl { sources.push("WSL"); }
-                s.status_message = format!(
-                    "Found {} binding(s) on port {} via {}",
-                    binding_count,
-                    port,
-                    sources.join("/")
+                s.status_message = t!(
+                    "ports.found-via",
+                    count = binding_count,
+                    port = port,
+                    sources = sources.join("/"),
                 );
                 s.status_type = "info".to_string();
             } else if has_orphans {
-                s.status_message = format!(
-                    "Found {} binding(s) on port {} - {} orphaned socket(s) detected!",
-                    binding_count,
-                    port,
-                    s.scan_result.orphan_pids.len()
+                s.status_message = t!(
+                    "ports.found-orphans",
+                    count = binding_count,
+                    port = port,
+                    orphans = s.scan_result.orphan_pids.len(),
                 );
                 s.status_type = "warning".to_string();
             } else if has_conflicts {
-                s.status_message = format!(
-                    "Found {} binding(s) on port {} - conflict detected!",
-                    binding_count, port
-                );
+                s.status_message = t!("ports.found-conflict", count = binding_count, port = port);
                 s.status_type = "warning".to_string();
             } else {
-                s.status_message = format!("Found {} binding(s) on port {}", binding_count, port);
+                s.status_message = t!("ports.found", count = binding_count, port = port);
                 s.status_type = String::new();
             }
         });
     };
 
-    // Kill process handler
-    let on_kill = move |pid: u32| {
-        let port = state().port_input;
+    // Kill process handler (`tree` also kills every descendant process)
+    let on_kill = move |(pid, tree): (u32, bool)| {
         spawn(async move {
-            match ports::kill_process(pid) {
-                Ok(_) => {
-                    state.write().status_message = format!("Terminated PID {}. Rescanning...", pid);
+            let result = if tree {
+                ports::kill_process_tree(pid).map(|count| t!("ports.killed-tree", pid = pid, count = count))
+            } else {
+                ports::kill_process(pid).map(|_| t!("ports.killed", pid = pid))
+            };
+            match result {
+                Ok(message) => {
+                    state.write().status_message = message;
                     state.write().status_type = "success".to_string();
                     // Rescan after kill (use enhanced scanner)
                     tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
-                    let result = ports::list_bindings_enhanced(port).await;
-                    state.write().scan_result = result;
+                    rescan(state).await;
+                }
+                Err(KillError::AccessDenied(denied_pid)) => {
+                    state.write().status_message = t!("ports.kill-access-denied", pid = denied_pid);
+                    state.write().status_type = "error".to_string();
+                    state.write().kill_needs_admin = true;
                 }
                 Err(e) => {
-                    state.write().status_message = format!("Failed to kill PID {}: {}", pid, e);
+                    state.write().status_message = t!("ports.kill-failed", pid = pid, error = e);
                     state.write().status_type = "error".to_string();
                 }
             }
         });
     };
 
+    // Relaunch elevated after an access-denied kill (exits this instance on success)
+    let on_restart_as_admin = move |_| {
+        if let Err(e) = admin::restart_as_admin() {
+            state.write().status_message = t!("common.restart-admin-failed", error = e);
+            state.write().status_type = "error".to_string();
+        }
+    };
+
+    // Kill-by-name handler (after confirmation) - stops respawning processes regardless of PID
+    let on_kill_by_name = move |_| {
+        let Some(name) = state.write().pending_kill_name.take() else {
+            return;
+        };
+        spawn(async move {
+            match ports::kill_processes_by_name(&name) {
+                Ok(pids) => {
+                    let pids = pids.iter().map(u32::to_string).collect::<Vec<_>>().join(", ");
+                    state.write().status_message = t!("ports.killed-by-name", name = name, pids = pids);
+                    state.write().status_type = "success".to_string();
+                    // Rescan after kill (use enhanced scanner)
+                    tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+                    rescan(state).await;
+                }
+                Err(e) => {
+                    state.write().status_message = t!("ports.kill-by-name-failed", error = e);
+                    state.write().status_type = "error".to_string();
+                }
+            }
+        });
+    };
+
+    // Connect test handler - checks the listener actually accepts connections
+    let on_test_connect = move |binding: PortBinding| {
+        state.write().status_message = t!("ports.connect-testing", address = binding.address());
+        state.write().status_type = String::new();
+
+        spawn(async move {
+            let test = ports::test_connect(&binding.local_ip, binding.local_port, CONNECT_TIMEOUT).await;
+            let (message, status_type) = match &test.outcome {
+                ConnectOutcome::Connected => (
+                    t!("ports.connect-ok", target = test.target, ms = test.elapsed_ms),
+                    "success",
+                ),
+                ConnectOutcome::Refused => (
+                    t!("ports.connect-refused", target = test.target, ms = test.elapsed_ms),
+                    "error",
+                ),
+                ConnectOutcome::TimedOut => (
+                    t!("ports.connect-timeout", target = test.target, ms = test.elapsed_ms),
+                    "warning",
+                ),
+                ConnectOutcome::Failed(e) => (
+                    t!("ports.connect-failed", target = test.target, error = e),
+                    "error",
+                ),
+            };
+            state.write().status_message = message;
+            state.write().status_type = status_type.to_string();
+        });
+    };
+
     // Force close orphan handler - diagnoses and optionally restarts http.sys
     let on_force_close = move |binding: PortBinding| {
         let admin = is_admin;
         spawn(async move {
-            state.write().status_message = format!(
-                "Diagnosing orphaned socket on {}:{}...",
-                binding.local_ip, binding.local_port
+            state.write().status_message = t!(
+                "ports.diagnosing",
+                address = format!("{}:{}", binding.local_ip, binding.local_port),
             );
             state.write().status_type = String::new();
 
@@ -126,63 +481,49 @@ pub fn PortsPage(is_admin: bool) -> Element {
                 Ok(msg) => {
                     // Found http.sys involvement - if admin, offer to restart
                     if admin {
-                        state.write().status_message = format!(
-                            "{}\n\nAttempting to restart HTTP service...",
-                            msg
-                        );
+                        state.write().status_message = t!("ports.restarting-http", details = msg);
                         
                         // Actually restart the http service
                         let restart_result = crate::system::network::restart_http_service().await;
                         let restart_ok = restart_result.iter().all(|o| o.succeeded());
                         
                         if restart_ok {
-                            state.write().status_message = "HTTP service restarted successfully. Rescanning port...".to_string();
+                            state.write().status_message = t!("ports.http-restarted");
                             state.write().status_type = "success".to_string();
                         } else {
-                            state.write().status_message = "HTTP service restart failed. Check output on Network tab.".to_string();
+                            state.write().status_message = t!("ports.http-restart-failed");
                             state.write().status_type = "warning".to_string();
                         }
                     } else {
-                        state.write().status_message = format!(
-                            "{}\n\n💡 Run as Administrator to auto-restart HTTP service, or use Network tab → Restart HTTP.",
-                            msg
-                        );
+                        state.write().status_message = t!("ports.http-needs-admin", details = msg);
                         state.write().status_type = "warning".to_string();
                     }
                     
                     // Rescan after a brief delay (use enhanced scanner)
                     tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
-                    let scan = ports::list_bindings_enhanced(state().port_input).await;
-                    state.write().scan_result = scan;
+                    rescan(state).await;
                 }
                 Err(e) => {
                     if admin {
                         // Even without http.sys detection, try restarting as it might help
-                        state.write().status_message = format!(
-                            "{}\n\nTrying HTTP service restart anyway...",
-                            e
-                        );
+                        state.write().status_message = t!("ports.restart-anyway", details = e);
                         
                         let restart_result = crate::system::network::restart_http_service().await;
                         let restart_ok = restart_result.iter().all(|o| o.succeeded());
                         
                         tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
-                        let scan = ports::list_bindings_enhanced(state().port_input).await;
-                        let no_orphans = scan.bindings.iter().all(|b| !b.is_orphan);
-                        state.write().scan_result = scan;
+                        rescan(state).await;
+                        let no_orphans = state().scan_result.bindings.iter().all(|b| !b.is_orphan);
                         
                         if restart_ok && no_orphans {
-                            state.write().status_message = "HTTP service restart cleared the orphaned socket!".to_string();
+                            state.write().status_message = t!("ports.http-cleared-orphan");
                             state.write().status_type = "success".to_string();
                         } else {
                             state.write().status_message = e;
                             state.write().status_type = "error".to_string();
                         }
                     } else {
-                        state.write().status_message = format!(
-                            "{}\n\n💡 Try running as Administrator for more options.",
-                            e
-                        );
+                        state.write().status_message = t!("ports.try-admin", details = e);
                         state.write().status_type = "error".to_string();
                     }
                 }
@@ -192,53 +533,346 @@ pub fn PortsPage(is_admin: bool) -> Element {
 
     // Suggest free port handler
     let on_suggest = move |_| {
-        if let Some(free_port) = ports::suggest_free_port(3000, 3100) {
-            state.write().port_input = free_port;
-            state.write().status_message = format!("Suggested free port: {}", free_port);
-            state.write().status_type = "success".to_string();
-        } else {
-            state.write().status_message = "No free port found in range 3000-3100".to_string();
+        let (start, end) = (state().suggest_start, state().suggest_end);
+        state.write().suggesting = true;
+
+        spawn(async move {
+            let suggestion = ports::suggest_free_port(start, end).await;
+            let mut s = state.write();
+            if let Some(free_port) = suggestion {
+                s.port_input = free_port;
+                s.status_message = t!("ports.suggested", port = free_port);
+                s.status_type = "success".to_string();
+            } else {
+                s.status_message = t!("ports.no-free-port", start = start, end = end);
+                s.status_type = "warning".to_string();
+            }
+            s.suggesting = false;
+        });
+    };
+
+    // Look up firewall rules covering the port input
+    let on_check_firewall = move |_| {
+        let port = state().port_input;
+        let mut s = state.write();
+        s.checking_firewall = true;
+        if s.firewall_port != port || s.allow_rule_name.trim().is_empty() {
+            s.allow_rule_name = t!("ports.allow-rule-default-name", port = port);
+        }
+        s.firewall_port = port;
+        s.status_message = t!("ports.firewall-checking", port = port);
+        s.status_type = String::new();
+        drop(s);
+
+        spawn(check_firewall(state, port));
+    };
+
+    // Create an inbound allow rule for the checked port, then show the updated rules
+    let on_allow_inbound = move |_| {
+        if !is_admin {
+            state.write().status_message = t!("common.requires-admin");
             state.write().status_type = "warning".to_string();
+            return;
+        }
+        let port = state().firewall_port;
+        let protocol = state().allow_rule_protocol;
+        let name = state().allow_rule_name.trim().to_string();
+        if let Err(e) = ports::validate_firewall_rule_name(&name) {
+            state.write().status_message = t!("ports.allow-rule-invalid", error = e);
+            state.write().status_type = "error".to_string();
+            return;
         }
+        state.write().creating_allow_rule = true;
+        state.write().status_message = t!("ports.allow-rule-creating", port = port);
+        state.write().status_type = String::new();
+
+        spawn(async move {
+            match ports::create_inbound_allow_rule(port, protocol, &name).await {
+                Ok(output) if output.succeeded() => {
+                    state.write().command_outputs = vec![output];
+                    state.write().checking_firewall = true;
+                    check_firewall(state, port).await;
+                    state.write().status_message = t!("ports.allow-rule-created", name = name, port = port);
+                    state.write().status_type = "success".to_string();
+                }
+                Ok(output) => {
+                    state.write().command_outputs = vec![output];
+                    state.write().status_message = t!("ports.allow-rule-failed", port = port);
+                    state.write().status_type = "error".to_string();
+                }
+                Err(e) => {
+                    state.write().status_message = t!("ports.allow-rule-invalid", error = e);
+                    state.write().status_type = "error".to_string();
+                }
+            }
+            state.write().creating_allow_rule = false;
+        });
+    };
+
+    // Show every listening TCP socket on the machine
+    let on_show_all = move |_| {
+        state.write().scope = PortsScanScope::AllListeners;
+        state.write().excluded_range = None;
+        state.write().is_scanning = true;
+        state.write().status_message = t!("ports.scanning-all");
+        state.write().status_type = String::new();
+
+        spawn(async move {
+            let result = ports::list_all_listeners().await;
+            let target = scan_target(&state());
+            let mut s = state.write();
+            if result.scan_failed() {
+                s.status_message = t!("ports.scan-failed", port = target);
+                s.status_type = "error".to_string();
+            } else {
+                s.status_message = t!(
+                    "ports.all-listeners-found",
+                    count = result.bindings.len(),
+                    ports = result.occupied_ports().len(),
+                );
+                s.status_type = "info".to_string();
+            }
+            s.record_scan(target, result);
+            s.is_scanning = false;
+        });
     };
 
-    // Copy report handler
+    // Scan every port of the selected preset in one socket table pass
+    let on_scan_preset = move |_| {
+        let Some(preset) = app_settings().port_presets.get(state().selected_preset).cloned() else {
+            return;
+        };
+        if preset.ports.is_empty() {
+            state.write().status_message = t!("ports.preset-empty", name = preset.name);
+            state.write().status_type = "warning".to_string();
+            return;
+        }
+        let protocol = state().protocol;
+        state.write().scope = PortsScanScope::Preset { name: preset.name.clone(), ports: preset.ports.clone() };
+        state.write().excluded_range = None;
+        state.write().is_scanning = true;
+        state.write().status_message = t!("ports.scanning-preset", name = preset.name);
+        state.write().status_type = String::new();
+
+        spawn(async move {
+            let result = ports::list_bindings_for_ports(&preset.ports, protocol).await;
+            let target = scan_target(&state());
+            let mut s = state.write();
+            let occupied = result.occupied_ports().len();
+            if result.scan_failed() {
+                s.status_message = t!("ports.scan-failed", port = target);
+                s.status_type = "error".to_string();
+            } else {
+                s.status_message = t!(
+                    "ports.preset-summary",
+                    name = preset.name,
+                    occupied = occupied,
+                    free = preset.ports.len() - occupied,
+                    count = result.bindings.len(),
+                );
+                s.status_type = "info".to_string();
+            }
+            s.record_scan(target, result);
+            s.is_scanning = false;
+        });
+    };
+
+    // Preset editing - every change is saved right away
+    let on_new_preset = move |_| {
+        app_settings.write().port_presets.push(PortPreset {
+            name: t!("ports.preset-new-name"),
+            ports: Vec::new(),
+        });
+        state.write().selected_preset = app_settings().port_presets.len() - 1;
+        state.write().editing_presets = true;
+        save_settings(state, app_settings);
+    };
+
+    let on_delete_preset = move |_| {
+        let index = state().selected_preset;
+        if index < app_settings().port_presets.len() {
+            app_settings.write().port_presets.remove(index);
+            state.write().selected_preset = index.saturating_sub(1);
+            save_settings(state, app_settings);
+        }
+    };
+
+    let on_add_preset_port = move |_| {
+        let index = state().selected_preset;
+        let port = match state().new_preset_port.trim().parse::<u16>() {
+            Ok(port) if port > 0 => port,
+            _ => {
+                state.write().status_message = t!("ports.preset-invalid-port");
+                state.write().status_type = "warning".to_string();
+                return;
+            }
+        };
+        let mut settings = app_settings.write();
+        let Some(preset) = settings.port_presets.get_mut(index) else {
+            return;
+        };
+        if !preset.ports.contains(&port) {
+            preset.ports.push(port);
+            preset.ports.sort_unstable();
+        }
+        drop(settings);
+        state.write().new_preset_port = String::new();
+        save_settings(state, app_settings);
+    };
+
+    // Copy report handler (only the rows currently shown)
     let on_copy = move |_| {
-        let bindings = &state().scan_result.bindings;
+        let s = state();
+        let bindings = visible_bindings(&s);
         if bindings.is_empty() {
-            state.write().status_message = "No data to copy".to_string();
+            state.write().status_message = t!("ports.no-data");
             state.write().status_type = "warning".to_string();
             return;
         }
 
-        let mut report = String::from("PID\tProcess\tLocal Address\tState\tScope\tStatus\n");
-        for b in bindings {
-            report.push_str(&format!(
-                "{}\t{}\t{}\t{}\t{}\t{}\n",
-                b.pid,
-                b.process_name,
-                b.address(),
-                b.state,
-                b.scope_description(),
-                b.process_status()
-            ));
+        let rows: Vec<[String; 11]> = bindings.into_iter().map(report_row).collect();
+        let report = match s.report_format {
+            ReportFormat::Plain => {
+                let mut report = REPORT_COLUMNS.join("\t");
+                report.push('\n');
+                for row in &rows {
+                    report.push_str(&row.join("\t"));
+                    report.push('\n');
+                }
+                report
+            }
+            ReportFormat::Csv => {
+                let mut report = REPORT_COLUMNS.map(csv_field).join(",");
+                report.push_str("\r\n");
+                for row in &rows {
+                    report.push_str(&row.iter().map(|cell| csv_field(cell)).collect::<Vec<_>>().join(","));
+                    report.push_str("\r\n");
+                }
+                report
+            }
+            ReportFormat::Markdown => {
+                // The scan context travels with the paste: what, when, and whether elevated
+                let (target, taken_at) = match s.viewing_history.and_then(|i| s.history.get(i)) {
+                    Some(snapshot) => (snapshot.target.clone(), snapshot.taken_at),
+                    None => (
+                        scan_target(&s),
+                        s.history.last().map_or_else(chrono::Local::now, |snapshot| snapshot.taken_at),
+                    ),
+                };
+                format!(
+                    "**Port scan:** {} - {} - {}\n\n{}",
+                    markdown_cell(&target),
+                    taken_at.format("%Y-%m-%d %H:%M:%S"),
+                    if is_admin { "running as Administrator" } else { "running as standard user" },
+                    markdown_table(&rows),
+                )
+            }
+        };
+
+        match copy_to_clipboard(&report) {
+            Ok(()) => {
+                state.write().status_message = t!("ports.report-copied");
+                state.write().status_type = "success".to_string();
+            }
+            Err(_) => {
+                state.write().status_message = t!("common.copy-failed");
+                state.write().status_type = "error".to_string();
+            }
         }
+    };
 
-        if let Ok(mut clipboard) = arboard::Clipboard::new() {
-            if clipboard.set_text(&report).is_ok() {
-                state.write().status_message = "Report copied to clipboard".to_string();
+    // Copy the commands that release a port from an excluded range
+    let on_copy_exclusion_guidance = move |_| {
+        let Some((port, range)) = state().excluded_range.clone() else {
+            return;
+        };
+        match copy_to_clipboard(&ports::excluded_range_guidance(port, &range)) {
+            Ok(()) => {
+                state.write().status_message = t!("ports.excluded-range-copied");
                 state.write().status_type = "success".to_string();
-            } else {
-                state.write().status_message = "Failed to copy to clipboard".to_string();
+            }
+            Err(e) => {
+                state.write().status_message = e;
                 state.write().status_type = "error".to_string();
             }
         }
     };
 
+    // Watch until the TIME_WAIT sockets on the scanned ports have expired
+    let on_watch_time_wait = move |_| {
+        state.write().watching = true;
+        state.write().watch_until_clear = true;
+    };
+
+    // Poll the port until nothing listens on it, then raise a toast. The loop exits as soon as
+    // `free_wait_port` changes (cancel button, leaving the page, or a wait on another port).
+    let on_notify_when_free = move |_| {
+        let port = state().port_input;
+        state.write().free_wait_port = Some(port);
+
+        spawn(async move {
+            let started = std::time::Instant::now();
+            loop {
+                if state.peek().free_wait_port != Some(port) {
+                    return;
+                }
+                let result = ports::list_bindings_range(port, port, ProtocolFilter::Tcp).await;
+                if result.scan_failed() {
+                    let mut s = state.write();
+                    s.free_wait_port = None;
+                    s.status_message = t!("ports.scan-failed", port = port);
+                    s.status_type = "error".to_string();
+                    return;
+                }
+                let listeners = result.bindings.iter().filter(|b| b.is_listener).count();
+                if listeners == 0 {
+                    break;
+                }
+                if started.elapsed() >= FREE_WAIT_TIMEOUT {
+                    let mut s = state.write();
+                    s.free_wait_port = None;
+                    s.status_message = t!("ports.free-wait-timeout", port = port, minutes = FREE_WAIT_TIMEOUT.as_secs() / 60);
+                    s.status_type = "warning".to_string();
+                    return;
+                }
+                tokio::time::sleep(FREE_WAIT_POLL).await;
+            }
+
+            state.write().free_wait_port = None;
+            state.write().status_message = t!("ports.port-free", port = port);
+            state.write().status_type = "success".to_string();
+            if let Err(e) = notify::show_toast(&t!("ports.port-free-title"), &t!("ports.port-free", port = port)).await {
+                tracing::warn!("Failed to show notification: {}", e);
+            }
+        });
+    };
+
+    // Watch toggle - scans right away when turned on
+    let on_toggle_watch = move |_| {
+        let watching = !state().watching;
+        state.write().watching = watching;
+        state.write().watch_until_clear = false;
+        if watching {
+            spawn(rescan(state));
+        }
+    };
+
+    // Expand or collapse a process group's bindings
+    let on_toggle_group = move |pid: u32| {
+        let mut s = state.write();
+        if !s.expanded_groups.remove(&pid) {
+            s.expanded_groups.insert(pid);
+        }
+    };
+
     // Clear handler
     let on_clear = move |_| {
         let mut s = state.write();
         s.scan_result = PortScanResult::default();
+        s.viewing_history = None;
+        s.firewall_rules = None;
+        s.excluded_range = None;
+        s.command_outputs = Vec::new();
         s.status_message = String::new();
         s.status_type = String::new();
     };
@@ -247,23 +881,68 @@ pub fn PortsPage(is_admin: bool) -> Element {
 
     // Read current state
     let current_state = state();
+    let result = current_state.displayed_result();
+    let viewing_history = current_state.viewing_history.is_some();
+    let time_wait_count = result.state_count("TIME_WAIT");
     
-    // Sort bindings based on current sort state
-    let mut sorted_bindings = current_state.scan_result.bindings.clone();
-    if let Some(sort_col) = current_state.sort_column {
-        let asc = current_state.sort_ascending;
-        sorted_bindings.sort_by(|a, b| {
-            let cmp = match sort_col {
-                PortsSortColumn::Source => a.source.description().cmp(b.source.description()),
-                PortsSortColumn::Pid => a.pid.cmp(&b.pid),
-                PortsSortColumn::Process => a.process_name.to_lowercase().cmp(&b.process_name.to_lowercase()),
-                PortsSortColumn::Address => a.address().cmp(&b.address()),
-                PortsSortColumn::State => a.state.cmp(&b.state),
-                PortsSortColumn::Scope => a.scope_description().cmp(b.scope_description()),
-            };
-            if asc { cmp } else { cmp.reverse() }
-        });
-    }
+    // Filter, then sort bindings based on current sort state
+    let mut sorted_bindings: Vec<PortBinding> = visible_bindings(&current_state)
+        .into_iter()
+        .cloned()
+        .collect();
+    let sort = current_state.sort_column.map(|column| (column, current_state.sort_ascending));
+    let order = |a: &PortBinding, b: &PortBinding| match sort {
+        Some((column, true)) => compare_bindings(a, b, column),
+        Some((column, false)) => compare_bindings(a, b, column).reverse(),
+        None => Ordering::Equal,
+    };
+    sorted_bindings.sort_by(order);
+
+    // Group-by-process view: groups of the full result, reduced to their visible members and
+    // ordered like their summary rows would be
+    let mut groups: Vec<(PortBinding, Vec<PortBinding>)> = if current_state.group_by_process {
+        result
+            .grouped()
+            .into_iter()
+            .filter_map(|(summary, mut members)| {
+                members.retain(|b| binding_visible(&current_state, b));
+                members.sort_by(order);
+                (!members.is_empty()).then_some((summary, members))
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+    groups.sort_by(|(a, _), (b, _)| order(a, b));
+
+    // Diff against the previous scan of the same target: new rows are marked, gone rows appended
+    let previous = current_state.previous_result().filter(|_| current_state.show_diff);
+    let added: HashSet<_> = match previous {
+        Some(previous) => {
+            let before: HashSet<_> = previous.bindings.iter().map(binding_key).collect();
+            result.bindings.iter().map(binding_key).filter(|key| !before.contains(key)).collect()
+        }
+        None => HashSet::new(),
+    };
+    let removed: Vec<PortBinding> = match previous {
+        Some(previous) => {
+            let now: HashSet<_> = result.bindings.iter().map(binding_key).collect();
+            previous
+                .bindings
+                .iter()
+                .filter(|b| !now.contains(&binding_key(b)) && binding_visible(&current_state, b))
+                .cloned()
+                .collect()
+        }
+        None => Vec::new(),
+    };
+    let added_class = |b: &PortBinding| if added.contains(&binding_key(b)) { "diff-added" } else { "" };
+
+    let show_port = result.is_range();
+    let presets = app_settings().port_presets.clone();
+    let selected_preset = presets.get(current_state.selected_preset).cloned();
+
+    let watch_target = scan_target(&current_state);
 
     // Determine sort direction for display
     let sort_dir = if current_state.sort_ascending {
@@ -275,8 +954,8 @@ pub fn PortsPage(is_admin: bool) -> Element {
     rsx! {
         // Page header
         header { class: "page-header",
-            h1 { class: "page-title", "🔌 Port Scanner" }
-            p { class: "page-subtitle", "Scan TCP ports and manage processes" }
+            h1 { class: "page-title", {t!("ports.title")} }
+            p { class: "page-subtitle", {t!("ports.subtitle")} }
         }
 
         // Page content
@@ -285,7 +964,7 @@ pub fn PortsPage(is_admin: bool) -> Element {
             div { class: "action-bar",
                 div { class: "action-bar-group",
                     div { class: "input-group",
-                        label { "Port:" }
+                        label { {t!("ports.port-label")} }
                         input {
                             r#type: "number",
                             class: "input input-number",
@@ -298,6 +977,49 @@ pub fn PortsPage(is_admin: bool) -> Element {
                                 }
                             },
                         }
+                        if current_state.range_mode {
+                            label { {t!("ports.range-to")} }
+                            input {
+                                r#type: "number",
+                                class: "input input-number",
+                                value: "{current_state.port_end_input}",
+                                min: 1,
+                                max: 65535,
+                                oninput: move |e| {
+                                    if let Ok(v) = e.value().parse::<u16>() {
+                                        state.write().port_end_input = v;
+                                    }
+                                },
+                            }
+                        }
+                        label { class: "checkbox-label",
+                            title: t!("ports.range-hint", max = ports::MAX_RANGE_PORTS),
+                            input {
+                                r#type: "checkbox",
+                                checked: current_state.range_mode,
+                                onchange: move |e| state.write().range_mode = e.checked(),
+                            }
+                            {t!("ports.range-mode")}
+                        }
+                    }
+                    div { class: "input-group",
+                        label { {t!("ports.protocol-label")} }
+                        select {
+                            class: "input",
+                            value: "{current_state.protocol.label()}",
+                            onchange: move |e| {
+                                if let Some(p) = ProtocolFilter::ALL.into_iter().find(|p| p.label() == e.value()) {
+                                    state.write().protocol = p;
+                                }
+                            },
+                            for option in ProtocolFilter::ALL {
+                                option {
+                                    value: "{option.label()}",
+                                    selected: option == current_state.protocol,
+                                    {t!(option.label_key())}
+                                }
+                            }
+                        }
                     }
                     button {
                         class: "btn btn-primary",
@@ -306,7 +1028,7 @@ pub fn PortsPage(is_admin: bool) -> Element {
                         if current_state.is_scanning {
                             span { class: "spinner" }
                         } else {
-                            "Scan"
+                            {t!("ports.scan")}
                         }
                     }
                 }
@@ -316,124 +1038,796 @@ pub fn PortsPage(is_admin: bool) -> Element {
                 div { class: "action-bar-group",
                     button {
                         class: "btn btn-secondary",
-                        onclick: on_suggest,
-                        "Suggest Free Port"
+                        onclick: on_show_all,
+                        disabled: current_state.is_scanning,
+                        {t!("ports.show-all")}
+                    }
+                    button {
+                        class: "btn btn-secondary",
+                        onclick: on_check_firewall,
+                        disabled: current_state.checking_firewall,
+                        title: t!("ports.firewall-hint"),
+                        if current_state.checking_firewall { {t!("ports.firewall-checking-short")} } else { {t!("ports.firewall")} }
+                    }
+                    button {
+                        class: "btn btn-secondary",
+                        title: t!("ports.remote-test-hint"),
+                        onclick: move |_| {
+                            let mut network = network_state.write();
+                            network.port_test_port = state().port_input.to_string();
+                            if network.port_test_host.trim().is_empty() {
+                                network.port_test_host = "localhost".to_string();
+                            }
+                            drop(network);
+                            current_page.set(CurrentPage::Network);
+                        },
+                        {t!("ports.remote-test")}
                     }
                     button {
                         class: "btn btn-secondary",
                         onclick: on_copy,
-                        disabled: current_state.scan_result.bindings.is_empty(),
-                        "Copy Report"
+                        disabled: result.bindings.is_empty(),
+                        {t!("ports.copy-report")}
+                    }
+                    select {
+                        class: "input",
+                        title: t!("ports.report-format-hint"),
+                        value: "{current_state.report_format.label()}",
+                        onchange: move |e| {
+                            if let Some(f) = ReportFormat::ALL.into_iter().find(|f| f.label() == e.value()) {
+                                state.write().report_format = f;
+                            }
+                        },
+                        for format in ReportFormat::ALL {
+                            option {
+                                value: "{format.label()}",
+                                selected: format == current_state.report_format,
+                                {t!(format.label_key())}
+                            }
+                        }
                     }
                     button {
                         class: "btn btn-ghost",
                         onclick: on_clear,
-                        "Clear"
+                        {t!("common.clear")}
+                    }
+                }
+
+                div { class: "action-bar-divider" }
+
+                div { class: "action-bar-group",
+                    button {
+                        class: if current_state.watching { "btn btn-danger" } else { "btn btn-secondary" },
+                        onclick: on_toggle_watch,
+                        if current_state.watching { {t!("ports.watch-stop")} } else { {t!("ports.watch")} }
+                    }
+                    label { {t!("ports.watch-every")} }
+                    input {
+                        r#type: "number",
+                        class: "input input-number",
+                        value: "{current_state.watch_interval_secs}",
+                        min: 1,
+                        max: 60,
+                        oninput: move |e| {
+                            if let Ok(v) = e.value().parse::<u64>() {
+                                state.write().watch_interval_secs = v.clamp(1, 60);
+                            }
+                        },
+                    }
+                    span { class: "muted", "s" }
+                    button {
+                        class: "btn btn-secondary",
+                        onclick: on_notify_when_free,
+                        disabled: current_state.free_wait_port.is_some(),
+                        title: t!("ports.notify-free-hint"),
+                        {t!("ports.notify-free")}
+                    }
+                }
+
+                div { class: "action-bar-divider" }
+
+                div { class: "action-bar-group",
+                    button {
+                        class: "btn btn-secondary",
+                        onclick: on_suggest,
+                        disabled: current_state.suggesting,
+                        title: t!("ports.suggest-hint"),
+                        {t!("ports.suggest")}
+                    }
+                    label { {t!("ports.suggest-in")} }
+                    input {
+                        r#type: "number",
+                        class: "input input-number",
+                        value: "{current_state.suggest_start}",
+                        min: 1,
+                        max: 65535,
+                        oninput: move |e| {
+                            if let Ok(v) = e.value().parse::<u16>() {
+                                state.write().suggest_start = v;
+                            }
+                        },
+                    }
+                    label { {t!("ports.range-to")} }
+                    input {
+                        r#type: "number",
+                        class: "input input-number",
+                        value: "{current_state.suggest_end}",
+                        min: 1,
+                        max: 65535,
+                        oninput: move |e| {
+                            if let Ok(v) = e.value().parse::<u16>() {
+                                state.write().suggest_end = v;
+                            }
+                        },
+                    }
+                }
+            }
+
+            div { class: "action-bar",
+                div { class: "action-bar-group",
+                    label { {t!("ports.preset-label")} }
+                    select {
+                        class: "input",
+                        value: "{current_state.selected_preset}",
+                        onchange: move |e| {
+                            if let Ok(index) = e.value().parse::<usize>() {
+                                state.write().selected_preset = index;
+                            }
+                        },
+                        for (index, preset) in presets.iter().enumerate() {
+                            option {
+                                value: "{index}",
+                                selected: index == current_state.selected_preset,
+                                "{preset.name}"
+                            }
+                        }
+                    }
+                    button {
+                        class: "btn btn-secondary",
+                        onclick: on_scan_preset,
+                        disabled: current_state.is_scanning || selected_preset.is_none(),
+                        {t!("ports.scan-preset")}
+                    }
+                    button {
+                        class: if current_state.editing_presets { "btn btn-secondary" } else { "btn btn-ghost" },
+                        onclick: move |_| {
+                            let editing = !state().editing_presets;
+                            state.write().editing_presets = editing;
+                        },
+                        {t!("ports.edit-presets")}
+                    }
+                }
+
+                div { class: "action-bar-divider" }
+
+                div { class: "action-bar-group",
+                    label { {t!("ports.kill-name-label")} }
+                    input {
+                        r#type: "text",
+                        class: "input",
+                        placeholder: t!("ports.kill-name-placeholder"),
+                        value: "{current_state.kill_name_input}",
+                        oninput: move |e| state.write().kill_name_input = e.value(),
+                    }
+                    ActionButton {
+                        danger: DangerLevel::Destructive,
+                        class: "btn btn-danger",
+                        disabled: current_state.kill_name_input.trim().is_empty(),
+                        title: t!("ports.kill-by-name-hint"),
+                        onclick: move |_| {
+                            let name = state().kill_name_input.trim().to_string();
+                            state.write().pending_kill_name = Some(name);
+                        },
+                        {t!("ports.kill-by-name")}
+                    }
+                }
+            }
+
+            // Preset editor (edits the selected preset)
+            if current_state.editing_presets {
+                div { class: "section",
+                    h3 { class: "section-title", {t!("ports.presets-title")} }
+                    if let Some(preset) = selected_preset.clone() {
+                        div { class: "action-bar",
+                            div { class: "action-bar-group",
+                                label { {t!("ports.preset-name")} }
+                                input {
+                                    r#type: "text",
+                                    class: "input",
+                                    value: "{preset.name}",
+                                    onchange: move |e| {
+                                        let index = state().selected_preset;
+                                        if let Some(p) = app_settings.write().port_presets.get_mut(index) {
+                                            p.name = e.value();
+                                        }
+                                        save_settings(state, app_settings);
+                                    },
+                                }
+                                input {
+                                    r#type: "number",
+                                    class: "input input-number",
+                                    placeholder: t!("ports.port-label"),
+                                    value: "{current_state.new_preset_port}",
+                                    min: 1,
+                                    max: 65535,
+                                    oninput: move |e| state.write().new_preset_port = e.value(),
+                                }
+                                button {
+                                    class: "btn btn-secondary",
+                                    onclick: on_add_preset_port,
+                                    {t!("ports.preset-add-port")}
+                                }
+                            }
+                            div { class: "action-bar-group",
+                                button {
+                                    class: "btn btn-ghost",
+                                    onclick: on_new_preset,
+                                    {t!("ports.preset-new")}
+                                }
+                                button {
+                                    class: "btn btn-ghost",
+                                    onclick: on_delete_preset,
+                                    {t!("ports.preset-delete")}
+                                }
+                            }
+                        }
+                        if preset.ports.is_empty() {
+                            p { class: "muted", {t!("ports.preset-no-ports")} }
+                        } else {
+                            div { class: "preset-ports",
+                                for port in preset.ports.iter().copied() {
+                                    span { class: "preset-port",
+                                        span { class: "mono", "{port}" }
+                                        if let Some(service) = ports::well_known_service(port) {
+                                            span { class: "muted", "{service}" }
+                                        }
+                                        button {
+                                            class: "btn btn-ghost btn-sm",
+                                            title: t!("common.remove"),
+                                            onclick: move |_| {
+                                                let index = state().selected_preset;
+                                                if let Some(p) = app_settings.write().port_presets.get_mut(index) {
+                                                    p.ports.retain(|&existing| existing != port);
+                                                }
+                                                save_settings(state, app_settings);
+                                            },
+                                            "✕"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    } else {
+                        p { class: "muted", {t!("ports.presets-none")} }
+                        button {
+                            class: "btn btn-secondary",
+                            onclick: on_new_preset,
+                            {t!("ports.preset-new")}
+                        }
+                    }
+                }
+            }
+
+            if let Some(name) = current_state.pending_kill_name.clone() {
+                ConfirmDialog {
+                    title: t!("ports.kill-by-name-confirm-title", name = name),
+                    message: t!("ports.kill-by-name-confirm", name = name),
+                    confirm_label: t!("ports.kill-by-name"),
+                    danger: true,
+                    on_confirm: on_kill_by_name,
+                    on_cancel: move |_| state.write().pending_kill_name = None,
+                }
+            }
+
+            if current_state.watching {
+                div { class: "watch-indicator",
+                    span { class: "watch-dot" }
+                    {t!("ports.watching", target = watch_target, secs = current_state.watch_interval_secs)}
+                }
+            }
+
+            if let Some(port) = current_state.free_wait_port {
+                div { class: "watch-indicator",
+                    span { class: "watch-dot" }
+                    {t!("ports.free-waiting", port = port)}
+                    button {
+                        class: "btn btn-ghost btn-sm",
+                        onclick: move |_| state.write().free_wait_port = None,
+                        {t!("common.cancel")}
+                    }
+                }
+            }
+
+            // Kill was denied because the target runs elevated
+            if current_state.kill_needs_admin && !is_admin {
+                div { class: "status-bar warning",
+                    {t!("ports.kill-needs-admin")}
+                    " "
+                    button {
+                        class: "btn btn-primary btn-sm",
+                        onclick: on_restart_as_admin,
+                        {t!("common.restart-admin")}
+                    }
+                    " "
+                    button {
+                        class: "btn btn-ghost btn-sm",
+                        onclick: move |_| state.write().kill_needs_admin = false,
+                        {t!("common.dismiss")}
+                    }
+                }
+            }
+
+            // Scan history
+            if !current_state.history.is_empty() {
+                div { class: "action-bar",
+                    div { class: "action-bar-group",
+                        label { {t!("ports.history")} }
+                        select {
+                            class: "input",
+                            value: current_state.viewing_history.map(|i| i.to_string()).unwrap_or_default(),
+                            onchange: move |e| {
+                                state.write().viewing_history = e.value().parse().ok();
+                            },
+                            option { value: "", {t!("ports.history-live")} }
+                            for (index, snapshot) in current_state.history.iter().enumerate().rev() {
+                                option {
+                                    value: "{index}",
+                                    selected: current_state.viewing_history == Some(index),
+                                    {t!(
+                                        "ports.history-entry",
+                                        time = snapshot.taken_at.format("%H:%M:%S"),
+                                        target = snapshot.target,
+                                        count = snapshot.result.bindings.len(),
+                                    )}
+                                }
+                            }
+                        }
+                    }
+                    label { class: "checkbox-label",
+                        title: t!("ports.history-diff-hint"),
+                        input {
+                            r#type: "checkbox",
+                            checked: current_state.show_diff,
+                            onchange: move |e| state.write().show_diff = e.checked(),
+                        }
+                        {t!("ports.history-diff")}
+                    }
+                    if current_state.show_diff {
+                        if previous.is_some() {
+                            span { class: "muted",
+                                {t!("ports.history-diff-count", added = added.len(), removed = removed.len())}
+                            }
+                        } else {
+                            span { class: "muted", {t!("ports.history-diff-none")} }
+                        }
+                    }
+                }
+            }
+
+            if let Some(snapshot) = current_state.viewing_history.and_then(|i| current_state.history.get(i)) {
+                div { class: "status-bar info",
+                    {t!("ports.history-viewing", time = snapshot.taken_at.format("%H:%M:%S"), target = snapshot.target)}
+                    " "
+                    button {
+                        class: "btn btn-ghost btn-sm",
+                        onclick: move |_| state.write().viewing_history = None,
+                        {t!("ports.history-back")}
+                    }
+                }
+            }
+
+            // Socket table enumeration failed; an empty table here is not "free"
+            if let Some(error) = &result.socket_error {
+                if result.from_netstat {
+                    div { class: "status-bar warning", {t!("ports.socket-error-fallback", error = error)} }
+                } else {
+                    div { class: "status-bar error", {t!("ports.socket-error", error = error)} }
+                }
+            }
+
+            // Port is free but reserved by Windows
+            if let (Some((port, range)), false) = (&current_state.excluded_range, viewing_history) {
+                div { class: "status-bar warning",
+                    {t!("ports.excluded-range-banner", port = port, start = range.start(), end = range.end())}
+                    " "
+                    button {
+                        class: "btn btn-secondary btn-sm",
+                        title: t!("ports.excluded-range-copy-hint"),
+                        onclick: on_copy_exclusion_guidance,
+                        {t!("ports.excluded-range-copy")}
                     }
                 }
             }
 
             // Orphan warning
-            if !current_state.scan_result.orphan_pids.is_empty() {
+            if !result.orphan_pids.is_empty() {
                 div { class: "status-bar warning",
-                    "⚠️ Orphaned sockets detected! These are sockets where the process has exited but the connection remains. ",
-                    "This can happen with http.sys or crashed applications. Try 'Force Close' or restart your machine."
+                    {t!("ports.orphan-warning")}
                 }
             }
 
             // Docker/WSL info banner
-            if !current_state.scan_result.docker_bindings.is_empty() {
+            if !result.docker_bindings.is_empty() {
                 div { class: "status-bar info",
-                    "🐳 Docker container detected. To free this port, stop the container: ",
+                    {t!("ports.docker-banner")}
+                    " ",
                     code { "docker stop <container_name>" }
                 }
             }
 
             // WSL info banner
-            if !current_state.scan_result.wsl_bindings.is_empty() && current_state.scan_result.docker_bindings.is_empty() {
+            if !result.wsl_bindings.is_empty() && result.docker_bindings.is_empty() {
                 div { class: "status-bar info",
-                    "🐧 WSL process detected. To free this port, run inside WSL: ",
+                    {t!("ports.wsl-banner")}
+                    " ",
                     code { "kill <pid>" },
-                    " or terminate the WSL distro."
+                    " ",
+                    {t!("ports.wsl-banner-tail")}
                 }
             }
 
             // Shadow binding warning
-            if current_state.scan_result.shadow_detected {
+            if result.shadow_detected {
+                div { class: "status-bar warning",
+                    {t!("ports.shadow-warning")}
+                }
+            }
+
+            // TIME_WAIT pile-up warning
+            if time_wait_count > ports::TIME_WAIT_WARN_THRESHOLD {
                 div { class: "status-bar warning",
-                    "👻 Shadow binding detected! The port is in use at the kernel level but no visible process found. ",
-                    "This can happen with Docker/WSL not running, or Hyper-V networking. Try restarting Docker Desktop or WSL."
+                    {t!("ports.time-wait-warning", count = time_wait_count)}
+                    if !current_state.watch_until_clear {
+                        " "
+                        button {
+                            class: "btn btn-secondary btn-sm",
+                            onclick: on_watch_time_wait,
+                            {t!("ports.time-wait-watch")}
+                        }
+                    }
+                }
+            }
+
+            // Socket state counts
+            if !result.state_counts.is_empty() {
+                div { class: "stats-grid",
+                    for (socket_state, count) in result.state_counts.iter() {
+                        div {
+                            class: if (socket_state == "TIME_WAIT" || socket_state == "CLOSE_WAIT") && *count > ports::TIME_WAIT_WARN_THRESHOLD { "stat-card stat-card-warning" } else { "stat-card" },
+                            span { class: "stat-value", "{count}" }
+                            span { class: "stat-label", "{socket_state}" }
+                        }
+                    }
                 }
             }
 
             // Results table
-            if current_state.scan_result.bindings.is_empty() {
-                div { class: "empty-state",
-                    div { class: "empty-state-icon", "📋" }
-                    p { class: "empty-state-text", "Enter a port number and click Scan to find listeners" }
+            if result.bindings.is_empty() && removed.is_empty() {
+                // After a failed enumeration the error banner above explains the empty table
+                if !result.scan_failed() {
+                    div { class: "empty-state",
+                        div { class: "empty-state-icon", "📋" }
+                        p { class: "empty-state-text", {t!("ports.empty")} }
+                    }
                 }
             } else {
+                div { class: "action-bar",
+                    div { class: "action-bar-group",
+                        label { {t!("ports.filter")} }
+                        input {
+                            r#type: "text",
+                            class: "input",
+                            placeholder: t!("ports.filter-placeholder"),
+                            value: "{current_state.result_filter}",
+                            oninput: move |e| {
+                                state.write().result_filter = e.value();
+                            },
+                        }
+                    }
+                    label { class: "checkbox-label",
+                        title: t!("ports.listeners-only-hint"),
+                        input {
+                            r#type: "checkbox",
+                            checked: current_state.listeners_only,
+                            onchange: move |e| state.write().listeners_only = e.checked(),
+                        }
+                        {t!("ports.listeners-only")}
+                    }
+                    label { class: "checkbox-label",
+                        input {
+                            r#type: "checkbox",
+                            checked: current_state.group_by_process,
+                            onchange: move |e| state.write().group_by_process = e.checked(),
+                        }
+                        {t!("ports.group-by-process")}
+                    }
+                    if sorted_bindings.len() != result.bindings.len() {
+                        span { class: "muted",
+                            {t!(
+                                "ports.filter-count",
+                                shown = sorted_bindings.len(),
+                                total = result.bindings.len(),
+                                hidden = result.bindings.len() - sorted_bindings.len(),
+                            )}
+                        }
+                    }
+                }
+                div { class: "action-bar-group",
+                    label { class: "checkbox-label",
+                        input {
+                            r#type: "checkbox",
+                            checked: current_state.ipv4_only,
+                            onchange: move |e| {
+                                let mut s = state.write();
+                                s.ipv4_only = e.checked();
+                                if s.ipv4_only {
+                                    s.ipv6_only = false;
+                                }
+                            },
+                        }
+                        {t!("ports.ipv4-only")}
+                    }
+                    label { class: "checkbox-label",
+                        input {
+                            r#type: "checkbox",
+                            checked: current_state.ipv6_only,
+                            onchange: move |e| {
+                                let mut s = state.write();
+                                s.ipv6_only = e.checked();
+                                if s.ipv6_only {
+                                    s.ipv4_only = false;
+                                }
+                            },
+                        }
+                        {t!("ports.ipv6-only")}
+                    }
+                    label { class: "checkbox-label",
+                        input {
+                            r#type: "checkbox",
+                            checked: current_state.hide_loopback,
+                            onchange: move |e| state.write().hide_loopback = e.checked(),
+                        }
+                        {t!("ports.hide-loopback")}
+                    }
+                    label { class: "checkbox-label",
+                        title: t!("ports.hide-link-local-hint"),
+                        input {
+                            r#type: "checkbox",
+                            checked: current_state.hide_link_local,
+                            onchange: move |e| state.write().hide_link_local = e.checked(),
+                        }
+                        {t!("ports.hide-link-local")}
+                    }
+                }
                 table { class: "data-table",
                     thead {
                         tr {
                             SortableHeader {
                                 column: PortsSortColumn::Source,
-                                label: "Source".to_string(),
+                                label: t!("ports.col.source"),
                                 current_sort: current_state.sort_column,
                                 direction: sort_dir,
                                 on_sort: move |col| update_port_sort(state, col),
                             }
+                            SortableHeader {
+                                column: PortsSortColumn::Protocol,
+                                label: t!("ports.col.protocol"),
+                                current_sort: current_state.sort_column,
+                                direction: sort_dir,
+                                on_sort: move |col| update_port_sort(state, col),
+                            }
+                            if show_port {
+                                SortableHeader {
+                                    column: PortsSortColumn::Port,
+                                    label: t!("ports.col.port"),
+                                    current_sort: current_state.sort_column,
+                                    direction: sort_dir,
+                                    on_sort: move |col| update_port_sort(state, col),
+                                }
+                            }
                             SortableHeader {
                                 column: PortsSortColumn::Pid,
-                                label: "PID".to_string(),
+                                label: t!("ports.col.pid"),
                                 current_sort: current_state.sort_column,
                                 direction: sort_dir,
                                 on_sort: move |col| update_port_sort(state, col),
                             }
                             SortableHeader {
                                 column: PortsSortColumn::Process,
-                                label: "Process".to_string(),
+                                label: t!("ports.col.process"),
                                 current_sort: current_state.sort_column,
                                 direction: sort_dir,
                                 on_sort: move |col| update_port_sort(state, col),
                             }
                             SortableHeader {
                                 column: PortsSortColumn::Address,
-                                label: "Local Address".to_string(),
+                                label: t!("ports.col.address"),
+                                current_sort: current_state.sort_column,
+                                direction: sort_dir,
+                                on_sort: move |col| update_port_sort(state, col),
+                            }
+                            SortableHeader {
+                                column: PortsSortColumn::Remote,
+                                label: t!("ports.col.remote"),
                                 current_sort: current_state.sort_column,
                                 direction: sort_dir,
                                 on_sort: move |col| update_port_sort(state, col),
                             }
                             SortableHeader {
                                 column: PortsSortColumn::State,
-                                label: "State".to_string(),
+                                label: t!("ports.col.state"),
                                 current_sort: current_state.sort_column,
                                 direction: sort_dir,
                                 on_sort: move |col| update_port_sort(state, col),
                             }
                             SortableHeader {
                                 column: PortsSortColumn::Scope,
-                                label: "Scope".to_string(),
+                                label: t!("ports.col.scope"),
                                 current_sort: current_state.sort_column,
                                 direction: sort_dir,
                                 on_sort: move |col| update_port_sort(state, col),
                             }
-                            StaticHeader { label: "Actions".to_string() }
+                            StaticHeader { label: t!("ports.col.actions") }
                         }
                     }
                     tbody {
-                        for binding in sorted_bindings.iter().cloned() {
+                        if current_state.group_by_process {
+                            for (summary, members) in groups.iter().cloned() {
+                                if members.len() > 1 {
+                                    ProcessGroupRow {
+                                        is_conflict: result.conflict_pids.contains(&summary.pid),
+                                        expanded: current_state.expanded_groups.contains(&summary.pid),
+                                        summary,
+                                        members: members.clone(),
+                                        show_port,
+                                        read_only: viewing_history,
+                                        on_toggle: on_toggle_group,
+                                        on_kill,
+                                    }
+                                    if current_state.expanded_groups.contains(&members[0].pid) {
+                                        // Conflict highlighting lives on the group row
+                                        for binding in members.iter().cloned() {
+                                            PortRow {
+                                                extra_class: format!("group-member {}", added_class(&binding)),
+                                                binding,
+                                                is_conflict: false,
+                                                show_port,
+                                                read_only: viewing_history,
+                                                on_kill,
+                                                on_force_close,
+                                                on_test_connect,
+                                            }
+                                        }
+                                    }
+                                } else {
+                                    PortRow {
+                                        extra_class: added_class(&members[0]),
+                                        is_conflict: result.conflict_pids.contains(&summary.pid),
+                                        binding: members[0].clone(),
+                                        show_port,
+                                        read_only: viewing_history,
+                                        on_kill,
+                                        on_force_close,
+                                        on_test_connect,
+                                    }
+                                }
+                            }
+                        } else {
+                            for binding in sorted_bindings.iter().cloned() {
+                                PortRow {
+                                    extra_class: added_class(&binding),
+                                    binding: binding.clone(),
+                                    is_conflict: result.conflict_pids.contains(&binding.pid),
+                                    show_port,
+                                    read_only: viewing_history,
+                                    on_kill,
+                                    on_force_close,
+                                    on_test_connect,
+                                }
+                            }
+                        }
+                        for binding in removed.iter().cloned() {
                             PortRow {
-                                binding: binding.clone(),
-                                is_conflict: current_state.scan_result.conflict_pids.contains(&binding.pid),
-                                on_kill: move |pid| on_kill(pid),
-                                on_force_close: move |b| on_force_close(b),
+                                binding,
+                                is_conflict: false,
+                                show_port,
+                                read_only: true,
+                                extra_class: "diff-removed",
+                                on_kill,
+                                on_force_close,
+                                on_test_connect,
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Firewall rules for the checked port
+            if let Some(rules) = &current_state.firewall_rules {
+                div { class: "section",
+                    h3 { class: "section-title", {t!("ports.firewall-title", port = current_state.firewall_port)} }
+                    match rules {
+                        Ok(rules) if rules.is_empty() => rsx! {
+                            p { class: "muted", {t!("ports.firewall-none")} }
+                        },
+                        Ok(rules) => rsx! {
+                            table { class: "data-table",
+                                thead {
+                                    tr {
+                                        StaticHeader { label: t!("ports.firewall.col.name") }
+                                        StaticHeader { label: t!("ports.firewall.col.direction") }
+                                        StaticHeader { label: t!("ports.firewall.col.action") }
+                                        StaticHeader { label: t!("ports.firewall.col.enabled") }
+                                        StaticHeader { label: t!("ports.col.protocol") }
+                                        StaticHeader { label: t!("ports.firewall.col.ports") }
+                                    }
+                                }
+                                tbody {
+                                    for rule in rules.iter() {
+                                        tr { class: if rule.blocks_inbound() { "conflict" } else { "" },
+                                            td { "{rule.name}" }
+                                            td { "{rule.direction}" }
+                                            td { "{rule.action}" }
+                                            td { class: "muted",
+                                                if rule.enabled { {t!("common.yes")} } else { {t!("common.no")} }
+                                            }
+                                            td { class: "mono", "{rule.protocol}" }
+                                            td { class: "mono", "{rule.local_ports}" }
+                                        }
+                                    }
+                                }
+                            }
+                        },
+                        Err(e) => rsx! {
+                            div { class: "status-bar warning", "{e}" }
+                        },
+                    }
+                    div { class: "action-bar",
+                        div { class: "action-bar-group",
+                            label { {t!("ports.allow-rule-name")} }
+                            input {
+                                r#type: "text",
+                                class: "input",
+                                value: "{current_state.allow_rule_name}",
+                                oninput: move |e| state.write().allow_rule_name = e.value(),
+                            }
+                            select {
+                                class: "input",
+                                value: current_state.allow_rule_protocol.label(),
+                                onchange: move |e| {
+                                    state.write().allow_rule_protocol =
+                                        if e.value() == Protocol::Udp.label() { Protocol::Udp } else { Protocol::Tcp };
+                                },
+                                for protocol in [Protocol::Tcp, Protocol::Udp] {
+                                    option {
+                                        value: protocol.label(),
+                                        selected: current_state.allow_rule_protocol == protocol,
+                                        {protocol.label()}
+                                    }
+                                }
+                            }
+                            ActionButton {
+                                danger: DangerLevel::Modifying,
+                                class: "btn btn-primary",
+                                disabled: current_state.creating_allow_rule || !is_admin,
+                                onclick: on_allow_inbound,
+                                title: if !is_admin { t!("common.requires-admin") } else { t!("ports.allow-rule-hint", port = current_state.firewall_port) },
+                                if current_state.creating_allow_rule { {t!("ports.allow-rule-creating-short")} } else { {t!("ports.allow-rule")} }
                             }
                         }
                     }
                 }
             }
 
+            // Output of firewall commands
+            if !current_state.command_outputs.is_empty() {
+                OutputPanel {
+                    outputs: current_state.command_outputs.clone(),
+                    on_clear: move |_| state.write().command_outputs.clear(),
+                    is_admin,
+                }
+            }
+
             // Status bar
             if !current_state.status_message.is_empty() {
                 div {
@@ -445,13 +1839,48 @@ pub fn PortsPage(is_admin: bool) -> Element {
     }
 }
 
+/// Kill and kill-tree buttons shared by binding rows and process group rows
+#[component]
+fn KillButtons(
+    pid: u32,
+    /// Kill a PID; the flag requests killing its whole process tree
+    on_kill: EventHandler<(u32, bool)>,
+) -> Element {
+    rsx! {
+        div { class: "btn-split",
+            ActionButton {
+                danger: DangerLevel::Destructive,
+                class: "btn btn-danger btn-sm",
+                onclick: move |_| on_kill.call((pid, false)),
+                {t!("ports.kill")}
+            }
+            ActionButton {
+                danger: DangerLevel::Destructive,
+                class: "btn btn-danger btn-sm",
+                title: t!("ports.kill-tree-hint"),
+                onclick: move |_| on_kill.call((pid, true)),
+                {t!("ports.kill-tree")}
+            }
+        }
+    }
+}
+
 /// Individual port binding row
 #[component]
 fn PortRow(
     binding: PortBinding,
     is_conflict: bool,
-    on_kill: EventHandler<u32>,
+    /// Show the Port column (range scans mix several ports)
+    show_port: bool,
+    /// History snapshots and removed diff rows get no actions
+    read_only: bool,
+    /// Additional row classes (diff highlighting, group members)
+    #[props(into)]
+    extra_class: String,
+    /// Kill a PID; the flag requests killing its whole process tree
+    on_kill: EventHandler<(u32, bool)>,
     on_force_close: EventHandler<PortBinding>,
+    on_test_connect: EventHandler<PortBinding>,
 ) -> Element {
     // Determine row styling based on source and status
     let row_class = match binding.source {
@@ -477,7 +1906,23 @@ fn PortRow(
         BindingSource::Windows => "badge badge-windows",
     };
 
+    // Tooltip telling apart processes with the same name (e.g. several node.exe)
+    let process_details = [
+        binding.exe_path.as_ref().map(|path| t!("ports.process-path", path = path)),
+        binding.cmdline.as_ref().map(|cmd| t!("ports.process-cmdline", cmdline = cmd)),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>()
+    .join("\n");
+
     let binding_for_close = binding.clone();
+    let binding_for_test = binding.clone();
+    let service = ports::well_known_service(binding.local_port);
+    // Connect tests only make sense against a TCP listener with a known address
+    let can_test_connect = binding.protocol == Protocol::Tcp
+        && binding.is_listener
+        && binding.source != BindingSource::UnknownShadow;
     let pid_display = if binding.pid == 0 {
         "-".to_string()
     } else {
@@ -485,50 +1930,84 @@ fn PortRow(
     };
 
     rsx! {
-        tr { class: row_class,
+        tr { class: "{row_class} {extra_class}",
             td {
                 span { class: source_class, "{binding.source.description()}" }
             }
+            td { class: "mono", "{binding.protocol.label()}" }
+            if show_port {
+                td { class: "mono",
+                    "{binding.local_port}"
+                    if let Some(service) = service {
+                        span { class: "muted port-service", "{service}" }
+                    }
+                }
+            }
             td { class: "mono", "{pid_display}" }
-            td { "{binding.process_name}" }
-            td { class: "mono", "{binding.address()}" }
+            td { title: "{process_details}",
+                "{binding.process_name}"
+                if !binding.services.is_empty() {
+                    span { class: "muted port-service", {binding.services.join(", ")} }
+                }
+            }
+            td { class: "mono",
+                "{binding.address()}"
+                // Without a Port column the hint goes next to the address
+                if !show_port {
+                    if let Some(service) = service {
+                        span { class: "muted port-service", "{service}" }
+                    }
+                }
+            }
+            td { class: "mono", {binding.remote_address().unwrap_or_default()} }
             td { class: "muted", "{binding.state}" }
             td { class: row_class, "{binding.scope_description()}" }
             td {
-                // Action buttons based on source
-                match binding.source {
-                    BindingSource::Docker => rsx! {
-                        span { class: "muted hint",
-                            title: "Stop the Docker container to free this port",
-                            "🐳 docker stop"
-                        }
-                    },
-                    BindingSource::Wsl => rsx! {
-                        span { class: "muted hint",
-                            title: format!("Kill process {} in WSL distro: {}", binding.pid, binding.source_detail),
-                            "🐧 wsl kill {binding.pid}"
-                        }
-                    },
-                    BindingSource::UnknownShadow => rsx! {
-                        span { class: "muted hint",
-                            title: "Shadow binding - try restarting Docker/WSL",
-                            "👻 Unknown"
-                        }
-                    },
-                    BindingSource::Windows => rsx! {
-                        if binding.is_orphan {
+                if read_only {
+                    span { class: "muted", "-" }
+                } else {
+                    div { class: "row-actions",
+                        if can_test_connect {
                             button {
-                                class: "btn btn-warning btn-sm",
-                                onclick: move |_| on_force_close.call(binding_for_close.clone()),
-                                "Force Close"
+                                class: "btn btn-secondary btn-sm",
+                                title: t!("ports.test-connect-hint"),
+                                onclick: move |_| on_test_connect.call(binding_for_test.clone()),
+                                {t!("ports.test-connect")}
                             }
-                        } else if binding.is_system {
-                            span { class: "muted", "System" }
-                        } else {
-                            button {
-                                class: "btn btn-danger btn-sm",
-                                onclick: move |_| on_kill.call(binding.pid),
-                                "Kill"
+                        }
+                        // Action buttons based on source
+                        match binding.source {
+                            BindingSource::Docker => rsx! {
+                                span { class: "muted hint",
+                                    title: t!("ports.docker-stop-hint"),
+                                    "🐳 docker stop"
+                                }
+                            },
+                            BindingSource::Wsl => rsx! {
+                                span { class: "muted hint",
+                                    title: t!("ports.wsl-kill-hint", pid = binding.pid, distro = binding.source_detail),
+                                    "🐧 wsl kill {binding.pid}"
+                                }
+                            },
+                            BindingSource::UnknownShadow => rsx! {
+                                span { class: "muted hint",
+                                    title: t!("ports.shadow-hint"),
+                                    {t!("ports.unknown")}
+                                }
+                            },
+                            BindingSource::Windows => rsx! {
+                                if binding.is_orphan {
+                                    ActionButton {
+                                        danger: DangerLevel::Destructive,
+                                        class: "btn btn-warning btn-sm",
+                                        onclick: move |_| on_force_close.call(binding_for_close.clone()),
+                                        {t!("ports.force-close")}
+                                    }
+                                } else if binding.is_system {
+                                    span { class: "muted", {t!("ports.system")} }
+                                } else {
+                                    KillButtons { pid: binding.pid, on_kill }
+                                }
                             }
                         }
                     }
@@ -537,3 +2016,81 @@ fn PortRow(
         }
     }
 }
+
+/// Collapsed row for a process with several bindings (group-by-process view)
+#[component]
+fn ProcessGroupRow(
+    /// First binding of the group with the members' states merged
+    summary: PortBinding,
+    members: Vec<PortBinding>,
+    is_conflict: bool,
+    show_port: bool,
+    /// Member rows are shown below the group row
+    expanded: bool,
+    read_only: bool,
+    /// Expand/collapse the group of a PID
+    on_toggle: EventHandler<u32>,
+    /// Kill the group's PID; the flag requests killing its whole process tree
+    on_kill: EventHandler<(u32, bool)>,
+) -> Element {
+    let row_class = if summary.is_orphan {
+        "orphan"
+    } else if is_conflict {
+        "conflict"
+    } else {
+        ""
+    };
+
+    let mut protocols: Vec<&str> = members.iter().map(|b| b.protocol.label()).collect();
+    protocols.dedup();
+    let protocols = protocols.join("/");
+    let mut ports: Vec<u16> = members.iter().map(|b| b.local_port).collect();
+    ports.sort_unstable();
+    ports.dedup();
+    let ports = ports.iter().map(u16::to_string).collect::<Vec<_>>().join(", ");
+    let connections = members.iter().filter(|b| b.remote_address().is_some()).count();
+    let pid = summary.pid;
+
+    rsx! {
+        tr { class: "process-group {row_class}",
+            td {
+                span { class: "badge badge-windows", "{summary.source.description()}" }
+            }
+            td { class: "mono", "{protocols}" }
+            if show_port {
+                td { class: "mono", "{ports}" }
+            }
+            td { class: "mono", "{pid}" }
+            td {
+                "{summary.process_name}"
+                if !summary.services.is_empty() {
+                    span { class: "muted port-service", {summary.services.join(", ")} }
+                }
+            }
+            td {
+                button {
+                    class: "btn btn-ghost btn-sm group-toggle",
+                    onclick: move |_| on_toggle.call(pid),
+                    if expanded { "▾ " } else { "▸ " }
+                    {t!("ports.group-bindings", count = members.len())}
+                }
+            }
+            td { class: "muted",
+                if connections > 0 {
+                    {t!("ports.group-connections", count = connections)}
+                }
+            }
+            td { class: "muted", "{summary.state}" }
+            td { class: row_class, "{summary.scope_description()}" }
+            td {
+                if read_only || summary.is_orphan {
+                    span { class: "muted", "-" }
+                } else if summary.is_system {
+                    span { class: "muted", {t!("ports.system")} }
+                } else {
+                    KillButtons { pid, on_kill }
+                }
+            }
+        }
+    }
+}
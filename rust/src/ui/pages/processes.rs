@@ -4,61 +4,136 @@
 
 use dioxus::prelude::*;
 
-use crate::state::{ProcessesPageState, ProcessesSortColumn};
-use crate::system::processes;
-use crate::ui::components::{SortableHeader, StaticHeader, SortDirection};
-
-fn update_process_sort(mut state: Signal<ProcessesPageState>, column: ProcessesSortColumn) {
-    let mut s = state.write();
-    if s.sort_column == Some(column) {
-        s.sort_ascending = !s.sort_ascending;
-    } else {
-        s.sort_column = Some(column);
-        s.sort_ascending = true;
-        
-        // Default sort direction logic override
-        match column {
-            ProcessesSortColumn::Memory | ProcessesSortColumn::Cpu | 
-            ProcessesSortColumn::Handles => s.sort_ascending = false,
-            _ => {}
-        }
-    }
+use crate::state::{DangerLevel, ProcessesPageState, ProcessesSortColumn, TableColumn};
+use crate::system::processes::ProcessInfo;
+use crate::system::{health, processes};
+use crate::ui::components::{
+    copy_to_clipboard, ActionButton, ConfirmDialog, SortableHeader, SortDirection, StaticHeader,
+};
+
+/// Process table columns
+const PROCESS_COLUMNS: &[TableColumn<ProcessInfo, ProcessesSortColumn>] = &[
+    TableColumn {
+        id: ProcessesSortColumn::Pid,
+        label: "PID",
+        label_key: "processes.col.pid",
+        text: |p| p.pid.to_string(),
+        compare: |a, b| a.pid.cmp(&b.pid),
+        descending_first: false,
+    },
+    TableColumn {
+        id: ProcessesSortColumn::Name,
+        label: "Name",
+        label_key: "processes.col.name",
+        text: |p| p.name.clone(),
+        compare: |a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+        descending_first: false,
+    },
+    TableColumn {
+        id: ProcessesSortColumn::Memory,
+        label: "Memory",
+        label_key: "processes.col.memory",
+        text: |p| format!("{:.1} MB", p.memory_mb),
+        compare: |a, b| a.memory_mb.partial_cmp(&b.memory_mb).unwrap_or(std::cmp::Ordering::Equal),
+        descending_first: true,
+    },
+    TableColumn {
+        id: ProcessesSortColumn::Handles,
+        label: "Handles",
+        label_key: "processes.col.handles",
+        text: |p| if p.handle_count > 0 { p.handle_count.to_string() } else { "-".to_string() },
+        compare: |a, b| a.handle_count.cmp(&b.handle_count),
+        descending_first: true,
+    },
+    TableColumn {
+        id: ProcessesSortColumn::Threads,
+        label: "Threads",
+        label_key: "processes.col.threads",
+        text: |p| if p.thread_count > 0 { p.thread_count.to_string() } else { "-".to_string() },
+        compare: |a, b| a.thread_count.cmp(&b.thread_count),
+        descending_first: true,
+    },
+    TableColumn {
+        id: ProcessesSortColumn::Cpu,
+        label: "CPU %",
+        label_key: "processes.col.cpu",
+        text: |p| format!("{:.1}%", p.cpu_percent),
+        compare: |a, b| a.cpu_percent.partial_cmp(&b.cpu_percent).unwrap_or(std::cmp::Ordering::Equal),
+        descending_first: true,
+    },
+    TableColumn {
+        id: ProcessesSortColumn::Status,
+        label: "Status",
+        label_key: "processes.col.status",
+        text: |p| p.status.clone(),
+        compare: |a, b| a.status.cmp(&b.status),
+        descending_first: false,
+    },
+];
+
+/// Reload the system overview and the top-50 list for the current fetch mode
+fn refresh_processes(mut state: Signal<ProcessesPageState>) {
+    let sort_by = state().sort_by.clone();
+    state.write().is_running = true;
+    state.write().running_action = "Refresh".to_string();
+    state.write().status_message = t!("processes.loading");
+    state.write().status_type = String::new();
+
+    spawn(async move {
+        // Get system info
+        let sys_info = processes::get_system_info();
+
+        // Name the biggest commit consumers when the machine is close to its commit limit
+        let critical = sys_info
+            .commit
+            .map(|c| c.pressure() == processes::MemoryPressure::Danger)
+            .unwrap_or(false);
+        let top_private = if critical {
+            processes::get_top_by_private_bytes(3)
+        } else {
+            Vec::new()
+        };
+
+        // Get processes based on sort
+        let procs = if sort_by == "cpu" {
+            processes::get_top_by_cpu(50)
+        } else if sort_by == "handles" {
+            processes::get_top_by_handles(50)
+        } else {
+            processes::get_top_by_memory(50)
+        };
+
+        let view = match sort_by.as_str() {
+            "cpu" => t!("processes.fetch.cpu"),
+            "handles" => t!("processes.fetch.handles"),
+            _ => t!("processes.fetch.memory"),
+        };
+
+        let mut s = state.write();
+        s.system_info = sys_info;
+        s.top_private = top_private;
+        s.fast_startup = health::fast_startup_enabled();
+        s.processes = procs;
+        s.is_running = false;
+        s.running_action = String::new();
+        s.status_message = t!("processes.loaded", count = s.processes.len(), view = view);
+        s.status_type = "success".to_string();
+    });
 }
 
 /// Processes page
 #[component]
 pub fn ProcessesPage(is_admin: bool) -> Element {
     let mut state: Signal<ProcessesPageState> = use_context();
+    let mut confirm_explorer = use_signal(|| false);
 
     // Refresh processes
-    let on_refresh = move |_| {
-        let sort_by = state().sort_by.clone();
-        state.write().is_running = true;
-        state.write().running_action = "Refresh".to_string();
-        state.write().status_message = "Loading processes...".to_string();
-        state.write().status_type = String::new();
+    let on_refresh = move |_| refresh_processes(state);
 
-        spawn(async move {
-            // Get system info
-            let sys_info = processes::get_system_info();
-            
-            // Get processes based on sort
-            let procs = if sort_by == "cpu" {
-                processes::get_top_by_cpu(50)
-            } else if sort_by == "handles" {
-                processes::get_top_by_handles(50)
-            } else {
-                processes::get_top_by_memory(50)
-            };
-
-            let mut s = state.write();
-            s.system_info = sys_info;
-            s.processes = procs;
-            s.is_running = false;
-            s.running_action = String::new();
-            s.status_message = format!("Loaded {} processes (sorted by {})", s.processes.len(), sort_by);
-            s.status_type = "success".to_string();
-        });
+    // Switch to the memory view and reload (suggested by the commit pressure banner)
+    let on_show_memory = move |_| {
+        state.write().sort_by = "memory".to_string();
+        refresh_processes(state);
     };
 
     // Kill process handler
@@ -66,7 +141,7 @@ pub fn ProcessesPage(is_admin: bool) -> Element {
         let pid = match state().selected_pid {
             Some(p) => p,
             None => {
-                state.write().status_message = "Select a process first".to_string();
+                state.write().status_message = t!("processes.select-first");
                 state.write().status_type = "warning".to_string();
                 return;
             }
@@ -74,13 +149,13 @@ pub fn ProcessesPage(is_admin: bool) -> Element {
 
         state.write().is_running = true;
         state.write().running_action = "Kill".to_string();
-        state.write().status_message = format!("Killing process {}...", pid);
+        state.write().status_message = t!("processes.killing", pid = pid);
 
         spawn(async move {
             match processes::kill_process(pid) {
                 Ok(_) => {
                     let mut s = state.write();
-                    s.status_message = format!("Process {} terminated", pid);
+                    s.status_message = t!("processes.killed", pid = pid);
                     s.status_type = "success".to_string();
                     s.selected_pid = None;
                     // Remove from list
@@ -99,76 +174,236 @@ pub fn ProcessesPage(is_admin: bool) -> Element {
 
 
 
+    // Restart Explorer handler (runs after the confirm dialog)
+    let on_restart_explorer = move |_| {
+        confirm_explorer.set(false);
+        state.write().is_running = true;
+        state.write().running_action = "Explorer".to_string();
+        state.write().status_message = t!("processes.explorer-restarting");
+        state.write().status_type = String::new();
+
+        spawn(async move {
+            let result = processes::restart_explorer().await;
+            let mut s = state.write();
+            match result {
+                Ok(msg) => {
+                    s.status_message = msg;
+                    s.status_type = "success".to_string();
+                }
+                Err(e) => {
+                    s.status_message = e;
+                    s.status_type = "error".to_string();
+                }
+            }
+            s.is_running = false;
+            s.running_action = String::new();
+        });
+    };
+
+    // Disable Fast Startup so "Shut down" performs a full kernel restart
+    let on_disable_fast_startup = move |_| {
+        state.write().is_running = true;
+        state.write().running_action = "Fast Startup".to_string();
+        state.write().status_message = t!("processes.fast-startup-disabling");
+        state.write().status_type = String::new();
+
+        spawn(async move {
+            let output = health::set_fast_startup(false).await;
+            let mut s = state.write();
+            if output.succeeded() {
+                s.fast_startup = health::fast_startup_enabled();
+                s.status_message = t!("processes.fast-startup-disabled");
+                s.status_type = "success".to_string();
+            } else {
+                s.status_message = t!("processes.fast-startup-failed", error = output.stderr.trim());
+                s.status_type = "error".to_string();
+            }
+            s.is_running = false;
+            s.running_action = String::new();
+        });
+    };
+
     let current_state = state();
     
-    // Sort processes based on current sort state (local sort of the fetched list)
-    let mut sorted_processes = current_state.processes.clone();
-    if let Some(sort_col) = current_state.sort_column {
-        let asc = current_state.sort_ascending;
-        sorted_processes.sort_by(|a, b| {
-            let cmp = match sort_col {
-                ProcessesSortColumn::Pid => a.pid.cmp(&b.pid),
-                ProcessesSortColumn::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
-                ProcessesSortColumn::Memory => a.memory_mb.partial_cmp(&b.memory_mb).unwrap_or(std::cmp::Ordering::Equal),
-                ProcessesSortColumn::Handles => a.handle_count.cmp(&b.handle_count),
-                ProcessesSortColumn::Cpu => a.cpu_percent.partial_cmp(&b.cpu_percent).unwrap_or(std::cmp::Ordering::Equal),
-                ProcessesSortColumn::Status => a.status.cmp(&b.status),
-            };
-            if asc { cmp } else { cmp.reverse() }
-        });
-    }
+    // Filter and sort the fetched list through the shared table controller
+    let sorted_processes = current_state.table.visible_rows(&current_state.processes, PROCESS_COLUMNS);
+    let sort_dir = SortDirection::from_ascending(current_state.table.sort_ascending);
 
-    // Determine sort direction for display
-    let sort_dir = if current_state.sort_ascending {
-        SortDirection::Ascending
-    } else {
-        SortDirection::Descending
+    // Export the visible rows to the clipboard
+    let mut on_export = move |json: bool| {
+        let s = state();
+        let count = s.table.visible_rows(&s.processes, PROCESS_COLUMNS).len();
+        let text = if json {
+            s.table.export_json(&s.processes, PROCESS_COLUMNS)
+        } else {
+            s.table.export_csv(&s.processes, PROCESS_COLUMNS)
+        };
+        let mut s = state.write();
+        match copy_to_clipboard(&text) {
+            Ok(()) => {
+                s.status_message = t!("processes.copied", count = count, format = if json { "JSON" } else { "CSV" });
+                s.status_type = "success".to_string();
+            }
+            Err(e) => {
+                s.status_message = e;
+                s.status_type = "error".to_string();
+            }
+        }
     };
 
     rsx! {
         header { class: "page-header",
-            h1 { class: "page-title", "📊 Processes" }
-            p { class: "page-subtitle", "Top processes by resource usage" }
+            h1 { class: "page-title", {t!("processes.title")} }
+            p { class: "page-subtitle", {t!("processes.subtitle")} }
         }
 
         div { class: "page-split-layout",
             div { class: "page-controls",
                 // System overview
                 div { class: "section",
-                    h3 { class: "section-title", "System Overview" }
+                    h3 { class: "section-title", {t!("processes.overview")} }
                     div { class: "stats-grid",
                         div { class: "stat-card",
                             span { class: "stat-value", 
                                 "{current_state.system_info.used_memory_gb:.1} / {current_state.system_info.total_memory_gb:.1} GB" 
                             }
-                            span { class: "stat-label", "Memory" }
+                            span { class: "stat-label", {t!("processes.memory")} }
+                        }
+                        if let Some(commit) = current_state.system_info.commit {
+                            div {
+                                class: commit.pressure().card_class(),
+                                title: t!("processes.commit-hint", physical = format!("{:.1}", commit.physical_gb), pagefile = format!("{:.1}", commit.pagefile_gb)),
+                                span { class: "stat-value",
+                                    "{commit.used_gb:.1} / {commit.limit_gb:.1} GB"
+                                }
+                                span { class: "stat-label", {t!("processes.commit", percent = format!("{:.0}", commit.percent()))} }
+                                div { class: "progress-bar",
+                                    div {
+                                        class: "progress-fill",
+                                        style: "width: {commit.percent().min(100.0):.0}%",
+                                    }
+                                }
+                            }
                         }
                         div { class: "stat-card",
                             span { class: "stat-value", "{current_state.system_info.cpu_count}" }
-                            span { class: "stat-label", "CPU Cores" }
+                            span { class: "stat-label", {t!("processes.cpu-cores")} }
                         }
                         div { class: "stat-card",
                             span { class: "stat-value", "{current_state.system_info.process_count}" }
-                            span { class: "stat-label", "Processes" }
+                            span { class: "stat-label", {t!("processes.process-count")} }
+                        }
+                        if current_state.system_info.boot_time > 0 {
+                            div {
+                                class: if current_state.system_info.is_long_uptime() { "stat-card stat-card-warning" } else { "stat-card" },
+                                title: if current_state.system_info.is_long_uptime() {
+                                    t!("processes.long-uptime", days = processes::LONG_UPTIME_DAYS)
+                                } else {
+                                    String::new()
+                                },
+                                span { class: "stat-value", "{current_state.system_info.uptime_display()}" }
+                                span { class: "stat-label", {t!("processes.uptime", since = current_state.system_info.boot_time_display())} }
+                            }
+                        }
+                        if let Some(fast_startup) = current_state.fast_startup {
+                            div { class: "stat-card",
+                                span { class: "stat-value", if fast_startup { {t!("processes.on")} } else { {t!("processes.off")} } }
+                                span { class: "stat-label", {t!("processes.fast-startup")} }
+                            }
+                        }
+                    }
+
+                    if current_state.fast_startup == Some(true) {
+                        div { class: "fast-startup-hint",
+                            p { {t!("processes.fast-startup-hint")} }
+                            ActionButton {
+                                danger: DangerLevel::Modifying,
+                                class: "btn btn-secondary btn-sm",
+                                disabled: current_state.is_running || !is_admin,
+                                title: if !is_admin { t!("common.requires-admin") } else { String::new() },
+                                onclick: on_disable_fast_startup,
+                                if current_state.running_action == "Fast Startup" {
+                                    {t!("processes.fast-startup-disabling-short")}
+                                } else {
+                                    {t!("processes.fast-startup-disable")}
+                                }
+                            }
+                        }
+                    }
+
+                    if let Some(commit) = current_state.system_info.commit.filter(|c| c.pressure() == processes::MemoryPressure::Danger) {
+                        div { class: "commit-pressure-banner",
+                            p {
+                                strong { {t!("processes.commit-banner", percent = format!("{:.0}", commit.percent()))} }
+                                " "
+                                {t!("processes.commit-banner-detail")}
+                            }
+                            if !current_state.top_private.is_empty() {
+                                ul { class: "commit-top-list",
+                                    for proc in current_state.top_private.iter() {
+                                        li {
+                                            span { class: "mono", "{proc.memory_mb:.0} MB" }
+                                            " "
+                                            {t!("processes.commit-top", name = proc.name, pid = proc.pid)}
+                                        }
+                                    }
+                                }
+                            }
+                            button {
+                                class: "btn btn-secondary btn-sm",
+                                disabled: current_state.is_running,
+                                onclick: on_show_memory,
+                                {t!("processes.show-top-memory")}
+                            }
+                        }
+                    }
+                }
+
+                // Quick actions
+                div { class: "section",
+                    h3 { class: "section-title", {t!("processes.quick-actions")} }
+                    div { class: "quick-actions",
+                        ActionButton {
+                            danger: DangerLevel::Modifying,
+                            class: "quick-action-btn",
+                            disabled: current_state.is_running,
+                            title: t!("processes.explorer-hint"),
+                            onclick: move |_| confirm_explorer.set(true),
+                            if current_state.running_action == "Explorer" {
+                                {t!("processes.explorer-restarting-short")}
+                            } else {
+                                {t!("processes.explorer-restart")}
+                            }
                         }
                     }
                 }
 
                 // Controls
                 div { class: "section",
-                    h3 { class: "section-title", "View" }
+                    h3 { class: "section-title", {t!("processes.view")} }
                     div { class: "action-bar",
                         div { class: "action-bar-group",
-                            label { "Fetch Top 50 By:" }
+                            label { {t!("processes.fetch-by")} }
                             select {
                                 class: "input",
                                 value: "{current_state.sort_by}",
                                 onchange: move |e| {
                                     state.write().sort_by = e.value();
                                 },
-                                option { value: "memory", "Memory" }
-                                option { value: "cpu", "CPU" }
-                                option { value: "handles", "Handles (leak detection)" }
+                                option { value: "memory", {t!("processes.fetch.memory")} }
+                                option { value: "cpu", {t!("processes.fetch.cpu")} }
+                                option { value: "handles", {t!("processes.fetch.handles")} }
+                            }
+                        }
+
+                        div { class: "action-bar-group",
+                            label { {t!("processes.filter")} }
+                            input {
+                                r#type: "text",
+                                class: "input",
+                                placeholder: t!("processes.filter-placeholder"),
+                                value: "{current_state.table.filter}",
+                                oninput: move |e| state.write().table.filter = e.value(),
                             }
                         }
 
@@ -176,71 +411,57 @@ pub fn ProcessesPage(is_admin: bool) -> Element {
                             class: "btn btn-primary",
                             disabled: current_state.is_running,
                             onclick: on_refresh,
-                            if current_state.running_action == "Refresh" { "Loading..." } else { "🔄 Refresh" }
+                            if current_state.running_action == "Refresh" { {t!("common.loading")} } else { {t!("processes.refresh")} }
                         }
 
                         if current_state.selected_pid.is_some() {
-                            button {
+                            ActionButton {
+                                danger: DangerLevel::Modifying,
                                 class: "btn btn-danger",
                                 disabled: current_state.is_running,
                                 onclick: on_kill,
-                                title: if !is_admin { "May require Administrator for some processes" } else { "" },
-                                if current_state.running_action == "Kill" { "Killing..." } else { "☠ Kill Process" }
+                                title: if !is_admin { t!("processes.kill-admin-hint") } else { String::new() },
+                                if current_state.running_action == "Kill" { {t!("processes.killing-short")} } else { {t!("processes.kill")} }
                             }
                         }
                     }
                 }
 
                 // Process list
-                if !sorted_processes.is_empty() {
+                if !current_state.processes.is_empty() {
                     div { class: "section",
-                        h3 { class: "section-title", "Process List" }
+                        div { class: "section-header",
+                            h3 { class: "section-title",
+                                {t!("processes.list", shown = sorted_processes.len(), total = current_state.processes.len())}
+                            }
+                            div { class: "table-export",
+                                button {
+                                    class: "btn btn-ghost btn-sm",
+                                    title: t!("table.copy-csv-hint"),
+                                    onclick: move |_| on_export(false),
+                                    "📋 CSV"
+                                }
+                                button {
+                                    class: "btn btn-ghost btn-sm",
+                                    title: t!("table.copy-json-hint"),
+                                    onclick: move |_| on_export(true),
+                                    "📋 JSON"
+                                }
+                            }
+                        }
                         div { class: "service-list",
                             table { class: "data-table",
                                 thead {
                                     tr {
                                         StaticHeader { label: "".to_string() }
-                                        SortableHeader {
-                                            column: ProcessesSortColumn::Pid,
-                                            label: "PID".to_string(),
-                                            current_sort: current_state.sort_column,
-                                            direction: sort_dir,
-                                            on_sort: move |col| update_process_sort(state, col),
-                                        }
-                                        SortableHeader {
-                                            column: ProcessesSortColumn::Name,
-                                            label: "Name".to_string(),
-                                            current_sort: current_state.sort_column,
-                                            direction: sort_dir,
-                                            on_sort: move |col| update_process_sort(state, col),
-                                        }
-                                        SortableHeader {
-                                            column: ProcessesSortColumn::Memory,
-                                            label: "Memory".to_string(),
-                                            current_sort: current_state.sort_column,
-                                            direction: sort_dir,
-                                            on_sort: move |col| update_process_sort(state, col),
-                                        }
-                                        SortableHeader {
-                                            column: ProcessesSortColumn::Handles,
-                                            label: "Handles".to_string(),
-                                            current_sort: current_state.sort_column,
-                                            direction: sort_dir,
-                                            on_sort: move |col| update_process_sort(state, col),
-                                        }
-                                        SortableHeader {
-                                            column: ProcessesSortColumn::Cpu,
-                                            label: "CPU %".to_string(),
-                                            current_sort: current_state.sort_column,
-                                            direction: sort_dir,
-                                            on_sort: move |col| update_process_sort(state, col),
-                                        }
-                                        SortableHeader {
-                                            column: ProcessesSortColumn::Status,
-                                            label: "Status".to_string(),
-                                            current_sort: current_state.sort_column,
-                                            direction: sort_dir,
-                                            on_sort: move |col| update_process_sort(state, col),
+                                        for column in PROCESS_COLUMNS {
+                                            SortableHeader {
+                                                column: column.id,
+                                                label: t!(column.label_key),
+                                                current_sort: current_state.table.sort_column,
+                                                direction: sort_dir,
+                                                on_sort: move |col| state.write().table.toggle_sort(col, PROCESS_COLUMNS),
+                                            }
                                         }
                                     }
                                 }
@@ -254,6 +475,11 @@ pub fn ProcessesPage(is_admin: bool) -> Element {
                                             } else { 
                                                 "-".to_string() 
                                             };
+                                            let thread_display = if proc.thread_count > 0 {
+                                                proc.thread_count.to_string()
+                                            } else {
+                                                "-".to_string()
+                                            };
                                             rsx! {
                                                 tr {
                                                     class: if is_selected { "selected" } else { "" },
@@ -271,6 +497,7 @@ pub fn ProcessesPage(is_admin: bool) -> Element {
                                                     td { "{proc.name}" }
                                                     td { class: "mono", "{proc.memory_mb:.1} MB" }
                                                     td { class: "mono", "{handle_display}" }
+                                                    td { class: "mono", "{thread_display}" }
                                                     td { class: "mono", "{proc.cpu_percent:.1}%" }
                                                     td { class: "muted", "{proc.status}" }
                                                 }
@@ -285,11 +512,21 @@ pub fn ProcessesPage(is_admin: bool) -> Element {
                     div { class: "section",
                         div { class: "empty-state",
                             div { class: "empty-state-icon", "📊" }
-                            p { class: "empty-state-text", "Click Refresh to load process list" }
+                            p { class: "empty-state-text", {t!("processes.empty")} }
                         }
                     }
                 }
 
+                if confirm_explorer() {
+                    ConfirmDialog {
+                        title: t!("processes.explorer-confirm-title"),
+                        message: t!("processes.explorer-confirm"),
+                        confirm_label: t!("processes.explorer-confirm-button"),
+                        on_confirm: on_restart_explorer,
+                        on_cancel: move |_| confirm_explorer.set(false),
+                    }
+                }
+
                 if !current_state.status_message.is_empty() {
                     div {
                         class: format!("status-bar {}", current_state.status_type),
@@ -3,190 +3,1331 @@
 //! Windows services management - list, start, stop, restart.
 
 use dioxus::prelude::*;
+use std::collections::HashMap;
 
-use crate::state::{ServicesPageState, ServicesSortColumn};
-use crate::system::services;
-use crate::ui::components::{OutputPanel, SortableHeader, StaticHeader, SortDirection};
+use crate::state::{
+    csv_field, CommandOutput, CurrentPage, DangerLevel, ProcessesPageState, ServiceHistoryEntry, ServicesPageState,
+    ServicesSortColumn, TableColumn, TableController,
+};
+use crate::system::command::{pick_open_file, pick_save_file, reveal_in_explorer};
+use crate::system::processes;
+use crate::system::services::{
+    self, FailureAction, FailureActionKind, FailurePreset, RestartPlan, ServiceHost, ServiceInfo, StatusWait,
+};
+use crate::system::settings::{self, AppSettings};
+use crate::ui::components::{
+    copy_to_clipboard, ActionButton, ConfirmDialog, OutputPanel, RowWindow, SortDirection, SortableHeader,
+    VirtualScroll, VirtualSpacer, VIRTUAL_ROW_HEIGHT,
+};
+
+/// Service table columns
+const SERVICE_COLUMNS: &[TableColumn<ServiceInfo, ServicesSortColumn>] = &[
+    TableColumn {
+        id: ServicesSortColumn::Name,
+        label: "Name",
+        label_key: "services.col.name",
+        text: |s| s.name.clone(),
+        compare: |a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+        descending_first: false,
+    },
+    TableColumn {
+        id: ServicesSortColumn::DisplayName,
+        label: "Display Name",
+        label_key: "services.col.display-name",
+        text: |s| s.display_name.clone(),
+        compare: |a, b| a.display_name.to_lowercase().cmp(&b.display_name.to_lowercase()),
+        descending_first: false,
+    },
+    TableColumn {
+        id: ServicesSortColumn::Status,
+        label: "Status",
+        label_key: "services.col.status",
+        text: |s| s.status.clone(),
+        compare: |a, b| a.status.cmp(&b.status),
+        descending_first: false,
+    },
+    TableColumn {
+        id: ServicesSortColumn::StartType,
+        label: "Start Type",
+        label_key: "services.col.start-type",
+        text: |s| s.start_type.clone(),
+        compare: |a, b| a.start_type.cmp(&b.start_type),
+        descending_first: false,
+    },
+    TableColumn {
+        id: ServicesSortColumn::Account,
+        label: "Account",
+        label_key: "services.col.account",
+        text: |s| s.account.clone(),
+        compare: |a, b| a.account.to_lowercase().cmp(&b.account.to_lowercase()),
+        descending_first: false,
+    },
+    TableColumn {
+        id: ServicesSortColumn::ProcessId,
+        label: "PID",
+        label_key: "services.col.pid",
+        text: |s| if s.process_id == 0 { String::new() } else { s.process_id.to_string() },
+        compare: |a, b| a.process_id.cmp(&b.process_id),
+        descending_first: true,
+    },
+];
+
+/// Columns the Filter box searches
+const SEARCH_COLUMNS: &[TableColumn<ServiceInfo, ServicesSortColumn>] = SERVICE_COLUMNS.split_at(2).0;
+
+/// Loaded services that pass the filter box, status chip, and LocalSystem chip, in table order
+///
+/// Filters the already-loaded list only - typing never re-queries PowerShell.
+fn services_in_view(s: &ServicesPageState) -> Vec<ServiceInfo> {
+    let matching: Vec<ServiceInfo> = s
+        .services
+        .iter()
+        .filter(|svc| match &s.status_filter {
+            Some(status) => svc.status == *status,
+            None => true,
+        })
+        .filter(|svc| !s.local_system_only || svc.runs_as_local_system())
+        .filter(|svc| s.table.matches(*svc, SEARCH_COLUMNS))
+        .cloned()
+        .collect();
+    let sort_only = TableController { filter: String::new(), ..s.table.clone() };
+    sort_only.visible_rows(&matching, SERVICE_COLUMNS)
+}
+
+/// Drop the selection when a filter change hides the selected service
+fn keep_selection_visible(mut state: Signal<ServicesPageState>) {
+    let s = state();
+    if !s.selected_service.is_empty() && !services_in_view(&s).iter().any(|svc| svc.name == s.selected_service) {
+        state.write().selected_service = String::new();
+    }
+}
+
+/// After a relist, keep the selection and ticks on services that still exist (matched by name)
+///
+/// Returns the selected name when that service is gone, so the caller can say so.
+fn reconcile_selection(s: &mut ServicesPageState) -> Option<String> {
+    let names: Vec<String> = s.services.iter().map(|svc| svc.name.clone()).collect();
+    s.checked_services.retain(|name| names.contains(name));
+    if s.selected_service.is_empty() {
+        return None;
+    }
+    if let Some(name) = names.iter().find(|name| name.eq_ignore_ascii_case(&s.selected_service)) {
+        s.selected_service = name.clone();
+        return None;
+    }
+    if s.details.as_ref().is_some_and(|d| d.name.eq_ignore_ascii_case(&s.selected_service)) {
+        s.details = None;
+        s.details_raw = None;
+        s.failure_actions = None;
+    }
+    Some(std::mem::take(&mut s.selected_service))
+}
+
+/// Status chips as (status, count), Running and Stopped first
+fn status_counts(services: &[ServiceInfo]) -> Vec<(String, usize)> {
+    let mut counts: Vec<(String, usize)> = Vec::new();
+    for svc in services {
+        match counts.iter_mut().find(|(status, _)| *status == svc.status) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((svc.status.clone(), 1)),
+        }
+    }
+    let rank = |status: &str| match status {
+        "Running" => 0,
+        "Stopped" => 1,
+        _ => 2,
+    };
+    counts.sort_by(|a, b| rank(&a.0).cmp(&rank(&b.0)).then_with(|| a.0.cmp(&b.0)));
+    counts
+}
+
+/// Start/Stop/Restart, applied to one or several services
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ServiceAction {
+    Start,
+    Stop,
+    Restart,
+}
+
+impl ServiceAction {
+    /// `running_action` value while this action runs
+    fn label(&self) -> &'static str {
+        match self {
+            ServiceAction::Start => "Start",
+            ServiceAction::Stop => "Stop",
+            ServiceAction::Restart => "Restart",
+        }
+    }
+
+    /// Action for a history entry's label, if it can be re-run
+    fn from_label(label: &str) -> Option<Self> {
+        [ServiceAction::Start, ServiceAction::Stop, ServiceAction::Restart]
+            .into_iter()
+            .find(|action| action.label() == label)
+    }
+
+    /// Status the service should end up in
+    fn desired_status(&self) -> &'static str {
+        match self {
+            ServiceAction::Start | ServiceAction::Restart => "Running",
+            ServiceAction::Stop => "Stopped",
+        }
+    }
+}
+
+/// Put a re-queried status into the cached list so the table shows it without a relist
+///
+/// A stopped service has no host process any more; a new PID shows with the next list.
+fn update_service_status(s: &mut ServicesPageState, name: &str, status: &str) {
+    if status.is_empty() {
+        return;
+    }
+    for svc in s.services.iter_mut().chain(s.favorite_status.iter_mut()) {
+        if svc.name.eq_ignore_ascii_case(name) {
+            svc.status = status.to_string();
+            if status == "Stopped" {
+                svc.process_id = 0;
+            }
+        }
+    }
+}
+
+/// Service list file for auditors: a `#` comment line with computer and time, then CSV with a
+/// header row, or a JSON object carrying the same metadata; the action history follows the list
+fn format_services_export(rows: &[ServiceInfo], history: &[ServiceHistoryEntry], json: bool) -> String {
+    let computer = std::env::var("COMPUTERNAME").unwrap_or_default();
+    let exported = chrono::Local::now().format("%Y-%m-%d %H:%M:%S %z").to_string();
+    if json {
+        let export = serde_json::json!({
+            "computer": computer,
+            "exported": exported,
+            "services": rows,
+            "history": history,
+        });
+        return serde_json::to_string_pretty(&export).unwrap_or_default();
+    }
+
+    let mut csv = format!(
+        "# Services on {}, exported {}\r\nName,DisplayName,Status,StartType,Account,ProcessId\r\n",
+        computer, exported
+    );
+    for svc in rows {
+        let pid = if svc.process_id == 0 { String::new() } else { svc.process_id.to_string() };
+        let fields = [&svc.name, &svc.display_name, &svc.status, &svc.start_type, &svc.account, &pid];
+        csv.push_str(&fields.iter().map(|f| csv_field(f)).collect::<Vec<_>>().join(","));
+        csv.push_str("\r\n");
+    }
+    if !history.is_empty() {
+        csv.push_str("\r\n# Action history\r\n");
+        csv.push_str(&format_history_csv(history));
+    }
+    csv
+}
+
+/// Action history as CSV with a header row, oldest first
+fn format_history_csv(history: &[ServiceHistoryEntry]) -> String {
+    let mut csv = "Time,Service,Action,Result,Ok\r\n".to_string();
+    for entry in history {
+        let time = entry.at.format("%Y-%m-%d %H:%M:%S").to_string();
+        let ok = entry.ok.to_string();
+        let fields = [&time, &entry.service, &entry.action, &entry.result, &ok];
+        csv.push_str(&fields.iter().map(|f| csv_field(f)).collect::<Vec<_>>().join(","));
+        csv.push_str("\r\n");
+    }
+    csv
+}
+
+/// Localized label of a history action; Start/Stop/Restart are stored untranslated so they can be re-run
+fn history_action_text(action: &str) -> String {
+    match ServiceAction::from_label(action) {
+        Some(ServiceAction::Start) => t!("services.history-start"),
+        Some(ServiceAction::Stop) => t!("services.history-stop"),
+        Some(ServiceAction::Restart) => t!("services.history-restart"),
+        None => action.to_string(),
+    }
+}
+
+/// "90 s", "5 min", "1 d" for a period in seconds
+fn format_period(secs: u64) -> String {
+    match secs {
+        0 => "0 s".to_string(),
+        s if s % 86400 == 0 => format!("{} d", s / 86400),
+        s if s % 3600 == 0 => format!("{} h", s / 3600),
+        s if s % 60 == 0 => format!("{} min", s / 60),
+        s => format!("{} s", s),
+    }
+}
+
+/// One recovery action as shown in the details card
+fn describe_failure_action(action: &FailureAction, command: &str) -> String {
+    let delay = format_period(action.delay_ms / 1000);
+    match action.kind {
+        FailureActionKind::None => t!("services.failure-none"),
+        FailureActionKind::Restart => t!("services.failure-restart", delay = delay),
+        FailureActionKind::RunCommand => t!("services.failure-run", command = command, delay = delay),
+        FailureActionKind::Reboot => t!("services.failure-reboot", delay = delay),
+    }
+}
+
+/// Editor label of a recovery preset
+fn failure_preset_label(preset: FailurePreset) -> String {
+    match preset {
+        FailurePreset::RestartThreeTimes => t!("services.preset-restart"),
+        FailurePreset::TakeNoAction => t!("services.preset-none"),
+    }
+}
+
+/// sc.exe listings have no start types; read the selected service's with `sc qc`
+fn load_start_type_sc(mut state: Signal<ServicesPageState>, name: String) {
+    let missing = {
+        let s = state.peek();
+        s.sc_fallback && s.services.iter().any(|svc| svc.name == name && svc.start_type.is_empty())
+    };
+    if !missing {
+        return;
+    }
+    spawn(async move {
+        if let (Some(start_type), _) = services::get_start_type_sc(&name).await {
+            let mut s = state.write();
+            if let Some(svc) = s.services.iter_mut().find(|svc| svc.name == name) {
+                svc.start_type = start_type;
+            }
+        }
+    });
+}
+
+/// Background re-list for auto-refresh: marks rows whose status changed since the last list
+///
+/// Skipped while a start/stop runs, and the result dropped if one started meanwhile, so a
+/// stale list never overwrites that action's in-place status updates.
+async fn auto_refresh(mut state: Signal<ServicesPageState>) {
+    if state.peek().is_running {
+        return;
+    }
+    let (fresh, _) = services::list_services().await;
+    if fresh.is_empty() || state.peek().is_running {
+        return;
+    }
 
-fn update_service_sort(mut state: Signal<ServicesPageState>, column: ServicesSortColumn) {
     let mut s = state.write();
-    if s.sort_column == Some(column) {
-        s.sort_ascending = !s.sort_ascending;
+    let previous: HashMap<String, ServiceInfo> =
+        std::mem::take(&mut s.services).into_iter().map(|svc| (svc.name.clone(), svc)).collect();
+    let mut changed = Vec::new();
+    s.services = fresh
+        .into_iter()
+        .map(|mut svc| {
+            if let Some(old) = previous.get(&svc.name) {
+                if old.status != svc.status {
+                    changed.push(svc.name.clone());
+                }
+                // sc.exe listings have no start types; keep the ones already loaded
+                if svc.start_type.is_empty() {
+                    svc.start_type = old.start_type.clone();
+                }
+            }
+            svc
+        })
+        .collect();
+    s.sc_fallback = services::using_sc_fallback();
+    let favorites = s.favorites.clone();
+    s.favorite_status = s.services.iter().filter(|svc| favorites.contains(&svc.name)).cloned().collect();
+    if !changed.is_empty() {
+        s.status_message = t!("services.auto-changes", count = changed.len(), names = changed.join(", "));
+        s.status_type = "info".to_string();
+    }
+    if let Some(name) = reconcile_selection(&mut s) {
+        s.status_message = t!("services.selection-gone", name = name);
+        s.status_type = "warning".to_string();
+    }
+    s.changed_services = changed;
+}
+
+/// Re-read the status of every favorite service
+fn refresh_favorites(mut state: Signal<ServicesPageState>) {
+    let favorites = state.peek().favorites.clone();
+    if favorites.is_empty() {
+        state.write().favorite_status = Vec::new();
+        return;
+    }
+    state.write().loading_favorites = true;
+    spawn(async move {
+        let (status, _) = services::get_services(&favorites).await;
+        let mut s = state.write();
+        s.favorite_status = status;
+        s.loading_favorites = false;
+    });
+}
+
+/// Star or unstar a service and persist the favorites
+fn toggle_favorite(mut state: Signal<ServicesPageState>, mut app_settings: Signal<AppSettings>, name: String) {
+    {
+        let mut s = state.write();
+        if s.favorites.contains(&name) {
+            s.favorites.retain(|fav| *fav != name);
+            s.favorite_status.retain(|svc| svc.name != name);
+        } else {
+            s.favorites.push(name.clone());
+            if let Some(svc) = s.services.iter().find(|svc| svc.name == name).cloned() {
+                s.favorite_status.push(svc);
+            }
+        }
+    }
+    app_settings.write().favorite_services = state.peek().favorites.clone();
+    if let Err(e) = settings::save(&app_settings()) {
+        state.write().status_message = e;
+        state.write().status_type = "error".to_string();
+    }
+}
+
+/// "stuck in StopPending (PID 1234)" message for a service that never settled
+fn stuck_message(wait: &StatusWait) -> String {
+    match wait.pid {
+        Some(pid) => t!("services.stuck", name = wait.name, status = wait.status, pid = pid),
+        None => t!("services.stuck-no-pid", name = wait.name, status = wait.status),
+    }
+}
+
+/// Plain-language explanation of the Windows error code in a failed sc.exe create/delete
+fn sc_error_hint(output: &CommandOutput) -> Option<String> {
+    let hint = match services::sc_error_code(output)? {
+        5 => t!("services.sc-error-5"),
+        87 => t!("services.sc-error-87"),
+        123 => t!("services.sc-error-123"),
+        1051 => t!("services.sc-error-1051"),
+        1060 => t!("services.sc-error-1060"),
+        1072 => t!("services.sc-error-1072"),
+        1073 => t!("services.sc-error-1073"),
+        1078 => t!("services.sc-error-1078"),
+        code => t!("services.sc-error-other", code = code),
+    };
+    Some(hint)
+}
+
+/// History result for a finished wait: the status reached, or where it got stuck
+fn wait_result(wait: &StatusWait) -> String {
+    match wait.status.as_str() {
+        _ if wait.reached => wait.status.clone(),
+        "" => t!("services.history-unknown"),
+        status => t!("services.history-ended-in", status = status),
+    }
+}
+
+/// Services Start/Stop/Restart act on: the ticked ones, else the selected row
+fn action_targets(s: &ServicesPageState) -> Vec<String> {
+    if !s.checked_services.is_empty() {
+        s.checked_services.clone()
+    } else if !s.selected_service.is_empty() {
+        vec![s.selected_service.clone()]
     } else {
-        s.sort_column = Some(column);
-        s.sort_ascending = true;
+        Vec::new()
     }
 }
 
+/// Run Start/Stop/Restart on each target in turn, collecting every output; a failure doesn't stop the rest
+///
+/// Each service counts as done only once Get-Service reports the final state, waiting up
+/// to the configured timeout.
+fn run_service_action(
+    mut state: Signal<ServicesPageState>,
+    app_settings: Signal<AppSettings>,
+    action: ServiceAction,
+    targets: Vec<String>,
+) {
+    if targets.is_empty() {
+        state.write().status_message = t!("services.select-first");
+        state.write().status_type = "warning".to_string();
+        return;
+    }
+    let progress = move |name: &str| match action {
+        ServiceAction::Start => t!("services.starting", name = name),
+        ServiceAction::Stop => t!("services.stopping", name = name),
+        ServiceAction::Restart => t!("services.restarting", name = name),
+    };
+    {
+        let mut s = state.write();
+        s.is_running = true;
+        s.running_action = action.label().to_string();
+        s.command_outputs = Vec::new();
+        s.stuck_services.retain(|wait| !targets.contains(&wait.name));
+        s.status_message = progress(&targets[0]);
+        s.status_type = String::new();
+    }
+
+    let timeout = std::time::Duration::from_secs(app_settings().service_wait_secs.max(1));
+    spawn(async move {
+        let total = targets.len();
+        let mut failed: Vec<String> = Vec::new();
+        let mut last_wait = StatusWait::default();
+        let mut logon_failed: Vec<String> = Vec::new();
+        // Aborted or partly restored restart chains
+        let mut chain_notes: Vec<String> = Vec::new();
+        for (i, name) in targets.iter().enumerate() {
+            let counter = if total > 1 { format!(" ({}/{})", i + 1, total) } else { String::new() };
+            state.write().status_message = format!("{}{}", progress(name), counter);
+            if action == ServiceAction::Restart {
+                // Dependents are looked up again here: the preview may be stale by now
+                let (plan, lookup) = services::plan_restart(name).await;
+                let plan = match plan {
+                    Ok(plan) => plan,
+                    Err(e) => {
+                        let note = t!("services.dependents-failed", name = name, error = e);
+                        let mut s = state.write();
+                        s.command_outputs.push(lookup);
+                        s.record_action(name, action.label(), note.clone(), false);
+                        drop(s);
+                        chain_notes.push(note);
+                        failed.push(name.clone());
+                        continue;
+                    }
+                };
+                if !plan.dependents.is_empty() {
+                    state.write().status_message = format!(
+                        "{}{}",
+                        t!("services.restarting-with-dependents", name = name, count = plan.dependents.len()),
+                        counter
+                    );
+                }
+                let chain = services::restart_service(&plan, timeout).await;
+                {
+                    let mut s = state.write();
+                    s.command_outputs.extend(chain.outputs.iter().cloned());
+                    for wait in &chain.waits {
+                        update_service_status(&mut s, &wait.name, &wait.status);
+                        if wait.is_stuck() {
+                            s.stuck_services.push(wait.clone());
+                        }
+                    }
+                }
+                let mut notes = Vec::new();
+                if let Some(step) = &chain.failed_step {
+                    notes.push(t!("services.restart-aborted", name = name, step = step));
+                }
+                if !chain.not_restored.is_empty() {
+                    notes.push(t!("services.restart-not-restored", names = chain.not_restored.join(", ")));
+                }
+                let wait = chain.waits.iter().find(|w| w.name == *name).cloned().unwrap_or_default();
+                let mut result = wait_result(&wait);
+                if !plan.dependents.is_empty() {
+                    result = format!("{} ({})", result, t!("services.history-dependents", count = plan.dependents.len()));
+                }
+                if !notes.is_empty() {
+                    result = format!("{} - {}", result, notes.join(" "));
+                }
+                state.write().record_action(name, action.label(), result, chain.succeeded());
+                chain_notes.extend(notes);
+                if !chain.succeeded() {
+                    failed.push(name.clone());
+                    // Left stopped means the target itself failed to start again
+                    if wait.status == "Stopped" && services::is_logon_failure(name, &CommandOutput::default()).await {
+                        logon_failed.push(name.clone());
+                    }
+                }
+                last_wait = wait;
+                continue;
+            }
+            let output = match action {
+                ServiceAction::Start => services::start_service(name).await,
+                _ => services::stop_service(name).await,
+            };
+            state.write().command_outputs.push(output.clone());
+
+            state.write().status_message =
+                format!("{}{}", t!("services.waiting-for", name = name, status = action.desired_status()), counter);
+            let wait = services::wait_for_status(name, action.desired_status(), timeout).await;
+            {
+                let mut s = state.write();
+                update_service_status(&mut s, name, &wait.status);
+                if wait.is_stuck() {
+                    s.stuck_services.push(wait.clone());
+                }
+                s.record_action(name, action.label(), wait_result(&wait), wait.reached);
+            }
+            if !wait.reached {
+                failed.push(name.clone());
+                if action != ServiceAction::Stop && services::is_logon_failure(name, &output).await {
+                    logon_failed.push(name.clone());
+                }
+            }
+            last_wait = wait;
+        }
+
+        let mut s = state.write();
+        s.is_running = false;
+        s.running_action = String::new();
+
+        if total == 1 {
+            let name = &targets[0];
+            let ok = failed.is_empty();
+            s.status_message = if !logon_failed.is_empty() {
+                let account = s.services.iter().find(|svc| svc.name == *name).map(|svc| svc.account.clone()).unwrap_or_default();
+                t!("services.logon-failed", name = name, account = account)
+            } else if last_wait.is_stuck() {
+                stuck_message(&last_wait)
+            } else if !chain_notes.is_empty() {
+                chain_notes.join(" ")
+            } else {
+                match (action, ok) {
+                    (ServiceAction::Start, true) => t!("services.started", name = name),
+                    (ServiceAction::Start, false) => t!("services.start-failed", name = name),
+                    (ServiceAction::Stop, true) => t!("services.stopped", name = name),
+                    (ServiceAction::Stop, false) => t!("services.stop-failed", name = name),
+                    (ServiceAction::Restart, true) => t!("services.restarted", name = name),
+                    (ServiceAction::Restart, false) => t!("services.restart-failed", name = name),
+                }
+            };
+            s.status_type = if ok { "success" } else { "error" }.to_string();
+            return;
+        }
+
+        let ok = total - failed.len();
+        let summary = match action {
+            ServiceAction::Start => t!("services.bulk-started", ok = ok, failed = failed.len()),
+            ServiceAction::Stop => t!("services.bulk-stopped", ok = ok, failed = failed.len()),
+            ServiceAction::Restart => t!("services.bulk-restarted", ok = ok, failed = failed.len()),
+        };
+        s.status_message = if failed.is_empty() { summary } else { format!("{} ({})", summary, failed.join(", ")) };
+        if !logon_failed.is_empty() {
+            s.status_message = format!("{} - {}", s.status_message, t!("services.logon-failed-bulk", names = logon_failed.join(", ")));
+        }
+        if !chain_notes.is_empty() {
+            s.status_message = format!("{} - {}", s.status_message, chain_notes.join(" "));
+        }
+        s.status_type = match failed.len() {
+            0 => "success",
+            n if n == total => "error",
+            _ => "warning",
+        }
+        .to_string();
+    });
+}
+
 /// Services page with Windows service management
 #[component]
 pub fn ServicesPage(is_admin: bool) -> Element {
     // Get persistent state from context
     let mut state: Signal<ServicesPageState> = use_context();
+    let mut processes_state: Signal<ProcessesPageState> = use_context();
+    let mut current_page: Signal<CurrentPage> = use_context();
+    let mut app_settings: Signal<AppSettings> = use_context();
+    // Stuck service whose hosting process is about to be killed, with the process name (confirm dialog open)
+    let mut kill_target: Signal<Option<(StatusWait, ServiceHost)>> = use_signal(|| None);
+    // Service and binary path about to be deleted (type-the-name dialog open), and the typed name
+    let mut delete_target: Signal<Option<(String, String)>> = use_signal(|| None);
+    let mut delete_confirm_text = use_signal(String::new);
+    // Restart plans shown before a restart that also stops dependents (preview dialog open)
+    let mut restart_preview: Signal<Option<Vec<RestartPlan>>> = use_signal(|| None);
+
+    // Favorites strip shows live status each time the page opens
+    use_hook(move || refresh_favorites(state));
+
+    // Auto-refresh: the next list only starts after the previous one finished
+    use_future(move || async move {
+        loop {
+            let interval = state.peek().auto_refresh_secs.max(1);
+            tokio::time::sleep(tokio::time::Duration::from_secs(interval)).await;
+            if state.peek().auto_refresh {
+                auto_refresh(state).await;
+            }
+        }
+    });
+
+    // Scroll position of the service table, which only renders the rows on screen; it starts
+    // where the page was left and is saved back as the user scrolls
+    let service_window = use_signal(|| RowWindow {
+        scroll_top: state.peek().list_scroll_top,
+        ..Default::default()
+    });
+    use_effect(move || {
+        let top = service_window().scroll_top;
+        if state.peek().list_scroll_top != top {
+            state.write().list_scroll_top = top;
+        }
+    });
+
+    // Service row to scroll into view after the next render
+    let mut scroll_target: Signal<Option<String>> = use_signal(|| None);
+    use_effect(move || {
+        if let Some(name) = scroll_target() {
+            // The row may be outside the rendered window, so scroll the list by its index
+            let Some(index) = services_in_view(&state.peek()).iter().position(|svc| svc.name == name) else {
+                return;
+            };
+            document::eval(&format!(
+                "const list = document.getElementById('service-list'); \
+                 if (list) {{ list.scrollTop = {} - list.clientHeight / 2; list.scrollIntoView({{ block: 'nearest' }}); }}",
+                index as f64 * VIRTUAL_ROW_HEIGHT
+            ));
+        }
+    });
+
+    // Find service by port handler
+    let on_find_by_port = move |_| {
+        let input = state().port_lookup.trim().to_string();
+        let Ok(port) = input.parse::<u16>() else {
+            state.write().status_message = t!("services.port-invalid", port = input);
+            state.write().status_type = "warning".to_string();
+            return;
+        };
+        {
+            let mut s = state.write();
+            s.is_running = true;
+            s.running_action = "FindPort".to_string();
+            s.port_owners = None;
+            s.status_message = t!("services.port-searching", port = port);
+            s.status_type = String::new();
+        }
+
+        spawn(async move {
+            let result = services::find_services_by_port(port).await;
+            let found = result.as_ref().ok().and_then(|owners| owners.iter().flat_map(|o| o.services.first()).next().cloned());
+            // The table may not be loaded yet, or may predate the service starting
+            let reload = found.as_ref().is_some_and(|name| !state().services.iter().any(|svc| svc.name == *name));
+            let listed = if reload { Some(services::list_services().await) } else { None };
+
+            let mut s = state.write();
+            s.is_running = false;
+            s.running_action = String::new();
+            if let Some((svc_list, outputs)) = listed {
+                s.services = svc_list;
+                s.command_outputs = outputs;
+                s.sc_fallback = services::using_sc_fallback();
+            }
+
+            let owners = match result {
+                Ok(owners) => owners,
+                Err(e) => {
+                    s.status_message = t!("services.port-scan-failed", error = e);
+                    s.status_type = "error".to_string();
+                    return;
+                }
+            };
+
+            if let Some(name) = found {
+                let owner = owners.iter().find(|o| o.services.contains(&name)).cloned().unwrap_or_default();
+                s.status_message = t!("services.port-held-by", port = port, name = name, process = owner.process_name, pid = owner.pid);
+                s.status_type = "success".to_string();
+                s.selected_service = name.clone();
+                // Clear filters that would hide the match
+                let hidden = !services_in_view(&s).iter().any(|svc| svc.name == name);
+                if hidden {
+                    s.status_filter = None;
+                    s.table.filter = String::new();
+                }
+                scroll_target.set(Some(name));
+            } else if owners.is_empty() {
+                s.status_message = t!("services.port-free", port = port);
+                s.status_type = "warning".to_string();
+            } else if owners.iter().any(|o| o.is_system) {
+                s.status_message = t!("services.port-kernel", port = port);
+                s.status_type = "warning".to_string();
+            } else {
+                let owner = &owners[0];
+                s.status_message = t!("services.port-not-service", port = port, process = owner.process_name, pid = owner.pid);
+                s.status_type = "warning".to_string();
+            }
+            s.port_owners = Some(owners);
+        });
+    };
 
     // List services handler
     let on_list_services = move |_| {
-        let filter = state().filter.clone();
         state.write().is_running = true;
         state.write().running_action = "List".to_string();
-        state.write().status_message = "Loading services...".to_string();
+        state.write().status_message = t!("services.loading");
         state.write().status_type = String::new();
 
         spawn(async move {
-            let (svc_list, output) = if filter.is_empty() {
-                services::list_services().await
-            } else {
-                services::list_services_filtered(&filter).await
-            };
+            let (svc_list, outputs) = services::list_services().await;
             
             let count = svc_list.len();
-            let success = output.exit_code == 0;
+            let fallback = services::using_sc_fallback();
             
             let mut s = state.write();
             s.services = svc_list;
-            s.command_outputs = vec![output];
+            s.command_outputs = outputs;
+            s.sc_fallback = fallback;
+            s.changed_services.clear();
             s.is_running = false;
             s.running_action = String::new();
+            let gone = if count > 0 { reconcile_selection(&mut s) } else { None };
 
-            if success {
-                s.status_message = format!("Found {} services", count);
+            if fallback {
+                s.status_message = t!("services.found-sc", count = count);
+                s.status_type = "warning".to_string();
+            } else if count > 0 {
+                s.status_message = t!("services.found", count = count);
                 s.status_type = "success".to_string();
             } else {
-                s.status_message = "Failed to list services".to_string();
+                s.status_message = t!("services.list-failed");
                 s.status_type = "error".to_string();
             }
+            if let Some(name) = gone {
+                s.status_message = format!("{} - {}", s.status_message, t!("services.selection-gone", name = name));
+                s.status_type = "warning".to_string();
+            }
         });
     };
 
-    // Start service handler
-    let on_start_service = move |_| {
+    // Get details handler
+    let on_get_details = move |_| {
         let service_name = state().selected_service.clone();
         if service_name.is_empty() {
-            state.write().status_message = "Select a service first".to_string();
+            state.write().status_message = t!("services.select-first");
             state.write().status_type = "warning".to_string();
             return;
         }
 
         state.write().is_running = true;
-        state.write().running_action = "Start".to_string();
-        state.write().status_message = format!("Starting {}...", service_name);
+        state.write().running_action = "Details".to_string();
+        state.write().status_message = t!("services.getting-details", name = service_name);
         state.write().status_type = String::new();
 
         spawn(async move {
-            let output = services::start_service(&service_name).await;
-            let success = output.succeeded();
-            
+            let (result, output) = services::get_service_details_parsed(&service_name).await;
+            let (failure, failure_output) = services::get_failure_actions(&service_name).await;
+
+            let mut s = state.write();
+            s.command_outputs = vec![output, failure_output];
+            s.is_running = false;
+            s.running_action = String::new();
+            s.details_raw = None;
+            s.show_details_raw = false;
+            match failure {
+                Ok(actions) => {
+                    s.failure_actions = Some(actions);
+                    s.failure_actions_error = None;
+                }
+                Err(e) => {
+                    s.failure_actions = None;
+                    s.failure_actions_error = Some(e);
+                }
+            }
+
+            match result {
+                Ok(details) => {
+                    s.details = Some(details);
+                    s.status_message = t!("services.details", name = service_name);
+                    s.status_type = "success".to_string();
+                }
+                Err(e) => {
+                    s.details = None;
+                    s.status_message = format!("{}: {}", t!("services.details-failed", name = service_name), e);
+                    s.status_type = "error".to_string();
+                }
+            }
+        });
+    };
+
+    // Recovery preset picked in the details card editor
+    let mut failure_preset = use_signal(|| FailurePreset::RestartThreeTimes);
+
+    // Apply a recovery preset, then re-query to confirm it took effect
+    let on_apply_failure_preset = move |_| {
+        let Some(name) = state().details.as_ref().map(|d| d.name.clone()) else {
+            return;
+        };
+        let preset = failure_preset();
+        {
+            let mut s = state.write();
+            s.is_running = true;
+            s.running_action = "Recovery".to_string();
+            s.status_message = t!("services.setting-recovery", name = name);
+            s.status_type = String::new();
+        }
+
+        spawn(async move {
+            let output = services::set_failure_actions(&name, &preset.actions()).await;
+            let applied = output.succeeded();
+            let (result, check) = services::get_failure_actions(&name).await;
+
             let mut s = state.write();
-            s.command_outputs = vec![output];
+            s.command_outputs.push(output);
+            s.command_outputs.push(check);
             s.is_running = false;
             s.running_action = String::new();
+            let confirmed = result.as_ref().is_ok_and(|current| FailurePreset::matching(current) == Some(preset));
+            if let Ok(current) = result {
+                s.failure_actions = Some(current);
+                s.failure_actions_error = None;
+            }
+            if confirmed {
+                s.status_message = t!("services.recovery-set", name = name, preset = failure_preset_label(preset));
+                s.status_type = "success".to_string();
+            } else if applied {
+                s.status_message = t!("services.recovery-unconfirmed", name = name);
+                s.status_type = "warning".to_string();
+            } else {
+                s.status_message = t!("services.recovery-failed", name = name);
+                s.status_type = "error".to_string();
+            }
+        });
+    };
 
-            if success {
-                s.status_message = format!("Service {} started", service_name);
+    // Open regedit at the service's key
+    let on_open_registry = move |_| {
+        let Some(name) = state().details.as_ref().map(|d| d.name.clone()) else {
+            return;
+        };
+        spawn(async move {
+            let outputs = services::open_registry_key(&name).await;
+            let ok = outputs.iter().all(|o| o.succeeded());
+            let mut s = state.write();
+            s.command_outputs.extend(outputs);
+            if ok {
+                s.status_message = t!("services.registry-opened", key = services::registry_key(&name));
                 s.status_type = "success".to_string();
             } else {
-                s.status_message = format!("Failed to start {} - Run as Administrator", service_name);
+                s.status_message = t!("services.registry-failed");
                 s.status_type = "error".to_string();
             }
         });
     };
 
-    // Stop service handler
-    let on_stop_service = move |_| {
-        let service_name = state().selected_service.clone();
-        if service_name.is_empty() {
-            state.write().status_message = "Select a service first".to_string();
-            state.write().status_type = "warning".to_string();
+    // Recent Service Control Manager events mentioning the service, shown in the output panel
+    let on_recent_events = move |_| {
+        let Some((name, display_name)) = state().details.as_ref().map(|d| (d.name.clone(), d.display_name.clone())) else {
             return;
+        };
+        {
+            let mut s = state.write();
+            s.is_running = true;
+            s.running_action = "Events".to_string();
+            s.status_message = t!("services.events-loading", name = name);
+            s.status_type = String::new();
         }
 
-        state.write().is_running = true;
-        state.write().running_action = "Stop".to_string();
-        state.write().status_message = format!("Stopping {}...", service_name);
-        state.write().status_type = String::new();
+        spawn(async move {
+            let output = services::get_recent_events(&name, &display_name).await;
+            let mut s = state.write();
+            s.is_running = false;
+            s.running_action = String::new();
+            if !output.succeeded() {
+                s.status_message = t!("services.events-failed", name = name);
+                s.status_type = "error".to_string();
+            } else if output.stdout.trim().is_empty() {
+                s.status_message = t!("services.events-none", name = name, count = services::RECENT_EVENTS_SCANNED);
+                s.status_type = "info".to_string();
+            } else {
+                s.status_message = t!("services.events-shown", name = name);
+                s.status_type = "success".to_string();
+            }
+            s.command_outputs.push(output);
+        });
+    };
+
+    // Audit: Automatic services that aren't running, minus trigger-start ones
+    let on_audit = move |_| {
+        {
+            let mut s = state.write();
+            s.is_running = true;
+            s.running_action = "Audit".to_string();
+            s.status_message = t!("services.audit-running");
+            s.status_type = String::new();
+        }
 
         spawn(async move {
-            let output = services::stop_service(&service_name).await;
-            let success = output.succeeded();
-            
+            // sc.exe listings have no start types until a row is selected
+            if state.peek().sc_fallback {
+                let unknown: Vec<String> = state
+                    .peek()
+                    .services
+                    .iter()
+                    .filter(|svc| svc.start_type.is_empty() && svc.status != "Running")
+                    .map(|svc| svc.name.clone())
+                    .collect();
+                for name in unknown {
+                    if let (Some(start_type), _) = services::get_start_type_sc(&name).await {
+                        if let Some(svc) = state.write().services.iter_mut().find(|svc| svc.name == name) {
+                            svc.start_type = start_type;
+                        }
+                    }
+                }
+            }
+
+            let candidates = services::audit_auto_not_running(&state.peek().services);
+            let total = candidates.len();
+            let mut audit = services::AutoAudit::default();
+            for (i, svc) in candidates.into_iter().enumerate() {
+                state.write().status_message = t!("services.audit-triggers", name = svc.name, current = i + 1, total = total);
+                if services::has_start_trigger(&svc.name).await {
+                    audit.trigger_start.push(svc.name);
+                } else {
+                    audit.not_running.push(svc);
+                }
+            }
+
             let mut s = state.write();
-            s.command_outputs = vec![output];
             s.is_running = false;
             s.running_action = String::new();
+            if audit.not_running.is_empty() {
+                s.status_message = t!("services.audit-clean");
+                s.status_type = "success".to_string();
+            } else {
+                s.status_message = t!("services.audit-found", count = audit.not_running.len());
+                s.status_type = "warning".to_string();
+            }
+            s.auto_audit = Some(audit);
+        });
+    };
+
+    // Restart request - previews the dependents that go down with the targets, or restarts
+    // right away when there are none
+    let on_request_restart = move |_| {
+        let targets = action_targets(&state());
+        if targets.is_empty() {
+            return;
+        }
+        {
+            let mut s = state.write();
+            s.is_running = true;
+            s.running_action = "Restart".to_string();
+            s.status_message = t!("services.dependents-loading");
+            s.status_type = String::new();
+        }
+
+        spawn(async move {
+            let mut plans: Vec<RestartPlan> = Vec::new();
+            for name in &targets {
+                let (plan, output) = services::plan_restart(name).await;
+                match plan {
+                    Ok(plan) => plans.push(plan),
+                    Err(e) => {
+                        let mut s = state.write();
+                        s.is_running = false;
+                        s.running_action = String::new();
+                        s.status_message = t!("services.dependents-failed", name = name, error = e);
+                        s.status_type = "error".to_string();
+                        s.command_outputs.push(output);
+                        return;
+                    }
+                }
+            }
+            {
+                let mut s = state.write();
+                s.is_running = false;
+                s.running_action = String::new();
+                s.status_message = String::new();
+            }
+            if plans.iter().all(|plan| plan.dependents.is_empty()) {
+                run_service_action(state, app_settings, ServiceAction::Restart, targets);
+            } else {
+                restart_preview.set(Some(plans));
+            }
+        });
+    };
+
+    // Create service handler - adds the new service to the table and selects it
+    let on_create_service = move |_| {
+        let (name, display_name, bin_path, start_type) = {
+            let s = state();
+            (
+                s.new_service_name.trim().to_string(),
+                s.new_service_display_name.clone(),
+                s.new_service_bin_path.clone(),
+                s.new_service_start_type.clone(),
+            )
+        };
+        if let Err(e) = services::validate_new_service(&name, &bin_path) {
+            state.write().status_message = e;
+            state.write().status_type = "error".to_string();
+            return;
+        }
+        {
+            let mut s = state.write();
+            s.is_running = true;
+            s.running_action = "Create".to_string();
+            s.status_message = t!("services.creating", name = name);
+            s.status_type = String::new();
+        }
+
+        spawn(async move {
+            let output = match services::create_service(&name, &display_name, &bin_path, &start_type).await {
+                Ok(output) => output,
+                Err(e) => {
+                    let mut s = state.write();
+                    s.is_running = false;
+                    s.running_action = String::new();
+                    s.status_message = e;
+                    s.status_type = "error".to_string();
+                    return;
+                }
+            };
+            let created = output.succeeded();
+            let (rows, query) = if created {
+                services::list_services_filtered(&name).await
+            } else {
+                (Vec::new(), CommandOutput::default())
+            };
 
-            if success {
-                s.status_message = format!("Service {} stopped", service_name);
+            let mut s = state.write();
+            s.is_running = false;
+            s.running_action = String::new();
+            if created {
+                s.status_message = t!("services.created", name = name);
                 s.status_type = "success".to_string();
+                let result = s.status_message.clone();
+                s.record_action(&name, &t!("services.history-create"), result, true);
+                if let Some(row) = rows.into_iter().find(|svc| svc.name.eq_ignore_ascii_case(&name)) {
+                    s.services.retain(|svc| !svc.name.eq_ignore_ascii_case(&name));
+                    s.selected_service = row.name.clone();
+                    s.services.push(row);
+                }
+                s.new_service_name = String::new();
+                s.new_service_display_name = String::new();
+                s.new_service_bin_path = String::new();
+                s.command_outputs = vec![output, query];
             } else {
-                s.status_message = format!("Failed to stop {} - Run as Administrator", service_name);
+                s.status_message = match sc_error_hint(&output) {
+                    Some(hint) => format!("{} {}", t!("services.create-failed", name = name), hint),
+                    None => t!("services.create-failed", name = name),
+                };
                 s.status_type = "error".to_string();
+                let result = s.status_message.clone();
+                s.record_action(&name, &t!("services.history-create"), result, false);
+                s.command_outputs = vec![output];
             }
         });
     };
 
-    // Restart service handler
-    let on_restart_service = move |_| {
-        let service_name = state().selected_service.clone();
-        if service_name.is_empty() {
-            state.write().status_message = "Select a service first".to_string();
-            state.write().status_type = "warning".to_string();
+    // Delete request - checks the protect list before the type-the-name dialog opens
+    let on_request_delete = move |_| {
+        let name = state().selected_service.clone();
+        if name.is_empty() {
             return;
         }
-
-        state.write().is_running = true;
-        state.write().running_action = "Restart".to_string();
-        state.write().status_message = format!("Restarting {}...", service_name);
-        state.write().status_type = String::new();
+        {
+            let mut s = state.write();
+            s.is_running = true;
+            s.running_action = "DeleteCheck".to_string();
+            s.status_message = String::new();
+        }
 
         spawn(async move {
-            let output = services::restart_service(&service_name).await;
-            let success = output.succeeded();
-            
+            let (bin_path, output) = services::get_binary_path(&name).await;
             let mut s = state.write();
-            s.command_outputs = vec![output];
             s.is_running = false;
             s.running_action = String::new();
+            match bin_path {
+                None => {
+                    s.status_message = t!("services.delete-path-failed", name = name);
+                    s.status_type = "error".to_string();
+                    s.command_outputs.push(output);
+                }
+                Some(bin_path) => match services::delete_refusal(&name, &bin_path) {
+                    Some(reason) => {
+                        s.status_message = t!("services.delete-refused", reason = reason);
+                        s.status_type = "error".to_string();
+                    }
+                    None => {
+                        drop(s);
+                        delete_confirm_text.set(String::new());
+                        delete_target.set(Some((name, bin_path)));
+                    }
+                },
+            }
+        });
+    };
+
+    // Delete handler - drops the row once sc.exe accepted the deletion
+    let mut on_delete_service = move |name: String| {
+        delete_target.set(None);
+        {
+            let mut s = state.write();
+            s.is_running = true;
+            s.running_action = "Delete".to_string();
+            s.status_message = t!("services.deleting", name = name);
+            s.status_type = String::new();
+        }
 
-            if success {
-                s.status_message = format!("Service {} restarted", service_name);
+        spawn(async move {
+            let result = services::delete_service(&name).await;
+            let mut s = state.write();
+            s.is_running = false;
+            s.running_action = String::new();
+            let output = match result {
+                Ok(output) => output,
+                Err(reason) => {
+                    s.status_message = t!("services.delete-refused", reason = reason);
+                    s.status_type = "error".to_string();
+                    return;
+                }
+            };
+            if output.succeeded() {
+                s.status_message = t!("services.deleted", name = name);
                 s.status_type = "success".to_string();
+                let result = s.status_message.clone();
+                s.record_action(&name, &t!("services.history-delete"), result, true);
+                s.services.retain(|svc| svc.name != name);
+                s.checked_services.retain(|checked| *checked != name);
+                if s.selected_service == name {
+                    s.selected_service = String::new();
+                }
+                if s.details.as_ref().is_some_and(|d| d.name == name) {
+                    s.details = None;
+                    s.details_raw = None;
+                    s.failure_actions = None;
+                }
             } else {
-                s.status_message = format!("Failed to restart {} - Run as Administrator", service_name);
-                s.status_type = "error".to_string();
+                let marked = services::sc_error_code(&output) == Some(1072);
+                s.status_message = match sc_error_hint(&output) {
+                    Some(hint) => format!("{} {}", t!("services.delete-failed", name = name), hint),
+                    None => t!("services.delete-failed", name = name),
+                };
+                s.status_type = if marked { "warning" } else { "error" }.to_string();
+                let result = s.status_message.clone();
+                s.record_action(&name, &t!("services.history-delete"), result, false);
             }
+            s.command_outputs.push(output);
         });
     };
 
-    // Get details handler
-    let on_get_details = move |_| {
+    // Raw toggle for the details card - fetches the Format-List text the first time
+    let on_toggle_raw = move |_| {
+        let show = !state().show_details_raw;
+        state.write().show_details_raw = show;
+        let Some(name) = state().details.as_ref().map(|d| d.name.clone()) else {
+            return;
+        };
+        if !show || state().details_raw.is_some() {
+            return;
+        }
+        spawn(async move {
+            let output = services::get_service_details(&name).await;
+            let text = if output.succeeded() { output.stdout.trim().to_string() } else { output.stderr.trim().to_string() };
+            state.write().details_raw = Some(text);
+        });
+    };
+
+    // Jump to the hosting process on the Processes page, adding it if it isn't in the loaded top list
+    // Kill the process hosting a stuck service, then re-read the service's status
+    // Kill request - looks up every service in the host process before the confirm dialog opens
+    let on_request_kill = move |wait: StatusWait| {
+        let Some(pid) = wait.pid else { return };
+        spawn(async move {
+            let (host, output) = services::get_service_host(pid).await;
+            let host = host.unwrap_or_else(|| ServiceHost {
+                image: processes::get_process(pid).map(|p| p.name).unwrap_or_default(),
+                pid,
+                services: Vec::new(),
+            });
+            state.write().command_outputs.push(output);
+            kill_target.set(Some((wait, host)));
+        });
+    };
+
+    let mut on_kill_stuck = move |wait: StatusWait| {
+        kill_target.set(None);
+        let Some(pid) = wait.pid else { return };
+        {
+            let mut s = state.write();
+            s.is_running = true;
+            s.running_action = "Kill".to_string();
+            s.status_message = t!("services.killing-host", pid = pid, name = wait.name);
+            s.status_type = String::new();
+        }
+
+        spawn(async move {
+            let result = processes::kill_process(pid);
+            let after = services::wait_for_status(&wait.name, "Stopped", std::time::Duration::from_secs(5)).await;
+            let mut s = state.write();
+            s.is_running = false;
+            s.running_action = String::new();
+            update_service_status(&mut s, &wait.name, &after.status);
+            let action = t!("services.history-kill", pid = pid);
+            match result {
+                Ok(()) => {
+                    s.stuck_services.retain(|stuck| stuck.name != wait.name);
+                    s.status_message = t!("services.host-killed", pid = pid, name = wait.name, status = after.status);
+                    s.status_type = "success".to_string();
+                    s.record_action(&wait.name, &action, wait_result(&after), true);
+                }
+                Err(e) => {
+                    s.record_action(&wait.name, &action, e.clone(), false);
+                    s.status_message = e;
+                    s.status_type = "error".to_string();
+                }
+            }
+        });
+    };
+
+    let mut on_show_process = move |pid: u32| {
+        let Some(info) = processes::get_process(pid) else {
+            state.write().status_message = t!("services.process-gone", pid = pid);
+            state.write().status_type = "warning".to_string();
+            return;
+        };
+        let mut p = processes_state.write();
+        if !p.processes.iter().any(|proc| proc.pid == pid) {
+            p.processes.push(info);
+        }
+        p.selected_pid = Some(pid);
+        p.table.filter = pid.to_string();
+        drop(p);
+        current_page.set(CurrentPage::Processes);
+    };
+
+    // Select a dependency in the service table, clearing a filter that would hide it
+    let mut on_select_service = move |name: String| {
+        let mut s = state.write();
+        if !services_in_view(&s).iter().any(|svc| svc.name.eq_ignore_ascii_case(&name)) {
+            s.table.filter = String::new();
+            s.status_filter = None;
+        }
+        s.selected_service = s.services.iter().find(|svc| svc.name.eq_ignore_ascii_case(&name)).map(|svc| svc.name.clone()).unwrap_or(name);
+        let selected = s.selected_service.clone();
+        drop(s);
+        load_start_type_sc(state, selected);
+    };
+
+    // Start type handler - re-queries just this service to confirm the change in the table
+    let mut on_set_start_type = move |start_type: String| {
         let service_name = state().selected_service.clone();
         if service_name.is_empty() {
-            state.write().status_message = "Select a service first".to_string();
+            state.write().status_message = t!("services.select-first");
             state.write().status_type = "warning".to_string();
             return;
         }
 
         state.write().is_running = true;
-        state.write().running_action = "Details".to_string();
-        state.write().status_message = format!("Getting details for {}...", service_name);
+        state.write().running_action = "StartType".to_string();
+        state.write().status_message = t!("services.setting-start-type", name = service_name, start_type = start_type);
         state.write().status_type = String::new();
 
         spawn(async move {
-            let output = services::get_service_details(&service_name).await;
+            let output = services::set_start_type(&service_name, &start_type).await;
             let success = output.succeeded();
-            
+            let (updated, query) = services::list_services_filtered(&service_name).await;
+            let updated = updated.into_iter().find(|svc| svc.name.eq_ignore_ascii_case(&service_name));
+
             let mut s = state.write();
-            s.command_outputs = vec![output];
+            s.command_outputs = vec![output, query];
             s.is_running = false;
             s.running_action = String::new();
 
-            if success {
-                s.status_message = format!("Details for {}", service_name);
-                s.status_type = "success".to_string();
-            } else {
-                s.status_message = format!("Failed to get details for {}", service_name);
-                s.status_type = "error".to_string();
+            let reported = updated.as_ref().map(|svc| svc.start_type.clone());
+            if let Some(updated) = updated {
+                if let Some(row) = s.services.iter_mut().find(|svc| svc.name == updated.name) {
+                    *row = updated;
+                }
+            }
+
+            match reported {
+                _ if !success => {
+                    s.status_message = t!("services.start-type-failed", name = service_name);
+                    s.status_type = "error".to_string();
+                }
+                Some(reported) if !services::start_type_matches(&reported, &start_type) => {
+                    s.status_message = t!("services.start-type-unconfirmed", name = service_name, start_type = reported);
+                    s.status_type = "warning".to_string();
+                }
+                _ => {
+                    s.status_message = t!("services.start-type-set", name = service_name, start_type = start_type);
+                    s.status_type = "success".to_string();
+                }
             }
+            let result = s.status_message.clone();
+            let ok = s.status_type == "success";
+            s.record_action(&service_name, &t!("services.history-start-type", start_type = start_type), result, ok);
         });
     };
 
@@ -204,52 +1345,186 @@ pub fn ServicesPage(is_admin: bool) -> Element {
     let current_state = state();
     let outputs_for_panel = current_state.command_outputs.clone();
 
-    // Sort services based on current sort state
-    let mut sorted_services = current_state.services.clone();
-    if let Some(sort_col) = current_state.sort_column {
-        let asc = current_state.sort_ascending;
-        sorted_services.sort_by(|a, b| {
-            let cmp = match sort_col {
-                ServicesSortColumn::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
-                ServicesSortColumn::DisplayName => a.display_name.to_lowercase().cmp(&b.display_name.to_lowercase()),
-                ServicesSortColumn::Status => a.status.cmp(&b.status),
-                ServicesSortColumn::StartType => a.start_type.cmp(&b.start_type),
+    // Filter and sort the loaded services
+    let sorted_services = services_in_view(&current_state);
+    let visible_rows = service_window().visible(sorted_services.len());
+    let sort_dir = SortDirection::from_ascending(current_state.table.sort_ascending);
+    let status_chips = status_counts(&current_state.services);
+    let local_system_count = current_state.services.iter().filter(|svc| svc.runs_as_local_system()).count();
+
+    // Export the visible rows to the clipboard
+    let mut on_export = move |json: bool| {
+        let rows = services_in_view(&state());
+        let count = rows.len();
+        let as_is = TableController::<ServicesSortColumn>::default();
+        let text = if json {
+            as_is.export_json(&rows, SERVICE_COLUMNS)
+        } else {
+            as_is.export_csv(&rows, SERVICE_COLUMNS)
+        };
+        let mut s = state.write();
+        match copy_to_clipboard(&text) {
+            Ok(()) => {
+                s.status_message = t!("services.copied", count = count, format = if json { "JSON" } else { "CSV" });
+                s.status_type = "success".to_string();
+            }
+            Err(e) => {
+                s.status_message = e;
+                s.status_type = "error".to_string();
+            }
+        }
+    };
+
+    // Write the visible rows to a file chosen in a save dialog
+    let mut on_export_file = move |json: bool| {
+        let rows = services_in_view(&state());
+        let history = state().history.clone();
+        {
+            let mut s = state.write();
+            s.is_running = true;
+            s.running_action = "Export".to_string();
+            s.status_message = t!("services.export-choose");
+            s.status_type = String::new();
+        }
+
+        spawn(async move {
+            let (extension, filter) = if json {
+                ("json", "JSON files (*.json)|*.json|All files (*.*)|*.*")
+            } else {
+                ("csv", "CSV files (*.csv)|*.csv|All files (*.*)|*.*")
             };
-            if asc { cmp } else { cmp.reverse() }
+            let name = format!("services-{}.{}", chrono::Local::now().format("%Y%m%d-%H%M%S"), extension);
+            let picked = pick_save_file(&name, filter).await;
+
+            let mut s = state.write();
+            s.is_running = false;
+            s.running_action = String::new();
+            let Some(path) = picked else {
+                s.status_message = t!("services.export-cancelled");
+                s.status_type = "info".to_string();
+                return;
+            };
+            match std::fs::write(&path, format_services_export(&rows, &history, json)) {
+                Ok(()) => {
+                    s.status_message = t!("services.exported", count = rows.len(), path = path);
+                    s.status_type = "success".to_string();
+                }
+                Err(e) => {
+                    s.status_message = t!("services.export-failed", path = path, error = e);
+                    s.status_type = "error".to_string();
+                }
+            }
         });
-    }
+    };
 
-    // Determine sort direction for display
-    let sort_dir = if current_state.sort_ascending {
-        SortDirection::Ascending
+    // Stopping a critical service is destructive, anything else is a normal change
+    let targets = action_targets(&current_state);
+    let stop_danger = if targets.iter().any(|name| services::is_critical_service(name)) {
+        DangerLevel::Destructive
     } else {
-        SortDirection::Descending
+        DangerLevel::Modifying
     };
 
     rsx! {
         // Page header
         header { class: "page-header",
-            h1 { class: "page-title", "⚙️ Services" }
-            p { class: "page-subtitle", "Windows service management" }
+            h1 { class: "page-title", {t!("services.title")} }
+            p { class: "page-subtitle", {t!("services.subtitle")} }
         }
 
-        // Split layout
-        div { class: "page-split-layout",
-            // Scrollable controls
-            div { class: "page-controls",
+        // Split layout
+        div { class: "page-split-layout",
+            // Scrollable controls
+            div { class: "page-controls",
+                // Favorites strip
+                if !current_state.favorites.is_empty() {
+                    div { class: "section",
+                        div { class: "section-header",
+                            h3 { class: "section-title", {t!("services.favorites")} }
+                            button {
+                                class: "btn btn-ghost btn-sm",
+                                disabled: current_state.loading_favorites,
+                                onclick: move |_| refresh_favorites(state),
+                                if current_state.loading_favorites { {t!("common.loading")} } else { {t!("services.favorites-refresh")} }
+                            }
+                        }
+                        div { class: "favorite-strip",
+                            for name in current_state.favorites.iter().cloned() {
+                                {
+                                    let svc = current_state.favorite_status.iter().find(|svc| svc.name.eq_ignore_ascii_case(&name)).cloned();
+                                    let status = match &svc {
+                                        Some(svc) => svc.status.clone(),
+                                        None if current_state.loading_favorites => "…".to_string(),
+                                        None => t!("services.favorite-missing"),
+                                    };
+                                    let status_class = match status.as_str() {
+                                        "Running" => "badge badge-success",
+                                        "Stopped" => "badge",
+                                        _ => "badge badge-warning",
+                                    };
+                                    let display_name = svc.as_ref().map(|svc| svc.display_name.clone()).unwrap_or_default();
+                                    let (start_name, stop_name, unstar_name, select_name) = (name.clone(), name.clone(), name.clone(), name.clone());
+                                    rsx! {
+                                        div { class: "card favorite-card",
+                                            key: "{name}",
+                                            div { class: "favorite-card-header",
+                                                span {
+                                                    class: "mono favorite-card-name",
+                                                    title: "{display_name}",
+                                                    onclick: move |_| {
+                                                        state.write().selected_service = select_name.clone();
+                                                        scroll_target.set(Some(select_name.clone()));
+                                                    },
+                                                    "{name}"
+                                                }
+                                                button {
+                                                    class: "btn btn-ghost btn-sm",
+                                                    title: t!("services.unstar-hint"),
+                                                    onclick: move |_| toggle_favorite(state, app_settings, unstar_name.clone()),
+                                                    "★"
+                                                }
+                                            }
+                                            span { class: status_class, "{status}" }
+                                            div { class: "action-bar-group",
+                                                ActionButton {
+                                                    danger: DangerLevel::Modifying,
+                                                    class: "btn btn-primary btn-sm",
+                                                    disabled: current_state.is_running || !is_admin || svc.is_none() || status == "Running",
+                                                    title: if !is_admin { t!("common.requires-admin") } else { String::new() },
+                                                    onclick: move |_| run_service_action(state, app_settings, ServiceAction::Start, vec![start_name.clone()]),
+                                                    {t!("services.start")}
+                                                }
+                                                ActionButton {
+                                                    danger: if services::is_critical_service(&name) { DangerLevel::Destructive } else { DangerLevel::Modifying },
+                                                    class: "btn btn-danger btn-sm",
+                                                    disabled: current_state.is_running || !is_admin || svc.is_none() || status == "Stopped",
+                                                    title: if !is_admin { t!("common.requires-admin") } else { String::new() },
+                                                    onclick: move |_| run_service_action(state, app_settings, ServiceAction::Stop, vec![stop_name.clone()]),
+                                                    {t!("services.stop")}
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
                 // Search/Filter section
                 div { class: "section",
-                    h3 { class: "section-title", "Search Services" }
+                    h3 { class: "section-title", {t!("services.search")} }
                     div { class: "action-bar",
                         div { class: "action-bar-group",
-                            label { "Filter:" }
+                            label { {t!("services.filter")} }
                             input {
                                 r#type: "text",
                                 class: "input",
-                                placeholder: "e.g. docker, sql, iis...",
-                                value: "{current_state.filter}",
+                                placeholder: t!("services.filter-placeholder"),
+                                value: "{current_state.table.filter}",
                                 oninput: move |e| {
-                                    state.write().filter = e.value();
+                                    state.write().table.filter = e.value();
+                                    keep_selection_visible(state);
                                 },
                             }
                         }
@@ -258,57 +1533,314 @@ pub fn ServicesPage(is_admin: bool) -> Element {
                             class: "btn btn-primary",
                             disabled: current_state.is_running,
                             onclick: on_list_services,
-                            if current_state.running_action == "List" { "Loading..." } else { "List Services" }
+                            if current_state.running_action == "List" { {t!("common.loading")} } else { {t!("services.list")} }
+                        }
+
+                        div { class: "action-bar-group",
+                            title: t!("services.auto-refresh-hint"),
+                            label {
+                                input {
+                                    r#type: "checkbox",
+                                    checked: current_state.auto_refresh,
+                                    onchange: move |e| {
+                                        state.write().auto_refresh = e.checked();
+                                        if !e.checked() {
+                                            state.write().changed_services.clear();
+                                        }
+                                    },
+                                }
+                                {format!(" {}", t!("services.auto-refresh"))}
+                            }
+                            input {
+                                r#type: "number",
+                                class: "input input-number",
+                                value: "{current_state.auto_refresh_secs}",
+                                min: 2,
+                                max: 600,
+                                oninput: move |e| {
+                                    if let Ok(v) = e.value().parse::<u64>() {
+                                        state.write().auto_refresh_secs = v.clamp(2, 600);
+                                    }
+                                },
+                            }
+                            span { class: "muted", "s" }
+                        }
+
+                        button {
+                            class: "btn btn-secondary",
+                            disabled: current_state.is_running || current_state.services.is_empty(),
+                            title: if current_state.services.is_empty() { t!("services.audit-list-first") } else { t!("services.audit-hint") },
+                            onclick: on_audit,
+                            if current_state.running_action == "Audit" { {t!("common.loading")} } else { {t!("services.audit")} }
+                        }
+
+                        div { class: "action-bar-divider" }
+
+                        div { class: "action-bar-group",
+                            label { {t!("services.port-lookup")} }
+                            input {
+                                r#type: "number",
+                                class: "input input-number",
+                                min: 1,
+                                max: 65535,
+                                placeholder: "443",
+                                value: "{current_state.port_lookup}",
+                                oninput: move |e| state.write().port_lookup = e.value(),
+                            }
+                            button {
+                                class: "btn btn-secondary",
+                                disabled: current_state.is_running || current_state.port_lookup.trim().is_empty(),
+                                title: t!("services.port-lookup-hint"),
+                                onclick: on_find_by_port,
+                                if current_state.running_action == "FindPort" { {t!("common.loading")} } else { {t!("services.port-find")} }
+                            }
+                        }
+                    }
+
+                    if let Some(owners) = &current_state.port_owners {
+                        if !owners.is_empty() {
+                            div { class: "port-owners",
+                                for owner in owners.iter() {
+                                    div { class: "port-owner",
+                                        key: "{owner.pid}",
+                                        span { class: "badge", "{owner.protocol.label()}" }
+                                        span { class: "mono", "{owner.process_name} (PID {owner.pid})" }
+                                        if owner.is_system {
+                                            span { class: "muted", {t!("services.port-kernel-short")} }
+                                        } else if owner.services.is_empty() {
+                                            span { class: "muted", {t!("services.port-not-service-short")} }
+                                        } else {
+                                            span { class: "muted", "→" }
+                                            for name in owner.services.iter().cloned() {
+                                                button {
+                                                    class: "btn btn-ghost btn-sm mono",
+                                                    key: "{name}",
+                                                    onclick: move |_| {
+                                                        let mut s = state.write();
+                                                        s.selected_service = name.clone();
+                                                        if !services_in_view(&s).iter().any(|svc| svc.name == name) {
+                                                            s.status_filter = None;
+                                                            s.table.filter = String::new();
+                                                        }
+                                                        drop(s);
+                                                        scroll_target.set(Some(name.clone()));
+                                                    },
+                                                    "{name}"
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                if let Some(audit) = &current_state.auto_audit {
+                    div { class: "card auto-audit-card",
+                        div { class: "adapter-card-header",
+                            span { class: "card-title", {t!("services.audit-title", count = audit.not_running.len())} }
+                            button {
+                                class: "btn btn-ghost btn-sm",
+                                onclick: move |_| state.write().auto_audit = None,
+                                "✕"
+                            }
+                        }
+                        if audit.not_running.is_empty() {
+                            p { class: "muted", {t!("services.audit-clean")} }
+                        } else {
+                            table { class: "data-table",
+                                tbody {
+                                    for svc in audit.not_running.iter() {
+                                        {
+                                            // Live status from the table, so a started service shows as Running
+                                            let status = current_state
+                                                .services
+                                                .iter()
+                                                .find(|row| row.name == svc.name)
+                                                .map(|row| row.status.clone())
+                                                .unwrap_or_else(|| svc.status.clone());
+                                            let select_name = svc.name.clone();
+                                            let start_name = svc.name.clone();
+                                            rsx! {
+                                                tr { key: "{svc.name}",
+                                                    td {
+                                                        button {
+                                                            class: "btn btn-ghost btn-sm mono",
+                                                            onclick: move |_| {
+                                                                on_select_service(select_name.clone());
+                                                                scroll_target.set(Some(select_name.clone()));
+                                                            },
+                                                            "{svc.name}"
+                                                        }
+                                                    }
+                                                    td { "{svc.display_name}" }
+                                                    td { class: if status == "Running" { "success" } else { "" }, "{status}" }
+                                                    td { class: "muted", "{svc.start_type}" }
+                                                    td {
+                                                        if status != "Running" {
+                                                            ActionButton {
+                                                                danger: DangerLevel::Modifying,
+                                                                class: "btn btn-primary btn-sm",
+                                                                disabled: current_state.is_running || !is_admin,
+                                                                title: if !is_admin { t!("common.requires-admin") } else { String::new() },
+                                                                onclick: move |_| run_service_action(state, app_settings, ServiceAction::Start, vec![start_name.clone()]),
+                                                                {t!("services.start")}
+                                                            }
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        if !audit.trigger_start.is_empty() {
+                            p { class: "muted audit-note",
+                                title: audit.trigger_start.join(", "),
+                                {t!("services.audit-trigger-note", count = audit.trigger_start.len())}
+                            }
                         }
                     }
                 }
 
                 // Service list section
-                if !sorted_services.is_empty() {
+                if !current_state.services.is_empty() {
                     div { class: "section",
-                        h3 { class: "section-title", 
-                            "Services ({current_state.services.len()})" 
+                        div { class: "section-header",
+                            h3 { class: "section-title",
+                                {t!("services.table-title", shown = sorted_services.len(), total = current_state.services.len())}
+                                if current_state.sc_fallback {
+                                    span {
+                                        class: "badge badge-warning",
+                                        title: t!("services.sc-fallback-hint"),
+                                        {t!("services.sc-fallback")}
+                                    }
+                                }
+                            }
+                            div { class: "status-chips",
+                                for (status, count) in status_chips {
+                                    {
+                                        let active = current_state.status_filter.as_deref() == Some(status.as_str());
+                                        rsx! {
+                                            button {
+                                                class: if active { "status-chip active" } else { "status-chip" },
+                                                title: t!("services.status-chip-hint"),
+                                                onclick: move |_| {
+                                                    let mut s = state.write();
+                                                    s.status_filter = if active { None } else { Some(status.clone()) };
+                                                    drop(s);
+                                                    keep_selection_visible(state);
+                                                },
+                                                "{status} ({count})"
+                                            }
+                                        }
+                                    }
+                                }
+                                if local_system_count > 0 {
+                                    button {
+                                        class: if current_state.local_system_only { "status-chip active" } else { "status-chip" },
+                                        title: t!("services.local-system-hint"),
+                                        onclick: move |_| {
+                                            let only = !state().local_system_only;
+                                            state.write().local_system_only = only;
+                                            keep_selection_visible(state);
+                                        },
+                                        {t!("services.local-system-chip", count = local_system_count)}
+                                    }
+                                }
+                            }
+                            div { class: "table-export",
+                                button {
+                                    class: "btn btn-ghost btn-sm",
+                                    title: t!("table.copy-csv-hint"),
+                                    onclick: move |_| on_export(false),
+                                    "📋 CSV"
+                                }
+                                button {
+                                    class: "btn btn-ghost btn-sm",
+                                    title: t!("table.copy-json-hint"),
+                                    onclick: move |_| on_export(true),
+                                    "📋 JSON"
+                                }
+                                button {
+                                    class: "btn btn-ghost btn-sm",
+                                    disabled: current_state.is_running,
+                                    title: t!("services.export-csv-hint"),
+                                    onclick: move |_| on_export_file(false),
+                                    "💾 CSV"
+                                }
+                                button {
+                                    class: "btn btn-ghost btn-sm",
+                                    disabled: current_state.is_running,
+                                    title: t!("services.export-json-hint"),
+                                    onclick: move |_| on_export_file(true),
+                                    "💾 JSON"
+                                }
+                            }
                         }
-                        div { class: "service-list",
+                        VirtualScroll { window: service_window, id: "service-list", class: "service-list",
                             table { class: "data-table",
                                 thead {
                                     tr {
-                                        StaticHeader { label: "".to_string() }
-                                        SortableHeader {
-                                            column: ServicesSortColumn::Name,
-                                            label: "Name".to_string(),
-                                            current_sort: current_state.sort_column,
-                                            direction: sort_dir,
-                                            on_sort: move |col| update_service_sort(state, col),
-                                        }
-                                        SortableHeader {
-                                            column: ServicesSortColumn::DisplayName,
-                                            label: "Display Name".to_string(),
-                                            current_sort: current_state.sort_column,
-                                            direction: sort_dir,
-                                            on_sort: move |col| update_service_sort(state, col),
-                                        }
-                                        SortableHeader {
-                                            column: ServicesSortColumn::Status,
-                                            label: "Status".to_string(),
-                                            current_sort: current_state.sort_column,
-                                            direction: sort_dir,
-                                            on_sort: move |col| update_service_sort(state, col),
+                                        th {
+                                            {
+                                                let visible: Vec<String> = sorted_services.iter().map(|svc| svc.name.clone()).collect();
+                                                let all_checked = !visible.is_empty()
+                                                    && visible.iter().all(|name| current_state.checked_services.contains(name));
+                                                rsx! {
+                                                    input {
+                                                        r#type: "checkbox",
+                                                        title: t!("services.check-all-hint"),
+                                                        checked: all_checked,
+                                                        onchange: move |_| {
+                                                            let mut s = state.write();
+                                                            if all_checked {
+                                                                s.checked_services.retain(|name| !visible.contains(name));
+                                                            } else {
+                                                                for name in &visible {
+                                                                    if !s.checked_services.contains(name) {
+                                                                        s.checked_services.push(name.clone());
+                                                                    }
+                                                                }
+                                                            }
+                                                        },
+                                                    }
+                                                }
+                                            }
                                         }
-                                        SortableHeader {
-                                            column: ServicesSortColumn::StartType,
-                                            label: "Start Type".to_string(),
-                                            current_sort: current_state.sort_column,
-                                            direction: sort_dir,
-                                            on_sort: move |col| update_service_sort(state, col),
+                                        th { title: t!("services.star-hint"), "★" }
+                                        for column in SERVICE_COLUMNS {
+                                            SortableHeader {
+                                                column: column.id,
+                                                label: t!(column.label_key),
+                                                current_sort: current_state.table.sort_column,
+                                                direction: sort_dir,
+                                                on_sort: move |col| state.write().table.toggle_sort(col, SERVICE_COLUMNS),
+                                            }
                                         }
                                     }
                                 }
                                 tbody {
-                                    for svc in sorted_services.iter() {
+                                    VirtualSpacer { height: visible_rows.above, colspan: SERVICE_COLUMNS.len() + 2 }
+                                    for svc in sorted_services[visible_rows.range.clone()].iter() {
                                         {
                                             let is_selected = current_state.selected_service == svc.name;
+                                            let row_class = match (is_selected, current_state.changed_services.contains(&svc.name)) {
+                                                (selected, true) => format!(
+                                                    "{}changed{}",
+                                                    if selected { "selected " } else { "" },
+                                                    if svc.status == "Running" { " changed-running" } else { "" }
+                                                ),
+                                                (true, false) => "selected".to_string(),
+                                                (false, false) => String::new(),
+                                            };
+                                            let is_checked = current_state.checked_services.contains(&svc.name);
                                             let svc_name = svc.name.clone();
+                                            let check_name = svc.name.clone();
+                                            let star_name = svc.name.clone();
+                                            let is_favorite = current_state.favorites.contains(&svc.name);
                                             let status_class = match svc.status.as_str() {
                                                 "Running" => "success",
                                                 "Stopped" => "muted",
@@ -316,25 +1848,52 @@ pub fn ServicesPage(is_admin: bool) -> Element {
                                             };
                                             rsx! {
                                                 tr {
-                                                    class: if is_selected { "selected" } else { "" },
+                                                    key: "{svc.name}",
+                                                    id: "svc-row-{svc.name}",
+                                                    class: "{row_class}",
                                                     onclick: move |_| {
                                                         state.write().selected_service = svc_name.clone();
+                                                        load_start_type_sc(state, svc_name.clone());
                                                     },
-                                                    td { 
-                                                        input { 
-                                                            r#type: "radio",
-                                                            checked: is_selected,
-                                                            onchange: move |_| {},
+                                                    td {
+                                                        input {
+                                                            r#type: "checkbox",
+                                                            checked: is_checked,
+                                                            onclick: move |e| e.stop_propagation(),
+                                                            onchange: move |_| {
+                                                                let mut s = state.write();
+                                                                if is_checked {
+                                                                    s.checked_services.retain(|name| *name != check_name);
+                                                                } else {
+                                                                    s.checked_services.push(check_name.clone());
+                                                                }
+                                                            },
+                                                        }
+                                                    }
+                                                    td {
+                                                        button {
+                                                            class: if is_favorite { "btn btn-ghost btn-sm favorite-star active" } else { "btn btn-ghost btn-sm favorite-star" },
+                                                            title: if is_favorite { t!("services.unstar-hint") } else { t!("services.star-hint") },
+                                                            onclick: move |e| {
+                                                                e.stop_propagation();
+                                                                toggle_favorite(state, app_settings, star_name.clone());
+                                                            },
+                                                            if is_favorite { "★" } else { "☆" }
                                                         }
                                                     }
                                                     td { class: "mono", "{svc.name}" }
                                                     td { "{svc.display_name}" }
                                                     td { class: status_class, "{svc.status}" }
                                                     td { class: "muted", "{svc.start_type}" }
+                                                    td { class: "mono muted", "{svc.account}" }
+                                                    td { class: "mono muted",
+                                                        if svc.process_id != 0 { "{svc.process_id}" }
+                                                    }
                                                 }
                                             }
                                         }
                                     }
+                                    VirtualSpacer { height: visible_rows.below, colspan: SERVICE_COLUMNS.len() + 2 }
                                 }
                             }
                         }
@@ -343,53 +1902,549 @@ pub fn ServicesPage(is_admin: bool) -> Element {
 
                 // Service actions section
                 div { class: "section",
-                    h3 { class: "section-title", "Service Actions" }
+                    h3 { class: "section-title", {t!("services.actions")} }
                     
-                    if current_state.selected_service.is_empty() {
+                    if targets.is_empty() {
                         div { class: "status-bar",
-                            "Select a service from the list above to perform actions"
+                            {t!("services.select-hint")}
                         }
                     } else {
                         div { class: "action-bar",
-                            span { class: "mono", "Selected: {current_state.selected_service}" }
-                            
+                            if current_state.checked_services.is_empty() {
+                                span { class: "mono", {t!("services.selected", name = current_state.selected_service)} }
+                            } else {
+                                span {
+                                    title: current_state.checked_services.join(", "),
+                                    {t!("services.checked", count = current_state.checked_services.len())}
+                                }
+                                button {
+                                    class: "btn btn-ghost btn-sm",
+                                    onclick: move |_| state.write().checked_services.clear(),
+                                    "✕"
+                                }
+                            }
+
                             div { class: "action-bar-divider" }
 
-                            button {
+                            ActionButton {
+                                danger: DangerLevel::Modifying,
                                 class: "btn btn-primary",
                                 disabled: current_state.is_running || !is_admin,
-                                onclick: on_start_service,
-                                title: if !is_admin { "Requires Administrator" } else { "" },
-                                if current_state.running_action == "Start" { "Starting..." } else { "▶ Start" }
+                                onclick: move |_| run_service_action(state, app_settings, ServiceAction::Start, action_targets(&state())),
+                                title: if !is_admin { t!("common.requires-admin") } else { String::new() },
+                                if current_state.running_action == "Start" { {t!("services.start-running")} } else { {t!("services.start")} }
                             }
 
-                            button {
+                            ActionButton {
+                                danger: stop_danger,
                                 class: "btn btn-danger",
                                 disabled: current_state.is_running || !is_admin,
-                                onclick: on_stop_service,
-                                title: if !is_admin { "Requires Administrator" } else { "" },
-                                if current_state.running_action == "Stop" { "Stopping..." } else { "⏹ Stop" }
+                                onclick: move |_| run_service_action(state, app_settings, ServiceAction::Stop, action_targets(&state())),
+                                title: if !is_admin { t!("common.requires-admin") } else { String::new() },
+                                if current_state.running_action == "Stop" { {t!("services.stop-running")} } else { {t!("services.stop")} }
                             }
 
-                            button {
+                            ActionButton {
+                                danger: DangerLevel::Modifying,
                                 class: "btn btn-secondary",
                                 disabled: current_state.is_running || !is_admin,
-                                onclick: on_restart_service,
-                                title: if !is_admin { "Requires Administrator" } else { "" },
-                                if current_state.running_action == "Restart" { "Restarting..." } else { "🔄 Restart" }
+                                onclick: on_request_restart,
+                                title: if !is_admin { t!("common.requires-admin") } else { String::new() },
+                                if current_state.running_action == "Restart" { {t!("services.restart-running")} } else { {t!("services.restart")} }
+                            }
+
+                            div { class: "action-bar-group",
+                                title: t!("services.wait-timeout-hint"),
+                                label { {t!("services.wait-timeout")} }
+                                input {
+                                    r#type: "number",
+                                    class: "input input-number",
+                                    value: "{app_settings().service_wait_secs}",
+                                    min: 1,
+                                    max: 600,
+                                    onchange: move |e| {
+                                        if let Ok(secs) = e.value().parse::<u64>() {
+                                            let secs = secs.clamp(1, 600);
+                                            if app_settings().service_wait_secs != secs {
+                                                app_settings.write().service_wait_secs = secs;
+                                                if let Err(e) = settings::save(&app_settings()) {
+                                                    state.write().status_message = e;
+                                                    state.write().status_type = "error".to_string();
+                                                }
+                                            }
+                                        }
+                                    },
+                                }
                             }
 
                             button {
                                 class: "btn btn-ghost",
-                                disabled: current_state.is_running,
+                                disabled: current_state.is_running || current_state.selected_service.is_empty(),
+                                title: current_state.selected_service.clone(),
                                 onclick: on_get_details,
-                                if current_state.running_action == "Details" { "Loading..." } else { "ℹ Details" }
+                                if current_state.running_action == "Details" { {t!("common.loading")} } else { {t!("services.details-button")} }
+                            }
+
+                            if !current_state.selected_service.is_empty() {
+                                div { class: "action-bar-divider" }
+
+                                div { class: "action-bar-group",
+                                    label { {t!("services.start-type")} }
+                                    {
+                                        let current = current_state
+                                            .services
+                                            .iter()
+                                            .find(|svc| svc.name == current_state.selected_service)
+                                            .map(|svc| svc.start_type.clone())
+                                            .unwrap_or_default();
+                                        rsx! {
+                                            select {
+                                                class: "input",
+                                                disabled: current_state.is_running || !is_admin,
+                                                title: if !is_admin { t!("common.requires-admin") } else { String::new() },
+                                                onchange: move |e| {
+                                                    if e.value() != current {
+                                                        on_set_start_type(e.value());
+                                                    }
+                                                },
+                                                if !services::START_TYPES.contains(&current.as_str()) {
+                                                    option { value: "", selected: true, "{current}" }
+                                                }
+                                                for start_type in services::START_TYPES {
+                                                    option {
+                                                        value: "{start_type}",
+                                                        selected: current == *start_type,
+                                                        "{start_type}"
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
                             }
                         }
 
                         if !is_admin {
                             div { class: "status-bar warning",
-                                "⚠️ Start/Stop/Restart require Administrator privileges"
+                                {t!("services.admin-warning")}
+                            }
+                        }
+
+                        for wait in current_state.stuck_services.clone() {
+                            div { class: "status-bar warning action-bar",
+                                key: "{wait.name}",
+                                span { {stuck_message(&wait)} }
+                                if wait.pid.is_some() {
+                                    ActionButton {
+                                        danger: DangerLevel::Destructive,
+                                        class: "btn btn-danger btn-sm",
+                                        disabled: current_state.is_running || !is_admin,
+                                        title: if !is_admin { t!("common.requires-admin") } else { String::new() },
+                                        onclick: {
+                                            let wait = wait.clone();
+                                            move |_| on_request_kill(wait.clone())
+                                        },
+                                        {t!("services.kill-host")}
+                                    }
+                                }
+                                button {
+                                    class: "btn btn-ghost btn-sm",
+                                    onclick: {
+                                        let name = wait.name.clone();
+                                        move |_| state.write().stuck_services.retain(|stuck| stuck.name != name)
+                                    },
+                                    "✕"
+                                }
+                            }
+                        }
+
+                        if let Some(details) = &current_state.details {
+                            div { class: "card service-details-card",
+                                div { class: "adapter-card-header",
+                                    span { class: "card-title",
+                                        "{details.display_name} "
+                                        span { class: "mono muted", "({details.name})" }
+                                    }
+                                    div { class: "action-bar-group",
+                                        span {
+                                            class: match details.state.as_str() {
+                                                "Running" => "badge badge-success",
+                                                "Stopped" => "badge badge-muted",
+                                                _ => "badge badge-warning",
+                                            },
+                                            "{details.state}"
+                                        }
+                                        button {
+                                            class: "btn btn-ghost btn-sm",
+                                            title: services::registry_key(&details.name),
+                                            onclick: on_open_registry,
+                                            {t!("services.open-registry")}
+                                        }
+                                        button {
+                                            class: "btn btn-ghost btn-sm",
+                                            disabled: current_state.is_running,
+                                            title: t!("services.events-hint", count = services::RECENT_EVENTS_SCANNED),
+                                            onclick: on_recent_events,
+                                            if current_state.running_action == "Events" { {t!("common.loading")} } else { {t!("services.recent-events")} }
+                                        }
+                                        button {
+                                            class: if current_state.show_details_raw { "btn btn-secondary btn-sm" } else { "btn btn-ghost btn-sm" },
+                                            onclick: on_toggle_raw,
+                                            {t!("services.raw")}
+                                        }
+                                        button {
+                                            class: "btn btn-ghost btn-sm",
+                                            onclick: move |_| state.write().details = None,
+                                            "✕"
+                                        }
+                                    }
+                                }
+                                if current_state.show_details_raw {
+                                    match &current_state.details_raw {
+                                        Some(raw) => rsx! { pre { class: "service-details-raw mono", "{raw}" } },
+                                        None => rsx! { p { class: "muted", {t!("common.loading")} } },
+                                    }
+                                } else {
+                                    if !details.description.is_empty() {
+                                        p { class: "muted", "{details.description}" }
+                                    }
+                                    dl { class: "adapter-details",
+                                        dt { {t!("services.start-mode")} }
+                                        dd { "{details.start_mode}" }
+                                        dt { {t!("services.account")} }
+                                        dd { class: "mono", "{details.account}" }
+                                        dt { "PID" }
+                                        dd { class: "mono",
+                                            match details.pid {
+                                                Some(pid) => rsx! {
+                                                    button {
+                                                        class: "btn btn-ghost btn-sm mono",
+                                                        title: t!("services.show-process-hint"),
+                                                        onclick: move |_| on_show_process(pid),
+                                                        "{pid}"
+                                                    }
+                                                },
+                                                None => rsx! { span { class: "muted", "-" } },
+                                            }
+                                        }
+                                        if details.exit_code != 0 {
+                                            dt { {t!("services.exit-code")} }
+                                            dd { class: "mono error",
+                                                "{details.exit_code}"
+                                                if details.service_exit_code != 0 {
+                                                    span { class: "muted", {format!(" {}", t!("services.service-exit-code", code = details.service_exit_code))} }
+                                                }
+                                            }
+                                        }
+                                        dt { {t!("services.recovery")} }
+                                        dd {
+                                            if let Some(error) = &current_state.failure_actions_error {
+                                                span { class: "muted", "{error}" }
+                                            }
+                                            if let Some(failure) = &current_state.failure_actions {
+                                                div { class: "failure-actions",
+                                                    if failure.actions.is_empty() {
+                                                        span { {t!("services.failure-none")} }
+                                                    }
+                                                    for (i, action) in failure.actions.iter().enumerate() {
+                                                        {
+                                                            let which = match i {
+                                                                0 => t!("services.failure-first"),
+                                                                1 => t!("services.failure-second"),
+                                                                _ => t!("services.failure-subsequent"),
+                                                            };
+                                                            let text = describe_failure_action(action, &failure.command);
+                                                            rsx! {
+                                                                span { "{which}: {text}" }
+                                                            }
+                                                        }
+                                                    }
+                                                    if !failure.actions.is_empty() {
+                                                        span { class: "muted", {t!("services.failure-reset", period = format_period(failure.reset_period_secs))} }
+                                                    }
+                                                }
+                                            }
+                                            div { class: "action-bar-group",
+                                                select {
+                                                    class: "input",
+                                                    disabled: current_state.is_running || !is_admin,
+                                                    onchange: move |e| {
+                                                        if let Some(preset) = e.value().parse::<usize>().ok().and_then(|i| FailurePreset::ALL.get(i)) {
+                                                            failure_preset.set(*preset);
+                                                        }
+                                                    },
+                                                    for (i, preset) in FailurePreset::ALL.into_iter().enumerate() {
+                                                        option {
+                                                            value: "{i}",
+                                                            selected: failure_preset() == preset,
+                                                            {failure_preset_label(preset)}
+                                                        }
+                                                    }
+                                                }
+                                                ActionButton {
+                                                    danger: DangerLevel::Modifying,
+                                                    class: "btn btn-secondary btn-sm",
+                                                    disabled: current_state.is_running || !is_admin,
+                                                    title: if !is_admin { t!("common.requires-admin") } else { String::new() },
+                                                    onclick: on_apply_failure_preset,
+                                                    if current_state.running_action == "Recovery" { {t!("common.loading")} } else { {t!("services.recovery-apply")} }
+                                                }
+                                            }
+                                        }
+                                        dt { {t!("services.binary-path")} }
+                                        dd { class: "mono",
+                                            "{details.binary_path}"
+                                            {
+                                                let executable = details.executable().to_string();
+                                                rsx! {
+                                                    button {
+                                                        class: "btn btn-ghost btn-sm",
+                                                        title: t!("services.open-location-hint"),
+                                                        onclick: move |_| {
+                                                            if let Err(e) = reveal_in_explorer(&executable) {
+                                                                state.write().status_message = e;
+                                                                state.write().status_type = "error".to_string();
+                                                            }
+                                                        },
+                                                        "📂"
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        dt { {t!("services.depends-on")} }
+                                        dd {
+                                            if details.depends_on.is_empty() {
+                                                span { class: "muted", "-" }
+                                            }
+                                            for dependency in details.depends_on.iter() {
+                                                {
+                                                    let name = dependency.clone();
+                                                    rsx! {
+                                                        button {
+                                                            class: "btn btn-ghost btn-sm mono",
+                                                            onclick: move |_| on_select_service(name.clone()),
+                                                            "{dependency}"
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        dt { {t!("services.dependents")} }
+                                        dd {
+                                            if details.dependents.is_empty() {
+                                                span { class: "muted", "-" }
+                                            }
+                                            for dependent in details.dependents.iter() {
+                                                {
+                                                    let name = dependent.clone();
+                                                    rsx! {
+                                                        button {
+                                                            class: "btn btn-ghost btn-sm mono",
+                                                            onclick: move |_| on_select_service(name.clone()),
+                                                            "{dependent}"
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // History section: what was done to which service, newest first
+                div { class: "section",
+                    div { class: "section-header",
+                        button {
+                            class: "btn btn-ghost section-toggle",
+                            onclick: move |_| {
+                                let show = !state().show_history;
+                                state.write().show_history = show;
+                            },
+                            if current_state.show_history { "▾ " } else { "▸ " }
+                            {t!("services.history", count = current_state.history.len())}
+                        }
+                        if current_state.show_history && !current_state.history.is_empty() {
+                            button {
+                                class: "btn btn-ghost btn-sm",
+                                title: t!("services.history-copy-hint"),
+                                onclick: move |_| {
+                                    let text = format_history_csv(&state().history);
+                                    let mut s = state.write();
+                                    match copy_to_clipboard(&text) {
+                                        Ok(()) => {
+                                            s.status_message = t!("services.history-copied", count = s.history.len());
+                                            s.status_type = "success".to_string();
+                                        }
+                                        Err(e) => {
+                                            s.status_message = e;
+                                            s.status_type = "error".to_string();
+                                        }
+                                    }
+                                },
+                                "📋 CSV"
+                            }
+                        }
+                    }
+
+                    if current_state.show_history {
+                        if current_state.history.is_empty() {
+                            p { class: "muted", {t!("services.history-empty")} }
+                        } else {
+                            div { class: "service-history",
+                                table { class: "data-table",
+                                    tbody {
+                                        for (i, entry) in current_state.history.iter().enumerate().rev() {
+                                            {
+                                                let rerun = ServiceAction::from_label(&entry.action);
+                                                let danger = if rerun == Some(ServiceAction::Stop) && services::is_critical_service(&entry.service) {
+                                                    DangerLevel::Destructive
+                                                } else {
+                                                    DangerLevel::Modifying
+                                                };
+                                                let service = entry.service.clone();
+                                                let time = entry.at.format("%H:%M:%S").to_string();
+                                                rsx! {
+                                                    tr { key: "{i}",
+                                                        td { class: "mono muted", title: "{entry.at}", "{time}" }
+                                                        td { class: "mono", "{entry.service}" }
+                                                        td { {history_action_text(&entry.action)} }
+                                                        td { class: if entry.ok { "success" } else { "error" }, "{entry.result}" }
+                                                        td {
+                                                            if let Some(action) = rerun {
+                                                                ActionButton {
+                                                                    danger,
+                                                                    class: "btn btn-ghost btn-sm",
+                                                                    disabled: current_state.is_running || !is_admin,
+                                                                    title: if !is_admin { t!("common.requires-admin") } else { t!("services.history-rerun-hint") },
+                                                                    onclick: move |_| run_service_action(state, app_settings, action, vec![service.clone()]),
+                                                                    "↻"
+                                                                }
+                                                            }
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // Advanced section: create and delete services
+                div { class: "section",
+                    div { class: "section-header",
+                        button {
+                            class: "btn btn-ghost section-toggle",
+                            onclick: move |_| {
+                                let show = !state().show_advanced;
+                                state.write().show_advanced = show;
+                            },
+                            if current_state.show_advanced { "▾ " } else { "▸ " }
+                            {t!("services.advanced")}
+                        }
+                    }
+
+                    if current_state.show_advanced {
+                        h4 { class: "subsection-title", {t!("services.create-title")} }
+                        div { class: "action-bar",
+                            div { class: "action-bar-group",
+                                label { {t!("services.create-name")} }
+                                input {
+                                    r#type: "text",
+                                    class: "input mono",
+                                    placeholder: "MyService",
+                                    value: "{current_state.new_service_name}",
+                                    oninput: move |e| state.write().new_service_name = e.value(),
+                                }
+                            }
+                            div { class: "action-bar-group",
+                                label { {t!("services.create-display-name")} }
+                                input {
+                                    r#type: "text",
+                                    class: "input",
+                                    placeholder: t!("services.create-display-name-placeholder"),
+                                    value: "{current_state.new_service_display_name}",
+                                    oninput: move |e| state.write().new_service_display_name = e.value(),
+                                }
+                            }
+                            div { class: "action-bar-group",
+                                label { {t!("services.start-type")} }
+                                select {
+                                    class: "input",
+                                    onchange: move |e| state.write().new_service_start_type = e.value(),
+                                    for start_type in services::START_TYPES {
+                                        option {
+                                            value: "{start_type}",
+                                            selected: current_state.new_service_start_type == *start_type,
+                                            "{start_type}"
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        div { class: "action-bar",
+                            div { class: "action-bar-group",
+                                label { {t!("services.create-bin-path")} }
+                                input {
+                                    r#type: "text",
+                                    class: "input url-input mono",
+                                    placeholder: r"C:\Program Files\MyApp\service.exe --service",
+                                    value: "{current_state.new_service_bin_path}",
+                                    oninput: move |e| state.write().new_service_bin_path = e.value(),
+                                }
+                                button {
+                                    class: "btn btn-ghost",
+                                    onclick: move |_| {
+                                        spawn(async move {
+                                            if let Some(path) = pick_open_file(
+                                                &t!("services.create-bin-pick"),
+                                                &t!("services.create-bin-filter"),
+                                            )
+                                            .await
+                                            {
+                                                state.write().new_service_bin_path = path;
+                                            }
+                                        });
+                                    },
+                                    {t!("services.browse")}
+                                }
+                            }
+                            ActionButton {
+                                danger: DangerLevel::Modifying,
+                                class: "btn btn-primary",
+                                disabled: current_state.is_running
+                                    || !is_admin
+                                    || current_state.new_service_name.trim().is_empty()
+                                    || current_state.new_service_bin_path.trim().is_empty(),
+                                title: if !is_admin { t!("common.requires-admin") } else { String::new() },
+                                onclick: on_create_service,
+                                if current_state.running_action == "Create" { {t!("services.create-running")} } else { {t!("services.create")} }
+                            }
+                        }
+
+                        h4 { class: "subsection-title", {t!("services.delete-title-section")} }
+                        div { class: "action-bar",
+                            if current_state.selected_service.is_empty() {
+                                span { class: "muted", {t!("services.select-hint")} }
+                            } else {
+                                span { class: "mono", {t!("services.selected", name = current_state.selected_service)} }
+                            }
+                            ActionButton {
+                                danger: DangerLevel::Destructive,
+                                class: "btn btn-danger",
+                                disabled: current_state.is_running || !is_admin || current_state.selected_service.is_empty(),
+                                title: if !is_admin { t!("common.requires-admin") } else { t!("services.delete-hint") },
+                                onclick: on_request_delete,
+                                if matches!(current_state.running_action.as_str(), "DeleteCheck" | "Delete") {
+                                    {t!("services.delete-running")}
+                                } else {
+                                    {t!("services.delete")}
+                                }
                             }
                         }
                     }
@@ -404,10 +2459,97 @@ pub fn ServicesPage(is_admin: bool) -> Element {
                 }
             }
 
+            if let Some((wait, host)) = kill_target() {
+                ConfirmDialog {
+                    title: t!("services.kill-host-title", name = wait.name),
+                    message: t!("services.kill-host-confirm", name = wait.name, pid = host.pid, process = host.image),
+                    confirm_label: t!("services.kill-host"),
+                    danger: true,
+                    on_confirm: {
+                        let wait = wait.clone();
+                        move |_| on_kill_stuck(wait.clone())
+                    },
+                    on_cancel: move |_| kill_target.set(None),
+                    if host.is_shared() {
+                        p { class: "status-bar warning",
+                            {t!(
+                                "services.kill-shared-host",
+                                process = host.image,
+                                count = host.services.len(),
+                                others = host.services.iter().filter(|name| !name.eq_ignore_ascii_case(&wait.name)).cloned().collect::<Vec<_>>().join(", ")
+                            )}
+                        }
+                    }
+                }
+            }
+
+            if let Some(plans) = restart_preview() {
+                ConfirmDialog {
+                    title: t!("services.restart-preview-title"),
+                    message: t!("services.restart-preview-message"),
+                    confirm_label: t!("services.restart"),
+                    on_confirm: {
+                        let targets: Vec<String> = plans.iter().map(|plan| plan.name.clone()).collect();
+                        move |_| {
+                            restart_preview.set(None);
+                            run_service_action(state, app_settings, ServiceAction::Restart, targets.clone());
+                        }
+                    },
+                    on_cancel: move |_| restart_preview.set(None),
+                    for plan in plans.iter() {
+                        div { class: "restart-plan",
+                            key: "{plan.name}",
+                            div { class: "mono", strong { "{plan.name}" } }
+                            if plan.dependents.is_empty() {
+                                div { class: "muted", {t!("services.restart-preview-alone")} }
+                            } else {
+                                div {
+                                    {t!("services.restart-preview-stop")}
+                                    span { class: "mono", {format!(" {} → {}", plan.dependents.join(" → "), plan.name)} }
+                                }
+                                div {
+                                    {t!("services.restart-preview-start")}
+                                    span { class: "mono",
+                                        {format!(" {} → {}", plan.name, plan.dependents.iter().rev().cloned().collect::<Vec<_>>().join(" → "))}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if let Some((name, bin_path)) = delete_target() {
+                ConfirmDialog {
+                    title: t!("services.delete-confirm-title", name = name),
+                    message: t!("services.delete-confirm", name = name, path = bin_path),
+                    confirm_label: t!("services.delete"),
+                    danger: true,
+                    confirm_disabled: delete_confirm_text() != name,
+                    on_confirm: {
+                        let name = name.clone();
+                        move |_| on_delete_service(name.clone())
+                    },
+                    on_cancel: move |_| delete_target.set(None),
+                    label { class: "confirm-type-label",
+                        {t!("services.delete-type-name", name = name)}
+                        input {
+                            r#type: "text",
+                            class: "input mono",
+                            autofocus: true,
+                            value: "{delete_confirm_text}",
+                            oninput: move |e| delete_confirm_text.set(e.value()),
+                        }
+                    }
+                }
+            }
+
             // Fixed output panel at bottom
             OutputPanel {
                 outputs: outputs_for_panel,
                 on_clear: move |_| on_clear(()),
+                is_admin,
+                on_rerun: move |output| state.write().command_outputs.push(output),
             }
         }
     }
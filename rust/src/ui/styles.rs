@@ -957,6 +957,1102 @@ body {
     font-size: 14px;
     line-height: 1.6;
 }
+
+/* ========================================
+   Safe Mode
+   ======================================== */
+
+.safe-mode-toggle {
+    display: flex;
+    align-items: center;
+    gap: var(--space-sm);
+    width: 100%;
+    padding: var(--space-sm) var(--space-md);
+    margin-bottom: var(--space-sm);
+    border-radius: var(--radius-md);
+    border: 1px solid var(--border-color);
+    font-size: 12px;
+    font-family: var(--font-sans);
+    cursor: pointer;
+    transition: all var(--transition-fast);
+}
+
+.safe-mode-toggle.on {
+    background: var(--accent-muted);
+    color: var(--accent-secondary);
+}
+
+.safe-mode-toggle.off {
+    background: var(--warning-muted);
+    color: var(--warning);
+    border-color: var(--warning);
+}
+
+.safe-mode-banner {
+    padding: var(--space-xs) var(--space-lg);
+    background: var(--warning-muted);
+    border-bottom: 1px solid var(--warning);
+    color: var(--warning);
+    font-size: 12px;
+}
+
+.safe-mode-locked {
+    cursor: not-allowed;
+}
+
+/* ========================================
+   Modal Dialog
+   ======================================== */
+
+.modal-overlay {
+    position: fixed;
+    top: 0;
+    left: 0;
+    right: 0;
+    bottom: 0;
+    background: rgba(0, 0, 0, 0.7);
+    backdrop-filter: blur(4px);
+    z-index: 1100;
+    display: flex;
+    align-items: center;
+    justify-content: center;
+    animation: fadeIn 0.15s ease-out;
+}
+
+.modal {
+    width: 100%;
+    max-width: 460px;
+    background: var(--bg-secondary);
+    border: 1px solid var(--border-color);
+    border-radius: var(--radius-lg);
+    box-shadow: var(--shadow-lg);
+    padding: var(--space-lg);
+    animation: slideUp 0.2s ease-out;
+}
+
+.modal-title {
+    font-size: 16px;
+    font-weight: 600;
+    margin-bottom: var(--space-md);
+}
+
+.modal-message {
+    font-size: 13px;
+    color: var(--text-secondary);
+    margin-bottom: var(--space-lg);
+    white-space: pre-wrap;
+}
+
+.modal-actions {
+    display: flex;
+    justify-content: flex-end;
+    gap: var(--space-sm);
+}
+
+/* ========================================
+   Processes - Commit Pressure
+   ======================================== */
+
+.commit-pressure-banner {
+    margin-top: var(--space-md);
+    padding: var(--space-md);
+    border: 1px solid var(--error);
+    border-radius: var(--radius-md);
+    background: var(--error-muted);
+    color: var(--text-primary);
+    font-size: 13px;
+    display: flex;
+    flex-direction: column;
+    gap: var(--space-sm);
+    align-items: flex-start;
+}
+
+.commit-top-list {
+    margin: 0;
+    padding-left: var(--space-lg);
+    color: var(--text-secondary);
+}
+
+/* ========================================
+   Sidebar - Reboot Status
+   ======================================== */
+
+.reboot-status {
+    display: block;
+    width: 100%;
+    padding: var(--space-xs) var(--space-md);
+    margin-bottom: var(--space-sm);
+    border-radius: var(--radius-md);
+    border: 1px solid transparent;
+    background: transparent;
+    font-size: 11px;
+    font-family: var(--font-sans);
+    text-align: left;
+    cursor: pointer;
+}
+
+.reboot-status.clear {
+    color: var(--text-muted);
+}
+
+.reboot-status.pending {
+    background: var(--warning-muted);
+    border-color: var(--warning);
+    color: var(--warning);
+}
+
+/* ========================================
+   Network - URL Health
+   ======================================== */
+
+.url-input {
+    flex: 1;
+    min-width: 240px;
+}
+
+.checkbox-label {
+    display: inline-flex;
+    align-items: center;
+    gap: var(--space-xs);
+    font-size: 13px;
+    color: var(--text-secondary);
+    cursor: pointer;
+}
+
+.url-badge {
+    display: inline-block;
+    padding: 2px 8px;
+    border-radius: var(--radius-sm);
+    font-size: 11px;
+    font-weight: 600;
+}
+
+.url-badge.up {
+    background: var(--success-muted);
+    color: var(--success);
+}
+
+.url-badge.down {
+    background: var(--error-muted);
+    color: var(--error);
+}
+
+.url-badge.unknown {
+    background: var(--bg-tertiary);
+    color: var(--text-muted);
+}
+
+.url-row.clickable {
+    cursor: pointer;
+}
+
+.url-error-row td {
+    color: var(--error);
+    font-size: 12px;
+    white-space: pre-wrap;
+    word-break: break-all;
+}
+
+/* ========================================
+   Network - Packet Capture
+   ======================================== */
+
+.capture-indicator {
+    display: flex;
+    align-items: center;
+    gap: var(--space-sm);
+    margin-top: var(--space-sm);
+    padding: var(--space-sm) var(--space-md);
+    border: 1px solid var(--error);
+    border-radius: var(--radius-md);
+    background: var(--error-muted);
+    color: var(--error);
+    font-size: 13px;
+    font-weight: 600;
+}
+
+.capture-dot {
+    width: 10px;
+    height: 10px;
+    border-radius: 50%;
+    background: var(--error);
+    animation: capture-pulse 1.2s ease-in-out infinite;
+}
+
+@keyframes capture-pulse {
+    0%, 100% { opacity: 1; }
+    50% { opacity: 0.3; }
+}
+
+/* ========================================
+   Restore Point Option
+   ======================================== */
+
+.restore-point-option {
+    display: flex;
+    flex-direction: column;
+    gap: var(--space-xs);
+    margin-bottom: var(--space-md);
+    font-size: 12px;
+}
+
+/* ========================================
+   Disk - System Integrity
+   ======================================== */
+
+.integrity-progress {
+    display: flex;
+    flex-direction: column;
+    gap: var(--space-xs);
+    margin-top: var(--space-sm);
+    font-size: 12px;
+}
+
+/* ========================================
+   Processes - Fast Startup
+   ======================================== */
+
+.fast-startup-hint {
+    margin-top: var(--space-md);
+    padding: var(--space-md);
+    border: 1px solid var(--border-color);
+    border-radius: var(--radius-md);
+    background: var(--bg-tertiary);
+    color: var(--text-secondary);
+    font-size: 13px;
+    display: flex;
+    flex-direction: column;
+    gap: var(--space-sm);
+    align-items: flex-start;
+}
+
+/* ========================================
+   Table Controller - Header & Export
+   ======================================== */
+
+.section-header {
+    display: flex;
+    align-items: center;
+    justify-content: space-between;
+    gap: var(--space-sm);
+}
+
+.table-export {
+    display: flex;
+    gap: var(--space-xs);
+}
+
+/* ========================================
+   Output Re-run
+   ======================================== */
+
+.output-entry + .output-entry {
+    border-top: 1px solid var(--border-color);
+    margin-top: var(--space-sm);
+    padding-top: var(--space-sm);
+}
+
+.output-entry-header {
+    display: flex;
+    justify-content: flex-end;
+}
+
+/* ========================================
+   Language Selector
+   ======================================== */
+
+.language-select {
+    display: flex;
+    align-items: center;
+    justify-content: space-between;
+    gap: var(--space-sm);
+    font-size: 12px;
+}
+
+.language-select .input {
+    padding: 2px var(--space-sm);
+    font-size: 12px;
+}
+
+/* ========================================
+   Split Buttons
+   ======================================== */
+
+.btn-split {
+    display: inline-flex;
+    gap: 1px;
+}
+
+.btn-split .btn:first-child {
+    border-top-right-radius: 0;
+    border-bottom-right-radius: 0;
+}
+
+.btn-split .btn:last-child {
+    border-top-left-radius: 0;
+    border-bottom-left-radius: 0;
+}
+
+/* ========================================
+   Ports - Watch Mode
+   ======================================== */
+
+.watch-indicator {
+    display: flex;
+    align-items: center;
+    gap: var(--space-sm);
+    margin-bottom: var(--space-md);
+    padding: var(--space-sm) var(--space-md);
+    border: 1px solid var(--accent-primary);
+    border-radius: var(--radius-md);
+    background: var(--accent-muted);
+    font-size: 13px;
+    font-weight: 600;
+}
+
+.watch-dot {
+    width: 10px;
+    height: 10px;
+    border-radius: 50%;
+    background: var(--accent-primary);
+    animation: capture-pulse 1.2s ease-in-out infinite;
+}
+
+/* ========================================
+   Ports - Row Actions
+   ======================================== */
+
+.row-actions {
+    display: inline-flex;
+    align-items: center;
+    gap: var(--space-xs);
+}
+
+/* ========================================
+   Ports - Service Hints
+   ======================================== */
+
+.port-service {
+    margin-left: var(--space-sm);
+    font-family: var(--font-sans);
+    font-size: 12px;
+}
+
+/* ========================================
+   Ports - Presets
+   ======================================== */
+
+.preset-ports {
+    display: flex;
+    flex-wrap: wrap;
+    gap: var(--space-sm);
+}
+
+.preset-port {
+    display: inline-flex;
+    align-items: center;
+    gap: var(--space-xs);
+    padding: var(--space-xs) var(--space-sm);
+    background: var(--bg-tertiary);
+    border: 1px solid var(--border-color);
+    border-radius: var(--radius-md);
+    font-size: 13px;
+}
+
+/* ========================================
+   Scan History Diff
+   ======================================== */
+
+.data-table .diff-added td {
+    background: var(--success-muted);
+}
+
+.data-table .diff-removed td {
+    background: var(--error-muted);
+    text-decoration: line-through;
+    opacity: 0.7;
+}
+
+/* ========================================
+   Ports Process Groups
+   ======================================== */
+
+.data-table .process-group td {
+    font-weight: 500;
+}
+
+.data-table .group-member td:first-child {
+    padding-left: var(--space-lg);
+}
+
+.group-toggle {
+    font-family: var(--font-mono);
+}
+
+/* ========================================
+   Network - Adapters
+   ======================================== */
+
+.adapter-grid {
+    display: grid;
+    grid-template-columns: repeat(auto-fill, minmax(280px, 1fr));
+    gap: var(--space-md);
+}
+
+.adapter-card {
+    padding: var(--space-md);
+}
+
+.adapter-card-header {
+    display: flex;
+    align-items: center;
+    justify-content: space-between;
+    gap: var(--space-sm);
+}
+
+.adapter-card-header .card-title {
+    margin-bottom: 0;
+}
+
+.adapter-card .muted {
+    font-size: 12px;
+    margin: var(--space-xs) 0 var(--space-sm);
+}
+
+.adapter-down {
+    opacity: 0.55;
+}
+
+.adapter-details {
+    display: grid;
+    grid-template-columns: auto 1fr;
+    gap: var(--space-xs) var(--space-md);
+    margin: 0;
+    font-size: 12px;
+}
+
+.adapter-card-actions {
+    display: flex;
+    justify-content: flex-end;
+    margin-top: var(--space-sm);
+}
+
+.adapter-details dt {
+    color: var(--text-secondary);
+}
+
+.adapter-details dd {
+    margin: 0;
+    word-break: break-all;
+}
+
+.badge-success {
+    background: var(--success-muted);
+    color: var(--success);
+    border: 1px solid var(--success);
+}
+
+.badge-muted {
+    background: var(--bg-tertiary);
+    color: var(--text-muted);
+    border: 1px solid var(--border-color);
+}
+
+/* ========================================
+   Output Line Highlights
+   ======================================== */
+
+.output-line-error {
+    color: var(--error);
+}
+
+.output-line-warning {
+    color: var(--warning);
+}
+
+/* ========================================
+   Routing Table
+   ======================================== */
+
+.data-table tr.route-default td {
+    background: var(--success-muted);
+    font-weight: 600;
+}
+
+/* ========================================
+   DNS Cache
+   ======================================== */
+
+.data-table tr.dns-hosts td {
+    background: var(--warning-muted);
+}
+
+/* ========================================
+   Certificate Expiry
+   ======================================== */
+
+.cert-expiry.expiring {
+    color: var(--warning);
+    font-weight: 600;
+}
+
+.cert-expiry.expired {
+    color: var(--error);
+    font-weight: 600;
+}
+
+/* ========================================
+   Latency Monitor
+   ======================================== */
+
+.latency-strip {
+    display: flex;
+    align-items: flex-end;
+    gap: 1px;
+    height: 48px;
+    padding: var(--space-xs);
+    margin-bottom: var(--space-sm);
+    background: var(--bg-tertiary);
+    border-radius: var(--radius-sm);
+}
+
+.latency-bar {
+    flex: 1;
+    min-height: 2px;
+    max-width: 6px;
+    background: var(--success);
+    border-radius: 1px 1px 0 0;
+}
+
+.latency-bar.loss {
+    height: 100%;
+    background: var(--error);
+    opacity: 0.8;
+}
+
+.latency-loss {
+    color: var(--error);
+    font-weight: 600;
+}
+
+/* ========================================
+   Diagnostic Checklist
+   ======================================== */
+
+.checklist {
+    list-style: none;
+    margin: 0;
+    padding: 0;
+    display: flex;
+    flex-direction: column;
+    gap: var(--space-sm);
+}
+
+.checklist-item {
+    display: flex;
+    align-items: flex-start;
+    gap: var(--space-sm);
+}
+
+.checklist-item .muted {
+    font-size: 12px;
+}
+
+.checklist-mark {
+    width: 1.2em;
+    font-weight: 700;
+    text-align: center;
+}
+
+.checklist-item.passed .checklist-mark {
+    color: var(--success);
+}
+
+.checklist-item.failed .checklist-mark {
+    color: var(--error);
+}
+
+/* ========================================
+   Adapter Statistics
+   ======================================== */
+
+.adapter-errors {
+    color: var(--error);
+    font-weight: 600;
+}
+
+/* ========================================
+   Connectivity Matrix
+   ======================================== */
+
+.connectivity-grid {
+    display: grid;
+    grid-template-columns: repeat(2, minmax(0, 1fr));
+    gap: var(--space-sm);
+}
+
+.connectivity-cell {
+    display: flex;
+    flex-direction: column;
+    gap: var(--space-xs);
+    border-left: 3px solid var(--success);
+}
+
+.connectivity-cell.failed {
+    border-left-color: var(--error);
+    background: var(--error-muted);
+}
+
+.connectivity-cell-header {
+    display: flex;
+    align-items: center;
+    gap: var(--space-sm);
+}
+
+.connectivity-cell-header .mono {
+    margin-left: auto;
+}
+
+.connectivity-cell.passed .checklist-mark {
+    color: var(--success);
+}
+
+.connectivity-cell.failed .checklist-mark {
+    color: var(--error);
+}
+
+.connectivity-cell p {
+    margin: 0;
+    font-size: 12px;
+    word-break: break-word;
+}
+
+/* ========================================
+   Docker Containers
+   ======================================== */
+
+.container-actions {
+    display: flex;
+    gap: var(--space-xs);
+    white-space: nowrap;
+}
+
+/* ========================================
+   Output Follow
+   ======================================== */
+
+/* Reversed column keeps the scroll position pinned to the bottom as lines are appended */
+.output-panel-fixed .output-panel-content.output-panel-follow {
+    display: flex;
+    flex-direction: column-reverse;
+}
+
+/* ========================================
+   Docker Stats
+   ======================================== */
+
+.data-table .warning {
+    color: var(--warning);
+    font-weight: 600;
+}
+
+/* ========================================
+   Container Details
+   ======================================== */
+
+.container-details-card {
+    margin-top: var(--space-md);
+    padding: var(--space-md);
+}
+
+.container-details-card .adapter-card-header {
+    margin: var(--space-sm) 0;
+}
+
+.container-details-card .card-title {
+    margin-bottom: 0;
+}
+
+.container-details-card .mono {
+    font-family: var(--font-mono);
+}
+
+.container-details-card .error {
+    color: var(--error);
+}
+
+.badge-error {
+    background: var(--error-muted);
+    color: var(--error);
+    border: 1px solid var(--error);
+}
+
+/* ========================================
+   Port Conflicts
+   ======================================== */
+
+.port-conflict-list {
+    display: flex;
+    flex-direction: column;
+    gap: var(--space-sm);
+}
+
+.port-conflict-card {
+    padding: var(--space-md);
+    border-color: var(--warning);
+    background: var(--warning-muted);
+}
+
+.port-conflict-card .card-title {
+    margin-bottom: 0;
+}
+
+.port-conflict-card .muted {
+    margin: var(--space-xs) 0 0;
+    font-size: 12px;
+}
+
+/* ========================================
+   Docker Engine Checklist
+   ======================================== */
+
+.engine-checklist {
+    list-style: none;
+    margin: 0 0 var(--space-md);
+    padding: 0;
+    display: flex;
+    flex-direction: column;
+    gap: var(--space-xs);
+}
+
+.engine-checklist li {
+    display: grid;
+    grid-template-columns: 24px 140px 1fr;
+    align-items: baseline;
+    font-size: 13px;
+}
+
+.engine-check-label {
+    font-weight: 500;
+}
+
+/* ========================================
+   System Prune Preview
+   ======================================== */
+
+.prune-preview {
+    margin-bottom: var(--space-md);
+    font-size: 13px;
+}
+
+.prune-preview .warning {
+    color: var(--warning);
+}
+
+/* ========================================
+   Container Health
+   ======================================== */
+
+.data-table tr.error td {
+    background: var(--error-muted);
+}
+
+.health-probe {
+    border-top: 1px solid var(--border-color);
+    padding: var(--space-sm) 0;
+}
+
+.health-probe-header {
+    display: flex;
+    align-items: center;
+    gap: var(--space-sm);
+    font-size: 12px;
+}
+
+.health-probe pre {
+    margin: var(--space-xs) 0 0;
+    font-size: 12px;
+    white-space: pre-wrap;
+    word-break: break-all;
+}
+
+/* ========================================
+   Compose Validation
+   ======================================== */
+
+.compose-check {
+    margin-bottom: var(--space-sm);
+    white-space: pre-wrap;
+    word-break: break-word;
+}
+
+/* ========================================
+   Registry Logins
+   ======================================== */
+
+.registry-logins {
+    display: flex;
+    flex-wrap: wrap;
+    align-items: center;
+    gap: 6px;
+    margin-top: 8px;
+    font-size: 12px;
+}
+
+.registry-login-form {
+    margin-top: 8px;
+}
+
+/* ========================================
+   Capture on Exit
+   ======================================== */
+
+.exit-watch {
+    display: flex;
+    align-items: center;
+    gap: 8px;
+    margin-top: 6px;
+    font-size: 12px;
+}
+
+/* ========================================
+   Service Details
+   ======================================== */
+
+.service-details-card {
+    margin-top: 12px;
+}
+
+.service-details-card dd .btn-sm {
+    margin-right: 4px;
+}
+
+.service-details-raw {
+    max-height: 320px;
+    overflow: auto;
+    font-size: 12px;
+    white-space: pre-wrap;
+}
+
+/* ========================================
+   Status Chips
+   ======================================== */
+
+.status-chips {
+    display: flex;
+    flex-wrap: wrap;
+    gap: var(--space-xs);
+}
+
+.status-chip {
+    padding: 2px var(--space-sm);
+    border: 1px solid var(--border-color);
+    border-radius: 999px;
+    background: transparent;
+    color: var(--text-secondary);
+    font-size: 12px;
+    cursor: pointer;
+}
+
+.status-chip:hover {
+    border-color: var(--accent-primary);
+    color: var(--text-primary);
+}
+
+.status-chip.active {
+    background: var(--accent-muted);
+    border-color: var(--accent-primary);
+    color: var(--text-primary);
+}
+
+/* ========================================
+   Services: find by port
+   ======================================== */
+
+.port-owners {
+    display: flex;
+    flex-direction: column;
+    gap: var(--space-xs);
+    margin-top: var(--space-sm);
+}
+
+.port-owner {
+    display: flex;
+    align-items: center;
+    flex-wrap: wrap;
+    gap: var(--space-sm);
+}
+
+/* ========================================
+   Services: favorites
+   ======================================== */
+
+.favorite-strip {
+    display: flex;
+    flex-wrap: wrap;
+    gap: var(--space-sm);
+}
+
+.favorite-card {
+    display: flex;
+    flex-direction: column;
+    gap: var(--space-xs);
+    min-width: 180px;
+    padding: var(--space-sm);
+}
+
+.favorite-card-header {
+    display: flex;
+    align-items: center;
+    justify-content: space-between;
+    gap: var(--space-sm);
+}
+
+.favorite-card-name {
+    cursor: pointer;
+    overflow: hidden;
+    text-overflow: ellipsis;
+    white-space: nowrap;
+}
+
+.favorite-star {
+    color: var(--text-secondary);
+}
+
+.favorite-star.active {
+    color: var(--accent-primary);
+}
+
+/* ========================================
+   Services: recovery actions
+   ======================================== */
+
+.failure-actions {
+    display: flex;
+    flex-direction: column;
+    gap: 2px;
+    margin-bottom: var(--space-xs);
+}
+
+/* ========================================
+   Services: auto-refresh changes
+   ======================================== */
+
+@keyframes row-changed-flash {
+    from { background: var(--warning-muted); }
+}
+
+@keyframes row-running-flash {
+    from { background: var(--success-muted); }
+}
+
+.data-table tr.changed td {
+    animation: row-changed-flash 2s ease-out;
+}
+
+.data-table tr.changed td:first-child {
+    box-shadow: inset 3px 0 0 var(--warning);
+}
+
+.data-table tr.changed-running td {
+    animation-name: row-running-flash;
+}
+
+.data-table tr.changed-running td:first-child {
+    box-shadow: inset 3px 0 0 var(--success);
+}
+
+/* ========================================
+   Services - Advanced (create / delete)
+   ======================================== */
+
+.section-toggle {
+    font-size: 14px;
+    font-weight: 600;
+    color: var(--text-secondary);
+    text-transform: uppercase;
+    letter-spacing: 0.05em;
+    padding-left: 0;
+}
+
+.subsection-title {
+    font-size: 13px;
+    font-weight: 600;
+    color: var(--text-secondary);
+    margin: var(--space-md) 0 var(--space-sm);
+}
+
+.confirm-type-label {
+    display: flex;
+    flex-direction: column;
+    gap: var(--space-xs);
+    font-size: 13px;
+    color: var(--text-secondary);
+    margin-top: var(--space-sm);
+}
+
+/* ========================================
+   Services - Restart preview
+   ======================================== */
+
+.restart-plan {
+    display: flex;
+    flex-direction: column;
+    gap: var(--space-xs);
+    font-size: 13px;
+    padding: var(--space-sm) 0;
+    border-bottom: 1px solid var(--border-color);
+}
+
+.restart-plan:last-of-type {
+    border-bottom: none;
+}
+
+/* ========================================
+   Services - Automatic but not running audit
+   ======================================== */
+
+.auto-audit-card {
+    margin-top: var(--space-md);
+}
+
+.auto-audit-card .audit-note {
+    margin-top: var(--space-sm);
+    font-size: 12px;
+}
+
+/* ========================================
+   Windowed tables
+   ======================================== */
+
+.virtual-rows td {
+    height: 36px;
+    padding-top: 0;
+    padding-bottom: 0;
+    white-space: nowrap;
+}
+
+.virtual-rows .virtual-spacer td,
+.virtual-rows .virtual-spacer:hover td {
+    padding: 0;
+    border: none;
+    background: transparent;
+}
+
+/* ========================================
+   Services - Action history
+   ======================================== */
+
+.service-history {
+    max-height: 240px;
+    overflow-y: auto;
+    border-radius: var(--radius-lg);
+}
+
+.service-history td {
+    padding-top: var(--space-xs);
+    padding-bottom: var(--space-xs);
+}
 "#;
 
 